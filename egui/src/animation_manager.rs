@@ -1,10 +1,20 @@
-use epaint::ahash::AHashMap;
+use epaint::ahash::{AHashMap, AHashSet};
 
 use crate::{emath::remap_clamp, Id, InputState};
 
+/// Keeps track of all animations.
+///
+/// All animations are based on [`InputState::time`], not on the number of frames,
+/// so an animation will take the same amount of time regardless of the frame rate.
 #[derive(Clone, Default)]
 pub(crate) struct AnimationManager {
     bools: AHashMap<Id, BoolAnim>,
+
+    /// Animations that were in progress at some point during the previous frame.
+    active_last_frame: AHashSet<Id>,
+
+    /// Animations that have been in progress so far this frame.
+    active_this_frame: AHashSet<Id>,
 }
 
 #[derive(Clone, Debug)]
@@ -15,6 +25,20 @@ struct BoolAnim {
 }
 
 impl AnimationManager {
+    pub fn begin_frame(&mut self) {
+        self.active_last_frame = std::mem::take(&mut self.active_this_frame);
+    }
+
+    /// Is any animation in progress this frame (or was one in progress last frame)?
+    pub fn has_active_animations(&self) -> bool {
+        !self.active_this_frame.is_empty() || !self.active_last_frame.is_empty()
+    }
+
+    /// Was any animation in progress during this frame?
+    pub fn any_active_this_frame(&self) -> bool {
+        !self.active_this_frame.is_empty()
+    }
+
     /// See `Context::animate_bool` for documentation
     pub fn animate_bool(
         &mut self,
@@ -23,7 +47,7 @@ impl AnimationManager {
         id: Id,
         value: bool,
     ) -> f32 {
-        match self.bools.get_mut(&id) {
+        let animated_value = match self.bools.get_mut(&id) {
             None => {
                 self.bools.insert(
                     id,
@@ -40,8 +64,12 @@ impl AnimationManager {
             }
             Some(anim) => {
                 if anim.value != value {
+                    // If we are toggled in the middle of an animation we want to
+                    // continue from where we are, not jump to the other end:
+                    let time_since_toggle = (input.time - anim.toggle_time) as f32;
+                    let remaining = (animation_time - time_since_toggle).max(0.0);
                     anim.value = value;
-                    anim.toggle_time = input.time;
+                    anim.toggle_time = input.time - remaining as f64;
                 }
 
                 let time_since_toggle = (input.time - anim.toggle_time) as f32;
@@ -56,6 +84,13 @@ impl AnimationManager {
                     remap_clamp(time_since_toggle, 0.0..=animation_time, 1.0..=0.0)
                 }
             }
+        };
+
+        let animation_in_progress = 0.0 < animated_value && animated_value < 1.0;
+        if animation_in_progress {
+            self.active_this_frame.insert(id);
         }
+
+        animated_value
     }
 }
@@ -50,8 +50,11 @@ pub struct Area {
     order: Order,
     default_pos: Option<Pos2>,
     anchor: Option<(Align2, Vec2)>,
+    widget_anchor: Option<WidgetAnchor>,
     new_pos: Option<Pos2>,
     drag_bounds: Option<Rect>,
+    constrain: bool,
+    scale: f32,
 }
 
 impl Area {
@@ -65,7 +68,10 @@ impl Area {
             default_pos: None,
             new_pos: None,
             anchor: None,
+            widget_anchor: None,
             drag_bounds: None,
+            constrain: false,
+            scale: 1.0,
         }
     }
 
@@ -142,6 +148,9 @@ impl Area {
     /// The offset is added to the position, so e.g. an offset of `[-5.0, 5.0]`
     /// would move the window left and down from the given anchor.
     ///
+    /// The anchor is applied every frame, overriding any stored position,
+    /// so the area stays in place when the screen is resized.
+    ///
     /// Anchoring also makes the window immovable.
     ///
     /// It is an error to set both an anchor and a position.
@@ -150,14 +159,78 @@ impl Area {
         self.movable(false)
     }
 
+    /// Keep the area next to another widget, following it as it moves or scrolls.
+    ///
+    /// The `pivot` point of the area is put at the `widget_point` of the widget's rect, plus `offset`.
+    /// For instance, `Align2::LEFT_TOP` and `Align2::RIGHT_TOP` puts the area to the right of the widget,
+    /// aligned with its top.
+    ///
+    /// The widget is looked up by its [`Response::id`], using its rect from this frame
+    /// if it has already been shown, else from last frame.
+    /// If the widget isn't shown, the area stays where it was.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// let response = ui.button("Save");
+    /// egui::Area::new("save_hint")
+    ///     .anchor_to_widget(response.id, egui::Align2::LEFT_TOP, egui::Align2::RIGHT_TOP, [4.0, 0.0])
+    ///     .show(ui.ctx(), |ui| {
+    ///         ui.label("Unsaved changes!");
+    ///     });
+    /// ```
+    ///
+    /// This also makes the area immovable, and overrides [`Self::anchor`].
+    pub fn anchor_to_widget(
+        mut self,
+        widget_id: Id,
+        pivot: Align2,
+        widget_point: Align2,
+        offset: impl Into<Vec2>,
+    ) -> Self {
+        self.widget_anchor = Some(WidgetAnchor {
+            widget_id,
+            pivot,
+            widget_point,
+            offset: offset.into(),
+        });
+        self.movable(false)
+    }
+
     /// Constrain the area up to which the window can be dragged.
     pub fn drag_bounds(mut self, bounds: Rect) -> Self {
         self.drag_bounds = Some(bounds);
         self
     }
 
+    /// Keep the area fully on screen (within [`crate::InputState::screen_rect`]) every frame,
+    /// also when the screen is resized. Default: `false`.
+    ///
+    /// If the area is too large to fit, its top-left corner is kept on screen.
+    ///
+    /// Without this, an area is only kept within the central area when shown,
+    /// and an area larger than that can be dragged partially off screen.
+    /// See also [`crate::Context::move_window_onto_screen`].
+    pub fn constrain(mut self, constrain: bool) -> Self {
+        self.constrain = constrain;
+        self
+    }
+
+    /// Render the area at a different scale than the rest of the ui, e.g. `2.0` for a magnified view.
+    /// Default: `1.0`. Scales below `0.01` are clamped to it.
+    ///
+    /// The contents are laid out as usual and then scaled around the top-left corner of the area,
+    /// so text is not re-rasterized and will look blurry when magnified.
+    /// Hovering and clicking works as expected, and [`Response::interact_pointer_pos`] is in the coordinates of the area,
+    /// but pointer deltas (e.g. when dragging a [`DragValue`]) are not scaled.
+    pub fn scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
     pub(crate) fn get_pivot(&self) -> Align2 {
-        if let Some((pivot, _)) = self.anchor {
+        if let Some(widget_anchor) = self.widget_anchor {
+            widget_anchor.pivot
+        } else if let Some((pivot, _)) = self.anchor {
             pivot
         } else {
             Align2::LEFT_TOP
@@ -165,12 +238,24 @@ impl Area {
     }
 }
 
+/// See [`Area::anchor_to_widget`].
+#[derive(Clone, Copy, Debug)]
+struct WidgetAnchor {
+    widget_id: Id,
+    pivot: Align2,
+    widget_point: Align2,
+    offset: Vec2,
+}
+
 pub(crate) struct Prepared {
     layer_id: LayerId,
     state: State,
+    anchored: bool,
     movable: bool,
     enabled: bool,
     drag_bounds: Option<Rect>,
+    constrain: bool,
+    scale: f32,
 }
 
 impl Area {
@@ -184,7 +269,10 @@ impl Area {
             default_pos,
             new_pos,
             anchor,
+            widget_anchor,
             drag_bounds,
+            constrain,
+            scale,
         } = self;
 
         let layer_id = LayerId::new(order, id);
@@ -202,10 +290,31 @@ impl Area {
             if is_new {
                 // unknown size
                 ctx.request_repaint()
-            } else {
-                let screen = ctx.available_rect();
-                state.pos = anchor.align_size_within_rect(state.size, screen).min + offset;
             }
+            // Always override the stored position, so we follow the screen as it is resized:
+            let screen = ctx.available_rect();
+            state.pos = anchor.align_size_within_rect(state.size, screen).min + offset;
+        }
+
+        if let Some(widget_anchor) = widget_anchor {
+            let widget_rect = ctx
+                .frame_state()
+                .tracked_widget_rect(widget_anchor.widget_id);
+            if is_new {
+                // unknown size
+                ctx.request_repaint()
+            }
+            if let Some(widget_rect) = widget_rect {
+                let point = widget_anchor.widget_point.pos_in_rect(&widget_rect);
+                let rect = widget_anchor
+                    .pivot
+                    .anchor_rect(Rect::from_min_size(point, state.size));
+                state.pos = rect.min + widget_anchor.offset;
+            }
+        }
+
+        if constrain {
+            state.pos = ctx.constrain_window_rect_to_screen(state.rect()).min;
         }
 
         state.pos = ctx.round_pos_to_pixels(state.pos);
@@ -213,9 +322,12 @@ impl Area {
         Prepared {
             layer_id,
             state,
+            anchored: anchor.is_some() || widget_anchor.is_some(),
             movable,
             enabled,
             drag_bounds,
+            constrain,
+            scale,
         }
     }
 
@@ -269,6 +381,11 @@ impl Prepared {
     }
 
     pub(crate) fn content_ui(&self, ctx: &CtxRef) -> Ui {
+        // Now that the position is final for this frame:
+        ctx.memory()
+            .areas
+            .set_layer_scale(self.layer_id, self.state.pos, self.scale);
+
         let max_rect = Rect::from_min_size(self.state.pos, Vec2::INFINITY);
         let shadow_radius = ctx.style().visuals.window_shadow.extrusion; // hacky
         let bounds = self.drag_bounds.unwrap_or_else(|| ctx.input().screen_rect);
@@ -304,12 +421,20 @@ impl Prepared {
         let Prepared {
             layer_id,
             mut state,
+            anchored,
             movable,
             enabled,
             drag_bounds,
+            constrain,
+            scale: _,
         } = self;
 
-        state.size = content_ui.min_rect().size();
+        let new_size = content_ui.min_rect().size();
+        if anchored && new_size != state.size {
+            // The anchored position depends on the size, so place us correctly next frame:
+            ctx.request_repaint();
+        }
+        state.size = new_size;
 
         let interact_id = layer_id.id.with("move");
         let sense = if movable {
@@ -329,7 +454,7 @@ impl Prepared {
         );
 
         if move_response.dragged() && movable {
-            state.pos += ctx.input().pointer.delta();
+            state.pos += move_response.drag_delta();
         }
 
         if let Some(bounds) = drag_bounds {
@@ -337,6 +462,9 @@ impl Prepared {
         } else {
             state.pos = ctx.constrain_window_rect(state.rect()).min;
         }
+        if constrain {
+            state.pos = ctx.constrain_window_rect_to_screen(state.rect()).min;
+        }
 
         if (move_response.dragged() || move_response.clicked())
             || pointer_pressed_on_area(ctx, layer_id)
@@ -149,8 +149,8 @@ impl CollapsingHeader {
     /// If the label is unique and static this is fine,
     /// but if it changes or there are several `CollapsingHeader` with the same title
     /// you need to provide a unique id source with [`Self::id_source`].
-    pub fn new(label: impl ToString) -> Self {
-        let label = Label::new(label).wrap(false);
+    pub fn new(label: impl Into<WidgetText>) -> Self {
+        let label = Label::from(label.into()).wrap(false);
         let id_source = Id::new(label.text());
         Self {
             label,
@@ -268,7 +268,7 @@ impl CollapsingHeader {
             paint_icon(ui, openness, &icon_response);
         }
 
-        ui.painter().galley(text_pos, galley, text_color);
+        label.paint_galley_impl(ui, text_pos, galley, false, text_color);
 
         Prepared {
             id,
@@ -1,6 +1,8 @@
 use crate::{style::WidgetVisuals, *};
 use epaint::Shape;
 
+const MAX_COMBO_HEIGHT: f32 = 128.0;
+
 // TODO: this should be builder struct so we can set options like width.
 
 /// A drop-down selection menu with a descriptive label.
@@ -23,8 +25,10 @@ use epaint::Shape;
 pub struct ComboBox {
     id_source: Id,
     label: Option<Label>,
-    selected_text: String,
+    selected_text: WidgetText,
     width: Option<f32>,
+    width_to_content: bool,
+    searchable: bool,
 }
 
 impl ComboBox {
@@ -34,8 +38,10 @@ impl ComboBox {
         Self {
             id_source: Id::new(label.text()),
             label: Some(label),
-            selected_text: Default::default(),
+            selected_text: "".into(),
             width: None,
+            width_to_content: false,
+            searchable: false,
         }
     }
 
@@ -44,8 +50,10 @@ impl ComboBox {
         Self {
             id_source: Id::new(id_source),
             label: Default::default(),
-            selected_text: Default::default(),
+            selected_text: "".into(),
             width: None,
+            width_to_content: false,
+            searchable: false,
         }
     }
 
@@ -55,20 +63,47 @@ impl ComboBox {
         self
     }
 
+    /// Make the button and menu wide enough for the widest item, so the width doesn't change with the selection.
+    ///
+    /// Any [`Self::width`] is then the minimum width.
+    /// Only [`Self::show_index`] and [`Self::show_multi`] know their items;
+    /// [`Self::show_ui`] just fits the selected text.
+    pub fn width_to_content(mut self) -> Self {
+        self.width_to_content = true;
+        self
+    }
+
+    /// Show a text field at the top of the list, for finding items by typing part of their name.
+    ///
+    /// Up and down arrow keys move the highlight through the matching items, and Enter selects the highlighted one.
+    /// Only used by [`Self::show_index`], since the items of [`Self::show_ui`] can't be filtered.
+    /// Default: `false`.
+    pub fn searchable(mut self, searchable: bool) -> Self {
+        self.searchable = searchable;
+        self
+    }
+
     /// What we show as the currently selected value
-    #[allow(clippy::needless_pass_by_value)]
-    pub fn selected_text(mut self, selected_text: impl ToString) -> Self {
-        self.selected_text = selected_text.to_string();
+    pub fn selected_text(mut self, selected_text: impl Into<WidgetText>) -> Self {
+        self.selected_text = selected_text.into();
         self
     }
 
     /// Show the combo box, with the given ui code for the menu contents.
     pub fn show_ui(self, ui: &mut Ui, menu_contents: impl FnOnce(&mut Ui)) -> Response {
+        self.show_popup(ui, |ui| {
+            ScrollArea::from_max_height(MAX_COMBO_HEIGHT).show(ui, menu_contents);
+        })
+    }
+
+    fn show_popup(self, ui: &mut Ui, popup_contents: impl FnOnce(&mut Ui)) -> Response {
         let Self {
             id_source,
             label,
             selected_text,
             width,
+            width_to_content: _,
+            searchable: _,
         } = self;
 
         let button_id = ui.make_persistent_id(id_source);
@@ -77,7 +112,7 @@ impl ComboBox {
             if let Some(width) = width {
                 ui.spacing_mut().slider_width = width; // yes, this is ugly. Will remove later.
             }
-            let mut response = combo_box(ui, button_id, selected_text, menu_contents);
+            let mut response = combo_box(ui, button_id, selected_text, popup_contents);
             if let Some(label) = label {
                 response.widget_info(|| WidgetInfo::labeled(WidgetType::ComboBox, label.text()));
                 response |= ui.add(label);
@@ -91,6 +126,7 @@ impl ComboBox {
 
     /// Show a list of items with the given selected index.
     ///
+    /// With [`Self::searchable`] the list can be filtered.
     ///
     /// ```
     /// # #[derive(Debug, PartialEq)]
@@ -113,18 +149,106 @@ impl ComboBox {
         len: usize,
         get: impl Fn(usize) -> String,
     ) -> Response {
-        let slf = self.selected_text(get(*selected));
+        let slf = self
+            .selected_text(get(*selected))
+            .fit_to_items(ui, len, &get, 0.0);
 
         let mut changed = false;
 
-        let mut response = slf.show_ui(ui, |ui| {
-            for i in 0..len {
-                if ui.selectable_label(i == *selected, get(i)).clicked() {
-                    *selected = i;
-                    changed = true;
-                }
+        let mut response = if slf.searchable {
+            let popup_id = ui.make_persistent_id(slf.id_source).with("popup");
+            let search_id = popup_id.with("search");
+            let response = slf.show_popup(ui, |ui| {
+                changed = search_list(ui, search_id, selected, len, &get);
+            });
+            if !ui.memory().is_popup_open(popup_id) {
+                // Start over the next time the list is opened:
+                ui.memory().id_data_temp.remove(&search_id);
             }
-        });
+            response
+        } else {
+            slf.show_ui(ui, |ui| {
+                for i in 0..len {
+                    if ui.selectable_label(i == *selected, get(i)).clicked() {
+                        *selected = i;
+                        changed = true;
+                    }
+                }
+            })
+        };
+
+        if changed {
+            response.mark_changed();
+        }
+        response
+    }
+
+    /// With [`Self::width_to_content`], make room for the widest item (plus `extra_width`, e.g. for a checkbox).
+    fn fit_to_items(
+        mut self,
+        ui: &Ui,
+        len: usize,
+        get: &impl Fn(usize) -> String,
+        extra_width: f32,
+    ) -> Self {
+        if self.width_to_content {
+            let id = ui.make_persistent_id(self.id_source).with("content_width");
+            let spacing = ui.spacing();
+            let width = widest_item(ui, id, len, get)
+                + extra_width
+                + spacing.item_spacing.x
+                + spacing.icon_width;
+            self.width = Some(self.width.map_or(width, |min_width| min_width.max(width)));
+        }
+        self
+    }
+
+    /// Show a list of checkboxes, one for each item, for picking any number of them.
+    ///
+    /// The list stays open while the user toggles the checkboxes.
+    /// Unless [`Self::selected_text`] is set, the button shows a summary of what is selected, e.g. "3 selected".
+    ///
+    /// ```
+    /// # let mut ui = &mut egui::Ui::__test();
+    /// let fruits = ["Apple", "Banana", "Cherry"];
+    /// let mut selected = [true, false, true];
+    /// egui::ComboBox::from_label("Fruits").show_multi(ui, &mut selected, |i| fruits[i].to_owned());
+    /// ```
+    pub fn show_multi(
+        self,
+        ui: &mut Ui,
+        selected: &mut [bool],
+        get: impl Fn(usize) -> String,
+    ) -> Response {
+        let slf = if self.selected_text.text().is_empty() {
+            let num_selected = selected.iter().filter(|&&s| s).count();
+            let summary = match num_selected {
+                0 => ui.ctx().tr("None"),
+                1 => get(selected.iter().position(|&s| s).unwrap_or_default()),
+                n if n == selected.len() => ui.ctx().tr("All"),
+                n => format!("{} {}", n, ui.ctx().tr("selected")),
+            };
+            self.selected_text(summary)
+        } else {
+            self
+        };
+        let checkbox_width = ui.spacing().icon_width + ui.spacing().icon_spacing;
+        let slf = slf.fit_to_items(ui, selected.len(), &get, checkbox_width);
+
+        let mut changed = false;
+        let mut response = ui
+            .scope(|ui| {
+                ui.style_mut()
+                    .interaction
+                    .menu_close_policy
+                    .close_on_click_inside = false;
+                slf.show_ui(ui, |ui| {
+                    for (i, selected) in selected.iter_mut().enumerate() {
+                        changed |= ui.checkbox(selected, get(i)).changed();
+                    }
+                })
+            })
+            .inner;
 
         if changed {
             response.mark_changed();
@@ -152,14 +276,16 @@ impl ComboBox {
 pub fn combo_box_with_label(
     ui: &mut Ui,
     label: impl Into<Label>,
-    selected: impl ToString,
+    selected: impl Into<WidgetText>,
     menu_contents: impl FnOnce(&mut Ui),
 ) -> Response {
     let label = label.into();
     let button_id = ui.make_persistent_id(label.text());
 
     ui.horizontal(|ui| {
-        let mut response = combo_box(ui, button_id, selected, menu_contents);
+        let mut response = combo_box(ui, button_id, selected, |ui| {
+            ScrollArea::from_max_height(MAX_COMBO_HEIGHT).show(ui, menu_contents);
+        });
         response.widget_info(|| WidgetInfo::labeled(WidgetType::ComboBox, label.text()));
         response |= ui.add(label);
         response
@@ -167,13 +293,127 @@ pub fn combo_box_with_label(
     .inner
 }
 
-#[allow(clippy::needless_pass_by_value)]
+/// The width of the widest item, measured again only when the items change.
+fn widest_item(ui: &Ui, id: Id, len: usize, get: &impl Fn(usize) -> String) -> f32 {
+    let texts: Vec<String> = (0..len).map(get).collect();
+    let texts_id = Id::new(&texts);
+    if let Some(&(measured_for, width)) = ui.memory().id_data_temp.get::<(Id, f32)>(&id) {
+        if measured_for == texts_id {
+            return width;
+        }
+    }
+
+    let width = texts
+        .iter()
+        .map(|text| ui.fonts().layout_no_wrap(TextStyle::Button, text).size.x)
+        .fold(0.0, f32::max);
+    ui.memory().id_data_temp.insert(id, (texts_id, width));
+    width
+}
+
+/// The filter of a [`ComboBox::searchable`] list, while it is open.
+#[derive(Clone, Debug, Default)]
+struct SearchState {
+    filter: String,
+    /// Index into the matching items.
+    highlighted: usize,
+}
+
+/// The contents of a searchable popup. Returns `true` if an item was selected.
+fn search_list(
+    ui: &mut Ui,
+    search_id: Id,
+    selected: &mut usize,
+    len: usize,
+    get: &impl Fn(usize) -> String,
+) -> bool {
+    let state = ui
+        .memory()
+        .id_data_temp
+        .get::<SearchState>(&search_id)
+        .cloned();
+    let just_opened = state.is_none();
+    let mut state = state.unwrap_or_else(|| SearchState {
+        filter: String::new(),
+        highlighted: *selected,
+    });
+
+    let hint_text = ui.ctx().tr("Search…");
+    let search = ui.add(
+        TextEdit::singleline(&mut state.filter)
+            .id(search_id)
+            .hint_text(hint_text)
+            .desired_width(f32::INFINITY),
+    );
+    if just_opened {
+        search.request_focus();
+    }
+    if search.changed() {
+        state.highlighted = 0;
+    }
+
+    let filter = state.filter.to_lowercase();
+    let matches: Vec<usize> = (0..len)
+        .filter(|&i| get(i).to_lowercase().contains(&filter))
+        .collect();
+
+    let mut scroll_to_highlighted = just_opened;
+    if search.has_focus() {
+        if ui.input().key_pressed(Key::ArrowDown) {
+            state.highlighted += 1;
+            scroll_to_highlighted = true;
+        }
+        if ui.input().key_pressed(Key::ArrowUp) {
+            state.highlighted = state.highlighted.saturating_sub(1);
+            scroll_to_highlighted = true;
+        }
+    }
+    state.highlighted = state.highlighted.min(matches.len().saturating_sub(1));
+
+    let mut changed = false;
+    // The text field gives up focus when Enter is pressed:
+    if search.lost_focus() && ui.input().key_pressed(Key::Enter) {
+        if let Some(&i) = matches.get(state.highlighted) {
+            *selected = i;
+            changed = true;
+        }
+        ui.memory().close_popup();
+    }
+
+    ScrollArea::from_max_height(MAX_COMBO_HEIGHT).show(ui, |ui| {
+        for (nr, &i) in matches.iter().enumerate() {
+            let response = ui.selectable_label(i == *selected, get(i));
+            if nr == state.highlighted {
+                let visuals = ui.visuals();
+                let corner_radius = visuals.widgets.hovered.corner_radius;
+                ui.painter()
+                    .rect_stroke(response.rect, corner_radius, visuals.selection.stroke);
+                if scroll_to_highlighted {
+                    response.scroll_to_me(Align::Center);
+                }
+            }
+            if response.clicked() {
+                *selected = i;
+                changed = true;
+            }
+        }
+        if matches.is_empty() {
+            let text = ui.ctx().tr("No matches");
+            ui.add(Label::new(text).weak());
+        }
+    });
+
+    ui.memory().id_data_temp.insert(search_id, state);
+    changed
+}
+
 fn combo_box(
     ui: &mut Ui,
     button_id: Id,
-    selected: impl ToString,
-    menu_contents: impl FnOnce(&mut Ui),
+    selected: impl Into<WidgetText>,
+    popup_contents: impl FnOnce(&mut Ui),
 ) -> Response {
+    let selected = selected.into();
     let popup_id = button_id.with("popup");
 
     let button_active = ui.memory().is_popup_open(popup_id);
@@ -182,9 +422,7 @@ fn combo_box(
         let full_minimum_width = ui.spacing().slider_width;
         let icon_size = Vec2::splat(ui.spacing().icon_width);
 
-        let galley = ui
-            .fonts()
-            .layout_no_wrap(TextStyle::Button, selected.to_string());
+        let galley = selected.layout(ui, TextStyle::Button, None);
 
         let width = galley.size.x + ui.spacing().item_spacing.x + icon_size.x;
         let width = width.at_least(full_minimum_width);
@@ -200,17 +438,13 @@ fn combo_box(
         paint_icon(ui.painter(), icon_rect.expand(visuals.expansion), visuals);
 
         let text_rect = Align2::LEFT_CENTER.align_size_within_rect(galley.size, rect);
-        ui.painter()
-            .galley(text_rect.min, galley, visuals.text_color());
+        selected.paint_galley(ui, text_rect.min, galley, visuals.text_color());
     });
 
     if button_response.clicked() {
         ui.memory().toggle_popup(popup_id);
     }
-    const MAX_COMBO_HEIGHT: f32 = 128.0;
-    crate::popup::popup_below_widget(ui, popup_id, &button_response, |ui| {
-        ScrollArea::from_max_height(MAX_COMBO_HEIGHT).show(ui, menu_contents)
-    });
+    crate::popup::popup_below_widget(ui, popup_id, &button_response, popup_contents);
 
     button_response
 }
@@ -0,0 +1,150 @@
+//! Standard modal dialogs, e.g. for asking "Are you sure?".
+//!
+//! Keep showing the dialog every frame until it returns a result:
+//!
+//! ```
+//! # let mut ctx = egui::CtxRef::default();
+//! # ctx.begin_frame(Default::default());
+//! # let ctx = &ctx;
+//! # let mut confirm_delete = true;
+//! use egui::dialogs::{self, DialogResult};
+//!
+//! if confirm_delete {
+//!     if let Some(result) = dialogs::confirm("Delete file", "This cannot be undone.").show(ctx) {
+//!         confirm_delete = false;
+//!         if result == DialogResult::Ok {
+//!             // delete the file
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::*;
+
+/// Which button of a [`Dialog`] was pressed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DialogResult {
+    Ok,
+    Cancel,
+    Yes,
+    No,
+}
+
+impl DialogResult {
+    fn text(self) -> &'static str {
+        match self {
+            Self::Ok => "OK",
+            Self::Cancel => "Cancel",
+            Self::Yes => "Yes",
+            Self::No => "No",
+        }
+    }
+}
+
+/// The buttons at the bottom of a [`Dialog`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DialogButtons {
+    Ok,
+    OkCancel,
+    YesNo,
+}
+
+impl DialogButtons {
+    /// The button for accepting, and the one for declining (if any).
+    fn results(self) -> (DialogResult, Option<DialogResult>) {
+        match self {
+            Self::Ok => (DialogResult::Ok, None),
+            Self::OkCancel => (DialogResult::Ok, Some(DialogResult::Cancel)),
+            Self::YesNo => (DialogResult::Yes, Some(DialogResult::No)),
+        }
+    }
+}
+
+/// A modal [`Window`] in the middle of the screen with a message and some buttons.
+///
+/// Enter presses the focused button (the first one, unless the user tabs to another),
+/// and Escape declines (or just closes a dialog with only an OK button).
+///
+/// See also [`confirm`], [`question`] and [`message`].
+#[must_use = "You should call .show()"]
+#[derive(Clone, Debug)]
+pub struct Dialog {
+    title: String,
+    message: String,
+    buttons: DialogButtons,
+}
+
+impl Dialog {
+    /// The title is also used as the id of the window, so should be unique.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(title: impl ToString, message: impl ToString) -> Self {
+        Self {
+            title: title.to_string(),
+            message: message.to_string(),
+            buttons: DialogButtons::OkCancel,
+        }
+    }
+
+    /// Default: [`DialogButtons::OkCancel`].
+    pub fn buttons(mut self, buttons: DialogButtons) -> Self {
+        self.buttons = buttons;
+        self
+    }
+
+    /// Returns `Some` on the frame the user picks a button.
+    pub fn show(self, ctx: &CtxRef) -> Option<DialogResult> {
+        let Self {
+            title,
+            message,
+            buttons,
+        } = self;
+        let (accept, decline) = buttons.results();
+
+        let mut result = None;
+        Window::new(title)
+            .modal(true)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(Align2::CENTER_CENTER, Vec2::ZERO)
+            .show(ctx, |ui| {
+                ui.label(message);
+                ui.add_space(ui.spacing().item_spacing.y);
+                ui.horizontal(|ui| {
+                    let mut buttons = vec![];
+                    for choice in std::iter::once(accept).chain(decline) {
+                        let text = ui.ctx().tr(choice.text());
+                        buttons.push((choice, ui.button(text)));
+                    }
+
+                    if !buttons.iter().any(|(_, response)| response.has_focus()) {
+                        buttons[0].1.request_focus();
+                    }
+                    for (choice, response) in buttons {
+                        if response.clicked() {
+                            result = Some(choice);
+                        }
+                    }
+                });
+            });
+
+        if ctx.input().key_pressed(Key::Escape) {
+            result = Some(decline.unwrap_or(accept));
+        }
+        result
+    }
+}
+
+/// Ask the user to confirm something, with OK and Cancel buttons.
+pub fn confirm(title: impl ToString, message: impl ToString) -> Dialog {
+    Dialog::new(title, message).buttons(DialogButtons::OkCancel)
+}
+
+/// Ask the user a question, with Yes and No buttons.
+pub fn question(title: impl ToString, message: impl ToString) -> Dialog {
+    Dialog::new(title, message).buttons(DialogButtons::YesNo)
+}
+
+/// Tell the user something, with just an OK button.
+pub fn message(title: impl ToString, message: impl ToString) -> Dialog {
+    Dialog::new(title, message).buttons(DialogButtons::Ok)
+}
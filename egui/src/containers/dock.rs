@@ -0,0 +1,682 @@
+//! Docking: split an area into resizable regions with tabs, which the user can rearrange.
+//!
+//! The layout is a tree of [`DockNode`]s that you own (and can store, e.g. with the `persistence` feature).
+//! The user can drag a tab onto another tab group to move it there,
+//! or onto the edge of a tab group to split that group in two.
+//!
+//! Dropping a tab outside of the tab groups undocks it into a floating [`Window`].
+//! While such a window is dragged, drop targets appear in the middle of the tab group below the pointer
+//! and at the edges of the dock area. Dropping the window on one of them docks it again.
+//!
+//! Only the tabs of a [`DockArea`] can be docked. A [`Window`] that you show yourself is not part
+//! of any [`DockArea`], so to make its contents dockable, show them in a tab instead.
+//!
+//! ```
+//! # let ui = &mut egui::Ui::__test();
+//! use egui::containers::dock::{DockArea, DockLayout, DockNode};
+//!
+//! // Keep this around between frames:
+//! let mut layout = DockLayout::new(DockNode::horizontal(
+//!     DockNode::tabs(&["Files", "Search"]),
+//!     DockNode::tabs(&["Editor"]),
+//!     0.25,
+//! ));
+//!
+//! DockArea::new("my_dock_area").show_inside(ui, &mut layout, |ui, tab| {
+//!     ui.label(format!("Contents of {}", tab));
+//! });
+//! ```
+
+use std::hash::Hash;
+
+use crate::*;
+
+/// A node in the tree of a [`DockLayout`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+pub enum DockNode {
+    /// Two nodes next to each other (`horizontal`) or above each other.
+    Split {
+        horizontal: bool,
+        /// How much of the space goes to `first`, in `0..=1`.
+        fraction: f32,
+        first: Box<DockNode>,
+        second: Box<DockNode>,
+    },
+    /// A group of tabs, of which one is shown at a time.
+    Tabs {
+        tabs: Vec<String>,
+        /// Index into `tabs` of the tab being shown.
+        active: usize,
+    },
+}
+
+impl DockNode {
+    /// A group of tabs, with the first one active.
+    pub fn tabs(tabs: impl IntoIterator<Item = impl ToString>) -> Self {
+        Self::Tabs {
+            tabs: tabs.into_iter().map(|tab| tab.to_string()).collect(),
+            active: 0,
+        }
+    }
+
+    /// `left` and `right` next to each other, with `fraction` of the width going to `left`.
+    pub fn horizontal(left: DockNode, right: DockNode, fraction: f32) -> Self {
+        Self::Split {
+            horizontal: true,
+            fraction,
+            first: Box::new(left),
+            second: Box::new(right),
+        }
+    }
+
+    /// `top` above `bottom`, with `fraction` of the height going to `top`.
+    pub fn vertical(top: DockNode, bottom: DockNode, fraction: f32) -> Self {
+        Self::Split {
+            horizontal: false,
+            fraction,
+            first: Box::new(top),
+            second: Box::new(bottom),
+        }
+    }
+
+    fn empty() -> Self {
+        Self::Tabs {
+            tabs: vec![],
+            active: 0,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        matches!(self, Self::Tabs { tabs, .. } if tabs.is_empty())
+    }
+
+    /// `path` says which child to pick at each split: `false` for `first`, `true` for `second`.
+    fn node_mut(&mut self, path: &[bool]) -> &mut DockNode {
+        match (self, path.split_first()) {
+            (Self::Split { first, second, .. }, Some((&which, rest))) => {
+                if which {
+                    second.node_mut(rest)
+                } else {
+                    first.node_mut(rest)
+                }
+            }
+            (node, _) => node,
+        }
+    }
+}
+
+/// The layout of a [`DockArea`]: which tabs there are, and where they are.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+pub struct DockLayout {
+    pub root: DockNode,
+    /// Tabs that have been undocked, each shown in a [`Window`] of its own.
+    pub floating: Vec<String>,
+}
+
+impl DockLayout {
+    pub fn new(root: DockNode) -> Self {
+        Self {
+            root,
+            floating: vec![],
+        }
+    }
+
+    /// Remove the tab at `index` in the tab group at `path`.
+    ///
+    /// If that leaves the group empty, the group is removed and its sibling takes the place of their parent.
+    /// Returns the tab, and whether or not that happened.
+    fn remove_tab(&mut self, path: &[bool], index: usize) -> Option<(String, bool)> {
+        let tab = match self.root.node_mut(path) {
+            DockNode::Tabs { tabs, active } if index < tabs.len() => {
+                let tab = tabs.remove(index);
+                *active = (*active).min(tabs.len().saturating_sub(1));
+                tab
+            }
+            _ => return None,
+        };
+
+        let mut removed_group = false;
+        if let Some((&which, parent_path)) = path.split_last() {
+            let parent = self.root.node_mut(parent_path);
+            if let DockNode::Split { first, second, .. } = &mut *parent {
+                let (emptied, sibling) = if which {
+                    (second, first)
+                } else {
+                    (first, second)
+                };
+                if emptied.is_empty() {
+                    let sibling = std::mem::replace(&mut **sibling, DockNode::empty());
+                    *parent = sibling;
+                    removed_group = true;
+                }
+            }
+        }
+
+        Some((tab, removed_group))
+    }
+
+    fn insert_tab(&mut self, path: &[bool], zone: DropZone, tab: String) {
+        let node = self.root.node_mut(path);
+        if zone == DropZone::Center || node.is_empty() {
+            if let DockNode::Tabs { tabs, active } = &mut *node {
+                tabs.push(tab);
+                *active = tabs.len() - 1;
+                return;
+            }
+        }
+
+        let old = std::mem::replace(node, DockNode::empty());
+        let new = DockNode::tabs(std::iter::once(tab));
+        *node = match zone {
+            DropZone::Left | DropZone::Center => DockNode::horizontal(new, old, 0.5),
+            DropZone::Right => DockNode::horizontal(old, new, 0.5),
+            DropZone::Top => DockNode::vertical(new, old, 0.5),
+            DropZone::Bottom => DockNode::vertical(old, new, 0.5),
+        };
+    }
+
+    /// Move the tab at `index` of the group at `from` to the `zone` of the group at `to`.
+    fn move_tab(&mut self, from: &[bool], index: usize, to: &[bool], zone: DropZone) {
+        if from == to {
+            let tab_count = match self.root.node_mut(from) {
+                DockNode::Tabs { tabs, .. } => tabs.len(),
+                DockNode::Split { .. } => 0,
+            };
+            if zone == DropZone::Center || tab_count <= 1 {
+                return; // Nothing to do
+            }
+        }
+
+        let (tab, removed_group) = match self.remove_tab(from, index) {
+            Some(removed) => removed,
+            None => return,
+        };
+
+        let mut to = to.to_vec();
+        if removed_group {
+            // The sibling of the removed group moved up one level:
+            let (&which, parent_path) = from.split_last().unwrap();
+            let sibling_path: Vec<bool> = parent_path.iter().copied().chain(Some(!which)).collect();
+            if to.starts_with(&sibling_path) {
+                to.remove(parent_path.len());
+            }
+        }
+
+        self.insert_tab(&to, zone, tab);
+    }
+
+    /// Move the tab at `index` of the group at `path` to [`Self::floating`].
+    ///
+    /// Returns its index in [`Self::floating`], or `None` if there is no such tab.
+    fn undock_tab(&mut self, path: &[bool], index: usize) -> Option<usize> {
+        let (tab, _) = self.remove_tab(path, index)?;
+        self.floating.push(tab);
+        Some(self.floating.len() - 1)
+    }
+
+    /// Move the floating tab at `index` to the `zone` of the group (or split) at `to`.
+    fn dock_floating(&mut self, index: usize, to: &[bool], zone: DropZone) {
+        if index < self.floating.len() {
+            let tab = self.floating.remove(index);
+            self.insert_tab(to, zone, tab);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Where a dragged tab goes in the tab group it is dropped on.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum DropZone {
+    /// Add it to the group.
+    Center,
+    /// Split the group, and put the tab on this side.
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+impl DropZone {
+    /// The outer quarter of each side of a group splits it, the middle adds to it.
+    fn at(rect: Rect, pos: Pos2) -> Self {
+        let x = remap(pos.x, rect.x_range(), -1.0..=1.0);
+        let y = remap(pos.y, rect.y_range(), -1.0..=1.0);
+        if x.abs() < 0.5 && y.abs() < 0.5 {
+            Self::Center
+        } else if x.abs() > y.abs() {
+            if x < 0.0 {
+                Self::Left
+            } else {
+                Self::Right
+            }
+        } else if y < 0.0 {
+            Self::Top
+        } else {
+            Self::Bottom
+        }
+    }
+
+    /// Where to show the drop targets for a dragged [`Window`]:
+    /// around the center of a tab group, or at the edges of the whole dock area.
+    fn window_targets(rect: Rect, around_center: bool) -> Vec<(Self, Rect)> {
+        const SIZE: f32 = 32.0;
+        const SPACING: f32 = 4.0;
+        let center = rect.center();
+        let side_offset = |side: Vec2| {
+            if around_center {
+                center + side * (SIZE + SPACING)
+            } else {
+                center + side * (rect.size() / 2.0 - Vec2::splat(SIZE / 2.0 + SPACING))
+            }
+        };
+        let mut targets = vec![
+            (Self::Left, side_offset(-Vec2::X)),
+            (Self::Right, side_offset(Vec2::X)),
+            (Self::Top, side_offset(-Vec2::Y)),
+            (Self::Bottom, side_offset(Vec2::Y)),
+        ];
+        if around_center {
+            targets.push((Self::Center, center));
+        }
+        targets
+            .into_iter()
+            .map(|(zone, pos)| (zone, Rect::from_center_size(pos, Vec2::splat(SIZE))))
+            .collect()
+    }
+
+    /// The part of `rect` that the dropped tab would end up in.
+    fn preview_rect(self, rect: Rect) -> Rect {
+        let mut preview = rect;
+        match self {
+            Self::Center => {}
+            Self::Left => preview.max.x = rect.center().x,
+            Self::Right => preview.min.x = rect.center().x,
+            Self::Top => preview.max.y = rect.center().y,
+            Self::Bottom => preview.min.y = rect.center().y,
+        }
+        preview
+    }
+}
+
+/// Shows a [`DockLayout`]. See the [module docs](crate::containers::dock) for an example.
+#[must_use = "You should call .show()"]
+pub struct DockArea {
+    id: Id,
+}
+
+/// A tab that is being dragged.
+struct DraggedTab {
+    path: Vec<bool>,
+    index: usize,
+    released: bool,
+}
+
+impl DockArea {
+    /// `id_source`: Something unique, e.g. `"my_dock_area"`.
+    pub fn new(id_source: impl Hash) -> Self {
+        Self {
+            id: Id::new(id_source),
+        }
+    }
+
+    /// Fill the remainder of the screen with the dock area, like a [`CentralPanel`].
+    ///
+    /// `add_tab` is called to show the contents of each visible tab.
+    pub fn show(
+        self,
+        ctx: &CtxRef,
+        layout: &mut DockLayout,
+        add_tab: impl FnMut(&mut Ui, &str),
+    ) -> Response {
+        CentralPanel::default()
+            .show(ctx, |ui| self.show_inside(ui, layout, add_tab))
+            .inner
+    }
+
+    /// Fill the remainder of the given [`Ui`] with the dock area.
+    ///
+    /// `add_tab` is called to show the contents of each visible tab.
+    pub fn show_inside(
+        self,
+        ui: &mut Ui,
+        layout: &mut DockLayout,
+        mut add_tab: impl FnMut(&mut Ui, &str),
+    ) -> Response {
+        let rect = ui.available_rect_before_wrap_finite();
+
+        let mut groups = vec![];
+        let mut dragged_tab = None;
+        self.node_ui(
+            ui,
+            &mut layout.root,
+            &mut vec![],
+            rect,
+            &mut groups,
+            &mut dragged_tab,
+            &mut add_tab,
+        );
+
+        // Shows where a dropped tab or window would go, on top of the tab contents (and windows):
+        let painter = Painter::new(
+            ui.ctx().clone(),
+            LayerId::new(Order::Foreground, self.id),
+            ui.ctx().input().screen_rect(),
+        );
+        let preview_fill = ui.visuals().selection.bg_fill.linear_multiply(0.5);
+
+        let pointer = ui.input().pointer.hover_pos();
+        if let (Some(dragged_tab), Some(pointer)) = (dragged_tab, pointer) {
+            let target = groups.iter().find(|(_, rect)| rect.contains(pointer));
+            if let Some((path, group_rect)) = target {
+                let zone = DropZone::at(*group_rect, pointer);
+                if dragged_tab.released {
+                    layout.move_tab(&dragged_tab.path, dragged_tab.index, path, zone);
+                } else {
+                    painter.rect_filled(zone.preview_rect(*group_rect), 0.0, preview_fill);
+                }
+            } else if dragged_tab.released {
+                if let Some(index) = layout.undock_tab(&dragged_tab.path, dragged_tab.index) {
+                    let window_id = self.floating_window_id(&layout.floating, index);
+                    if let Some(state) = ui.memory().areas.get_mut(window_id) {
+                        state.pos = pointer;
+                    }
+                }
+            }
+            if !dragged_tab.released {
+                ui.output().cursor_icon = CursorIcon::Grabbing;
+            }
+        }
+
+        let mut dragged_window = None;
+        for (index, tab) in layout.floating.iter().enumerate() {
+            let window_response = Window::new(tab)
+                .id(self.floating_window_id(&layout.floating, index))
+                .default_pos(pointer.unwrap_or_else(|| rect.center()))
+                .show(ui.ctx(), |ui| add_tab(ui, tab));
+            if let Some(window_response) = window_response {
+                let response = &window_response.response;
+                if response.dragged() || response.drag_released() {
+                    dragged_window = Some((index, response.drag_released()));
+                }
+            }
+        }
+
+        if let (Some((index, released)), Some(pointer)) = (dragged_window, pointer) {
+            let mut targets: Vec<(Vec<bool>, Rect, DropZone, Rect)> =
+                DropZone::window_targets(rect, false)
+                    .into_iter()
+                    .map(|(zone, target)| (vec![], rect, zone, target))
+                    .collect();
+            if let Some((path, group_rect)) = groups.iter().find(|(_, rect)| rect.contains(pointer))
+            {
+                targets.extend(
+                    DropZone::window_targets(*group_rect, true)
+                        .into_iter()
+                        .map(|(zone, target)| (path.clone(), *group_rect, zone, target)),
+                );
+            }
+
+            let hovered = targets.iter().find(|(.., target)| target.contains(pointer));
+            if released {
+                if let Some((path, _, zone, _)) = hovered {
+                    layout.dock_floating(index, path, *zone);
+                }
+            } else {
+                if let Some((_, node_rect, zone, _)) = hovered {
+                    painter.rect_filled(zone.preview_rect(*node_rect), 0.0, preview_fill);
+                }
+                let visuals = ui.visuals();
+                for (_, _, zone, target) in &targets {
+                    painter.rect(
+                        *target,
+                        2.0,
+                        visuals.widgets.inactive.bg_fill,
+                        visuals.widgets.inactive.bg_stroke,
+                    );
+                    let marker = zone.preview_rect(target.shrink(4.0));
+                    painter.rect_filled(marker, 0.0, visuals.selection.bg_fill);
+                }
+            }
+        }
+
+        ui.allocate_rect(rect, Sense::hover())
+    }
+
+    /// The id of the window of the floating tab at `index`.
+    ///
+    /// Tabs can share a name, so the id also counts the floating tabs before it with the same name.
+    fn floating_window_id(&self, floating: &[String], index: usize) -> Id {
+        let tab = &floating[index];
+        let same_name_before = floating[..index].iter().filter(|t| *t == tab).count();
+        self.id.with("floating").with(tab).with(same_name_before)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn node_ui(
+        &self,
+        ui: &mut Ui,
+        node: &mut DockNode,
+        path: &mut Vec<bool>,
+        rect: Rect,
+        groups: &mut Vec<(Vec<bool>, Rect)>,
+        dragged_tab: &mut Option<DraggedTab>,
+        add_tab: &mut dyn FnMut(&mut Ui, &str),
+    ) {
+        let node_id = self.id.with(path.as_slice());
+
+        match node {
+            DockNode::Split {
+                horizontal,
+                fraction,
+                first,
+                second,
+            } => {
+                let separator_width = ui.spacing().item_spacing.x;
+                let (first_rect, separator_rect, second_rect) =
+                    split_rect(rect, *horizontal, *fraction, separator_width);
+
+                let response = ui.interact(separator_rect, node_id, Sense::drag());
+                if let Some(pointer) = response.interact_pointer_pos() {
+                    if response.dragged() {
+                        let new_fraction = if *horizontal {
+                            remap(pointer.x, rect.x_range(), 0.0..=1.0)
+                        } else {
+                            remap(pointer.y, rect.y_range(), 0.0..=1.0)
+                        };
+                        *fraction = new_fraction.at_least(0.1).at_most(0.9);
+                    }
+                }
+                if response.hovered() || response.dragged() {
+                    ui.output().cursor_icon = if *horizontal {
+                        CursorIcon::ResizeHorizontal
+                    } else {
+                        CursorIcon::ResizeVertical
+                    };
+                }
+                let stroke = ui.style().interact(&response).bg_stroke;
+                let line = if *horizontal {
+                    let x = separator_rect.center().x;
+                    [pos2(x, rect.top()), pos2(x, rect.bottom())]
+                } else {
+                    let y = separator_rect.center().y;
+                    [pos2(rect.left(), y), pos2(rect.right(), y)]
+                };
+                ui.painter().line_segment(line, stroke);
+
+                path.push(false);
+                self.node_ui(ui, first, path, first_rect, groups, dragged_tab, add_tab);
+                path.pop();
+                path.push(true);
+                self.node_ui(ui, second, path, second_rect, groups, dragged_tab, add_tab);
+                path.pop();
+            }
+            DockNode::Tabs { tabs, active } => {
+                groups.push((path.clone(), rect));
+
+                let mut ui = ui.child_ui(rect, Layout::top_down(Align::Min));
+                ui.set_clip_rect(rect.intersect(ui.clip_rect()));
+
+                ui.horizontal(|ui| {
+                    for (index, tab) in tabs.iter().enumerate() {
+                        let response = ui.selectable_label(*active == index, tab);
+                        if response.clicked() {
+                            *active = index;
+                        }
+
+                        let drag_response =
+                            ui.interact(response.rect, node_id.with(index), Sense::drag());
+                        // Ignore clicks, so they don't move the tab:
+                        let pointer = &ui.input().pointer;
+                        let dragging = drag_response.dragged() && pointer.is_decidedly_dragging();
+                        let dropped = drag_response.drag_released() && !pointer.any_click();
+                        if dragging || dropped {
+                            *dragged_tab = Some(DraggedTab {
+                                path: path.clone(),
+                                index,
+                                released: dropped,
+                            });
+                        }
+                    }
+                });
+                ui.separator();
+
+                if let Some(tab) = tabs.get(*active) {
+                    ui.push_id(tab, |ui| add_tab(ui, tab));
+                }
+            }
+        }
+    }
+}
+
+/// Split `rect` in two, with a separator in between.
+fn split_rect(
+    rect: Rect,
+    horizontal: bool,
+    fraction: f32,
+    separator_width: f32,
+) -> (Rect, Rect, Rect) {
+    let fraction = fraction.at_least(0.0).at_most(1.0);
+    let (mut first, mut separator, mut second) = (rect, rect, rect);
+    if horizontal {
+        let x = lerp(rect.x_range(), fraction);
+        first.max.x = x - separator_width / 2.0;
+        separator.min.x = first.max.x;
+        separator.max.x = x + separator_width / 2.0;
+        second.min.x = separator.max.x;
+    } else {
+        let y = lerp(rect.y_range(), fraction);
+        first.max.y = y - separator_width / 2.0;
+        separator.min.y = first.max.y;
+        separator.max.y = y + separator_width / 2.0;
+        second.min.y = separator.max.y;
+    }
+    (first, separator, second)
+}
+
+#[cfg(test)]
+#[test]
+fn move_and_remove_tabs() {
+    let mut layout = DockLayout::new(DockNode::horizontal(
+        DockNode::tabs(["a", "b"]),
+        DockNode::vertical(DockNode::tabs(["c"]), DockNode::tabs(["d"]), 0.5),
+        0.5,
+    ));
+
+    // Moving the only tab of a group removes the group, and its sibling takes the place of the split:
+    layout.move_tab(&[true, false], 0, &[false], DropZone::Center);
+    assert_eq!(
+        layout.root,
+        DockNode::horizontal(
+            DockNode::Tabs {
+                tabs: vec!["a".to_owned(), "b".to_owned(), "c".to_owned()],
+                active: 2,
+            },
+            DockNode::tabs(["d"]),
+            0.5,
+        )
+    );
+
+    // Dropping a tab on the edge of its own group splits it:
+    layout.move_tab(&[false], 0, &[false], DropZone::Bottom);
+    assert_eq!(
+        layout.root,
+        DockNode::horizontal(
+            DockNode::vertical(
+                DockNode::Tabs {
+                    tabs: vec!["b".to_owned(), "c".to_owned()],
+                    active: 1,
+                },
+                DockNode::tabs(["a"]),
+                0.5,
+            ),
+            DockNode::tabs(["d"]),
+            0.5,
+        )
+    );
+
+    // The target path is adjusted when the sibling of the removed group moves up:
+    layout.move_tab(&[true], 0, &[false, true], DropZone::Right);
+    assert_eq!(
+        layout.root,
+        DockNode::vertical(
+            DockNode::Tabs {
+                tabs: vec!["b".to_owned(), "c".to_owned()],
+                active: 1,
+            },
+            DockNode::horizontal(DockNode::tabs(["a"]), DockNode::tabs(["d"]), 0.5),
+            0.5,
+        )
+    );
+
+    // Dropping a tab in the middle of its own group does nothing:
+    let before = layout.clone();
+    layout.move_tab(&[false], 0, &[false], DropZone::Center);
+    assert_eq!(layout, before);
+}
+
+#[cfg(test)]
+#[test]
+fn undock_and_dock_tabs() {
+    let mut layout = DockLayout::new(DockNode::horizontal(
+        DockNode::tabs(["a"]),
+        DockNode::tabs(["b"]),
+        0.5,
+    ));
+
+    assert_eq!(layout.undock_tab(&[false], 0), Some(0));
+    assert_eq!(layout.root, DockNode::tabs(["b"]));
+    assert_eq!(layout.floating, vec!["a".to_owned()]);
+
+    assert_eq!(layout.undock_tab(&[], 0), Some(1));
+    assert_eq!(layout.undock_tab(&[], 0), None);
+    assert_eq!(layout.root, DockNode::tabs(std::iter::empty::<&str>()));
+    assert_eq!(layout.floating, vec!["a".to_owned(), "b".to_owned()]);
+
+    // Docking to the edge of an empty group fills it instead of splitting it:
+    layout.dock_floating(1, &[], DropZone::Left);
+    assert_eq!(layout.root, DockNode::tabs(["b"]));
+
+    // Docking at the edge of the dock area splits the root:
+    layout.dock_floating(0, &[], DropZone::Top);
+    assert_eq!(
+        layout.root,
+        DockNode::vertical(DockNode::tabs(["a"]), DockNode::tabs(["b"]), 0.5)
+    );
+    assert!(layout.floating.is_empty());
+}
+
+#[cfg(test)]
+#[test]
+fn floating_window_ids_are_unique() {
+    let dock_area = DockArea::new("dock");
+    let floating = vec!["a".to_owned(), "b".to_owned(), "a".to_owned()];
+    let ids: Vec<Id> = (0..floating.len())
+        .map(|index| dock_area.floating_window_id(&floating, index))
+        .collect();
+    assert_ne!(ids[0], ids[1]);
+    assert_ne!(ids[0], ids[2]);
+    assert_ne!(ids[1], ids[2]);
+}
@@ -5,21 +5,30 @@
 pub(crate) mod area;
 pub(crate) mod collapsing_header;
 mod combo_box;
+pub mod dialogs;
+pub mod dock;
 pub(crate) mod frame;
 pub(crate) mod panel;
 pub mod popup;
+pub(crate) mod property_grid;
 pub(crate) mod resize;
 pub(crate) mod scroll_area;
+pub(crate) mod toasts;
 pub(crate) mod window;
+pub(crate) mod wizard;
 
 pub use {
     area::Area,
     collapsing_header::*,
     combo_box::*,
+    dock::{DockArea, DockLayout, DockNode},
     frame::Frame,
-    panel::{CentralPanel, SidePanel, TopPanel},
+    panel::{BottomPanel, CentralPanel, SidePanel, TopPanel},
     popup::*,
-    resize::Resize,
+    property_grid::{PropertyGrid, PropertyGridUi, PropertyLabel},
+    resize::{Resize, SizePolicy},
     scroll_area::ScrollArea,
-    window::Window,
+    toasts::{Toast, ToastKind, Toasts},
+    window::{Window, WindowResponse},
+    wizard::{Wizard, WizardResponse},
 };
@@ -6,77 +6,435 @@
 //! The order in which you add panels matter!
 //!
 //! Add [`CentralPanel`] and [`Window`]:s last.
+//! A panel added after the [`CentralPanel`] would cover it, so egui shows an error on screen when that happens
+//! (and panics if the `extra_debug_asserts` feature is on).
+//!
+//! You can also split up a [`Window`] (or any other [`Ui`]) with panels using
+//! [`SidePanel::show_inside`], [`TopPanel::show_inside`], [`BottomPanel::show_inside`]
+//! and [`CentralPanel::show_inside`].
+
+use std::ops::RangeInclusive;
 
 use crate::*;
 
+/// State regarding panels.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+pub(crate) struct PanelState {
+    /// The rect of the panel last frame.
+    pub rect: Rect,
+}
+
+/// Panels added after the [`CentralPanel`] cover it, so complain loudly about it.
+fn check_panel_order(ctx: &CtxRef, panel_rect: Rect, panel_type: &str) {
+    let central_panel_added = ctx.frame_state().central_panel_added;
+    if central_panel_added {
+        let text = format!(
+            "{} added after the CentralPanel. Add all panels before the CentralPanel.",
+            panel_type
+        );
+        ctx.debug_painter().error(panel_rect.left_top(), &text);
+    }
+    crate::egui_assert!(
+        !central_panel_added,
+        "{} added after the CentralPanel. Add all panels before the CentralPanel.",
+        panel_type
+    );
+}
+
+/// The interaction with the edge of a resizable panel.
+#[derive(Clone, Copy, Default)]
+struct ResizeHandle {
+    hovered: bool,
+    /// Where the pointer is, if the user is dragging the edge.
+    drag_pos: Option<Pos2>,
+    /// The user double-clicked the edge to reset the panel to its default size.
+    double_clicked: bool,
+}
+
+impl ResizeHandle {
+    /// `line` is the edge of the panel that the user can drag.
+    fn interact(ctx: &CtxRef, layer_id: LayerId, id: Id, line: [Pos2; 2]) -> Self {
+        let pointer = match ctx.input().pointer.hover_pos() {
+            Some(pointer) => pointer,
+            None => return Self::default(),
+        };
+        let resize_id = id.with("__resize");
+
+        let we_are_on_top =
+            ctx.layer_id_at(pointer).unwrap_or_else(LayerId::background) == layer_id;
+        let mouse_over_resize_line = we_are_on_top
+            && pointer.distance_to_segment(line) <= ctx.style().interaction.resize_grab_radius_side;
+
+        let any_pressed = ctx.input().pointer.any_pressed();
+        if any_pressed && ctx.input().pointer.any_down() && mouse_over_resize_line {
+            ctx.memory().interaction.drag_id = Some(resize_id);
+        }
+        let is_resizing = ctx.memory().interaction.drag_id == Some(resize_id);
+
+        let dragging_something_else =
+            any_pressed || (ctx.input().pointer.any_down() && !is_resizing);
+        let hovered = mouse_over_resize_line && !dragging_something_else;
+        let double_clicked = (hovered || is_resizing) && ctx.input().pointer.any_double_click();
+
+        if hovered || is_resizing {
+            ctx.output().cursor_icon = if line[0].x == line[1].x {
+                CursorIcon::ResizeHorizontal
+            } else {
+                CursorIcon::ResizeVertical
+            };
+        }
+
+        Self {
+            hovered,
+            drag_pos: if is_resizing { Some(pointer) } else { None },
+            double_clicked,
+        }
+    }
+
+    /// Highlight the edge, on top of the panel contents.
+    fn paint(&self, ui: &Ui, line: [Pos2; 2]) {
+        let visuals = &ui.style().visuals.widgets;
+        if self.drag_pos.is_some() {
+            ui.painter().line_segment(line, visuals.active.bg_stroke);
+        } else if self.hovered {
+            ui.painter().line_segment(line, visuals.hovered.bg_stroke);
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------
 
-/// A panel that covers the entire left side of the screen.
+/// Which side of the screen a [`SidePanel`] is on.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Side {
+    Left,
+    Right,
+}
+
+impl Side {
+    fn opposite(self) -> Self {
+        match self {
+            Side::Left => Side::Right,
+            Side::Right => Side::Left,
+        }
+    }
+
+    /// Set the width of `rect`, keeping this side where it is.
+    fn set_rect_width(self, rect: &mut Rect, width: f32) {
+        match self {
+            Side::Left => rect.max.x = rect.min.x + width,
+            Side::Right => rect.min.x = rect.max.x - width,
+        }
+    }
+
+    fn side_x(self, rect: Rect) -> f32 {
+        match self {
+            Side::Left => rect.left(),
+            Side::Right => rect.right(),
+        }
+    }
+
+    /// The inner edge of a panel on this side.
+    fn resize_line(self, rect: Rect) -> [Pos2; 2] {
+        let x = self.opposite().side_x(rect);
+        [pos2(x, rect.top()), pos2(x, rect.bottom())]
+    }
+}
+
+/// A panel that covers the entire left or right side of the screen.
 ///
-/// `SidePanel`s must be added before adding any [`CentralPanel`] or [`Window`]s.
+/// `SidePanel`s must be added before adding any [`CentralPanel`] or [`Window`]s,
+/// unless they are an [`Self::overlay`].
 ///
 /// ```
 /// # let mut ctx = egui::CtxRef::default();
 /// # ctx.begin_frame(Default::default());
 /// # let ctx = &ctx;
-/// egui::SidePanel::left("my_side_panel", 0.0).show(ctx, |ui| {
+/// egui::SidePanel::left("my_left_panel", 0.0).show(ctx, |ui| {
 ///    ui.label("Hello World!");
 /// });
+///
+/// egui::SidePanel::right("my_right_panel", 200.0)
+///     .resizable(true)
+///     .width_range(100.0..=400.0)
+///     .show(ctx, |ui| {
+///         ui.label("Drag my left edge to resize me!");
+///     });
 /// ```
 #[must_use = "You should call .show()"]
 pub struct SidePanel {
+    side: Side,
     id: Id,
-    max_width: f32,
     frame: Option<Frame>,
+    resizable: bool,
+    default_width: f32,
+    width_range: RangeInclusive<f32>,
+    overlay: bool,
+    scrim: Option<Color32>,
+    auto_scroll: [bool; 2],
 }
 
 impl SidePanel {
     /// `id_source`: Something unique, e.g. `"my_side_panel"`.
     /// The given `max_width` is a soft maximum (as always), and the actual panel may be smaller or larger.
+    /// If the panel is [`Self::resizable`], this is the width it starts out with.
     pub fn left(id_source: impl std::hash::Hash, max_width: f32) -> Self {
+        Self::new(Side::Left, id_source, max_width)
+    }
+
+    /// `id_source`: Something unique, e.g. `"my_side_panel"`.
+    /// The given `max_width` is a soft maximum (as always), and the actual panel may be smaller or larger.
+    /// If the panel is [`Self::resizable`], this is the width it starts out with.
+    pub fn right(id_source: impl std::hash::Hash, max_width: f32) -> Self {
+        Self::new(Side::Right, id_source, max_width)
+    }
+
+    fn new(side: Side, id_source: impl std::hash::Hash, default_width: f32) -> Self {
         Self {
+            side,
             id: Id::new(id_source),
-            max_width,
             frame: None,
+            resizable: false,
+            default_width,
+            width_range: 0.0..=f32::INFINITY,
+            overlay: false,
+            scrim: None,
+            auto_scroll: [false; 2],
         }
     }
 
+    /// Can the user resize the panel by dragging its inner edge? Default: `false`.
+    ///
+    /// The width the user picks is remembered (and persisted with the `persistence` feature).
+    /// Double-click the edge to go back to [`Self::default_width`].
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    /// The width of the panel before the user has resized it.
+    pub fn default_width(mut self, default_width: f32) -> Self {
+        self.default_width = default_width;
+        self
+    }
+
+    /// The user can't resize the panel outside of this range.
+    pub fn width_range(mut self, width_range: RangeInclusive<f32>) -> Self {
+        self.width_range = width_range;
+        self
+    }
+
     /// Change the background color, margins, etc.
     pub fn frame(mut self, frame: Frame) -> Self {
         self.frame = Some(frame);
         self
     }
+
+    /// Wrap the contents in a [`ScrollArea`] when they don't fit, instead of clipping them.
+    ///
+    /// `[horizontal, vertical]` says in which directions the contents may scroll,
+    /// e.g. `[false, true]` to only scroll vertically. Default: `[false, false]`.
+    pub fn auto_scroll(mut self, auto_scroll: [bool; 2]) -> Self {
+        self.auto_scroll = auto_scroll;
+        self
+    }
+
+    /// Float above the [`CentralPanel`] and any [`Window`]s (like a drawer on a phone)
+    /// instead of taking space from them. Default: `false`.
+    ///
+    /// An overlay panel can be shown at any time, also after the [`CentralPanel`].
+    pub fn overlay(mut self, overlay: bool) -> Self {
+        self.overlay = overlay;
+        self
+    }
+
+    /// Dim the rest of the screen with this color when the panel is an [`Self::overlay`],
+    /// e.g. `Color32::from_black_alpha(128)`.
+    ///
+    /// Nothing behind the scrim can be interacted with while the panel is shown.
+    pub fn scrim(mut self, scrim: Color32) -> Self {
+        self.scrim = Some(scrim);
+        self
+    }
 }
 
 impl SidePanel {
+    /// Show the panel at the left or right side of the screen.
     pub fn show<R>(
         self,
         ctx: &CtxRef,
         add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        if self.overlay {
+            return self.show_overlay(ctx, add_contents);
+        }
+
+        let side = self.side;
+        let layer_id = LayerId::background();
+        let clip_rect = ctx.input().screen_rect();
+        let inner_response =
+            self.show_impl(ctx, layer_id, clip_rect, ctx.available_rect(), add_contents);
+        let rect = inner_response.response.rect;
+
+        // Only inform ctx about what we actually used, so we can shrink the native window to fit.
+        check_panel_order(ctx, rect, "SidePanel");
+        match side {
+            Side::Left => ctx.frame_state().allocate_left_panel(rect),
+            Side::Right => ctx.frame_state().allocate_right_panel(rect),
+        }
+
+        inner_response
+    }
+
+    /// Show the panel at the left or right side of the given [`Ui`],
+    /// e.g. to split up a [`Window`] or the [`CentralPanel`].
+    ///
+    /// Anything you add to `ui` afterwards will go in the space left over by the panel.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// egui::SidePanel::left("tree_view", 120.0)
+    ///     .resizable(true)
+    ///     .show_inside(ui, |ui| {
+    ///         ui.label("Tree view");
+    ///     });
+    /// ui.label("Detail view");
+    /// ```
+    pub fn show_inside<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let side = self.side;
+        let available_rect = ui.available_rect_before_wrap_finite();
+        let inner_response = self.show_impl(
+            ui.ctx(),
+            ui.layer_id(),
+            ui.clip_rect(),
+            available_rect,
+            add_contents,
+        );
+        let rect = inner_response.response.rect;
+
+        ui.expand_to_include_rect(rect);
+        let item_spacing = ui.spacing().item_spacing;
+        let mut remaining = Rect::EVERYTHING;
+        match side {
+            Side::Left => remaining.min.x = rect.max.x + item_spacing.x,
+            Side::Right => remaining.max.x = rect.min.x - item_spacing.x,
+        }
+        ui.cut_to(remaining);
+
+        inner_response
+    }
+
+    fn show_overlay<R>(
+        self,
+        ctx: &CtxRef,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let layer_id = LayerId::new(Order::Foreground, self.id);
+        let screen_rect = ctx.input().screen_rect();
+
+        if let Some(scrim) = self.scrim {
+            // Painted first, so it ends up behind the panel:
+            Painter::new(ctx.clone(), layer_id, screen_rect).rect_filled(screen_rect, 0.0, scrim);
+            ctx.register_opaque_rect(layer_id, screen_rect);
+        }
+
+        let resizable = self.resizable;
+        let inner_response = self.show_impl(
+            ctx,
+            layer_id,
+            screen_rect,
+            ctx.available_rect(),
+            add_contents,
+        );
+
+        let mut opaque_rect = inner_response.response.rect;
+        if resizable {
+            // Make sure the pointer can reach the resize handle just outside of the panel:
+            opaque_rect = opaque_rect.expand(ctx.style().interaction.resize_grab_radius_side);
+        }
+        ctx.register_opaque_rect(layer_id, opaque_rect);
+
+        inner_response
+    }
+
+    fn show_impl<R>(
+        self,
+        ctx: &CtxRef,
+        layer_id: LayerId,
+        clip_rect: Rect,
+        available_rect: Rect,
+        add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> InnerResponse<R> {
         let Self {
+            side,
             id,
-            max_width,
             frame,
+            resizable,
+            default_width,
+            width_range,
+            overlay: _,
+            scrim: _,
+            auto_scroll,
         } = self;
 
-        let mut panel_rect = ctx.available_rect();
-        panel_rect.max.x = panel_rect.max.x.at_most(panel_rect.min.x + max_width);
+        let clamp_width = |width: f32, available_width: f32| {
+            width
+                .at_least(*width_range.start())
+                .at_most(*width_range.end())
+                .at_most(available_width)
+        };
+
+        let mut panel_rect = available_rect;
+        {
+            let mut width = default_width;
+            if resizable {
+                if let Some(state) = ctx.memory().id_data.get::<PanelState>(&id) {
+                    width = state.rect.width();
+                }
+            }
+            side.set_rect_width(&mut panel_rect, clamp_width(width, available_rect.width()));
+        }
 
-        let layer_id = LayerId::background();
+        let mut resize_handle = ResizeHandle::default();
+        if resizable {
+            resize_handle = ResizeHandle::interact(ctx, layer_id, id, side.resize_line(panel_rect));
+            if let Some(pointer) = resize_handle.drag_pos {
+                let width = (pointer.x - side.side_x(panel_rect)).abs();
+                side.set_rect_width(&mut panel_rect, clamp_width(width, available_rect.width()));
+            }
+            if resize_handle.double_clicked {
+                // Stored in `PanelState` below, so it sticks:
+                let width = clamp_width(default_width, available_rect.width());
+                side.set_rect_width(&mut panel_rect, width);
+            }
+        }
 
-        let clip_rect = ctx.input().screen_rect();
         let mut panel_ui = Ui::new(ctx.clone(), layer_id, id, panel_rect, clip_rect);
 
         let frame = frame.unwrap_or_else(|| Frame::side_top_panel(&ctx.style()));
         let inner_response = frame.show(&mut panel_ui, |ui| {
             ui.set_min_height(ui.max_rect_finite().height()); // Make sure the frame fills the full height
-            add_contents(ui)
+            if resizable {
+                ui.set_min_width(ui.max_rect_finite().width()); // Keep the width the user picked
+            }
+            if auto_scroll[0] || auto_scroll[1] {
+                ScrollArea::new(auto_scroll).show_if_overflowing(ui, add_contents)
+            } else {
+                add_contents(ui)
+            }
         });
 
-        // Only inform ctx about what we actually used, so we can shrink the native window to fit.
-        ctx.frame_state()
-            .allocate_left_panel(inner_response.response.rect);
+        let rect = inner_response.response.rect;
+        if resizable {
+            ctx.memory().id_data.insert(id, PanelState { rect });
+        }
+
+        resize_handle.paint(&panel_ui, side.resize_line(rect));
 
         inner_response
     }
@@ -99,8 +457,11 @@ impl SidePanel {
 #[must_use = "You should call .show()"]
 pub struct TopPanel {
     id: Id,
-    max_height: Option<f32>,
     frame: Option<Frame>,
+    resizable: bool,
+    default_height: Option<f32>,
+    height_range: RangeInclusive<f32>,
+    auto_scroll: [bool; 2],
 }
 
 impl TopPanel {
@@ -110,48 +471,378 @@ impl TopPanel {
     pub fn top(id_source: impl std::hash::Hash) -> Self {
         Self {
             id: Id::new(id_source),
-            max_height: None,
             frame: None,
+            resizable: false,
+            default_height: None,
+            height_range: 0.0..=f32::INFINITY,
+            auto_scroll: [false; 2],
         }
     }
 
+    /// Can the user resize the panel by dragging its bottom edge? Default: `false`.
+    ///
+    /// The height the user picks is remembered (and persisted with the `persistence` feature).
+    /// Double-click the edge to go back to [`Self::default_height`].
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    /// The height of the panel before the user has resized it.
+    /// Default is that of `interact_size.y` (i.e. a button).
+    pub fn default_height(mut self, default_height: f32) -> Self {
+        self.default_height = Some(default_height);
+        self
+    }
+
+    /// The user can't resize the panel outside of this range.
+    pub fn height_range(mut self, height_range: RangeInclusive<f32>) -> Self {
+        self.height_range = height_range;
+        self
+    }
+
     /// Change the background color, margins, etc.
     pub fn frame(mut self, frame: Frame) -> Self {
         self.frame = Some(frame);
         self
     }
+
+    /// Scroll the contents when they don't fit, see [`SidePanel::auto_scroll`].
+    pub fn auto_scroll(mut self, auto_scroll: [bool; 2]) -> Self {
+        self.auto_scroll = auto_scroll;
+        self
+    }
 }
 
 impl TopPanel {
+    /// Show the panel at the top of the screen.
     pub fn show<R>(
         self,
         ctx: &CtxRef,
         add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let layer_id = LayerId::background();
+        let clip_rect = ctx.input().screen_rect();
+        let inner_response =
+            self.show_impl(ctx, layer_id, clip_rect, ctx.available_rect(), add_contents);
+        let rect = inner_response.response.rect;
+
+        // Only inform ctx about what we actually used, so we can shrink the native window to fit.
+        check_panel_order(ctx, rect, "TopPanel");
+        ctx.frame_state().allocate_top_panel(rect);
+
+        inner_response
+    }
+
+    /// Show the panel at the top of the given [`Ui`],
+    /// e.g. to split up a [`Window`] or the [`CentralPanel`].
+    ///
+    /// Anything you add to `ui` afterwards will go in the space left over by the panel.
+    pub fn show_inside<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let available_rect = ui.available_rect_before_wrap_finite();
+        let inner_response = self.show_impl(
+            ui.ctx(),
+            ui.layer_id(),
+            ui.clip_rect(),
+            available_rect,
+            add_contents,
+        );
+        let rect = inner_response.response.rect;
+
+        ui.expand_to_include_rect(rect);
+        let item_spacing = ui.spacing().item_spacing;
+        let mut remaining = Rect::EVERYTHING;
+        remaining.min.y = rect.max.y + item_spacing.y;
+        ui.cut_to(remaining);
+
+        inner_response
+    }
+
+    fn show_impl<R>(
+        self,
+        ctx: &CtxRef,
+        layer_id: LayerId,
+        clip_rect: Rect,
+        available_rect: Rect,
+        add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> InnerResponse<R> {
         let Self {
             id,
-            max_height,
             frame,
+            resizable,
+            default_height,
+            height_range,
+            auto_scroll,
         } = self;
-        let max_height = max_height.unwrap_or_else(|| ctx.style().spacing.interact_size.y);
+        let default_height = default_height.unwrap_or_else(|| ctx.style().spacing.interact_size.y);
+
+        let clamp_height = |height: f32, available_height: f32| {
+            height
+                .at_least(*height_range.start())
+                .at_most(*height_range.end())
+                .at_most(available_height)
+        };
+
+        let mut panel_rect = available_rect;
+        {
+            let mut height = default_height;
+            if resizable {
+                if let Some(state) = ctx.memory().id_data.get::<PanelState>(&id) {
+                    height = state.rect.height();
+                }
+            }
+            panel_rect.max.y = panel_rect.min.y + clamp_height(height, available_rect.height());
+        }
 
-        let mut panel_rect = ctx.available_rect();
-        panel_rect.max.y = panel_rect.max.y.at_most(panel_rect.min.y + max_height);
+        let mut resize_handle = ResizeHandle::default();
+        if resizable {
+            let bottom_line = [panel_rect.left_bottom(), panel_rect.right_bottom()];
+            resize_handle = ResizeHandle::interact(ctx, layer_id, id, bottom_line);
+            if let Some(pointer) = resize_handle.drag_pos {
+                let height = pointer.y - panel_rect.top();
+                panel_rect.max.y = panel_rect.min.y + clamp_height(height, available_rect.height());
+            }
+            if resize_handle.double_clicked {
+                let height = clamp_height(default_height, available_rect.height());
+                panel_rect.max.y = panel_rect.min.y + height;
+            }
+        }
 
-        let layer_id = LayerId::background();
+        let mut panel_ui = Ui::new(ctx.clone(), layer_id, id, panel_rect, clip_rect);
+
+        let frame = frame.unwrap_or_else(|| Frame::side_top_panel(&ctx.style()));
+        let inner_response = frame.show(&mut panel_ui, |ui| {
+            ui.set_min_width(ui.max_rect_finite().width()); // Make the frame fill full width
+            if resizable {
+                ui.set_min_height(ui.max_rect_finite().height()); // Keep the height the user picked
+            }
+            if auto_scroll[0] || auto_scroll[1] {
+                ScrollArea::new(auto_scroll).show_if_overflowing(ui, add_contents)
+            } else {
+                add_contents(ui)
+            }
+        });
+
+        let rect = inner_response.response.rect;
+        if resizable {
+            ctx.memory().id_data.insert(id, PanelState { rect });
+        }
+
+        resize_handle.paint(&panel_ui, [rect.left_bottom(), rect.right_bottom()]);
+
+        inner_response
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A panel that covers the entire bottom side of the screen, e.g. for a status bar or a log console.
+///
+/// `BottomPanel`s must be added before adding any [`CentralPanel`] or [`Window`]s.
+///
+/// ```
+/// # let mut ctx = egui::CtxRef::default();
+/// # ctx.begin_frame(Default::default());
+/// # let ctx = &ctx;
+/// egui::BottomPanel::bottom("my_bottom_panel").show(ctx, |ui| {
+///    ui.label("Hello World!");
+/// });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct BottomPanel {
+    id: Id,
+    frame: Option<Frame>,
+    resizable: bool,
+    default_height: Option<f32>,
+    height_range: RangeInclusive<f32>,
+    auto_scroll: [bool; 2],
+}
+
+impl BottomPanel {
+    /// `id_source`: Something unique, e.g. `"my_bottom_panel"`.
+    /// Default height is that of `interact_size.y` (i.e. a button),
+    /// but the panel will expand as needed.
+    pub fn bottom(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id: Id::new(id_source),
+            frame: None,
+            resizable: false,
+            default_height: None,
+            height_range: 0.0..=f32::INFINITY,
+            auto_scroll: [false; 2],
+        }
+    }
+
+    /// Can the user resize the panel by dragging its top edge? Default: `false`.
+    ///
+    /// The height the user picks is remembered (and persisted with the `persistence` feature).
+    /// Double-click the edge to go back to [`Self::default_height`].
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    /// The height of the panel before the user has resized it.
+    /// Default is that of `interact_size.y` (i.e. a button).
+    pub fn default_height(mut self, default_height: f32) -> Self {
+        self.default_height = Some(default_height);
+        self
+    }
+
+    /// The user can't resize the panel outside of this range.
+    pub fn height_range(mut self, height_range: RangeInclusive<f32>) -> Self {
+        self.height_range = height_range;
+        self
+    }
+
+    /// Change the background color, margins, etc.
+    pub fn frame(mut self, frame: Frame) -> Self {
+        self.frame = Some(frame);
+        self
+    }
+
+    /// Scroll the contents when they don't fit, see [`SidePanel::auto_scroll`].
+    pub fn auto_scroll(mut self, auto_scroll: [bool; 2]) -> Self {
+        self.auto_scroll = auto_scroll;
+        self
+    }
+}
 
+impl BottomPanel {
+    /// Show the panel at the bottom of the screen.
+    pub fn show<R>(
+        self,
+        ctx: &CtxRef,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let layer_id = LayerId::background();
         let clip_rect = ctx.input().screen_rect();
+        let inner_response =
+            self.show_impl(ctx, layer_id, clip_rect, ctx.available_rect(), add_contents);
+        let rect = inner_response.response.rect;
+
+        // Only inform ctx about what we actually used, so we can shrink the native window to fit.
+        check_panel_order(ctx, rect, "BottomPanel");
+        ctx.frame_state().allocate_bottom_panel(rect);
+
+        inner_response
+    }
+
+    /// Show the panel at the bottom of the given [`Ui`],
+    /// e.g. to split up a [`Window`] or the [`CentralPanel`].
+    ///
+    /// Anything you add to `ui` afterwards will go in the space left over by the panel.
+    pub fn show_inside<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let available_rect = ui.available_rect_before_wrap_finite();
+        let inner_response = self.show_impl(
+            ui.ctx(),
+            ui.layer_id(),
+            ui.clip_rect(),
+            available_rect,
+            add_contents,
+        );
+        let rect = inner_response.response.rect;
+
+        ui.expand_to_include_rect(rect);
+        let item_spacing = ui.spacing().item_spacing;
+        let mut remaining = Rect::EVERYTHING;
+        remaining.max.y = rect.min.y - item_spacing.y;
+        ui.cut_to(remaining);
+
+        inner_response
+    }
+
+    fn show_impl<R>(
+        self,
+        ctx: &CtxRef,
+        layer_id: LayerId,
+        clip_rect: Rect,
+        available_rect: Rect,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let Self {
+            id,
+            frame,
+            resizable,
+            default_height,
+            height_range,
+            auto_scroll,
+        } = self;
+        let default_height = default_height.unwrap_or_else(|| ctx.style().spacing.interact_size.y);
+
+        let clamp_height = |height: f32, available_height: f32| {
+            height
+                .at_least(*height_range.start())
+                .at_most(*height_range.end())
+                .at_most(available_height)
+        };
+
+        let mut panel_rect = available_rect;
+        {
+            // We need to know the height before laying out the contents,
+            // so we use the height of last frame:
+            let mut height = default_height;
+            if let Some(state) = ctx.memory().id_data.get::<PanelState>(&id) {
+                height = state.rect.height();
+            }
+            panel_rect.min.y = panel_rect.max.y - clamp_height(height, available_rect.height());
+        }
+
+        let mut resize_handle = ResizeHandle::default();
+        if resizable {
+            let top_line = [panel_rect.left_top(), panel_rect.right_top()];
+            resize_handle = ResizeHandle::interact(ctx, layer_id, id, top_line);
+            if let Some(pointer) = resize_handle.drag_pos {
+                let height = panel_rect.bottom() - pointer.y;
+                panel_rect.min.y = panel_rect.max.y - clamp_height(height, available_rect.height());
+            }
+            if resize_handle.double_clicked {
+                let height = clamp_height(default_height, available_rect.height());
+                panel_rect.min.y = panel_rect.max.y - height;
+            }
+        }
+
         let mut panel_ui = Ui::new(ctx.clone(), layer_id, id, panel_rect, clip_rect);
 
         let frame = frame.unwrap_or_else(|| Frame::side_top_panel(&ctx.style()));
+        let margin = frame.margin;
+        let mut content_height = 0.0;
         let inner_response = frame.show(&mut panel_ui, |ui| {
             ui.set_min_width(ui.max_rect_finite().width()); // Make the frame fill full width
-            add_contents(ui)
+            let inner = if auto_scroll[0] || auto_scroll[1] {
+                ScrollArea::new(auto_scroll).show_if_overflowing(ui, add_contents)
+            } else {
+                add_contents(ui)
+            };
+            content_height = ui.min_rect().height();
+            ui.set_min_height(ui.max_rect_finite().height()); // Stick to the bottom of the screen
+            inner
         });
 
-        // Only inform ctx about what we actually used, so we can shrink the native window to fit.
-        ctx.frame_state()
-            .allocate_top_panel(inner_response.response.rect);
+        let rect = inner_response.response.rect;
+        let state_rect = if resizable {
+            rect
+        } else {
+            // Fit the contents next frame:
+            let height = content_height + 2.0 * margin.y;
+            if (height - rect.height()).abs() > 0.5 {
+                ctx.request_repaint();
+            }
+            Rect::from_min_size(rect.min, vec2(rect.width(), height))
+        };
+        ctx.memory()
+            .id_data
+            .insert(id, PanelState { rect: state_rect });
+
+        resize_handle.paint(&panel_ui, [rect.left_top(), rect.right_top()]);
 
         inner_response
     }
@@ -177,6 +868,7 @@ impl TopPanel {
 #[derive(Default)]
 pub struct CentralPanel {
     frame: Option<Frame>,
+    auto_scroll: [bool; 2],
 }
 
 impl CentralPanel {
@@ -185,34 +877,91 @@ impl CentralPanel {
         self.frame = Some(frame);
         self
     }
+
+    /// Scroll the contents when they don't fit, see [`SidePanel::auto_scroll`].
+    pub fn auto_scroll(mut self, auto_scroll: [bool; 2]) -> Self {
+        self.auto_scroll = auto_scroll;
+        self
+    }
 }
 
 impl CentralPanel {
+    /// Show the panel in whatever space is left on the screen after the other panels.
     pub fn show<R>(
         self,
         ctx: &CtxRef,
         add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> InnerResponse<R> {
-        let Self { frame } = self;
-
-        let panel_rect = ctx.available_rect();
-
         let layer_id = LayerId::background();
         let id = Id::new("central_panel");
-
         let clip_rect = ctx.input().screen_rect();
+        let (inner_response, content_rect) = self.show_impl(
+            ctx,
+            layer_id,
+            id,
+            clip_rect,
+            ctx.available_rect(),
+            add_contents,
+        );
+
+        check_panel_order(ctx, inner_response.response.rect, "A second CentralPanel");
+
+        // Only inform ctx about what we actually used, so we can shrink the native window to fit.
+        ctx.frame_state()
+            .allocate_central_panel(inner_response.response.rect, content_rect);
+
+        inner_response
+    }
+
+    /// Fill the space left in the given [`Ui`], e.g. after adding panels with
+    /// [`SidePanel::show_inside`] or [`TopPanel::show_inside`].
+    pub fn show_inside<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let id = ui.id().with("central_panel");
+        let available_rect = ui.available_rect_before_wrap_finite();
+        let (inner_response, _) = self.show_impl(
+            ui.ctx(),
+            ui.layer_id(),
+            id,
+            ui.clip_rect(),
+            available_rect,
+            add_contents,
+        );
+        ui.expand_to_include_rect(inner_response.response.rect);
+        inner_response
+    }
+
+    /// Also returns the rect used by the contents, including the frame margin.
+    fn show_impl<R>(
+        self,
+        ctx: &CtxRef,
+        layer_id: LayerId,
+        id: Id,
+        clip_rect: Rect,
+        panel_rect: Rect,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> (InnerResponse<R>, Rect) {
+        let Self { frame, auto_scroll } = self;
+
         let mut panel_ui = Ui::new(ctx.clone(), layer_id, id, panel_rect, clip_rect);
 
         let frame = frame.unwrap_or_else(|| Frame::central_panel(&ctx.style()));
+        let margin = frame.margin;
+        let mut content_rect = Rect::NOTHING;
         let inner_response = frame.show(&mut panel_ui, |ui| {
+            let inner = if auto_scroll[0] || auto_scroll[1] {
+                ScrollArea::new(auto_scroll).show_if_overflowing(ui, add_contents)
+            } else {
+                add_contents(ui)
+            };
+            content_rect = ui.min_rect();
             ui.expand_to_include_rect(ui.max_rect()); // Expand frame to include it all
-            add_contents(ui)
+            inner
         });
 
-        // Only inform ctx about what we actually used, so we can shrink the native window to fit.
-        ctx.frame_state()
-            .allocate_central_panel(inner_response.response.rect);
-
-        inner_response
+        (inner_response, content_rect.expand2(margin))
     }
 }
@@ -35,6 +35,46 @@ impl MonoState {
 
 // ----------------------------------------------------------------------------
 
+/// Where to put a popup (tooltip, menu, combo box, …) next to `anchor_rect`
+/// so that it stays on the screen.
+///
+/// The popup goes below `anchor_rect`, unless it only fits above it (or there is more room above).
+/// It is moved left if it would stick out on the right side of the screen.
+///
+/// `size` is the expected size of the popup, e.g. what it was last frame.
+///
+/// Returns the top-left corner of the popup, and how tall it can be before it leaves the screen.
+/// If it is taller than that, put the contents in a [`ScrollArea`].
+pub(crate) fn popup_pos_on_screen(ctx: &CtxRef, anchor_rect: Rect, size: Vec2) -> (Pos2, f32) {
+    let screen_rect = ctx.input().screen_rect();
+
+    let room_below = (screen_rect.bottom() - anchor_rect.bottom()).at_least(0.0);
+    let room_above = (anchor_rect.top() - screen_rect.top()).at_least(0.0);
+    let below = size.y <= room_below || room_below >= room_above;
+
+    let x = anchor_rect
+        .left()
+        .at_most(screen_rect.right() - size.x)
+        .at_least(screen_rect.left());
+
+    if below {
+        (pos2(x, anchor_rect.bottom()), room_below)
+    } else {
+        let height = size.y.at_most(room_above);
+        (pos2(x, anchor_rect.top() - height), room_above)
+    }
+}
+
+/// The size of a popup [`Area`] last frame, if it was shown.
+pub(crate) fn last_popup_size(ctx: &CtxRef, area: &Area) -> Vec2 {
+    ctx.memory()
+        .areas
+        .get(area.id)
+        .map_or(Vec2::ZERO, |state| state.size)
+}
+
+// ----------------------------------------------------------------------------
+
 /// Show a tooltip at the current pointer position (if any).
 ///
 /// Most of the time it is easier to use [`Response::on_hover_ui`].
@@ -54,40 +94,53 @@ pub fn show_tooltip(ctx: &CtxRef, id: Id, add_contents: impl FnOnce(&mut Ui)) {
 }
 
 pub fn show_tooltip_at_pointer(ctx: &CtxRef, id: Id, add_contents: impl FnOnce(&mut Ui)) {
-    let suggested_pos = ctx
-        .input()
-        .pointer
-        .hover_pos()
-        .map(|pointer_pos| pointer_pos + vec2(16.0, 16.0));
-    show_tooltip_at(ctx, id, suggested_pos, add_contents)
+    // Below and to the right of the pointer, or above it if there is no room below:
+    let anchor_rect = ctx.input().pointer.hover_pos().map(|pointer_pos| {
+        Rect::from_min_max(
+            pointer_pos + vec2(16.0, -16.0),
+            pointer_pos + vec2(16.0, 16.0),
+        )
+    });
+    show_tooltip_next_to(ctx, id, anchor_rect, add_contents)
 }
 
 pub fn show_tooltip_under(ctx: &CtxRef, id: Id, rect: &Rect, add_contents: impl FnOnce(&mut Ui)) {
-    show_tooltip_at(
-        ctx,
-        id,
-        Some(rect.left_bottom() + vec2(-2.0, 4.0)),
-        add_contents,
-    )
+    let anchor_rect = Rect::from_min_max(
+        rect.left_top() + vec2(-2.0, -4.0),
+        rect.left_bottom() + vec2(-2.0, 4.0),
+    );
+    show_tooltip_next_to(ctx, id, Some(anchor_rect), add_contents)
 }
 
 pub fn show_tooltip_at(
     ctx: &CtxRef,
-    mut id: Id,
+    id: Id,
     suggested_position: Option<Pos2>,
     add_contents: impl FnOnce(&mut Ui),
+) {
+    let anchor_rect = suggested_position.map(|pos| Rect::from_min_max(pos, pos));
+    show_tooltip_next_to(ctx, id, anchor_rect, add_contents)
+}
+
+/// Show a tooltip below `anchor_rect`, or above it if there is no room below.
+fn show_tooltip_next_to(
+    ctx: &CtxRef,
+    mut id: Id,
+    anchor_rect: Option<Rect>,
+    add_contents: impl FnOnce(&mut Ui),
 ) {
     let mut tooltip_rect = Rect::NOTHING;
 
-    let position = if let Some((stored_id, stored_tooltip_rect)) = ctx.frame_state().tooltip_rect {
+    let anchor_rect = if let Some((stored_id, stored_tooltip_rect)) = ctx.frame_state().tooltip_rect
+    {
         // if there are multiple tooltips open they should use the same id for the `tooltip_size` caching to work.
         id = stored_id;
         tooltip_rect = stored_tooltip_rect;
-        tooltip_rect.left_bottom()
-    } else if let Some(position) = suggested_position {
-        position
+        tooltip_rect
+    } else if let Some(anchor_rect) = anchor_rect {
+        anchor_rect
     } else if ctx.memory().everything_is_visible() {
-        Pos2::default()
+        Rect::from_min_max(Pos2::default(), Pos2::default())
     } else {
         return; // No good place for a tooltip :(
     };
@@ -98,8 +151,8 @@ pub fn show_tooltip_at(
         .get_or_default::<crate::containers::popup::MonoState>()
         .tooltip_size(id);
     let expected_size = expected_size.unwrap_or_else(|| vec2(64.0, 32.0));
-    let position = position.min(ctx.input().screen_rect().right_bottom() - expected_size);
-    let position = position.max(ctx.input().screen_rect().left_top());
+    // Tooltips can't be scrolled, so we ignore the max height:
+    let (position, _) = popup_pos_on_screen(ctx, anchor_rect, expected_size);
 
     let response = show_tooltip_area(ctx, id, position, add_contents);
     ctx.memory()
@@ -152,6 +205,9 @@ fn show_tooltip_area(
 ///
 /// Useful for drop-down menus (combo boxes) or suggestion menus under text fields.
 ///
+/// If there is no room below the widget the popup is shown above it instead,
+/// and if it is taller than the screen it gets a scroll bar.
+///
 /// You must open the popup with [`Memory::open_popup`] or  [`Memory::toggle_popup`].
 ///
 /// ```
@@ -175,23 +231,47 @@ pub fn popup_below_widget(
 ) {
     if ui.memory().is_popup_open(popup_id) {
         let parent_clip_rect = ui.clip_rect();
+        let area = Area::new(popup_id).order(Order::Foreground);
+        let layer_id = area.layer();
+        let size = last_popup_size(ui.ctx(), &area);
+        let (pos, max_height) = popup_pos_on_screen(ui.ctx(), widget_response.rect, size);
 
-        Area::new(popup_id)
-            .order(Order::Foreground)
-            .fixed_pos(widget_response.rect.left_bottom())
-            .show(ui.ctx(), |ui| {
-                ui.set_clip_rect(parent_clip_rect); // for when the combo-box is in a scroll area.
-                let frame = Frame::popup(ui.style());
-                let frame_margin = frame.margin;
-                frame.show(ui, |ui| {
-                    ui.with_layout(Layout::top_down_justified(Align::LEFT), |ui| {
-                        ui.set_width(widget_response.rect.width() - 2.0 * frame_margin.x);
-                        add_contents(ui)
-                    });
+        let area_response = area.fixed_pos(pos).show(ui.ctx(), |ui| {
+            ui.set_clip_rect(parent_clip_rect); // for when the combo-box is in a scroll area.
+            let frame = Frame::popup(ui.style());
+            let frame_margin = frame.margin;
+            frame.show(ui, |ui| {
+                ui.with_layout(Layout::top_down_justified(Align::LEFT), |ui| {
+                    ui.set_width(widget_response.rect.width() - 2.0 * frame_margin.x);
+                    let max_height = (max_height - 2.0 * frame_margin.y).at_least(0.0);
+                    ScrollArea::from_max_height(max_height).show(ui, add_contents)
                 });
             });
+        });
 
-        if ui.input().key_pressed(Key::Escape) || widget_response.clicked_elsewhere() {
+        let close_requested = std::mem::take(&mut ui.ctx().frame_state().close_menu);
+        let mut close = close_requested || ui.input().key_pressed(Key::Escape);
+        if widget_response.clicked_elsewhere() {
+            let close_policy = ui.style().interaction.menu_close_policy;
+            let pointer_pos = ui.input().pointer.interact_pos();
+            let clicked_in_popup =
+                pointer_pos.map_or(false, |pos| area_response.rect.contains(pos));
+            // Clicking a text field in the popup (e.g. to move the cursor) shouldn't close it:
+            let focused = ui.memory().interaction.focus.focused();
+            let clicked_focused = focused.map_or(false, |focused| {
+                ui.ctx()
+                    .frame_state()
+                    .interact_rects
+                    .iter()
+                    .any(|(layer, id, rect)| {
+                        *layer == layer_id
+                            && *id == focused
+                            && pointer_pos.map_or(false, |pos| rect.contains(pos))
+                    })
+            });
+            close |= !clicked_in_popup || (close_policy.close_on_click_inside && !clicked_focused);
+        }
+        if close {
             ui.memory().close_popup();
         }
     }
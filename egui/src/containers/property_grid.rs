@@ -0,0 +1,247 @@
+//! Two-column "name: value" editors, see [`PropertyGrid`].
+
+use crate::{containers::collapsing_header, *};
+
+/// The name of a property in a [`PropertyGrid`], with an optional tooltip.
+///
+/// You can pass a `&str` or `String` wherever this is expected.
+#[derive(Clone, Debug)]
+pub struct PropertyLabel {
+    text: String,
+    tooltip: Option<String>,
+}
+
+impl PropertyLabel {
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(text: impl ToString) -> Self {
+        Self {
+            text: text.to_string(),
+            tooltip: None,
+        }
+    }
+
+    /// Show this text when hovering the label.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn tooltip(mut self, tooltip: impl ToString) -> Self {
+        self.tooltip = Some(tooltip.to_string());
+        self
+    }
+}
+
+impl From<&str> for PropertyLabel {
+    fn from(text: &str) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<String> for PropertyLabel {
+    fn from(text: String) -> Self {
+        Self {
+            text,
+            tooltip: None,
+        }
+    }
+}
+
+/// A [`Grid`] of properties: a column of names, and a column of widgets for editing the values.
+///
+/// Properties can be put into collapsible groups,
+/// and can have a button for resetting them to their default value.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut name = String::new();
+/// # let mut speed = 1.0;
+/// # let mut visible = true;
+/// egui::PropertyGrid::new("my_properties").show(ui, |grid| {
+///     grid.row("Name", |ui| ui.text_edit_singleline(&mut name));
+///     grid.group("Movement", |grid| {
+///         grid.row_with_default(
+///             egui::PropertyLabel::new("Speed").tooltip("In meters per second"),
+///             &mut speed,
+///             1.0,
+///             |ui, speed| ui.add(egui::DragValue::new(speed)),
+///         );
+///     });
+///     grid.row("Visible", |ui| ui.checkbox(&mut visible, ""));
+/// });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct PropertyGrid {
+    id_source: Id,
+    striped: bool,
+    min_label_width: f32,
+}
+
+impl PropertyGrid {
+    /// Create a new [`PropertyGrid`] with a locally unique identifier.
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            striped: true,
+            min_label_width: 0.0,
+        }
+    }
+
+    /// If `true`, add a subtle background color to every other row.
+    /// Default: `true`.
+    pub fn striped(mut self, striped: bool) -> Self {
+        self.striped = striped;
+        self
+    }
+
+    /// Make the label column at least this wide.
+    ///
+    /// Otherwise it is as wide as the widest label,
+    /// so use this to line up several property grids under each other.
+    pub fn min_label_width(mut self, min_label_width: f32) -> Self {
+        self.min_label_width = min_label_width;
+        self
+    }
+
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut PropertyGridUi<'_>) -> R,
+    ) -> InnerResponse<R> {
+        let Self {
+            id_source,
+            striped,
+            min_label_width,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source);
+        Grid::new(id_source).striped(striped).show(ui, |ui| {
+            add_contents(&mut PropertyGridUi {
+                ui,
+                id,
+                depth: 0,
+                min_label_width,
+            })
+        })
+    }
+}
+
+/// Adds rows to a [`PropertyGrid`].
+pub struct PropertyGridUi<'a> {
+    ui: &'a mut Ui,
+    /// For the open/closed state of groups.
+    id: Id,
+    /// How many groups deep we are.
+    depth: usize,
+    min_label_width: f32,
+}
+
+impl<'a> PropertyGridUi<'a> {
+    /// The id of the grid, or of the group we are in.
+    ///
+    /// Combine it with the label of a property to give its widgets a unique id.
+    pub fn id(&self) -> Id {
+        self.id
+    }
+
+    /// Add a property, with `add_contents` showing the widget(s) for its value.
+    ///
+    /// The returned response covers both the label and the value.
+    pub fn row<R>(
+        &mut self,
+        label: impl Into<PropertyLabel>,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let label_response = self.label_cell(label.into());
+        let InnerResponse { inner, response } = self.ui.horizontal(add_contents);
+        self.ui.end_row();
+        InnerResponse::new(inner, label_response.union(response))
+    }
+
+    /// Like [`Self::row`], but with a button for setting `value` back to `default`.
+    ///
+    /// The returned response is marked as changed when the button is clicked.
+    pub fn row_with_default<T: PartialEq, R>(
+        &mut self,
+        label: impl Into<PropertyLabel>,
+        value: &mut T,
+        default: T,
+        add_contents: impl FnOnce(&mut Ui, &mut T) -> R,
+    ) -> InnerResponse<R> {
+        let label_response = self.label_cell(label.into());
+        let InnerResponse { inner, response } = self.ui.horizontal(|ui| add_contents(ui, value));
+
+        let reset_text = self.ui.ctx().tr("Reset to default");
+        let reset = self
+            .ui
+            .add(Button::new("⟲").small().enabled(*value != default))
+            .on_hover_text(reset_text);
+        self.ui.end_row();
+
+        let mut response = label_response.union(response);
+        if reset.clicked() {
+            *value = default;
+            response.mark_changed();
+        }
+        InnerResponse::new(inner, response)
+    }
+
+    /// A collapsible group of properties, open by default.
+    ///
+    /// Returns `None` if the group is closed.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn group<R>(
+        &mut self,
+        name: impl ToString,
+        add_contents: impl FnOnce(&mut PropertyGridUi<'_>) -> R,
+    ) -> Option<R> {
+        let name = name.to_string();
+        let id = self.id.with(&name);
+        let indent = self.indent();
+        let min_label_width = self.min_label_width;
+
+        let ui = &mut *self.ui;
+        let mut state = collapsing_header::State::from_memory_with_default_open(ui.ctx(), id, true);
+        ui.horizontal(|ui| {
+            ui.set_min_width(min_label_width);
+            ui.add_space(indent);
+            let icon_size = Vec2::splat(ui.spacing().icon_width);
+            let (_, icon_response) = ui.allocate_exact_size(icon_size, Sense::click());
+            let label_response = ui.add(Label::new(&name).strong().sense(Sense::click()));
+            if icon_response.clicked() || label_response.clicked() {
+                state.toggle(ui);
+            }
+            collapsing_header::paint_icon(ui, state.openness(ui.ctx(), id), &icon_response);
+        });
+        ui.end_row();
+        ui.memory().id_data.insert(id, state);
+
+        if collapsing_header::State::is_open(ui.ctx(), id) == Some(true) {
+            Some(add_contents(&mut PropertyGridUi {
+                ui,
+                id,
+                depth: self.depth + 1,
+                min_label_width,
+            }))
+        } else {
+            None
+        }
+    }
+
+    fn indent(&self) -> f32 {
+        self.depth as f32 * self.ui.spacing().indent
+    }
+
+    fn label_cell(&mut self, label: PropertyLabel) -> Response {
+        let indent = self.indent();
+        let min_label_width = self.min_label_width;
+        let PropertyLabel { text, tooltip } = label;
+        self.ui
+            .horizontal(|ui| {
+                ui.set_min_width(min_label_width);
+                ui.add_space(indent);
+                let response = ui.label(text);
+                match tooltip {
+                    Some(tooltip) => response.on_hover_text(tooltip),
+                    None => response,
+                }
+            })
+            .inner
+    }
+}
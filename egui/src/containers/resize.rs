@@ -16,6 +16,43 @@ pub(crate) struct State {
     pub(crate) requested_size: Option<Vec2>,
 }
 
+/// How a [`Resize`] region (e.g. a [`Window`]) picks its size when its contents change.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SizePolicy {
+    /// Start at the default size and grow to fit the contents.
+    /// The grown size is remembered, so the region never shrinks on its own.
+    /// The user can resize it (if resizable).
+    GrowToContent,
+
+    /// Always exactly as large as the contents, growing and shrinking with them every frame.
+    /// The user cannot resize it.
+    ///
+    /// The default size is the space the contents are laid out in,
+    /// e.g. the default width decides where long lines of text wrap.
+    FitContent,
+
+    /// Start at the default size, and then keep whatever size the user resizes it to.
+    /// Changing contents never changes the size: contents that do not fit are clipped,
+    /// so you may want to put them in a [`ScrollArea`].
+    RememberUserSize,
+
+    /// Always exactly this size. The user cannot resize it.
+    Fixed(Vec2),
+}
+
+impl Default for SizePolicy {
+    fn default() -> Self {
+        Self::GrowToContent
+    }
+}
+
+impl SizePolicy {
+    /// Is the size decided without looking at the contents?
+    fn ignores_content(&self) -> bool {
+        matches!(self, Self::RememberUserSize | Self::Fixed(_))
+    }
+}
+
 /// A region that can be resized by dragging the bottom right corner.
 #[derive(Clone, Copy, Debug)]
 #[must_use = "You should call .show()"]
@@ -31,6 +68,8 @@ pub struct Resize {
 
     default_size: Vec2,
 
+    size_policy: SizePolicy,
+
     with_stroke: bool,
 }
 
@@ -43,6 +82,7 @@ impl Default for Resize {
             min_size: Vec2::splat(16.0),
             max_size: Vec2::splat(f32::INFINITY),
             default_size: vec2(320.0, 128.0), // TODO: preferred size of `Resize` area.
+            size_policy: SizePolicy::default(),
             with_stroke: true,
         }
     }
@@ -105,14 +145,27 @@ impl Resize {
         self
     }
 
-    /// Won't expand to larger than this
+    /// Won't expand to larger than this, neither when resized by the user nor when growing to fit the contents.
+    /// Contents that don't fit are clipped.
     pub fn max_size(mut self, max_size: impl Into<Vec2>) -> Self {
         self.max_size = max_size.into();
         self
     }
+    /// Won't expand to larger than this
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_size.x = max_width;
+        self
+    }
+    /// Won't expand to larger than this
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_size.y = max_height;
+        self
+    }
 
     /// Can you resize it with the mouse?
-    /// Note that a window can still auto-resize
+    /// Note that a window can still auto-resize, depending on its [`SizePolicy`].
+    ///
+    /// Ignored for [`SizePolicy::FitContent`] and [`SizePolicy::Fixed`].
     pub fn resizable(mut self, resizable: bool) -> Self {
         self.resizable = resizable;
         self
@@ -120,6 +173,21 @@ impl Resize {
 
     pub fn is_resizable(&self) -> bool {
         self.resizable
+            && !matches!(
+                self.size_policy,
+                SizePolicy::FitContent | SizePolicy::Fixed(_)
+            )
+    }
+
+    /// How the size follows the contents. [`SizePolicy::GrowToContent`] by default.
+    pub fn size_policy(mut self, size_policy: SizePolicy) -> Self {
+        if let SizePolicy::Fixed(size) = size_policy {
+            self.default_size = size;
+            self.min_size = size;
+            self.max_size = size;
+        }
+        self.size_policy = size_policy;
+        self
     }
 
     /// Not manually resizable, just takes the size of its contents.
@@ -127,16 +195,12 @@ impl Resize {
     pub fn auto_sized(self) -> Self {
         self.min_size(Vec2::ZERO)
             .default_size(Vec2::splat(f32::INFINITY))
-            .resizable(false)
+            .size_policy(SizePolicy::FitContent)
     }
 
-    pub fn fixed_size(mut self, size: impl Into<Vec2>) -> Self {
-        let size = size.into();
-        self.default_size = size;
-        self.min_size = size;
-        self.max_size = size;
-        self.resizable = false;
-        self
+    /// Same as `.size_policy(SizePolicy::Fixed(size))`.
+    pub fn fixed_size(self, size: impl Into<Vec2>) -> Self {
+        self.size_policy(SizePolicy::Fixed(size.into()))
     }
 
     pub fn with_stroke(mut self, with_stroke: bool) -> Self {
@@ -160,17 +224,17 @@ impl Resize {
             ui.make_persistent_id(id_source)
         });
 
+        let default_size = self
+            .default_size
+            .at_least(self.min_size)
+            .at_most(self.max_size)
+            .at_most(
+                ui.input().screen_rect().size() - 2.0 * ui.spacing().window_padding, // hack for windows
+            );
+
         let mut state = *ui.memory().id_data.get_or_insert_with(id, || {
             ui.ctx().request_repaint(); // counter frame delay
 
-            let default_size = self
-                .default_size
-                .at_least(self.min_size)
-                .at_most(self.max_size)
-                .at_most(
-                    ui.input().screen_rect().size() - 2.0 * ui.spacing().window_padding, // hack for windows
-                );
-
             State {
                 desired_size: default_size,
                 last_content_size: vec2(0.0, 0.0),
@@ -185,7 +249,7 @@ impl Resize {
 
         let mut user_requested_size = state.requested_size.take();
 
-        let corner_response = if self.resizable {
+        let corner_response = if self.is_resizable() {
             // Resize-corner:
             let corner_size = Vec2::splat(ui.visuals().resize_corner_size);
             let corner_rect =
@@ -202,9 +266,12 @@ impl Resize {
             None
         };
 
-        if let Some(user_requested_size) = user_requested_size {
+        if self.size_policy == SizePolicy::FitContent {
+            // Lay out in the default size every frame, so that we can shrink again:
+            state.desired_size = default_size;
+        } else if let Some(user_requested_size) = user_requested_size {
             state.desired_size = user_requested_size;
-        } else {
+        } else if self.size_policy == SizePolicy::GrowToContent {
             // We are not being actively resized, so auto-expand to include size of last frame.
             // This prevents auto-shrinking if the contents contain width-filling widgets (separators etc)
             // but it makes a lot of interactions with `Window`s nicer.
@@ -222,14 +289,18 @@ impl Resize {
 
         let mut content_clip_rect = inner_rect.expand(ui.visuals().clip_rect_margin);
 
-        // If we pull the resize handle to shrink, we want to TRY to shrink it.
-        // After laying out the contents, we might be much bigger.
-        // In those cases we don't want the clip_rect to be smaller, because
-        // then we will clip the contents of the region even thought the result gets larger. This is simply ugly!
-        // So we use the memory of last_content_size to make the clip rect large enough.
-        content_clip_rect.max = content_clip_rect.max.max(
-            inner_rect.min + state.last_content_size + Vec2::splat(ui.visuals().clip_rect_margin),
-        );
+        if !self.size_policy.ignores_content() {
+            // If we pull the resize handle to shrink, we want to TRY to shrink it.
+            // After laying out the contents, we might be much bigger.
+            // In those cases we don't want the clip_rect to be smaller, because
+            // then we will clip the contents of the region even thought the result gets larger. This is simply ugly!
+            // So we use the memory of last_content_size to make the clip rect large enough.
+            content_clip_rect.max = content_clip_rect.max.max(
+                inner_rect.min
+                    + state.last_content_size.at_most(self.max_size)
+                    + Vec2::splat(ui.visuals().clip_rect_margin),
+            );
+        }
 
         content_clip_rect = content_clip_rect.intersect(ui.clip_rect()); // Respect parent region
 
@@ -263,24 +334,32 @@ impl Resize {
 
         // ------------------------------
 
-        let size = if self.with_stroke || self.resizable {
+        let size = if self.size_policy.ignores_content() {
+            state.desired_size
+        } else if self.size_policy == SizePolicy::GrowToContent
+            && (self.with_stroke || self.resizable)
+        {
             // We show how large we are,
             // so we must follow the contents:
 
-            state.desired_size = state.desired_size.max(state.last_content_size);
+            state.desired_size = state
+                .desired_size
+                .max(state.last_content_size)
+                .at_most(self.max_size);
 
             // We are as large as we look
             state.desired_size
         } else {
-            // Probably a window.
+            // Probably a window, or fitting the contents.
             state.last_content_size
         };
+        let size = size.at_least(self.min_size).at_most(self.max_size);
         ui.advance_cursor_after_rect(Rect::from_min_size(content_ui.min_rect().min, size));
 
         // ------------------------------
 
         if self.with_stroke && corner_response.is_some() {
-            let rect = Rect::from_min_size(content_ui.min_rect().left_top(), state.desired_size);
+            let rect = Rect::from_min_size(content_ui.min_rect().left_top(), size);
             let rect = rect.expand(2.0); // breathing room for content
             ui.painter().add(epaint::Shape::Rect {
                 rect,
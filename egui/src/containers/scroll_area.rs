@@ -1,4 +1,4 @@
-use crate::*;
+use crate::{style::ScrollBarVisuals, *};
 
 #[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
@@ -7,35 +7,96 @@ pub(crate) struct State {
     /// Positive offset means scrolling down/right
     offset: Vec2,
 
-    show_scroll: bool,
+    /// Were the horizontal/vertical scroll bars shown last frame?
+    show_scroll: [bool; 2],
 
     /// Momentum, used for kinetic scrolling
     #[cfg_attr(feature = "persistence", serde(skip))]
     pub vel: Vec2,
-    /// Mouse offset relative to the top of the handle when started moving the handle.
-    scroll_start_offset_from_top: Option<f32>,
+    /// Mouse offset relative to the top (or left) of the handle when started moving the handle.
+    scroll_start_offset_from_top: [Option<f32>; 2],
+
+    /// Ongoing smooth scroll, if any.
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    animation: Option<ScrollAnimation>,
+
+    /// Was the scroll area scrolled to the bottom at the end of last frame?
+    /// Used by [`ScrollArea::stick_to_bottom`].
+    scrolled_to_bottom: bool,
+
+    /// When the scroll area was last hovered, scrolled or dragged.
+    /// Used to fade out the scroll bars when [`ScrollBarVisuals::auto_hide`] is set.
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    last_activity_time: f64,
+}
+
+/// An ongoing smooth scroll (see [`ScrollArea::smooth_scrolling`]).
+#[derive(Clone, Copy, Debug)]
+struct ScrollAnimation {
+    from_offset_y: f32,
+    to_offset_y: f32,
+    start_time: f64,
 }
 
 impl Default for State {
     fn default() -> Self {
         Self {
             offset: Vec2::ZERO,
-            show_scroll: false,
+            show_scroll: [false; 2],
             vel: Vec2::ZERO,
-            scroll_start_offset_from_top: None,
+            scroll_start_offset_from_top: [None; 2],
+            animation: None,
+            scrolled_to_bottom: true,
+            last_activity_time: f64::NEG_INFINITY,
+        }
+    }
+}
+
+impl State {
+    /// Scroll to the given vertical offset, either directly or with an animation.
+    fn scroll_to_y(&mut self, offset_y: f32, time: f64, animate: bool) {
+        if animate {
+            self.animation = Some(ScrollAnimation {
+                from_offset_y: self.offset.y,
+                to_offset_y: offset_y,
+                start_time: time,
+            });
+        } else {
+            self.offset.y = offset_y;
+            self.animation = None;
         }
     }
+
+    /// Where we will end up once any ongoing smooth scroll is done.
+    fn target_offset_y(&self) -> f32 {
+        self.animation
+            .map_or(self.offset.y, |animation| animation.to_offset_y)
+    }
 }
 
-// TODO: rename VScroll
-/// Add vertical scrolling to a contained [`Ui`].
+/// Add vertical and/or horizontal scrolling to a contained [`Ui`].
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// egui::ScrollArea::both().max_height(200.0).show(ui, |ui| {
+///     ui.label("A very long and very wide text");
+/// });
+/// ```
 #[derive(Clone, Debug)]
 #[must_use = "You should call .show()"]
 pub struct ScrollArea {
-    max_height: f32,
+    /// Do we have horizontal/vertical scrolling?
+    has_bar: [bool; 2],
+    max_size: Vec2,
     always_show_scroll: bool,
     id_source: Option<Id>,
     offset: Option<Vec2>,
+    smooth_scroll_duration: f32,
+    scroll_easing: fn(f32) -> f32,
+    stick_to_bottom: bool,
+    overscroll_bounce: bool,
+    scroll_bar_width: Option<f32>,
+    auto_hide_scroll_bar: Option<bool>,
 }
 
 impl ScrollArea {
@@ -46,14 +107,59 @@ impl ScrollArea {
 
     /// Use `f32::INFINITY` if you want the scroll area to expand to fit the surrounding Ui
     pub fn from_max_height(max_height: f32) -> Self {
+        Self::vertical().max_height(max_height)
+    }
+
+    /// Create a vertical scroll area that fills the surrounding [`Ui`].
+    pub fn vertical() -> Self {
+        Self::new([false, true])
+    }
+
+    /// Create a horizontal scroll area.
+    ///
+    /// Hold shift to scroll it with a vertical mouse wheel.
+    pub fn horizontal() -> Self {
+        Self::new([true, false])
+    }
+
+    /// Create a scroll area that scrolls in both directions.
+    pub fn both() -> Self {
+        Self::new([true, true])
+    }
+
+    /// Scroll horizontally and/or vertically, `[horizontal, vertical]`.
+    pub(crate) fn new(has_bar: [bool; 2]) -> Self {
         Self {
-            max_height,
+            has_bar,
+            max_size: Vec2::INFINITY,
             always_show_scroll: false,
             id_source: None,
             offset: None,
+            smooth_scroll_duration: 0.0,
+            scroll_easing: ease_out_cubic,
+            stick_to_bottom: false,
+            overscroll_bounce: false,
+            scroll_bar_width: None,
+            auto_hide_scroll_bar: None,
         }
     }
 
+    /// The scroll area will never be wider than this.
+    ///
+    /// Default: `f32::INFINITY` (fill the surrounding [`Ui`]).
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_size.x = max_width;
+        self
+    }
+
+    /// The scroll area will never be higher than this.
+    ///
+    /// Default: `f32::INFINITY` (fill the surrounding [`Ui`]).
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_size.y = max_height;
+        self
+    }
+
     /// If `false` (default), the scroll bar will be hidden when not needed/
     /// If `true`, the scroll bar will always be displayed even if not needed.
     pub fn always_show_scroll(mut self, always_show_scroll: bool) -> Self {
@@ -75,13 +181,84 @@ impl ScrollArea {
         self.offset = Some(Vec2::new(0.0, offset));
         self
     }
+
+    /// Set the horizontal and vertical scroll offset position.
+    pub fn scroll_offset_2d(mut self, offset: Vec2) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Animate scrolling from the mouse wheel and from [`Ui::scroll_to_cursor`](crate::ui::Ui::scroll_to_cursor)
+    /// and [`Response::scroll_to_me`](crate::Response::scroll_to_me) over the given number of seconds.
+    ///
+    /// Dragging the content or the scroll bar is never animated.
+    ///
+    /// Default: `0.0` (no animation).
+    pub fn smooth_scrolling(mut self, duration: f32) -> Self {
+        self.smooth_scroll_duration = duration;
+        self
+    }
+
+    /// The easing function used by [`Self::smooth_scrolling`].
+    /// It maps the time fraction `[0, 1]` to the distance fraction `[0, 1]`.
+    ///
+    /// Default: ease-out cubic.
+    pub fn scroll_easing(mut self, easing: fn(f32) -> f32) -> Self {
+        self.scroll_easing = easing;
+        self
+    }
+
+    /// Keep the view scrolled to the bottom as content is added, e.g. for a log or a chat.
+    ///
+    /// Scrolling up releases the view, and scrolling back down to the bottom pins it again.
+    pub fn stick_to_bottom(mut self) -> Self {
+        self.stick_to_bottom = true;
+        self
+    }
+
+    /// Let dragging and flinging the contents go past the ends, with resistance,
+    /// and spring back when released, like on most touch screens.
+    ///
+    /// Default: `false`.
+    pub fn overscroll_bounce(mut self, overscroll_bounce: bool) -> Self {
+        self.overscroll_bounce = overscroll_bounce;
+        self
+    }
+
+    /// Width of the scroll bars, not including the margin to the contents.
+    ///
+    /// Default: [`ScrollBarVisuals::width`] of the current [`Visuals`].
+    pub fn scroll_bar_width(mut self, width: f32) -> Self {
+        self.scroll_bar_width = Some(width);
+        self
+    }
+
+    /// Fade out the scroll bars after a moment of inactivity,
+    /// and fade them back in when the scroll area is hovered or scrolled.
+    /// The scroll bars keep their space, so the contents don't move when they fade.
+    ///
+    /// Default: [`ScrollBarVisuals::auto_hide`] of the current [`Visuals`].
+    pub fn auto_hide_scroll_bar(mut self, auto_hide: bool) -> Self {
+        self.auto_hide_scroll_bar = Some(auto_hide);
+        self
+    }
+}
+
+fn ease_out_cubic(t: f32) -> f32 {
+    1.0 - (1.0 - t).powi(3)
 }
 
 struct Prepared {
     id: Id,
     state: State,
-    current_scroll_bar_width: f32,
+    has_bar: [bool; 2],
+    /// How much space the horizontal (`x`) and vertical (`y`) scroll bars take up.
+    current_bar_use: Vec2,
     always_show_scroll: bool,
+    smooth_scroll_duration: f32,
+    stick_to_bottom: bool,
+    overscroll_bounce: bool,
+    scroll_bar: ScrollBarVisuals,
     inner_rect: Rect,
     content_ui: Ui,
 }
@@ -89,10 +266,17 @@ struct Prepared {
 impl ScrollArea {
     fn begin(self, ui: &mut Ui) -> Prepared {
         let Self {
-            max_height,
+            has_bar,
+            max_size,
             always_show_scroll,
             id_source,
             offset,
+            smooth_scroll_duration,
+            scroll_easing,
+            stick_to_bottom,
+            overscroll_bounce,
+            scroll_bar_width,
+            auto_hide_scroll_bar,
         } = self;
 
         let ctx = ui.ctx().clone();
@@ -103,47 +287,85 @@ impl ScrollArea {
 
         if let Some(offset) = offset {
             state.offset = offset;
+            state.animation = None;
+            state.scrolled_to_bottom = false;
+        }
+
+        if let Some(animation) = state.animation {
+            let t = if smooth_scroll_duration > 0.0 {
+                (ctx.input().time - animation.start_time) as f32 / smooth_scroll_duration
+            } else {
+                1.0
+            };
+            if t >= 1.0 {
+                state.offset.y = animation.to_offset_y;
+                state.animation = None;
+            } else {
+                let range = animation.from_offset_y..=animation.to_offset_y;
+                state.offset.y = lerp(range, scroll_easing(t));
+            }
         }
 
         // content: size of contents (generally large; that's why we want scroll bars)
         // outer: size of scroll area including scroll bar(s)
         // inner: excluding scroll bar(s). The area we clip the contents to.
 
-        let max_scroll_bar_width = max_scroll_bar_width_with_margin(ui);
-
-        let current_scroll_bar_width = if always_show_scroll {
-            max_scroll_bar_width
-        } else {
-            max_scroll_bar_width * ui.ctx().animate_bool(id, state.show_scroll)
+        let default_scroll_bar = ui.visuals().scroll_bar;
+        let scroll_bar = ScrollBarVisuals {
+            width: scroll_bar_width.unwrap_or(default_scroll_bar.width),
+            auto_hide: auto_hide_scroll_bar.unwrap_or(default_scroll_bar.auto_hide),
+            ..default_scroll_bar
         };
+        let max_scroll_bar_width = max_scroll_bar_width_with_margin(ui, &scroll_bar);
+
+        let mut current_bar_use = Vec2::ZERO;
+        for d in 0..2 {
+            if has_bar[d] {
+                current_bar_use[d] = if always_show_scroll {
+                    max_scroll_bar_width
+                } else {
+                    max_scroll_bar_width
+                        * ctx.animate_bool(bar_animation_id(id, d), state.show_scroll[d])
+                };
+            }
+        }
 
         let available_outer = ui.available_rect_before_wrap();
 
-        let outer_size = vec2(
-            available_outer.width(),
-            available_outer.height().at_most(max_height),
-        );
+        let outer_size = available_outer.size().at_most(max_size);
 
-        let inner_size = outer_size - vec2(current_scroll_bar_width, 0.0);
+        // The vertical bar takes up width, the horizontal bar takes up height:
+        let inner_size = outer_size - vec2(current_bar_use.y, current_bar_use.x);
         let inner_rect = Rect::from_min_size(available_outer.min, inner_size);
 
+        // The contents try to fit the width of the viewport, but may grow wider than it
+        // if horizontal scrolling is enabled.
+        let mut content_max_size = inner_size;
+        if has_bar[1] {
+            content_max_size.y = f32::INFINITY;
+        }
+
         let mut content_ui = ui.child_ui(
-            Rect::from_min_size(
-                inner_rect.min - state.offset,
-                vec2(inner_size.x, f32::INFINITY),
-            ),
+            Rect::from_min_size(inner_rect.min - state.offset, content_max_size),
             *ui.layout(),
         );
         let mut content_clip_rect = inner_rect.expand(ui.visuals().clip_rect_margin);
         content_clip_rect = content_clip_rect.intersect(ui.clip_rect());
-        content_clip_rect.max.x = ui.clip_rect().max.x - current_scroll_bar_width; // Nice handling of forced resizing beyond the possible
+        if !has_bar[0] {
+            content_clip_rect.max.x = ui.clip_rect().max.x - current_bar_use.y; // Nice handling of forced resizing beyond the possible
+        }
         content_ui.set_clip_rect(content_clip_rect);
 
         Prepared {
             id,
             state,
-            current_scroll_bar_width,
+            has_bar,
+            current_bar_use,
             always_show_scroll,
+            smooth_scroll_duration,
+            stick_to_bottom,
+            overscroll_bounce,
+            scroll_bar,
             inner_rect,
             content_ui,
         }
@@ -155,6 +377,109 @@ impl ScrollArea {
         prepared.end(ui);
         ret
     }
+
+    /// Efficiently show only the visible part of a large number of rows of the same height.
+    ///
+    /// `add_contents` is only called for the rows in view, and the rest is filled with empty space.
+    /// `row_height_sans_spacing` should not include `item_spacing.y`.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// let row_height = ui.fonts().row_height(egui::TextStyle::Body);
+    /// // let row_height = ui.spacing().interact_size.y; // if you are adding buttons instead of labels.
+    /// let num_rows = 100_000;
+    /// egui::ScrollArea::auto_sized().show_rows(ui, row_height, num_rows, |ui, row_range| {
+    ///     for row in row_range {
+    ///         ui.label(format!("Row {}/{}", row + 1, num_rows));
+    ///     }
+    /// });
+    /// ```
+    pub fn show_rows<R>(
+        self,
+        ui: &mut Ui,
+        row_height_sans_spacing: f32,
+        total_rows: usize,
+        add_contents: impl FnOnce(&mut Ui, std::ops::Range<usize>) -> R,
+    ) -> R {
+        let spacing = ui.spacing().item_spacing.y;
+        let row_height_with_spacing = row_height_sans_spacing + spacing;
+
+        let mut prepared = self.begin(ui);
+        let content_ui = &mut prepared.content_ui;
+
+        // The visible part of the contents, relative to their top:
+        let top = content_ui.max_rect().top();
+        let clip_rect = content_ui.clip_rect();
+        let visible_range = (clip_rect.top() - top)..=(clip_rect.bottom() - top);
+
+        let max_row = ((*visible_range.end() / row_height_with_spacing).ceil() as usize + 1)
+            .at_most(total_rows);
+        let min_row =
+            ((*visible_range.start() / row_height_with_spacing).floor() as usize).at_most(max_row);
+
+        let rows_rect = Rect::from_x_y_ranges(
+            content_ui.max_rect().x_range(),
+            top + min_row as f32 * row_height_with_spacing
+                ..=top + max_row as f32 * row_height_with_spacing,
+        );
+        let ret = content_ui
+            .allocate_ui_at_rect(rows_rect, |ui| add_contents(ui, min_row..max_row))
+            .inner;
+
+        // Make the contents as high as if all rows had been added:
+        let total_height = total_rows as f32 * row_height_with_spacing - spacing;
+        content_ui.set_min_height(total_height.at_least(0.0));
+
+        prepared.end(ui);
+        ret
+    }
+
+    /// Like [`Self::show`], but only wrap the contents in the scroll area
+    /// if they didn't fit in the available space last frame,
+    /// and only scroll in the directions they didn't fit in.
+    ///
+    /// Used by the `auto_scroll` option of panels and windows.
+    pub(crate) fn show_if_overflowing<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> R {
+        let id_source = self.id_source.unwrap_or_else(|| Id::new("scroll_area"));
+        let overflow_id = ui.make_persistent_id(id_source).with("overflowing");
+        let was_overflowing = ui
+            .memory()
+            .id_data_temp
+            .get::<[bool; 2]>(&overflow_id)
+            .copied()
+            .unwrap_or([false; 2]);
+        let available_size = ui.available_rect_before_wrap().size();
+
+        let mut content_size = Vec2::ZERO;
+        let add_and_measure = |ui: &mut Ui| {
+            let InnerResponse { inner, response } = ui.scope(add_contents);
+            content_size = response.rect.size();
+            inner
+        };
+        let has_bar = [
+            self.has_bar[0] && was_overflowing[0],
+            self.has_bar[1] && was_overflowing[1],
+        ];
+        let inner = if has_bar[0] || has_bar[1] {
+            Self { has_bar, ..self }.show(ui, add_and_measure)
+        } else {
+            add_and_measure(ui)
+        };
+
+        let is_overflowing = [
+            content_size.x > available_size.x + 0.5,
+            content_size.y > available_size.y + 0.5,
+        ];
+        if is_overflowing != was_overflowing {
+            ui.memory().id_data_temp.insert(overflow_id, is_overflowing);
+            ui.ctx().request_repaint();
+        }
+        inner
+    }
 }
 
 impl Prepared {
@@ -162,55 +487,128 @@ impl Prepared {
         let Prepared {
             id,
             mut state,
-            inner_rect,
+            has_bar,
+            mut current_bar_use,
             always_show_scroll,
-            mut current_scroll_bar_width,
+            smooth_scroll_duration,
+            stick_to_bottom,
+            overscroll_bounce,
+            scroll_bar,
+            inner_rect,
             content_ui,
         } = self;
 
+        let initial_offset = state.offset;
         let content_size = content_ui.min_size();
+        let time = ui.input().time;
+        let smooth_scroll = smooth_scroll_duration > 0.0;
+
+        if stick_to_bottom && has_bar[1] && state.scrolled_to_bottom && state.animation.is_none() {
+            // Follow any content that was added since last frame:
+            let bottom_offset = (content_size.y - inner_rect.height()).at_least(0.0);
+            if state.offset.y != bottom_offset {
+                state.offset.y = bottom_offset;
+                ui.ctx().request_repaint();
+            }
+        }
 
-        // We take the scroll target so only this ScrollArea will use it.
-        let scroll_target = content_ui.ctx().frame_state().scroll_target.take();
-        if let Some((scroll_y, align)) = scroll_target {
-            let center_factor = align.to_factor();
-
-            let top = content_ui.min_rect().top();
-            let visible_range = top..=top + content_ui.clip_rect().height();
-            let offset_y = scroll_y - lerp(visible_range, center_factor);
-
-            let mut spacing = ui.spacing().item_spacing.y;
-
-            // Depending on the alignment we need to add or subtract the spacing
-            spacing *= remap(center_factor, 0.0..=1.0, -1.0..=1.0);
+        for d in 0..2 {
+            if !has_bar[d] {
+                continue;
+            }
 
-            state.offset.y = offset_y + spacing;
+            // We take the scroll target so only this ScrollArea will use it.
+            let scroll_target = content_ui.ctx().frame_state().scroll_target[d].take();
+            if let Some((target_range, align)) = scroll_target {
+                // Everything in content coordinates:
+                let content_min = content_ui.min_rect().min[d];
+                let target_range =
+                    (*target_range.start() - content_min)..=(*target_range.end() - content_min);
+                let current_offset = if d == 1 {
+                    state.target_offset_y()
+                } else {
+                    state.offset.x
+                };
+                let spacing = ui.spacing().item_spacing[d];
+
+                let new_offset = if let Some(align) = align {
+                    let center_factor = align.to_factor();
+                    let visible_range = 0.0..=content_ui.clip_rect().size()[d];
+                    let offset =
+                        lerp(target_range, center_factor) - lerp(visible_range, center_factor);
+
+                    // Depending on the alignment we need to add or subtract the spacing
+                    Some(offset + spacing * remap(center_factor, 0.0..=1.0, -1.0..=1.0))
+                } else {
+                    // Scroll as little as possible to make the target visible:
+                    let visible_size = inner_rect.size()[d];
+                    if *target_range.start() < current_offset {
+                        Some(*target_range.start() - spacing)
+                    } else if *target_range.end() > current_offset + visible_size {
+                        Some(*target_range.end() - visible_size + spacing)
+                    } else {
+                        None
+                    }
+                };
+
+                if let Some(new_offset) = new_offset {
+                    if d == 1 {
+                        state.scroll_to_y(new_offset, time, smooth_scroll);
+                    } else {
+                        state.offset.x = new_offset;
+                    }
+                }
+            }
         }
 
-        let width = if inner_rect.width().is_finite() {
-            inner_rect.width().max(content_size.x) // Expand width to fit content
-        } else {
-            // ScrollArea is in an infinitely wide parent
-            content_size.x
+        let inner_rect = {
+            let mut inner_size = inner_rect.size();
+            if !has_bar[0] {
+                inner_size.x = if inner_size.x.is_finite() {
+                    inner_size.x.max(content_size.x) // Expand width to fit content
+                } else {
+                    // ScrollArea is in an infinitely wide parent
+                    content_size.x
+                };
+            } else if !inner_size.x.is_finite() {
+                inner_size.x = content_size.x;
+            }
+            if !has_bar[1] {
+                inner_size.y = content_size.y;
+            }
+            Rect::from_min_size(inner_rect.min, inner_size)
         };
 
-        let inner_rect = Rect::from_min_size(inner_rect.min, vec2(width, inner_rect.height()));
-
         let outer_rect = Rect::from_min_size(
             inner_rect.min,
-            inner_rect.size() + vec2(current_scroll_bar_width, 0.0),
+            inner_rect.size() + vec2(current_bar_use.y, current_bar_use.x),
         );
 
-        let content_is_too_small = content_size.y > inner_rect.height();
+        let content_is_too_large = [
+            has_bar[0] && content_size.x > inner_rect.width(),
+            has_bar[1] && content_size.y > inner_rect.height(),
+        ];
+        let max_offset = content_size - inner_rect.size();
 
-        if content_is_too_small {
+        if content_is_too_large[0] || content_is_too_large[1] {
             // Drag contents to scroll (for touch screens mostly):
             let content_response = ui.interact(inner_rect, id.with("area"), Sense::drag());
 
             let input = ui.input();
             if content_response.dragged() {
-                state.offset.y -= input.pointer.delta().y;
+                for d in 0..2 {
+                    if content_is_too_large[d] {
+                        let mut delta = input.pointer.delta()[d];
+                        let overscrolled = state.offset[d] < 0.0 || state.offset[d] > max_offset[d];
+                        if overscroll_bounce && overscrolled {
+                            // Resist dragging past the ends:
+                            delta *= 0.5;
+                        }
+                        state.offset[d] -= delta;
+                    }
+                }
                 state.vel = input.pointer.velocity();
+                state.animation = None;
             } else {
                 let stop_speed = 20.0; // Pixels per second.
                 let friction_coeff = 1000.0; // Pixels per second squared.
@@ -223,101 +621,199 @@ impl Prepared {
                     state.vel -= friction * state.vel.normalized();
                     // Offset has an inverted coordinate system compared to
                     // the velocity, so we subtract it instead of adding it
-                    state.offset.y -= state.vel.y * dt;
+                    for d in 0..2 {
+                        if content_is_too_large[d] {
+                            state.offset[d] -= state.vel[d] * dt;
+                        }
+                    }
                     ui.ctx().request_repaint();
                 }
+
+                if overscroll_bounce {
+                    for d in 0..2 {
+                        let edge = state.offset[d].clamp(0.0, max_offset[d].at_least(0.0));
+                        let overscroll = state.offset[d] - edge;
+                        if content_is_too_large[d] && overscroll != 0.0 {
+                            // Brake hard past the end, and spring back:
+                            state.vel[d] *= (-20.0 * dt).exp();
+                            state.offset[d] = edge + overscroll * (-10.0 * dt).exp();
+                            if (state.offset[d] - edge).abs() < 0.5 {
+                                state.offset[d] = edge;
+                                state.vel[d] = 0.0;
+                            }
+                            ui.ctx().request_repaint();
+                        }
+                    }
+                }
             }
         }
 
-        let max_offset = content_size.y - inner_rect.height();
-        if ui.rect_contains_pointer(outer_rect) {
+        let is_hovered = ui.rect_contains_pointer(outer_rect);
+        if is_hovered {
+            let shift = ui.input().modifiers.shift;
             let mut frame_state = ui.ctx().frame_state();
-            let scroll_delta = frame_state.scroll_delta;
+            let mut scroll_delta = frame_state.scroll_delta;
+            if shift && has_bar[0] {
+                // Shift turns a vertical mouse wheel into horizontal scrolling:
+                scroll_delta = vec2(scroll_delta.x + scroll_delta.y, 0.0);
+            }
+
+            let mut consumed = false;
+
+            if has_bar[1] {
+                let target_offset_y = state.target_offset_y();
+                let scrolling_up = target_offset_y > 0.0 && scroll_delta.y > 0.0;
+                let scrolling_down = target_offset_y < max_offset.y && scroll_delta.y < 0.0;
+
+                if scrolling_up || scrolling_down {
+                    if smooth_scroll {
+                        let new_offset_y = (target_offset_y - scroll_delta.y)
+                            .min(max_offset.y)
+                            .max(0.0);
+                        state.scroll_to_y(new_offset_y, time, true);
+                    } else {
+                        state.offset.y -= scroll_delta.y;
+                    }
+                    consumed = true;
+                }
+            }
 
-            let scrolling_up = state.offset.y > 0.0 && scroll_delta.y > 0.0;
-            let scrolling_down = state.offset.y < max_offset && scroll_delta.y < 0.0;
+            if has_bar[0] {
+                let scrolling_left = state.offset.x > 0.0 && scroll_delta.x > 0.0;
+                let scrolling_right = state.offset.x < max_offset.x && scroll_delta.x < 0.0;
 
-            if scrolling_up || scrolling_down {
-                state.offset.y -= scroll_delta.y;
+                if scrolling_left || scrolling_right {
+                    state.offset.x -= scroll_delta.x;
+                    consumed = true;
+                }
+            }
+
+            if consumed {
                 // Clear scroll delta so no parent scroll will use it.
                 frame_state.scroll_delta = Vec2::ZERO;
             }
         }
 
-        let show_scroll_this_frame = content_is_too_small || always_show_scroll;
+        let show_scroll_this_frame = [
+            content_is_too_large[0] || (has_bar[0] && always_show_scroll),
+            content_is_too_large[1] || (has_bar[1] && always_show_scroll),
+        ];
 
-        let max_scroll_bar_width = max_scroll_bar_width_with_margin(ui);
+        let max_scroll_bar_width = max_scroll_bar_width_with_margin(ui, &scroll_bar);
 
-        if show_scroll_this_frame && current_scroll_bar_width <= 0.0 {
-            // Avoid frame delay; start showing scroll bar right away:
-            current_scroll_bar_width = max_scroll_bar_width * ui.ctx().animate_bool(id, true);
-        }
+        let bar_opacity = if scroll_bar.auto_hide {
+            let is_active = is_hovered
+                || state.offset != initial_offset
+                || state.animation.is_some()
+                || state.scroll_start_offset_from_top != [None; 2];
+            if is_active {
+                state.last_activity_time = time;
+            }
+            let fade_delay = 1.0; // Seconds of inactivity before the scroll bars fade out.
+            let recently_active = time - state.last_activity_time < fade_delay;
+            if recently_active {
+                // Make sure we get a frame when it is time to fade out:
+                ui.ctx().request_repaint();
+            }
+            ui.ctx()
+                .animate_bool(id.with("auto_hide"), is_active || recently_active)
+        } else {
+            1.0
+        };
+
+        for d in 0..2 {
+            if show_scroll_this_frame[d] && current_bar_use[d] <= 0.0 {
+                // Avoid frame delay; start showing scroll bar right away:
+                current_bar_use[d] =
+                    max_scroll_bar_width * ui.ctx().animate_bool(bar_animation_id(id, d), true);
+            }
+
+            if current_bar_use[d] <= 0.0 {
+                continue;
+            }
 
-        if current_scroll_bar_width > 0.0 {
-            let animation_t = current_scroll_bar_width / max_scroll_bar_width;
+            let animation_t = current_bar_use[d] / max_scroll_bar_width;
             // margin between contents and scroll bar
             let margin = animation_t * ui.spacing().item_spacing.x;
-            let left = inner_rect.right() + margin;
-            let right = outer_rect.right();
-            let corner_radius = (right - left) / 2.0;
-            let top = inner_rect.top();
-            let bottom = inner_rect.bottom();
-
-            let outer_scroll_rect = Rect::from_min_max(
-                pos2(left, inner_rect.top()),
-                pos2(right, inner_rect.bottom()),
-            );
+
+            // "main" is along the scroll bar, "cross" is across it.
+            let min_main = inner_rect.min[d];
+            let max_main = inner_rect.max[d];
+            let (min_cross, max_cross) = if d == 0 {
+                (inner_rect.bottom() + margin, outer_rect.bottom())
+            } else {
+                (inner_rect.right() + margin, outer_rect.right())
+            };
+            let corner_radius = scroll_bar.corner_radius.min((max_cross - min_cross) / 2.0);
+
+            let bar_rect = |main: std::ops::RangeInclusive<f32>| {
+                if d == 0 {
+                    Rect::from_x_y_ranges(main, min_cross..=max_cross)
+                } else {
+                    Rect::from_x_y_ranges(min_cross..=max_cross, main)
+                }
+            };
+
+            let outer_scroll_rect = bar_rect(min_main..=max_main);
 
             let from_content =
-                |content_y| remap_clamp(content_y, 0.0..=content_size.y, top..=bottom);
+                |content| remap_clamp(content, 0.0..=content_size[d], min_main..=max_main);
 
-            let handle_rect = Rect::from_min_max(
-                pos2(left, from_content(state.offset.y)),
-                pos2(right, from_content(state.offset.y + inner_rect.height())),
+            let handle_rect = bar_rect(
+                from_content(state.offset[d])
+                    ..=from_content(state.offset[d] + inner_rect.size()[d]),
             );
 
-            let interact_id = id.with("vertical");
+            let interact_id = id.with(if d == 0 { "horizontal" } else { "vertical" });
             let response = ui.interact(outer_scroll_rect, interact_id, Sense::click_and_drag());
 
             if let Some(pointer_pos) = response.interact_pointer_pos() {
-                let scroll_start_offset_from_top =
-                    state.scroll_start_offset_from_top.get_or_insert_with(|| {
+                let scroll_start_offset_from_top = state.scroll_start_offset_from_top[d]
+                    .get_or_insert_with(|| {
                         if handle_rect.contains(pointer_pos) {
-                            pointer_pos.y - handle_rect.top()
+                            pointer_pos[d] - handle_rect.min[d]
                         } else {
-                            let handle_top_pos_at_bottom = bottom - handle_rect.height();
+                            let handle_top_pos_at_bottom = max_main - handle_rect.size()[d];
                             // Calculate the new handle top position, centering the handle on the mouse.
-                            let new_handle_top_pos = (pointer_pos.y - handle_rect.height() / 2.0)
-                                .clamp(top, handle_top_pos_at_bottom);
-                            pointer_pos.y - new_handle_top_pos
+                            let new_handle_top_pos = (pointer_pos[d] - handle_rect.size()[d] / 2.0)
+                                .clamp(min_main, handle_top_pos_at_bottom);
+                            pointer_pos[d] - new_handle_top_pos
                         }
                     });
 
-                let new_handle_top = pointer_pos.y - *scroll_start_offset_from_top;
-                state.offset.y = remap(new_handle_top, top..=bottom, 0.0..=content_size.y);
+                let new_handle_top = pointer_pos[d] - *scroll_start_offset_from_top;
+                state.offset[d] = remap(new_handle_top, min_main..=max_main, 0.0..=content_size[d]);
+                if d == 1 {
+                    state.animation = None;
+                }
             } else {
-                state.scroll_start_offset_from_top = None;
+                state.scroll_start_offset_from_top[d] = None;
             }
 
-            let unbounded_offset_y = state.offset.y;
-            state.offset.y = state.offset.y.max(0.0);
-            state.offset.y = state.offset.y.min(max_offset);
+            if !overscroll_bounce || response.dragged() {
+                let unbounded_offset = state.offset[d];
+                state.offset[d] = state.offset[d].max(0.0);
+                state.offset[d] = state.offset[d].min(max_offset[d]);
 
-            if state.offset.y != unbounded_offset_y {
-                state.vel = Vec2::ZERO;
+                if state.offset[d] != unbounded_offset {
+                    state.vel[d] = 0.0;
+                }
             }
 
             // Avoid frame-delay by calculating a new handle rect:
-            let mut handle_rect = Rect::from_min_max(
-                pos2(left, from_content(state.offset.y)),
-                pos2(right, from_content(state.offset.y + inner_rect.height())),
+            let mut handle_rect = bar_rect(
+                from_content(state.offset[d])
+                    ..=from_content(state.offset[d] + inner_rect.size()[d]),
             );
-            let min_handle_height = (2.0 * corner_radius).max(8.0);
-            if handle_rect.size().y < min_handle_height {
-                handle_rect = Rect::from_center_size(
-                    handle_rect.center(),
-                    vec2(handle_rect.size().x, min_handle_height),
-                );
+            let min_handle_size = (2.0 * corner_radius).max(8.0);
+            if handle_rect.size()[d] < min_handle_size {
+                let mut size = handle_rect.size();
+                size[d] = min_handle_size;
+                handle_rect = Rect::from_center_size(handle_rect.center(), size);
+            }
+
+            if bar_opacity <= 0.0 {
+                continue;
             }
 
             let visuals = ui.style().interact(&response);
@@ -325,38 +821,56 @@ impl Prepared {
             ui.painter().add(epaint::Shape::Rect {
                 rect: outer_scroll_rect,
                 corner_radius,
-                fill: ui.visuals().extreme_bg_color,
+                fill: scroll_bar.track_fill.linear_multiply(bar_opacity),
                 stroke: Default::default(),
-                // fill: visuals.bg_fill,
-                // stroke: visuals.bg_stroke,
             });
 
             ui.painter().add(epaint::Shape::Rect {
                 rect: handle_rect.expand(-2.0),
                 corner_radius,
-                fill: visuals.bg_fill,
-                stroke: visuals.bg_stroke,
+                fill: visuals.bg_fill.linear_multiply(bar_opacity),
+                stroke: Stroke::new(
+                    visuals.bg_stroke.width,
+                    visuals.bg_stroke.color.linear_multiply(bar_opacity),
+                ),
             });
         }
 
         let size = vec2(
             outer_rect.size().x,
-            outer_rect.size().y.min(content_size.y), // shrink if content is so small that we don't need scroll bars
+            // shrink if content is so small that we don't need scroll bars
+            outer_rect.size().y.min(content_size.y + current_bar_use.x),
         );
         ui.advance_cursor_after_rect(Rect::from_min_size(outer_rect.min, size));
 
-        if show_scroll_this_frame != state.show_scroll {
+        if show_scroll_this_frame != state.show_scroll || state.animation.is_some() {
             ui.ctx().request_repaint();
         }
 
-        state.offset.y = state.offset.y.min(content_size.y - inner_rect.height());
-        state.offset.y = state.offset.y.max(0.0);
+        for d in 0..2 {
+            if overscroll_bounce && content_is_too_large[d] {
+                continue; // Springs back by itself
+            }
+            state.offset[d] = state.offset[d].min(content_size[d] - inner_rect.size()[d]);
+            state.offset[d] = state.offset[d].max(0.0);
+        }
         state.show_scroll = show_scroll_this_frame;
+        state.scrolled_to_bottom =
+            state.target_offset_y() >= content_size.y - inner_rect.height() - 1.0;
 
         ui.memory().id_data.insert(id, state);
     }
 }
 
-fn max_scroll_bar_width_with_margin(ui: &Ui) -> f32 {
-    ui.spacing().item_spacing.x + 16.0
+/// The id used to animate the horizontal (`d == 0`) or vertical (`d == 1`) scroll bar.
+fn bar_animation_id(id: Id, d: usize) -> Id {
+    if d == 0 {
+        id.with("horizontal_animation")
+    } else {
+        id
+    }
+}
+
+fn max_scroll_bar_width_with_margin(ui: &Ui, scroll_bar: &ScrollBarVisuals) -> f32 {
+    ui.spacing().item_spacing.x + scroll_bar.width
 }
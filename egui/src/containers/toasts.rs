@@ -0,0 +1,189 @@
+//! Short notifications that disappear by themselves, see [`Toasts`].
+
+use crate::*;
+
+/// How a [`Toast`] looks.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ToastKind {
+    Info,
+    Warning,
+    Error,
+}
+
+/// A short notification, shown with [`Toasts`].
+#[derive(Clone, Debug)]
+pub struct Toast {
+    kind: ToastKind,
+    text: String,
+    duration: f32,
+}
+
+impl Toast {
+    pub fn new(kind: ToastKind, text: impl Into<String>) -> Self {
+        Self {
+            kind,
+            text: text.into(),
+            duration: 4.0,
+        }
+    }
+
+    pub fn info(text: impl Into<String>) -> Self {
+        Self::new(ToastKind::Info, text)
+    }
+
+    pub fn warning(text: impl Into<String>) -> Self {
+        Self::new(ToastKind::Warning, text)
+    }
+
+    pub fn error(text: impl Into<String>) -> Self {
+        Self::new(ToastKind::Error, text)
+    }
+
+    /// For how many seconds the toast is shown. Default: 4.
+    pub fn duration(mut self, duration: f32) -> Self {
+        self.duration = duration;
+        self
+    }
+}
+
+/// The toasts that have been added but not yet dismissed.
+#[derive(Clone, Debug, Default)]
+struct Queue {
+    next_id: u64,
+    /// Each toast, with its id and when it was added.
+    toasts: Vec<(u64, Toast, f64)>,
+}
+
+fn queue_id() -> Id {
+    Id::new("__toasts")
+}
+
+/// Shows short notifications stacked in a corner of the screen,
+/// which fade out after a while, or when clicked.
+///
+/// Add toasts from anywhere with [`Toasts::add`], and call [`Toasts::show`] once per frame:
+///
+/// ```
+/// # let mut ctx = egui::CtxRef::default();
+/// # ctx.begin_frame(Default::default());
+/// # let ctx = &ctx;
+/// # let ui = &mut egui::Ui::__test();
+/// if ui.button("Save").clicked() {
+///     egui::Toasts::add(ctx, egui::Toast::info("Saved!"));
+/// }
+///
+/// egui::Toasts::default()
+///     .anchor(egui::Align2::RIGHT_BOTTOM, [-8.0, -8.0])
+///     .show(ctx);
+/// ```
+#[must_use = "You should call .show()"]
+#[derive(Clone, Copy, Debug)]
+pub struct Toasts {
+    anchor: Align2,
+    offset: Vec2,
+    width: f32,
+}
+
+impl Default for Toasts {
+    fn default() -> Self {
+        Self {
+            anchor: Align2::RIGHT_BOTTOM,
+            offset: vec2(-8.0, -8.0),
+            width: 240.0,
+        }
+    }
+}
+
+impl Toasts {
+    /// Queue a toast, to be shown by [`Toasts::show`].
+    pub fn add(ctx: &CtxRef, toast: Toast) {
+        let time = ctx.input().time;
+        let mut memory = ctx.memory();
+        let queue = memory.id_data_temp.get_mut_or_default::<Queue>(queue_id());
+        queue.toasts.push((queue.next_id, toast, time));
+        queue.next_id += 1;
+        ctx.request_repaint();
+    }
+
+    /// Which corner (or edge) of the screen to show the toasts in, and how far from it.
+    /// The newest toast is closest to the corner. Default: the bottom right corner.
+    pub fn anchor(mut self, anchor: Align2, offset: impl Into<Vec2>) -> Self {
+        self.anchor = anchor;
+        self.offset = offset.into();
+        self
+    }
+
+    /// The width of each toast. Default: 240.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn show(self, ctx: &CtxRef) {
+        let Self {
+            anchor,
+            mut offset,
+            width,
+        } = self;
+
+        let now = ctx.input().time;
+        let mut queue = ctx
+            .memory()
+            .id_data_temp
+            .get_or_default::<Queue>(queue_id())
+            .clone();
+        // Stack away from the edge we are anchored to:
+        let direction = if anchor.y() == Align::Max { -1.0 } else { 1.0 };
+        let spacing = ctx.style().spacing.item_spacing.y;
+
+        let mut shown = Vec::with_capacity(queue.toasts.len());
+        for (id, toast, mut added) in queue.toasts.into_iter().rev() {
+            let area_id = queue_id().with(id);
+            let time_left = added + toast.duration as f64 - now;
+            let opacity = ctx.animate_bool(area_id, time_left > 0.0);
+            if time_left <= 0.0 && opacity <= 0.0 {
+                continue;
+            }
+            if time_left > 0.0 {
+                ctx.request_repaint_after(time_left as f32);
+            }
+
+            let response = Area::new(area_id)
+                .order(Order::Foreground)
+                .anchor(anchor, offset)
+                .show(ctx, |ui| {
+                    toast_ui(ui, &toast, width, opacity);
+                });
+            if response.clicked() {
+                added = now - toast.duration as f64; // start fading out
+            }
+            offset.y += direction * (response.rect.height() + spacing);
+            shown.push((id, toast, added));
+        }
+        shown.reverse();
+        queue.toasts = shown;
+
+        ctx.memory().id_data_temp.insert(queue_id(), queue);
+    }
+}
+
+fn toast_ui(ui: &mut Ui, toast: &Toast, width: f32, opacity: f32) {
+    let visuals = ui.visuals();
+    let (icon, icon_color) = match toast.kind {
+        ToastKind::Info => ("ℹ", visuals.hyperlink_color),
+        ToastKind::Warning => ("⚠", visuals.warn_fg_color),
+        ToastKind::Error => ("❗", visuals.error_fg_color),
+    };
+    let text_color = visuals.text_color().linear_multiply(opacity);
+    let icon_color = icon_color.linear_multiply(opacity);
+
+    Frame::popup(ui.style())
+        .multiply_with_opacity(opacity)
+        .show(ui, |ui| {
+            ui.set_width(width);
+            ui.horizontal_wrapped(|ui| {
+                ui.add(Label::new(icon).text_color(icon_color));
+                ui.add(Label::new(&toast.text).text_color(text_color));
+            });
+        });
+}
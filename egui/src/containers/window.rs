@@ -5,14 +5,19 @@ use epaint::*;
 
 use super::*;
 
+/// Custom contents of the title bar, see [`Window::title_bar_ui`].
+type TitleBarUi<'a> = Box<dyn FnOnce(&mut Ui) + 'a>;
+
 /// Builder for a floating window which can be dragged, closed, collapsed, resized and scrolled (off by default).
 ///
 /// You can customize:
 /// * title
 /// * default, minimum, maximum and/or fixed size
-/// * if the window has a scroll area (off by default)
+/// * how the size follows the contents (see [`SizePolicy`])
+/// * if the window has a scroll area, or only when needed (off by default)
 /// * if the window can be collapsed (minimized) to just the title bar (yes, by default)
 /// * if there should be a close button (none by default)
+/// * if the window is modal, blocking everything behind it (no by default)
 ///
 /// ```
 /// # let mut ctx = egui::CtxRef::default();
@@ -28,20 +33,26 @@ pub struct Window<'open> {
     area: Area,
     frame: Option<Frame>,
     resize: Resize,
-    scroll: Option<ScrollArea>,
+    scroll: [bool; 2],
+    auto_scroll: [bool; 2],
     collapsible: bool,
     with_title_bar: bool,
+    title_bar_ui: Option<TitleBarUi<'open>>,
+    confirm_close: bool,
+    content_hash: Option<Id>,
+    modal: bool,
 }
 
 impl<'open> Window<'open> {
     /// The window title is used as a unique [`Id`] and must be unique, and should not change.
     /// This is true even if you disable the title bar with `.title_bar(false)`.
     /// If you need a changing title, you must call `window.id(…)` with a fixed id.
-    #[allow(clippy::needless_pass_by_value)]
-    pub fn new(title: impl ToString) -> Self {
-        let title = title.to_string();
-        let area = Area::new(&title);
-        let title_label = Label::new(title).text_style(TextStyle::Heading).wrap(false);
+    pub fn new(title: impl Into<WidgetText>) -> Self {
+        let mut title_label = Label::from(title.into()).wrap(false);
+        if title_label.text_style.is_none() {
+            title_label = title_label.text_style(TextStyle::Heading);
+        }
+        let area = Area::new(title_label.text());
         Self {
             title_label,
             open: None,
@@ -51,9 +62,14 @@ impl<'open> Window<'open> {
                 .with_stroke(false)
                 .min_size([96.0, 32.0])
                 .default_size([340.0, 420.0]), // Default inner size of a window
-            scroll: None,
+            scroll: [false; 2],
+            auto_scroll: [false; 2],
             collapsible: true,
             with_title_bar: true,
+            title_bar_ui: None,
+            confirm_close: false,
+            content_hash: None,
+            modal: false,
         }
     }
 
@@ -73,6 +89,33 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Don't close the window when the close button is clicked, so you can first ask e.g. "Save changes?".
+    ///
+    /// Check [`WindowResponse::close_clicked`] instead, and set the bool given to [`Self::open`]
+    /// to `false` yourself when the window should close.
+    ///
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// # ctx.begin_frame(Default::default());
+    /// # let ctx = &ctx;
+    /// # let mut open = true;
+    /// # let mut ask_to_save = false;
+    /// # let unsaved_changes = true;
+    /// let response = egui::Window::new("Document")
+    ///     .open(&mut open)
+    ///     .confirm_close(unsaved_changes)
+    ///     .show(ctx, |ui| {
+    ///         ui.label("…");
+    ///     });
+    /// if response.map_or(false, |response| response.close_clicked) {
+    ///     ask_to_save = true;
+    /// }
+    /// ```
+    pub fn confirm_close(mut self, confirm_close: bool) -> Self {
+        self.confirm_close = confirm_close;
+        self
+    }
+
     /// If `false` the window will be grayed out and non-interactive.
     pub fn enabled(mut self, enabled: bool) -> Self {
         self.area = self.area.enabled(enabled);
@@ -109,6 +152,32 @@ impl<'open> Window<'open> {
         self.resize = self.resize.min_height(min_height);
         self
     }
+    /// Set minimum size of the window, not counting the title bar and frame margins.
+    ///
+    /// The window can't be made smaller than this, neither by the user nor by auto-sizing.
+    pub fn min_size(mut self, min_size: impl Into<Vec2>) -> Self {
+        self.resize = self.resize.min_size(min_size);
+        self
+    }
+
+    /// Set maximum width of the window.
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.resize = self.resize.max_width(max_width);
+        self
+    }
+    /// Set maximum height of the window.
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.resize = self.resize.max_height(max_height);
+        self
+    }
+    /// Set maximum size of the window, not counting the title bar and frame margins.
+    ///
+    /// The window can't be made larger than this, neither by the user nor by growing to fit its contents.
+    /// Contents that don't fit are clipped, so you may want to use [`Self::vscroll`] or [`Self::hscroll`] too.
+    pub fn max_size(mut self, max_size: impl Into<Vec2>) -> Self {
+        self.resize = self.resize.max_size(max_size);
+        self
+    }
 
     /// Set current position of the window.
     /// If the window is movable it is up to you to keep track of where it moved to!
@@ -131,6 +200,22 @@ impl<'open> Window<'open> {
     /// The offset is added to the position, so e.g. an offset of `[-5.0, 5.0]`
     /// would move the window left and down from the given anchor.
     ///
+    /// The anchor overrides any position stored in memory (e.g. from before the window was anchored),
+    /// so the window stays glued to the corner or edge when the native window is resized.
+    /// This is useful for overlays like an FPS counter:
+    ///
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// # ctx.begin_frame(Default::default());
+    /// # let ctx = &ctx;
+    /// egui::Window::new("FPS")
+    ///     .anchor(egui::Align2::RIGHT_BOTTOM, [-8.0, -8.0])
+    ///     .title_bar(false)
+    ///     .show(ctx, |ui| {
+    ///         ui.label("60 FPS");
+    ///     });
+    /// ```
+    ///
     /// Anchoring also makes the window immovable.
     ///
     /// It is an error to set both an anchor and a position.
@@ -139,6 +224,21 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Keep the window next to another widget, following it as it moves or scrolls.
+    /// See [`Area::anchor_to_widget`].
+    pub fn anchor_to_widget(
+        mut self,
+        widget_id: Id,
+        pivot: Align2,
+        widget_point: Align2,
+        offset: impl Into<Vec2>,
+    ) -> Self {
+        self.area = self
+            .area
+            .anchor_to_widget(widget_id, pivot, widget_point, offset);
+        self
+    }
+
     /// Set initial size of the window.
     pub fn default_size(mut self, default_size: impl Into<Vec2>) -> Self {
         self.resize = self.resize.default_size(default_size);
@@ -168,6 +268,8 @@ impl<'open> Window<'open> {
     }
 
     /// Sets the window size and prevents it from being resized by dragging its edges.
+    ///
+    /// Same as `.size_policy(SizePolicy::Fixed(size))`.
     pub fn fixed_size(mut self, size: impl Into<Vec2>) -> Self {
         self.resize = self.resize.fixed_size(size);
         self
@@ -179,12 +281,39 @@ impl<'open> Window<'open> {
     }
 
     /// Can the user resize the window by dragging its edges?
-    /// Note that even if you set this to `false` the window may still auto-resize.
+    /// Note that even if you set this to `false` the window may still auto-resize,
+    /// depending on its [`SizePolicy`].
     pub fn resizable(mut self, resizable: bool) -> Self {
         self.resize = self.resize.resizable(resizable);
         self
     }
 
+    /// How the window size follows its contents. [`SizePolicy::GrowToContent`] by default.
+    ///
+    /// See also [`Self::fit_to_content`], [`Self::remember_user_size`] and [`Self::fixed_size`].
+    pub fn size_policy(mut self, size_policy: SizePolicy) -> Self {
+        self.resize = self.resize.size_policy(size_policy);
+        self
+    }
+
+    /// Always make the window exactly as large as its contents, growing and shrinking with them.
+    /// The user cannot resize the window.
+    ///
+    /// The default width (see [`Self::default_width`]) decides where text wraps.
+    ///
+    /// Same as `.size_policy(SizePolicy::FitContent)`.
+    pub fn fit_to_content(self) -> Self {
+        self.size_policy(SizePolicy::FitContent)
+    }
+
+    /// Keep the size the user resized the window to, no matter how the contents change.
+    /// Contents that don't fit are clipped, so you may want to combine this with [`Self::vscroll`] or [`Self::hscroll`].
+    ///
+    /// Same as `.size_policy(SizePolicy::RememberUserSize)`.
+    pub fn remember_user_size(self) -> Self {
+        self.size_policy(SizePolicy::RememberUserSize)
+    }
+
     /// Can the window be collapsed by clicking on its title?
     pub fn collapsible(mut self, collapsible: bool) -> Self {
         self.collapsible = collapsible;
@@ -198,28 +327,96 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Show your own widgets in the title bar instead of the title, e.g. an icon and some extra buttons.
+    ///
+    /// The collapse and close buttons are still shown,
+    /// and you can still drag the window (and double-click to collapse it) by the empty parts of the title bar.
+    /// The title given to [`Self::new`] is still used as the [`Id`] of the window.
+    ///
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// # ctx.begin_frame(Default::default());
+    /// # let ctx = &ctx;
+    /// egui::Window::new("Server")
+    ///     .title_bar_ui(|ui| {
+    ///         ui.colored_label(egui::Color32::GREEN, "⏺");
+    ///         ui.heading("Server");
+    ///         if ui.small_button("⟲").on_hover_text("Restart").clicked() {
+    ///             // …
+    ///         }
+    ///     })
+    ///     .show(ctx, |ui| {
+    ///         ui.label("Running");
+    ///     });
+    /// ```
+    pub fn title_bar_ui(mut self, title_bar_ui: impl FnOnce(&mut Ui) + 'open) -> Self {
+        self.title_bar_ui = Some(Box::new(title_bar_ui));
+        self
+    }
+
     /// Not resizable, just takes the size of its contents.
     /// Also disabled scrolling.
     /// Text will not wrap, but will instead make your window width expand.
+    ///
+    /// Like [`Self::fit_to_content`], but without a limit on the width.
     pub fn auto_sized(mut self) -> Self {
         self.resize = self.resize.auto_sized();
-        self.scroll = None;
+        self.scroll = [false; 2];
         self
     }
 
-    /// Enable/disable scrolling. `false` by default.
-    pub fn scroll(mut self, scroll: bool) -> Self {
-        if scroll {
-            if self.scroll.is_none() {
-                self.scroll = Some(ScrollArea::auto_sized());
-            }
-            crate::egui_assert!(
-                self.scroll.is_some(),
-                "Window::scroll called multiple times"
-            );
-        } else {
-            self.scroll = None;
-        }
+    /// Put the window contents in a vertical [`ScrollArea`], so they can be taller than the window.
+    /// `false` by default.
+    ///
+    /// The scroll area fills the height of the window,
+    /// so you can resize the window to any height, independent of the contents.
+    /// Use [`Self::auto_scroll`] instead to only add a scroll area when the contents don't fit.
+    pub fn vscroll(mut self, vscroll: bool) -> Self {
+        self.scroll[1] = vscroll;
+        self
+    }
+
+    /// Put the window contents in a horizontal [`ScrollArea`], so they can be wider than the window.
+    /// `false` by default.
+    ///
+    /// Can be combined with [`Self::vscroll`] to scroll in both directions.
+    pub fn hscroll(mut self, hscroll: bool) -> Self {
+        self.scroll[0] = hscroll;
+        self
+    }
+
+    #[deprecated = "Renamed vscroll"]
+    pub fn scroll(self, scroll: bool) -> Self {
+        self.vscroll(scroll)
+    }
+
+    /// Scroll the contents when they don't fit, see [`SidePanel::auto_scroll`].
+    ///
+    /// This happens when the window can't grow to fit its contents,
+    /// e.g. with [`SizePolicy::RememberUserSize`] or a fixed size.
+    /// Ignored if [`Self::vscroll`] or [`Self::hscroll`] is enabled.
+    pub fn auto_scroll(mut self, auto_scroll: [bool; 2]) -> Self {
+        self.auto_scroll = auto_scroll;
+        self
+    }
+
+    /// Keep the whole window on screen every frame, also when the screen is resized.
+    /// If the window is too large to fit, the title bar is kept on screen. Default: `false`.
+    ///
+    /// See also [`Context::move_window_onto_screen`].
+    pub fn constrain(mut self, constrain: bool) -> Self {
+        self.area = self.area.constrain(constrain);
+        self
+    }
+
+    /// Render the window at a different scale than the rest of the ui,
+    /// e.g. `2.0` for a magnified preview. Default: `1.0`.
+    ///
+    /// The finished window is scaled, so this is not a replacement for
+    /// [`crate::Context::set_pixels_per_point`] when the text needs to be sharp.
+    /// See [`Area::scale`] for the limitations.
+    pub fn scale(mut self, scale: f32) -> Self {
+        self.area = self.area.scale(scale);
         self
     }
 
@@ -228,11 +425,51 @@ impl<'open> Window<'open> {
         self.area = self.area.drag_bounds(bounds);
         self
     }
+
+    /// Make the window modal.
+    ///
+    /// While a modal window is open it stays on top of all other windows,
+    /// the rest of the screen is dimmed, and nothing behind it can be interacted with
+    /// (neither with the pointer nor the keyboard).
+    ///
+    /// Use this together with [`Self::open`] or only call `show` while the dialog should be shown,
+    /// or else the user will be stuck.
+    /// Only one modal window should be shown at a time.
+    ///
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// # ctx.begin_frame(Default::default());
+    /// # let ctx = &ctx;
+    /// let mut open = true;
+    /// egui::Window::new("Are you sure?")
+    ///     .modal(true)
+    ///     .open(&mut open)
+    ///     .show(ctx, |ui| {
+    ///         ui.label("This cannot be undone.");
+    ///     });
+    /// ```
+    pub fn modal(mut self, modal: bool) -> Self {
+        self.modal = modal;
+        self
+    }
+
+    /// Declare that the contents of the window only depend on this value.
+    ///
+    /// As long as the hash of it stays the same, and the user is not interacting with the window
+    /// (the pointer is not over it, and none of its widgets have keyboard focus),
+    /// `add_contents` is not called. Instead what the window painted last time is painted again.
+    ///
+    /// This can save a lot of CPU when you have many windows and only some of them change.
+    /// Make sure to include everything that affects the contents, or the window will show stale data!
+    pub fn content_hash(mut self, content_hash: impl std::hash::Hash) -> Self {
+        self.content_hash = Some(Id::new(content_hash));
+        self
+    }
 }
 
 impl<'open> Window<'open> {
     /// Returns `None` if the windows is not open (if [`Window::open`] was called with `&mut false`.
-    pub fn show(self, ctx: &CtxRef, add_contents: impl FnOnce(&mut Ui)) -> Option<Response> {
+    pub fn show(self, ctx: &CtxRef, add_contents: impl FnOnce(&mut Ui)) -> Option<WindowResponse> {
         self.show_impl(ctx, Box::new(add_contents))
     }
 
@@ -240,7 +477,7 @@ impl<'open> Window<'open> {
         self,
         ctx: &CtxRef,
         add_contents: Box<dyn FnOnce(&mut Ui) + 'c>,
-    ) -> Option<Response> {
+    ) -> Option<WindowResponse> {
         let Window {
             title_label,
             open,
@@ -248,8 +485,13 @@ impl<'open> Window<'open> {
             frame,
             resize,
             scroll,
+            auto_scroll,
             collapsible,
             with_title_bar,
+            title_bar_ui,
+            confirm_close,
+            content_hash,
+            modal,
         } = self;
 
         let frame = frame.unwrap_or_else(|| Frame::window(&ctx.style()));
@@ -263,6 +505,34 @@ impl<'open> Window<'open> {
 
         let area_id = area.id;
         let area_layer_id = area.layer();
+
+        let screen_rect = ctx.input().screen_rect();
+        if modal {
+            ctx.memory().areas.set_modal(area_layer_id);
+            ctx.register_opaque_rect(area_layer_id, screen_rect);
+        }
+
+        if let Some(content_hash) = content_hash {
+            if let Some(response) =
+                replay_cached_content(ctx, area_layer_id, area.is_enabled(), content_hash)
+            {
+                return Some(WindowResponse {
+                    response,
+                    close_clicked: false,
+                });
+            }
+        }
+        if modal {
+            // Painted first, so it ends up behind the window (and in the cached content):
+            let scrim = ctx.style().visuals.window_modal_scrim;
+            Painter::new(ctx.clone(), area_layer_id, screen_rect).rect_filled(
+                screen_rect,
+                0.0,
+                scrim,
+            );
+        }
+        let ids_before: Option<epaint::ahash::AHashSet<Id>> =
+            content_hash.map(|_| ctx.frame_state().used_ids.keys().copied().collect());
         let resize_id = area_id.with("resize");
         let collapsing_id = area_id.with("collapsing");
 
@@ -302,6 +572,8 @@ impl<'open> Window<'open> {
                     window_interaction,
                     ctx,
                     margins,
+                    resize.min_size,
+                    resize.max_size,
                     area_layer_id,
                     area.state_mut(),
                     resize_id,
@@ -314,6 +586,7 @@ impl<'open> Window<'open> {
         let hover_interaction = resize_hover(ctx, possible, area_layer_id, last_frame_outer_rect);
 
         let mut area_content_ui = area.content_ui(ctx);
+        let mut close_clicked = false;
 
         {
             // BEGIN FRAME --------------------------------
@@ -331,6 +604,7 @@ impl<'open> Window<'open> {
                 let title_bar = show_title_bar(
                     &mut frame.content_ui,
                     title_label,
+                    title_bar_ui,
                     show_close_button,
                     collapsing_id,
                     &mut collapsing,
@@ -349,8 +623,10 @@ impl<'open> Window<'open> {
                             ui.add_space(title_content_spacing);
                         }
 
-                        if let Some(scroll) = scroll {
-                            scroll.show(ui, add_contents);
+                        if scroll[0] || scroll[1] {
+                            ScrollArea::new(scroll).show(ui, add_contents);
+                        } else if auto_scroll[0] || auto_scroll[1] {
+                            ScrollArea::new(auto_scroll).show_if_overflowing(ui, add_contents);
                         } else {
                             add_contents(ui);
                         }
@@ -364,11 +640,11 @@ impl<'open> Window<'open> {
             // END FRAME --------------------------------
 
             if let Some(title_bar) = title_bar {
-                title_bar.ui(
+                close_clicked = title_bar.ui(
                     &mut area_content_ui,
                     outer_rect,
                     &content_response,
-                    open,
+                    show_close_button,
                     &mut collapsing,
                     collapsible,
                 );
@@ -399,7 +675,151 @@ impl<'open> Window<'open> {
         }
         let full_response = area.end(ctx, area_content_ui);
 
-        Some(full_response)
+        if let (Some(content_hash), Some(ids_before)) = (content_hash, ids_before) {
+            cache_content(ctx, area_layer_id, content_hash, &ids_before);
+        }
+
+        if close_clicked && !confirm_close {
+            if let Some(open) = open {
+                *open = false;
+            }
+        }
+
+        Some(WindowResponse {
+            response: full_response,
+            close_clicked,
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Returned by [`Window::show`].
+#[derive(Debug)]
+pub struct WindowResponse {
+    /// The response of the whole window.
+    pub response: Response,
+    /// Was the close button clicked this frame? See [`Window::confirm_close`].
+    pub close_clicked: bool,
+}
+
+// ----------------------------------------------------------------------------
+
+/// What a [`Window`] with a [`Window::content_hash`] painted, so that it can be painted again.
+#[derive(Clone)]
+struct CachedContent {
+    content_hash: Id,
+    style: std::sync::Arc<Style>,
+    screen_rect: Rect,
+    area_state: area::State,
+    shapes: Vec<ClippedShape>,
+    /// The ids registered by the widgets in the window, and where.
+    ids: Vec<(Id, Pos2)>,
+}
+
+fn cached_content_id(layer_id: LayerId) -> Id {
+    layer_id.id.with("cached_content")
+}
+
+/// Is the user (potentially) interacting with the window?
+fn is_interacting(ctx: &Context, cached: &CachedContent) -> bool {
+    let interact_radius = ctx.style().interaction.resize_grab_radius_side;
+    let rect = cached.area_state.rect().expand(interact_radius);
+    let pointer_over = ctx
+        .input()
+        .pointer
+        .hover_pos()
+        .map_or(false, |pos| rect.contains(pos));
+    let focus = ctx.memory().interaction.focus.focused();
+    let has_focus = focus.map_or(false, |focus| cached.ids.iter().any(|(id, _)| *id == focus));
+    pointer_over || has_focus
+}
+
+/// Paint what the window painted last time, if nothing has changed.
+fn replay_cached_content(
+    ctx: &CtxRef,
+    layer_id: LayerId,
+    enabled: bool,
+    content_hash: Id,
+) -> Option<Response> {
+    let cached = ctx
+        .memory()
+        .id_data_temp
+        .get::<CachedContent>(&cached_content_id(layer_id))
+        .cloned()?;
+
+    let unchanged = cached.content_hash == content_hash
+        && std::sync::Arc::ptr_eq(&cached.style, &ctx.style())
+        && cached.screen_rect == ctx.input().screen_rect()
+        && ctx.memory().areas.visible_last_frame(&layer_id)
+        && ctx
+            .memory()
+            .areas
+            .get(layer_id.id)
+            .map_or(false, |state| state.rect() == cached.area_state.rect());
+    if !unchanged || is_interacting(ctx, &cached) {
+        return None;
+    }
+
+    for (id, pos) in &cached.ids {
+        ctx.register_interaction_id(*id, *pos);
+    }
+    ctx.graphics()
+        .list(layer_id)
+        .lock()
+        .extend_clipped(cached.shapes.iter().cloned());
+
+    // Same as `Area::end`, so that clicking the window still brings it to the front:
+    let response = ctx.interact(
+        Rect::EVERYTHING,
+        ctx.style().spacing.item_spacing,
+        layer_id,
+        layer_id.id.with("move"),
+        cached.area_state.rect(),
+        Sense::click(),
+        enabled,
+    );
+    ctx.memory().areas.set_state(layer_id, cached.area_state);
+    Some(response)
+}
+
+/// Remember what the window painted this frame, unless it may look different next frame anyway.
+fn cache_content(
+    ctx: &CtxRef,
+    layer_id: LayerId,
+    content_hash: Id,
+    ids_before: &epaint::ahash::AHashSet<Id>,
+) {
+    let cache_id = cached_content_id(layer_id);
+    let area_state = match ctx.memory().areas.get(layer_id.id) {
+        Some(area_state) => *area_state,
+        None => return,
+    };
+    let cached = CachedContent {
+        content_hash,
+        style: ctx.style(),
+        screen_rect: ctx.input().screen_rect(),
+        area_state,
+        shapes: ctx
+            .graphics()
+            .list(layer_id)
+            .lock()
+            .clipped_shapes()
+            .to_vec(),
+        ids: ctx
+            .frame_state()
+            .used_ids
+            .iter()
+            .filter(|(id, _)| !ids_before.contains(id))
+            .map(|(id, pos)| (*id, *pos))
+            .collect(),
+    };
+
+    // Hover effects and animations would be frozen, and a focused widget must keep running:
+    if ctx.has_active_animations() || is_interacting(ctx, &cached) {
+        ctx.memory().id_data_temp.remove(&cache_id);
+    } else {
+        ctx.memory().id_data_temp.insert(cache_id, cached);
     }
 }
 
@@ -487,16 +907,25 @@ impl WindowInteraction {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn interact(
     window_interaction: WindowInteraction,
     ctx: &Context,
     margins: Vec2,
+    min_size: Vec2,
+    max_size: Vec2,
     area_layer_id: LayerId,
     area_state: &mut area::State,
     resize_id: Id,
     drag_bounds: Option<Rect>,
 ) -> Option<WindowInteraction> {
     let new_rect = move_and_resize_window(ctx, &window_interaction)?;
+    let new_rect = clamp_window_size(
+        new_rect,
+        &window_interaction,
+        min_size + margins,
+        max_size + margins,
+    );
     let new_rect = ctx.round_rect_to_pixels(new_rect);
 
     let new_rect = if let Some(bounds) = drag_bounds {
@@ -520,6 +949,28 @@ fn interact(
     Some(window_interaction)
 }
 
+/// Keep the size of a window being resized within `min_size..=max_size`
+/// by moving the edges being dragged, so that the opposite edges stay put.
+fn clamp_window_size(
+    mut rect: Rect,
+    window_interaction: &WindowInteraction,
+    min_size: Vec2,
+    max_size: Vec2,
+) -> Rect {
+    let size = rect.size().at_most(max_size).at_least(min_size);
+    if window_interaction.left {
+        rect.min.x = rect.max.x - size.x;
+    } else if window_interaction.right {
+        rect.max.x = rect.min.x + size.x;
+    }
+    if window_interaction.top {
+        rect.min.y = rect.max.y - size.y;
+    } else if window_interaction.bottom {
+        rect.max.y = rect.min.y + size.y;
+    }
+    rect
+}
+
 fn move_and_resize_window(ctx: &Context, window_interaction: &WindowInteraction) -> Option<Rect> {
     window_interaction.set_cursor(ctx);
     let pointer_pos = ctx.input().pointer.interact_pos()?;
@@ -733,7 +1184,8 @@ fn paint_frame_interaction(
 struct TitleBar {
     id: Id,
     title_label: Label,
-    title_galley: std::sync::Arc<Galley>,
+    /// `None` if the title bar was filled with [`Window::title_bar_ui`].
+    title_galley: Option<std::sync::Arc<Galley>>,
     min_rect: Rect,
     rect: Rect,
 }
@@ -741,6 +1193,7 @@ struct TitleBar {
 fn show_title_bar(
     ui: &mut Ui,
     title_label: Label,
+    title_bar_ui: Option<TitleBarUi<'_>>,
     show_close_button: bool,
     collapsing_id: Id,
     collapsing: &mut collapsing_header::State,
@@ -769,14 +1222,26 @@ fn show_title_bar(
             collapsing_header::paint_icon(ui, openness, &collapse_button_response);
         }
 
-        let title_galley = title_label.layout(ui);
-
-        let minimum_width = if collapsible || show_close_button {
-            // If at least one button is shown we make room for both buttons (since title is centered):
-            2.0 * (pad + button_size.x + item_spacing.x) + title_galley.size.x
+        let (title_galley, minimum_width) = if let Some(title_bar_ui) = title_bar_ui {
+            title_bar_ui(ui);
+            // Make room for the close button, which is added at the end:
+            let close_button_width = if show_close_button {
+                item_spacing.x + button_size.x + pad
+            } else {
+                pad
+            };
+            (None, ui.min_rect().width() + close_button_width)
         } else {
-            pad + title_galley.size.x + pad
+            let title_galley = title_label.layout(ui);
+            let minimum_width = if collapsible || show_close_button {
+                // If at least one button is shown we make room for both buttons (since title is centered):
+                2.0 * (pad + button_size.x + item_spacing.x) + title_galley.size.x
+            } else {
+                pad + title_galley.size.x + pad
+            };
+            (Some(title_galley), minimum_width)
         };
+        let height = height.max(ui.min_rect().height());
         let min_rect = Rect::from_min_size(ui.min_rect().min, vec2(minimum_width, height));
         let id = ui.advance_cursor_after_rect(min_rect);
 
@@ -801,21 +1266,17 @@ impl TitleBar {
         ui: &mut Ui,
         outer_rect: Rect,
         content_response: &Option<Response>,
-        open: Option<&mut bool>,
+        show_close_button: bool,
         collapsing: &mut collapsing_header::State,
         collapsible: bool,
-    ) {
+    ) -> bool {
         if let Some(content_response) = &content_response {
             // Now we know how large we got to be:
             self.rect.max.x = self.rect.max.x.max(content_response.rect.max.x);
         }
 
-        if let Some(open) = open {
-            // Add close button now that we know our full width:
-            if self.close_button_ui(ui).clicked() {
-                *open = false;
-            }
-        }
+        // Add close button now that we know our full width:
+        let close_clicked = show_close_button && self.close_button_ui(ui).clicked();
 
         // Always have inactive style for the window.
         // It is VERY annoying to e.g. change it when moving the window.
@@ -823,11 +1284,12 @@ impl TitleBar {
 
         self.title_label = self.title_label.text_color(style.fg_stroke.color);
 
-        let full_top_rect = Rect::from_x_y_ranges(self.rect.x_range(), self.min_rect.y_range());
-        let text_pos = emath::align::center_size_in_rect(self.title_galley.size, full_top_rect);
-        let text_pos = text_pos.left_top() - 1.5 * Vec2::Y; // HACK: center on x-height of text (looks better)
-        self.title_label
-            .paint_galley(ui, text_pos, self.title_galley);
+        if let Some(title_galley) = self.title_galley {
+            let full_top_rect = Rect::from_x_y_ranges(self.rect.x_range(), self.min_rect.y_range());
+            let text_pos = emath::align::center_size_in_rect(title_galley.size, full_top_rect);
+            let text_pos = text_pos.left_top() - 1.5 * Vec2::Y; // HACK: center on x-height of text (looks better)
+            self.title_label.paint_galley(ui, text_pos, title_galley);
+        }
 
         if let Some(content_response) = &content_response {
             // paint separator between title and content:
@@ -848,6 +1310,8 @@ impl TitleBar {
         {
             collapsing.toggle(ui);
         }
+
+        close_clicked
     }
 
     fn close_button_ui(&self, ui: &mut Ui) -> Response {
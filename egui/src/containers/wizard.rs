@@ -0,0 +1,143 @@
+//! A sequence of steps with Back/Next buttons, see [`Wizard`].
+
+use crate::*;
+
+/// What is saved between frames.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+pub(crate) struct State {
+    step: usize,
+}
+
+/// Leads the user through a number of steps, e.g. for an installer or onboarding.
+///
+/// Shows which step the user is on, the contents of that step, and Back and Next buttons
+/// (Finish on the last step). The contents return whether the step is done,
+/// and the user can only move on once it is.
+///
+/// The current step is kept in [`Memory`], so it survives restarts when persistence is on.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut name = String::new();
+/// # let mut accepted = false;
+/// let response = egui::Wizard::new("setup", &["Welcome", "Name", "License"]).show(ui, |ui, step| {
+///     match step {
+///         0 => {
+///             ui.label("Welcome! This will set up your account.");
+///             true
+///         }
+///         1 => {
+///             ui.text_edit_singleline(&mut name);
+///             !name.is_empty()
+///         }
+///         _ => {
+///             ui.checkbox(&mut accepted, "I accept the license");
+///             accepted
+///         }
+///     }
+/// });
+/// if response.finished {
+///     // …
+/// }
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Wizard<'a> {
+    id_source: Id,
+    steps: &'a [&'a str],
+}
+
+impl<'a> Wizard<'a> {
+    /// `steps` are the titles of the steps, in order.
+    pub fn new(id_source: impl std::hash::Hash, steps: &'a [&'a str]) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            steps,
+        }
+    }
+
+    /// `add_contents` is given the index of the current step,
+    /// and returns `true` when that step is done so the user can move on.
+    ///
+    /// After Finish is clicked the wizard starts over at the first step.
+    pub fn show(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui, usize) -> bool,
+    ) -> WizardResponse {
+        let Self { id_source, steps } = self;
+        let id = ui.make_persistent_id(id_source);
+        let last_step = steps.len().saturating_sub(1);
+
+        let mut state = *ui.memory().id_data.get_or_default::<State>(id);
+        state.step = state.step.min(last_step);
+        let step = state.step;
+        let mut finished = false;
+
+        let response = ui
+            .vertical(|ui| {
+                ui.horizontal_wrapped(|ui| {
+                    for (i, title) in steps.iter().enumerate() {
+                        if i > 0 {
+                            ui.add(Label::new("›").weak());
+                        }
+                        let label = Label::new(format!("{}. {}", i + 1, title));
+                        if i == step {
+                            ui.add(label.strong());
+                        } else if i < step {
+                            // Finished steps can be revisited:
+                            if ui.add(label.sense(Sense::click())).clicked() {
+                                state.step = i;
+                            }
+                        } else {
+                            ui.add(label.weak());
+                        }
+                    }
+                });
+                ui.separator();
+
+                let done = add_contents(ui, step);
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    let back = Button::new(ui.ctx().tr("Back")).enabled(step > 0);
+                    if ui.add(back).clicked() {
+                        state.step = step - 1;
+                    }
+                    ui.with_layout(Layout::right_to_left(), |ui| {
+                        let text = if step == last_step { "Finish" } else { "Next" };
+                        let next = Button::new(ui.ctx().tr(text)).enabled(done);
+                        if ui.add(next).clicked() {
+                            if step == last_step {
+                                finished = true;
+                                state.step = 0;
+                            } else {
+                                state.step = step + 1;
+                            }
+                        }
+                    });
+                });
+            })
+            .response;
+
+        ui.memory().id_data.insert(id, state);
+
+        WizardResponse {
+            response,
+            step: state.step,
+            finished,
+        }
+    }
+}
+
+/// Returned by [`Wizard::show`].
+#[derive(Debug)]
+pub struct WizardResponse {
+    /// The response of the whole wizard.
+    pub response: Response,
+    /// The step the user is on now (for the next frame).
+    pub step: usize,
+    /// Was Finish clicked this frame?
+    pub finished: bool,
+}
@@ -12,6 +12,7 @@ use crate::{
     input_state::*,
     layers::GraphicLayers,
     mutex::{Mutex, MutexGuard},
+    repaint_stats::RepaintHistory,
     *,
 };
 use epaint::{stats::*, text::Fonts, *};
@@ -102,6 +103,27 @@ impl CtxRef {
         let mut self_: Context = (*self.0).clone();
         self_.begin_frame_mut(new_input);
         *self = Self(Arc::new(self_));
+
+        self.paint_budget_overlay();
+        if self.style().debug.design_mode {
+            crate::design_overlay::show(self);
+        }
+    }
+
+    /// Show the warnings from the last frame if the paint budget was exceeded.
+    fn paint_budget_overlay(&self) {
+        let show_overlay = self
+            .memory()
+            .options
+            .paint_budget
+            .map_or(false, |paint_budget| paint_budget.show_overlay);
+        if show_overlay {
+            let painter = self.debug_painter();
+            let mut pos = self.input.screen_rect().min + vec2(8.0, 8.0);
+            for warning in self.paint_budget_warnings() {
+                pos.y = painter.error(pos, warning).bottom() + 4.0;
+            }
+        }
     }
 
     // ---------------------------------------------------------------------
@@ -163,7 +185,21 @@ impl CtxRef {
                 .at_least(Vec2::splat(0.0))
                 .at_most(Vec2::splat(5.0)),
         ); // make it easier to click
-        let hovered = self.rect_contains_pointer(layer_id, clip_rect.intersect(interact_rect));
+        let interact_rect = clip_rect.intersect(interact_rect);
+
+        if sense.click || sense.drag {
+            self.frame_state()
+                .interact_rects
+                .push((layer_id, id, interact_rect));
+        }
+
+        let hovered_last_frame = if self.memory().options.interact_with_last_frame_rects {
+            self.memory().interaction.hovered_by_last_frame_rect(id)
+        } else {
+            None
+        };
+        let hovered = hovered_last_frame
+            .unwrap_or_else(|| self.rect_contains_pointer(layer_id, interact_rect));
         self.interact_with_hovered(layer_id, id, rect, sense, enabled, hovered)
     }
 
@@ -177,7 +213,14 @@ impl CtxRef {
         enabled: bool,
         hovered: bool,
     ) -> Response {
-        let hovered = hovered && enabled; // can't even hover disabled widgets
+        if self.frame_state().track_widget_rect(id, rect) {
+            // An area anchored to this widget needs to catch up:
+            self.request_repaint();
+        }
+
+        // Everything behind a modal window is blocked, including keyboard input:
+        let blocked_by_modal = self.memory().areas.is_blocked_by_modal(&layer_id);
+        let hovered = hovered && enabled && !blocked_by_modal; // can't even hover disabled widgets
 
         let mut response = Response {
             ctx: self.clone(),
@@ -196,7 +239,7 @@ impl CtxRef {
             changed: false, // must be set by the widget itself
         };
 
-        if !enabled || !sense.focusable || !layer_id.allow_interaction() {
+        if !enabled || !sense.focusable || !layer_id.allow_interaction() || blocked_by_modal {
             // Not interested or allowed input:
             self.memory().surrender_focus(id);
             return response;
@@ -251,16 +294,16 @@ impl CtxRef {
                                     || memory.interaction.drag_is_window)
                             {
                                 // potential start of a drag
-                                memory.interaction.drag_id = Some(id);
-                                memory.interaction.drag_is_window = false;
-                                memory.window_interaction = None; // HACK: stop moving windows (if any)
+                                memory.capture_pointer(id); // also stops moving windows (if any)
                                 response.is_pointer_button_down_on = true;
                                 response.dragged = true;
                             }
                         }
                     }
                     PointerEvent::Released(click) => {
-                        response.drag_released = response.dragged;
+                        // A widget that senses clicks does not count a click as a drag:
+                        let was_click = sense.click && click.is_some();
+                        response.drag_released = response.dragged && !was_click;
                         response.dragged = false;
 
                         if hovered && response.is_pointer_button_down_on {
@@ -276,8 +319,17 @@ impl CtxRef {
             }
         }
 
+        if sense.click && response.dragged && self.input.pointer.could_any_button_be_click() {
+            // The pointer has not moved far enough yet: this may still turn out to be a click.
+            response.dragged = false;
+        }
+
         if response.is_pointer_button_down_on {
-            response.interact_pointer_pos = self.input().pointer.interact_pos();
+            response.interact_pointer_pos = self
+                .input()
+                .pointer
+                .interact_pos()
+                .map(|pos| self.memory().areas.screen_to_layer_pos(&layer_id, pos));
         }
 
         if self.input.pointer.any_down() {
@@ -330,10 +382,23 @@ pub struct Context {
     graphics: Arc<Mutex<GraphicLayers>>,
     output: Arc<Mutex<Output>>,
 
-    paint_stats: Arc<Mutex<PaintStats>>,
+    paint_stats: Arc<Mutex<PaintStatsState>>,
 
     /// While positive, keep requesting repaints. Decrement at the end of each frame.
     repaint_requests: AtomicU32,
+
+    repaint_history: Arc<Mutex<RepaintHistory>>,
+
+    localization: Arc<Mutex<Arc<Localization>>>,
+}
+
+/// What we know about the last call to [`Context::tessellate`].
+#[derive(Default)]
+struct PaintStatsState {
+    stats: PaintStats,
+    font_texture_version: Option<u64>,
+    /// From `ctx.memory().options.paint_budget`.
+    budget_warnings: Vec<String>,
 }
 
 impl Clone for Context {
@@ -348,6 +413,8 @@ impl Clone for Context {
             output: self.output.clone(),
             paint_stats: self.paint_stats.clone(),
             repaint_requests: self.repaint_requests.load(SeqCst).into(),
+            repaint_history: self.repaint_history.clone(),
+            localization: self.localization.clone(),
         }
     }
 }
@@ -385,13 +452,46 @@ impl Context {
         self.frame_state.lock()
     }
 
+    /// How to translate the text of the built-in widgets, and how to format numbers.
+    pub fn localization(&self) -> Arc<Localization> {
+        self.localization.lock().clone()
+    }
+
+    /// Translate the text of the built-in widgets, and format numbers for the user's locale.
+    /// See [`Localization`].
+    pub fn set_localization(&self, localization: Localization) {
+        *self.localization.lock() = Arc::new(localization);
+    }
+
+    /// Translate some English text of a built-in widget, using [`Self::localization`].
+    pub fn tr(&self, text: &str) -> String {
+        self.localization().tr(text)
+    }
+
     /// Call this if there is need to repaint the UI, i.e. if you are showing an animation.
     /// If this is called at least once in a frame, then there will be another frame right after this.
     /// Call as many times as you wish, only one repaint will be issued.
     pub fn request_repaint(&self) {
+        self.request_repaint_because(RepaintCause::Request);
+    }
+
+    fn request_repaint_because(&self, cause: RepaintCause) {
         // request two frames of repaint, just to cover some corner cases (frame delays):
         let times_to_repaint = 2;
         self.repaint_requests.store(times_to_repaint, SeqCst);
+        self.repaint_history.lock().pending_cause = cause;
+    }
+
+    /// Request a repaint after this many seconds, i.e. for an animation that only needs to change once in a while.
+    ///
+    /// If this is called several times in a frame, the shortest delay wins.
+    /// See [`Output::repaint_after`].
+    pub fn request_repaint_after(&self, seconds: f32) {
+        let mut output = self.output();
+        output.repaint_after = Some(match output.repaint_after {
+            Some(repaint_after) => repaint_after.min(seconds),
+            None => seconds,
+        });
     }
 
     #[inline(always)]
@@ -399,6 +499,40 @@ impl Context {
         &self.input
     }
 
+    /// Was this keyboard shortcut pressed this frame?
+    ///
+    /// Only the first call for a shortcut returns `true`, so one key press doesn't trigger two things.
+    /// Check your shortcuts every frame, so they also work when e.g. the menu item for it isn't shown.
+    /// See [`KeyboardShortcut`] for an example.
+    pub fn consume_shortcut(&self, shortcut: &KeyboardShortcut) -> bool {
+        let pressed = self.input.events.iter().any(|event| {
+            matches!(
+                event,
+                Event::Key {
+                    key,
+                    pressed: true,
+                    modifiers,
+                } if *key == shortcut.key && shortcut.matches(*modifiers)
+            )
+        });
+        if !pressed {
+            return false;
+        }
+
+        let mut frame_state = self.frame_state();
+        if frame_state.consumed_shortcuts.contains(shortcut) {
+            false
+        } else {
+            frame_state.consumed_shortcuts.push(*shortcut);
+            true
+        }
+    }
+
+    /// How to show a shortcut to the user, e.g. in a menu: like `"Ctrl+S"`, or `"⌘S"` on Mac.
+    pub fn format_shortcut(&self, shortcut: &KeyboardShortcut) -> String {
+        shortcut.format(cfg!(target_os = "macos"))
+    }
+
     /// Not valid until first call to [`CtxRef::begin_frame()`].
     /// That's because since we don't know the proper `pixels_per_point` until then.
     pub fn fonts(&self) -> &Fonts {
@@ -512,6 +646,42 @@ impl Context {
         self.constrain_window_rect_to_area(window, self.available_rect())
     }
 
+    /// Constrain the position of a window/area so it fits within the screen.
+    /// If it is too large, its top-left corner (e.g. the title bar of a window) is kept on screen.
+    pub(crate) fn constrain_window_rect_to_screen(&self, window: Rect) -> Rect {
+        let screen = self.input().screen_rect();
+        let mut pos = window.min;
+        pos.x = pos
+            .x
+            .at_most(screen.right() - window.width())
+            .at_least(screen.left());
+        pos.y = pos
+            .y
+            .at_most(screen.bottom() - window.height())
+            .at_least(screen.top());
+        Rect::from_min_size(self.round_pos_to_pixels(pos), window.size())
+    }
+
+    /// Move a [`crate::Window`] or [`crate::Area`] fully onto the screen,
+    /// e.g. if the user has lost it after dragging it off screen.
+    ///
+    /// `id` is the id of the area, which for a [`crate::Window`] is `Id::new(title)`,
+    /// unless you have set [`crate::Window::id`].
+    /// Does nothing if there is no area with the given id.
+    ///
+    /// To always keep a window on screen, use [`crate::Window::constrain`].
+    pub fn move_window_onto_screen(&self, id: Id) {
+        let rect = match self.memory().areas.get(id) {
+            Some(state) => state.rect(),
+            None => return,
+        };
+        let pos = self.constrain_window_rect_to_screen(rect).min;
+        if let Some(state) = self.memory().areas.get_mut(id) {
+            state.pos = pos;
+        }
+        self.request_repaint();
+    }
+
     /// Constrain the position of a window/area
     /// so it fits within the provided boundary.
     pub(crate) fn constrain_window_rect_to_area(&self, window: Rect, mut area: Rect) -> Rect {
@@ -552,9 +722,22 @@ impl Context {
         if let Some(new_pixels_per_point) = self.memory().new_pixels_per_point.take() {
             input.pixels_per_point = new_pixels_per_point;
         }
+        input.pointer.max_click_dist = self.style().interaction.drag_threshold;
 
         self.input = input.begin_frame(new_raw_input);
+
+        {
+            // Resolve hovering against the final widget rectangles of last frame:
+            let interact_rects = std::mem::take(&mut self.frame_state().interact_rects);
+            let pointer_pos = self.input.pointer.interact_pos();
+            let top_layer = pointer_pos.and_then(|pos| self.layer_id_at(pos));
+            self.memory()
+                .interaction
+                .set_rects_last_frame(&interact_rects, top_layer, pointer_pos);
+        }
+
         self.frame_state.lock().begin_frame(&self.input);
+        self.animation_manager.lock().begin_frame();
 
         {
             // Load new fonts if required:
@@ -594,7 +777,9 @@ impl Context {
     #[must_use]
     pub fn end_frame(&self) -> (Output, Vec<ClippedShape>) {
         if self.input.wants_repaint() {
-            self.request_repaint();
+            self.request_repaint_because(RepaintCause::Input);
+        } else if self.animation_manager.lock().any_active_this_frame() {
+            self.request_repaint_because(RepaintCause::Animation);
         }
 
         self.memory()
@@ -607,16 +792,43 @@ impl Context {
             self.repaint_requests.fetch_sub(1, SeqCst);
             output.needs_repaint = true;
         }
+        {
+            let mut repaint_history = self.repaint_history.lock();
+            let cause = Some(repaint_history.pending_cause).filter(|_| output.needs_repaint);
+            repaint_history.end_frame(self.input.time, cause);
+        }
 
         let shapes = self.drain_paint_lists();
         (output, shapes)
     }
 
     fn drain_paint_lists(&self) -> Vec<ClippedShape> {
+        self.scale_layers();
         let memory = self.memory();
         self.graphics().drain(memory.areas.order()).collect()
     }
 
+    /// Layers with an [`crate::Area::scale`] are tessellated here, at their own `pixels_per_point`,
+    /// and the resulting meshes are then scaled.
+    fn scale_layers(&self) {
+        let layer_scales = self.memory().areas.layer_scales().clone();
+        if layer_scales.is_empty() {
+            return;
+        }
+        let tex_size = self.fonts().texture().size();
+        for (layer_id, (origin, scale)) in layer_scales {
+            let mut tessellation_options = self.memory().options.tessellation_options;
+            tessellation_options.pixels_per_point = self.pixels_per_point() * scale;
+            tessellation_options.aa_size = 1.0 / tessellation_options.pixels_per_point;
+            self.graphics().list(layer_id).lock().tessellate_and_scale(
+                origin,
+                scale,
+                tessellation_options,
+                tex_size,
+            );
+        }
+    }
+
     /// Tessellate the given shapes into triangle meshes.
     pub fn tessellate(&self, shapes: Vec<ClippedShape>) -> Vec<ClippedMesh> {
         let mut tessellation_options = self.memory().options.tessellation_options;
@@ -628,13 +840,75 @@ impl Context {
             tessellation_options,
             self.fonts().texture().size(),
         );
-        *self.paint_stats.lock() = paint_stats.with_clipped_meshes(&clipped_meshes);
+        let mut paint_stats = paint_stats.with_clipped_meshes(&clipped_meshes);
+
+        let font_texture_version = self.fonts().texture().version;
+        let mut state = self.paint_stats.lock();
+        if state.font_texture_version != Some(font_texture_version) {
+            paint_stats.texture_uploads += 1;
+        }
+        state.font_texture_version = Some(font_texture_version);
+        state.budget_warnings = match self.memory().options.paint_budget {
+            Some(paint_budget) => paint_budget.check(&paint_stats),
+            None => vec![],
+        };
+        state.stats = paint_stats;
+
         clipped_meshes
     }
 
+    /// Statistics about what was painted by the last call to [`Self::tessellate`].
+    pub fn paint_stats(&self) -> PaintStats {
+        self.paint_stats.lock().stats
+    }
+
+    /// Warnings from the last call to [`Self::tessellate`] if it exceeded `ctx.memory().options.paint_budget`.
+    ///
+    /// One warning per exceeded limit, so this is empty when the frame stayed within budget.
+    /// With [`crate::PaintBudget::show_overlay`] they are also painted on top of everything.
+    pub fn paint_budget_warnings(&self) -> Vec<String> {
+        self.paint_stats.lock().budget_warnings.clone()
+    }
+
+    /// How often egui asked for a repaint during the last second, and why.
+    pub fn repaint_stats(&self) -> RepaintStats {
+        self.repaint_history.lock().stats(self.input.time)
+    }
+
+    /// All widgets that described themselves with [`Response::widget_info`] last frame,
+    /// in the order they were added, if `ctx.memory().options.record_widget_info`
+    /// (or `screen_reader`) is on.
+    ///
+    /// Use this to build an accessibility tree, or to find widgets in automated tests.
+    pub fn widget_records(&self) -> Vec<WidgetRecord> {
+        self.frame_state().widget_records_last_frame.clone()
+    }
+
+    /// Widgets that got less space than they asked for last frame,
+    /// if `ctx.style().debug.show_overflow` is on.
+    /// Each warning names the `Id` of the widget, the size it wanted and the size it got.
+    /// The same warning is shown when hovering the red outline around the widget.
+    pub fn overflow_warnings(&self) -> Vec<String> {
+        self.frame_state().overflow_warnings_last_frame.clone()
+    }
+
+    /// `true` if the last frame did not ask for a repaint,
+    /// i.e. the backend can sleep until there is new input
+    /// (or until [`Output::repaint_after`], if set).
+    pub fn is_idle(&self) -> bool {
+        !self.repaint_history.lock().last_frame_requested_repaint
+    }
+
     // ---------------------------------------------------------------------
 
-    /// How much space is used by panels and windows.
+    /// How much space is used by panels and windows so far this frame.
+    ///
+    /// For the [`CentralPanel`] only the part covered by its contents (plus margin) is counted,
+    /// even though it paints its background over all of [`Self::available_rect`].
+    /// Side and top panels are counted in full.
+    ///
+    /// Returns [`Rect::NOTHING`] if nothing has been added yet.
+    /// Call this at the end of the frame (after all panels and windows) to get the full picture.
     pub fn used_rect(&self) -> Rect {
         let mut used = self.frame_state().used_by_panels;
         for window in self.memory().areas.visible_windows() {
@@ -643,10 +917,12 @@ impl Context {
         used
     }
 
-    /// How much space is used by panels and windows.
+    /// How much space is used by panels and windows, measured from the top left corner of the screen.
     /// You can shrink your egui area to this size and still fit all egui components.
+    ///
+    /// Returns [`Vec2::ZERO`] if nothing has been added yet.
     pub fn used_size(&self) -> Vec2 {
-        self.used_rect().max - Pos2::new(0.0, 0.0)
+        (self.used_rect().max - Pos2::new(0.0, 0.0)).at_least(Vec2::ZERO)
     }
 
     // ---------------------------------------------------------------------
@@ -708,14 +984,55 @@ impl Context {
         }
     }
 
+    /// Bring the given layer (e.g. of a [`crate::Window`]) to the top of its [`Order`],
+    /// just like when the user clicks it.
+    ///
+    /// The new order takes effect at the end of the frame.
+    pub fn move_to_top(&self, layer_id: LayerId) {
+        self.memory().areas.move_to_top(layer_id);
+    }
+
+    /// Put `layer_id` directly behind `other`, which must be in the same [`Order`].
+    pub fn move_behind(&self, layer_id: LayerId, other: LayerId) {
+        self.memory().areas.move_behind(layer_id, other);
+    }
+
+    /// All known layers, from the bottom to the top.
+    ///
+    /// The layer of a [`crate::Window`] is `LayerId::new(Order::Middle, Id::new(title))`,
+    /// unless you have set [`crate::Window::id`].
+    pub fn layer_order(&self) -> Vec<LayerId> {
+        self.memory().areas.order().to_vec()
+    }
+
     pub fn layer_id_at(&self, pos: Pos2) -> Option<LayerId> {
         let resize_grab_radius_side = self.style().interaction.resize_grab_radius_side;
         self.memory().layer_id_at(pos, resize_grab_radius_side)
     }
 
+    /// Mark `rect` of the given layer as opaque to the pointer:
+    /// nothing in the layers below it will be hovered or clicked within that rect.
+    ///
+    /// Windows and areas are always opaque. Use this for additional regions,
+    /// e.g. a dimmed background that covers the screen behind a modal dialog.
+    pub fn register_opaque_rect(&self, layer_id: LayerId, rect: Rect) {
+        self.memory().areas.register_opaque_rect(layer_id, rect);
+    }
+
+    /// The layer of the modal [`crate::Window`] currently shown, if any.
+    ///
+    /// While a modal window is shown, nothing behind it can be interacted with.
+    pub fn modal_layer(&self) -> Option<LayerId> {
+        self.memory().areas.modal_layer()
+    }
+
     pub(crate) fn rect_contains_pointer(&self, layer_id: LayerId, rect: Rect) -> bool {
         if let Some(pointer_pos) = self.input.pointer.interact_pos() {
-            rect.contains(pointer_pos) && self.layer_id_at(pointer_pos) == Some(layer_id)
+            let layer_pos = self
+                .memory()
+                .areas
+                .screen_to_layer_pos(&layer_id, pointer_pos);
+            rect.contains(layer_pos) && self.layer_id_at(pointer_pos) == Some(layer_id)
         } else {
             false
         }
@@ -730,18 +1047,27 @@ impl Context {
     /// Calling this with `value = true` will always yield a number larger than zero, quickly going towards one.
     /// Calling this with `value = false` will always yield a number less than one, quickly going towards zero.
     ///
-    /// The function will call [`Self::request_repaint()`] when appropriate.
+    /// The animation is time-based, so it will take [`Style::animation_time`] seconds
+    /// regardless of the frame rate.
+    /// While the animation is in progress, [`Self::end_frame`] will request a repaint.
     pub fn animate_bool(&self, id: Id, value: bool) -> f32 {
         let animation_time = self.style().animation_time;
-        let animated_value =
-            self.animation_manager
-                .lock()
-                .animate_bool(&self.input, animation_time, id, value);
-        let animation_in_progress = 0.0 < animated_value && animated_value < 1.0;
-        if animation_in_progress {
-            self.request_repaint();
-        }
-        animated_value
+        self.animate_bool_with_time(id, value, animation_time)
+    }
+
+    /// Like [`Self::animate_bool`] but allows you to control the animation time (in seconds).
+    pub fn animate_bool_with_time(&self, id: Id, value: bool, animation_time: f32) -> f32 {
+        self.animation_manager
+            .lock()
+            .animate_bool(&self.input, animation_time, id, value)
+    }
+
+    /// Is any animation currently in progress?
+    ///
+    /// This includes animations that were in progress last frame,
+    /// so it can be called before any animations have been queried this frame.
+    pub fn has_active_animations(&self) -> bool {
+        self.animation_manager.lock().has_active_animations()
     }
 
     /// Clear memory of any animations.
@@ -769,6 +1095,22 @@ impl Context {
                 self.set_fonts(font_definitions);
             });
 
+        CollapsingHeader::new("🖱 Interaction")
+            .default_open(false)
+            .show(ui, |ui| {
+                let mut interact_with_last_frame_rects =
+                    self.memory().options.interact_with_last_frame_rects;
+                ui.checkbox(
+                    &mut interact_with_last_frame_rects,
+                    "Interact with last frame rectangles",
+                )
+                .on_hover_text(
+                    "Resolve clicks against what was painted last frame, even if the layout changed",
+                );
+                self.memory().options.interact_with_last_frame_rects =
+                    interact_with_last_frame_rects;
+            });
+
         CollapsingHeader::new("✒ Painting")
             .default_open(true)
             .show(ui, |ui| {
@@ -819,8 +1161,38 @@ impl Context {
         CollapsingHeader::new("📊 Paint stats")
             .default_open(true)
             .show(ui, |ui| {
-                self.paint_stats.lock().ui(ui);
+                self.paint_stats().ui(ui);
             });
+        CollapsingHeader::new("🔁 Repaint stats")
+            .default_open(false)
+            .show(ui, |ui| {
+                self.repaint_stats().ui(ui);
+            });
+        CollapsingHeader::new("♿ Widget info")
+            .default_open(false)
+            .show(ui, |ui| {
+                self.widget_records_ui(ui);
+            });
+    }
+
+    fn widget_records_ui(&self, ui: &mut Ui) {
+        let mut record_widget_info = self.memory().options.record_widget_info;
+        ui.checkbox(&mut record_widget_info, "Record widget info")
+            .on_hover_text("What screen readers see. Hover an entry to see where the widget is.");
+        self.memory().options.record_widget_info = record_widget_info;
+
+        let records = self.widget_records();
+        ui.label(format!("{} widgets last frame", records.len()));
+        crate::ScrollArea::auto_sized().show(ui, |ui| {
+            for record in &records {
+                let response = ui.add(Label::new(format!("{:?}", record.info)).small());
+                if response.hovered() {
+                    ui.ctx()
+                        .debug_painter()
+                        .rect_stroke(record.rect, 0.0, (1.0, Color32::RED));
+                }
+            }
+        });
     }
 
     pub fn memory_ui(&self, ui: &mut crate::Ui) {
@@ -15,10 +15,13 @@ pub struct RawInput {
     /// How many points (logical pixels) the user scrolled
     pub scroll_delta: Vec2,
 
-    /// Zoom scale factor this frame (e.g. from ctrl-scroll or pinch gesture).
+    /// Zoom scale factor this frame (e.g. from a pinch gesture).
     /// * `zoom = 1`: no change (default).
     /// * `zoom < 1`: pinch together
     /// * `zoom > 1`: pinch spread
+    ///
+    /// You do not need to convert ctrl/cmd + scroll into zoom, egui does that for you.
+    /// Use [`crate::InputState::zoom_delta`] to read the combined zoom.
     pub zoom_delta: f32,
 
     #[deprecated = "Use instead: `screen_rect: Some(Rect::from_pos_size(Default::default(), screen_size))`"]
@@ -205,6 +208,48 @@ impl Modifiers {
     }
 }
 
+impl Modifiers {
+    pub const NONE: Self = Self {
+        alt: false,
+        ctrl: false,
+        shift: false,
+        mac_cmd: false,
+        command: false,
+    };
+    pub const ALT: Self = Self {
+        alt: true,
+        ..Self::NONE
+    };
+    pub const CTRL: Self = Self {
+        ctrl: true,
+        ..Self::NONE
+    };
+    pub const SHIFT: Self = Self {
+        shift: true,
+        ..Self::NONE
+    };
+    /// Ctrl on Windows and Linux, ⌘ on Mac. This is what you want for most keyboard shortcuts.
+    pub const COMMAND: Self = Self {
+        command: true,
+        ..Self::NONE
+    };
+}
+
+/// Combine modifiers, e.g. `Modifiers::COMMAND | Modifiers::SHIFT`.
+impl std::ops::BitOr for Modifiers {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self {
+            alt: self.alt || rhs.alt,
+            ctrl: self.ctrl || rhs.ctrl,
+            shift: self.shift || rhs.shift,
+            mac_cmd: self.mac_cmd || rhs.mac_cmd,
+            command: self.command || rhs.command,
+        }
+    }
+}
+
 /// Keyboard keys.
 ///
 /// Includes all keys egui is interested in (such as `Home` and `End`)
@@ -280,6 +325,128 @@ pub enum Key {
     X,
     Y,
     Z, // Used for cmd+Z (undo)
+
+    F1,
+    F2,
+    F3, // Used for F3 (find next)
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    F10,
+    F11,
+    F12,
+}
+
+impl Key {
+    /// A short name for showing to the user, e.g. `"A"`, `"1"` or `"Up"`.
+    pub fn name(self) -> String {
+        let name = match self {
+            Key::ArrowDown => "Down",
+            Key::ArrowLeft => "Left",
+            Key::ArrowRight => "Right",
+            Key::ArrowUp => "Up",
+            Key::Escape => "Esc",
+            Key::Num0 => "0",
+            Key::Num1 => "1",
+            Key::Num2 => "2",
+            Key::Num3 => "3",
+            Key::Num4 => "4",
+            Key::Num5 => "5",
+            Key::Num6 => "6",
+            Key::Num7 => "7",
+            Key::Num8 => "8",
+            Key::Num9 => "9",
+            _ => return format!("{:?}", self),
+        };
+        name.to_owned()
+    }
+}
+
+/// A key together with modifiers, e.g. `Ctrl+S`.
+///
+/// Check for it with [`crate::Context::consume_shortcut`],
+/// and show it in a menu with [`crate::Button::shortcut_text`]:
+///
+/// ```
+/// # let mut ctx = egui::CtxRef::default();
+/// # ctx.begin_frame(Default::default());
+/// # let ctx = &ctx;
+/// # let ui = &mut egui::Ui::__test();
+/// use egui::{Key, KeyboardShortcut, Modifiers};
+/// const SAVE: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::S);
+///
+/// // Every frame, so it works even when the menu is closed:
+/// let mut save = ctx.consume_shortcut(&SAVE);
+///
+/// egui::menu::bar(ui, |ui| {
+///     egui::menu::menu(ui, "File", |ui| {
+///         let button = egui::Button::new("Save").shortcut_text(ui.ctx().format_shortcut(&SAVE));
+///         save |= ui.add(button).clicked();
+///     });
+/// });
+/// if save {
+///     // …
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct KeyboardShortcut {
+    pub modifiers: Modifiers,
+    pub key: Key,
+}
+
+impl KeyboardShortcut {
+    pub const fn new(modifiers: Modifiers, key: Key) -> Self {
+        Self { modifiers, key }
+    }
+
+    /// Does pressing [`Self::key`] with these modifiers down trigger the shortcut?
+    ///
+    /// The modifiers must match exactly, so `Ctrl+S` is not triggered by `Ctrl+Shift+S`.
+    pub fn matches(&self, modifiers: Modifiers) -> bool {
+        let wanted = self.modifiers;
+        let command_like = wanted.command || wanted.ctrl || wanted.mac_cmd;
+        wanted.alt == modifiers.alt
+            && wanted.shift == modifiers.shift
+            && (!wanted.command || modifiers.command)
+            && (!wanted.ctrl || modifiers.ctrl)
+            && (!wanted.mac_cmd || modifiers.mac_cmd)
+            && (command_like || !(modifiers.command || modifiers.ctrl || modifiers.mac_cmd))
+    }
+
+    /// Like `"Ctrl+Shift+S"`, or `"Shift+⌘S"` on Mac.
+    pub fn format(&self, is_mac: bool) -> String {
+        let modifiers = self.modifiers;
+        let mut text = String::new();
+        if is_mac {
+            if modifiers.ctrl {
+                text += "Ctrl+";
+            }
+            if modifiers.alt {
+                text += "Option+";
+            }
+            if modifiers.shift {
+                text += "Shift+";
+            }
+            if modifiers.command || modifiers.mac_cmd {
+                text += "⌘";
+            }
+        } else {
+            if modifiers.ctrl || modifiers.command {
+                text += "Ctrl+";
+            }
+            if modifiers.alt {
+                text += "Alt+";
+            }
+            if modifiers.shift {
+                text += "Shift+";
+            }
+        }
+        text += &self.key.name();
+        text
+    }
 }
 
 impl RawInput {
@@ -359,3 +526,22 @@ impl From<u32> for TouchId {
         Self(id as u64)
     }
 }
+
+#[cfg(test)]
+#[test]
+fn keyboard_shortcut() {
+    let save = KeyboardShortcut::new(Modifiers::COMMAND, Key::S);
+    let ctrl = Modifiers {
+        ctrl: true,
+        command: true,
+        ..Default::default()
+    };
+    assert!(save.matches(ctrl));
+    assert!(!save.matches(ctrl | Modifiers::SHIFT));
+    assert!(!save.matches(Modifiers::NONE));
+    assert!(!KeyboardShortcut::new(Modifiers::NONE, Key::S).matches(ctrl));
+    assert_eq!(save.format(false), "Ctrl+S");
+    assert_eq!(save.format(true), "⌘S");
+    let zoom_in = KeyboardShortcut::new(Modifiers::COMMAND | Modifiers::SHIFT, Key::Num1);
+    assert_eq!(zoom_in.format(false), "Ctrl+Shift+1");
+}
@@ -21,11 +21,30 @@ pub struct Output {
     /// Call `Context::request_repaint()` instead and it will do so for you.
     pub needs_repaint: bool,
 
+    /// If set, egui would like to be repainted after this many seconds, even if there are no new events.
+    ///
+    /// This is used for slow animations, e.g. the frames of an animated image.
+    /// Ignore this if [`Self::needs_repaint`] is set.
+    ///
+    /// As an egui user: don't set this value directly.
+    /// Call `Context::request_repaint_after()` instead and it will do so for you.
+    pub repaint_after: Option<f32>,
+
     /// Events that may be useful to e.g. a screen reader.
     pub events: Vec<OutputEvent>,
 
     /// Position of text edit cursor (used for IME).
     pub text_cursor_pos: Option<crate::Pos2>,
+
+    /// The user started dragging a custom title bar (see [`crate::Response::native_title_bar`]).
+    ///
+    /// The backend should start moving the native window with the mouse, until the mouse button is released.
+    pub drag_window: bool,
+
+    /// The user double-clicked a custom title bar (see [`crate::Response::native_title_bar`]).
+    ///
+    /// The backend should maximize the native window, or restore it if it is already maximized.
+    pub toggle_maximized: bool,
 }
 
 impl Output {
@@ -37,12 +56,13 @@ impl Output {
 
     /// This can be used by a text-to-speech system to describe the events (if any).
     pub fn events_description(&self) -> String {
-        // only describe last event:
-        if let Some(event) = self.events.iter().rev().next() {
+        // only describe last focus event:
+        for event in self.events.iter().rev() {
             match event {
                 OutputEvent::WidgetEvent(WidgetEvent::Focus, widget_info) => {
                     return widget_info.description();
                 }
+                OutputEvent::WidgetEvent(_, _) => {}
             }
         }
         Default::default()
@@ -201,10 +221,11 @@ impl Default for CursorIcon {
 
 /// Things that happened during this frame that the integration may be interested in.
 ///
-/// In particular, these events may be useful for accessability, i.e. for screen readers.
+/// In particular, these events may be useful for accessability, i.e. for screen readers,
+/// and for playing sounds or giving haptic feedback when the user interacts with widgets.
 #[derive(Clone, PartialEq)]
 pub enum OutputEvent {
-    /// A widget gained keyboard focus (by tab key).
+    /// Something happened to a widget, e.g. it gained keyboard focus (by tab key) or was clicked.
     WidgetEvent(WidgetEvent, WidgetInfo),
 }
 
@@ -216,10 +237,31 @@ impl std::fmt::Debug for OutputEvent {
     }
 }
 
+/// Something that happened to a widget.
+///
+/// Integrations can map these to e.g. platform sounds or haptic feedback on mobile.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum WidgetEvent {
     /// Keyboard focused moved onto the widget.
     Focus,
+
+    /// The widget was clicked, e.g. a [`crate::Button`] was pressed.
+    Clicked,
+
+    /// A [`crate::Checkbox`] (or another widget with a selected state) changed its value.
+    /// [`WidgetInfo::selected`] is the new value.
+    ///
+    /// This comes in addition to [`Self::Clicked`].
+    Toggled,
+
+    /// The user started dragging the widget, e.g. a [`crate::Slider`].
+    DragStarted,
+
+    /// The user stopped dragging the widget.
+    DragReleased,
+
+    /// A [`crate::TextEdit`] lost keyboard focus, e.g. because the user pressed enter.
+    TextCommitted,
     // /// Started hovering a new widget.
     // Hover, // TODO: cursor hovered events
 }
@@ -270,6 +312,18 @@ impl std::fmt::Debug for WidgetInfo {
     }
 }
 
+/// The [`WidgetInfo`] of a widget, and where it is.
+///
+/// Collected each frame when `ctx.memory().options.record_widget_info` is on,
+/// e.g. to build an accessibility tree. See [`crate::Context::widget_records`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct WidgetRecord {
+    pub layer_id: crate::LayerId,
+    pub id: crate::Id,
+    pub rect: crate::Rect,
+    pub info: WidgetInfo,
+}
+
 impl WidgetInfo {
     pub fn new(typ: WidgetType) -> Self {
         Self {
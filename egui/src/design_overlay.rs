@@ -0,0 +1,295 @@
+//! The design mode overlay, enabled with [`crate::style::DebugOptions::design_mode`]:
+//! rulers along the top and left edge of the screen, guide lines that you drag out of the rulers,
+//! and the distances between the hovered widget and its neighbors.
+
+use crate::*;
+
+const RULER_SIZE: f32 = 16.0;
+const GUIDE_COLOR: Color32 = Color32::from_rgb(0, 200, 255);
+const MEASURE_COLOR: Color32 = Color32::from_rgb(255, 0, 160);
+
+/// The guide lines, in screen coordinates.
+#[derive(Clone, Debug, Default)]
+struct Guides {
+    /// The x coordinates of the vertical guides.
+    vertical: Vec<f32>,
+    /// The y coordinates of the horizontal guides.
+    horizontal: Vec<f32>,
+    /// The guide being dragged: `(is_vertical, index)`.
+    dragged: Option<(bool, usize)>,
+}
+
+/// Called at the start of each frame, so the rulers block the pointer for the rest of the frame.
+/// The widgets are measured using the rectangles from the last frame.
+pub(crate) fn show(ctx: &CtxRef) {
+    let layer_id = LayerId::new(Order::Debug, Id::new("design_overlay"));
+    let screen = ctx.input().screen_rect();
+    let top_ruler = Rect::from_min_max(screen.min, pos2(screen.right(), screen.top() + RULER_SIZE));
+    let left_ruler = Rect::from_min_max(
+        screen.min,
+        pos2(screen.left() + RULER_SIZE, screen.bottom()),
+    );
+
+    let guides_id = Id::new("design_overlay_guides");
+    let mut guides = ctx
+        .memory()
+        .id_data_temp
+        .get_or_default::<Guides>(guides_id)
+        .clone();
+    drag_guides(ctx, &mut guides, top_ruler, left_ruler);
+
+    let painter = Painter::new(ctx.clone(), layer_id, screen);
+    measure_hovered_widget(ctx, &painter);
+    paint_guides(&painter, &guides, screen);
+    paint_ruler(ctx, &painter, top_ruler, false);
+    paint_ruler(ctx, &painter, left_ruler, true);
+
+    // Don't let clicks on the rulers or guides reach the widgets below:
+    let grab_radius = ctx.style().interaction.resize_grab_radius_side;
+    ctx.register_opaque_rect(layer_id, top_ruler);
+    ctx.register_opaque_rect(layer_id, left_ruler);
+    for &x in &guides.vertical {
+        let rect = Rect::from_x_y_ranges(x - grab_radius..=x + grab_radius, screen.y_range());
+        ctx.register_opaque_rect(layer_id, rect);
+    }
+    for &y in &guides.horizontal {
+        let rect = Rect::from_x_y_ranges(screen.x_range(), y - grab_radius..=y + grab_radius);
+        ctx.register_opaque_rect(layer_id, rect);
+    }
+
+    ctx.memory().id_data_temp.insert(guides_id, guides);
+}
+
+/// Drag a new guide out of a ruler, move an existing one, or remove one by dragging it back onto its ruler.
+fn drag_guides(ctx: &CtxRef, guides: &mut Guides, top_ruler: Rect, left_ruler: Rect) {
+    let pointer = &ctx.input().pointer;
+    let pos = match pointer.interact_pos() {
+        Some(pos) => pos,
+        None => return,
+    };
+    let grab_radius = ctx.style().interaction.resize_grab_radius_side;
+
+    if pointer.any_pressed() && pointer.button_down(PointerButton::Primary) {
+        guides.dragged = if top_ruler.contains(pos) {
+            guides.horizontal.push(pos.y);
+            Some((false, guides.horizontal.len() - 1))
+        } else if left_ruler.contains(pos) {
+            guides.vertical.push(pos.x);
+            Some((true, guides.vertical.len() - 1))
+        } else if let Some(index) = guides
+            .vertical
+            .iter()
+            .position(|x| (x - pos.x).abs() <= grab_radius)
+        {
+            Some((true, index))
+        } else {
+            guides
+                .horizontal
+                .iter()
+                .position(|y| (y - pos.y).abs() <= grab_radius)
+                .map(|index| (false, index))
+        };
+    }
+
+    if let Some((vertical, index)) = guides.dragged {
+        let (coordinates, value, ruler) = if vertical {
+            (&mut guides.vertical, pos.x, left_ruler)
+        } else {
+            (&mut guides.horizontal, pos.y, top_ruler)
+        };
+        if pointer.button_down(PointerButton::Primary) {
+            coordinates[index] = value;
+        } else {
+            if ruler.contains(pos) {
+                coordinates.remove(index);
+            }
+            guides.dragged = None;
+        }
+    }
+}
+
+fn paint_ruler(ctx: &CtxRef, painter: &Painter, ruler: Rect, vertical: bool) {
+    let style = ctx.style();
+    let visuals = &style.visuals;
+    let text_color = visuals.text_color();
+    let stroke = Stroke::new(1.0, text_color);
+    painter.rect_filled(ruler, 0.0, visuals.extreme_bg_color);
+
+    let (start, end) = if vertical {
+        (ruler.top() + RULER_SIZE, ruler.bottom())
+    } else {
+        (ruler.left() + RULER_SIZE, ruler.right())
+    };
+    let mut value = (start / 10.0).ceil() * 10.0;
+    while value <= end {
+        let rounded = value.round() as i64;
+        let tick_length = if rounded % 100 == 0 {
+            RULER_SIZE
+        } else if rounded % 50 == 0 {
+            0.5 * RULER_SIZE
+        } else {
+            0.25 * RULER_SIZE
+        };
+        if vertical {
+            let x = ruler.right();
+            painter.line_segment([pos2(x - tick_length, value), pos2(x, value)], stroke);
+        } else {
+            let y = ruler.bottom();
+            painter.line_segment([pos2(value, y - tick_length), pos2(value, y)], stroke);
+        }
+        if rounded % 100 == 0 {
+            let pos = if vertical {
+                pos2(ruler.left() + 1.0, value + 1.0)
+            } else {
+                pos2(value + 2.0, ruler.top())
+            };
+            painter.text(pos, Align2::LEFT_TOP, rounded, TextStyle::Small, text_color);
+        }
+        value += 10.0;
+    }
+
+    // Where the pointer is:
+    if let Some(pointer) = ctx.input().pointer.hover_pos() {
+        let points = if vertical {
+            [
+                pos2(ruler.left(), pointer.y),
+                pos2(ruler.right(), pointer.y),
+            ]
+        } else {
+            [
+                pos2(pointer.x, ruler.top()),
+                pos2(pointer.x, ruler.bottom()),
+            ]
+        };
+        painter.line_segment(points, (1.0, MEASURE_COLOR));
+    }
+}
+
+fn paint_guides(painter: &Painter, guides: &Guides, screen: Rect) {
+    let stroke = Stroke::new(1.0, GUIDE_COLOR);
+    let text_style = TextStyle::Small;
+    for &x in &guides.vertical {
+        painter.line_segment([pos2(x, screen.top()), pos2(x, screen.bottom())], stroke);
+        let pos = pos2(x + 2.0, screen.top() + RULER_SIZE + 2.0);
+        painter.text(pos, Align2::LEFT_TOP, x.round(), text_style, GUIDE_COLOR);
+    }
+    for &y in &guides.horizontal {
+        painter.line_segment([pos2(screen.left(), y), pos2(screen.right(), y)], stroke);
+        let pos = pos2(screen.left() + RULER_SIZE + 2.0, y - 2.0);
+        painter.text(pos, Align2::LEFT_BOTTOM, y.round(), text_style, GUIDE_COLOR);
+    }
+}
+
+/// Outline the widget under the pointer, and show how far it is from its closest neighbor in each direction.
+fn measure_hovered_widget(ctx: &CtxRef, painter: &Painter) {
+    let pointer = match ctx.input().pointer.hover_pos() {
+        Some(pointer) => pointer,
+        None => return,
+    };
+    let layer_id = ctx.layer_id_at(pointer);
+    let rects: Vec<Rect> = ctx
+        .widget_records()
+        .into_iter()
+        .filter(|record| Some(record.layer_id) == layer_id)
+        .map(|record| record.rect)
+        .collect();
+    let hovered = match closest(&rects, |rect| {
+        if rect.contains(pointer) {
+            Some(rect.area())
+        } else {
+            None
+        }
+    }) {
+        Some(hovered) => hovered,
+        None => return,
+    };
+
+    painter.rect_stroke(hovered, 0.0, (1.0, MEASURE_COLOR));
+    painter.text(
+        hovered.left_bottom() + vec2(0.0, 2.0),
+        Align2::LEFT_TOP,
+        format!("{:.1} × {:.1}", hovered.width(), hovered.height()),
+        TextStyle::Small,
+        MEASURE_COLOR,
+    );
+
+    let overlaps = |a: std::ops::RangeInclusive<f32>, b: std::ops::RangeInclusive<f32>| {
+        a.start() < b.end() && b.start() < a.end()
+    };
+    let side_by_side = |rect: &Rect| overlaps(rect.y_range(), hovered.y_range());
+    let stacked = |rect: &Rect| overlaps(rect.x_range(), hovered.x_range());
+    let gap = |condition: bool, distance: f32| if condition { Some(distance) } else { None };
+
+    // The gap to the closest widget in each direction, as the two points to measure between:
+    let mut gaps: Vec<[Pos2; 2]> = vec![];
+    let right = closest(&rects, |r| {
+        gap(
+            side_by_side(r) && r.left() >= hovered.right(),
+            r.left() - hovered.right(),
+        )
+    });
+    let left = closest(&rects, |r| {
+        gap(
+            side_by_side(r) && r.right() <= hovered.left(),
+            hovered.left() - r.right(),
+        )
+    });
+    let below = closest(&rects, |r| {
+        gap(
+            stacked(r) && r.top() >= hovered.bottom(),
+            r.top() - hovered.bottom(),
+        )
+    });
+    let above = closest(&rects, |r| {
+        gap(
+            stacked(r) && r.bottom() <= hovered.top(),
+            hovered.top() - r.bottom(),
+        )
+    });
+    for (rect, is_right) in left
+        .map(|r| (r, false))
+        .into_iter()
+        .chain(right.map(|r| (r, true)))
+    {
+        let y = 0.5 * (rect.top().max(hovered.top()) + rect.bottom().min(hovered.bottom()));
+        let (x0, x1) = if is_right {
+            (hovered.right(), rect.left())
+        } else {
+            (rect.right(), hovered.left())
+        };
+        gaps.push([pos2(x0, y), pos2(x1, y)]);
+    }
+    for (rect, is_below) in above
+        .map(|r| (r, false))
+        .into_iter()
+        .chain(below.map(|r| (r, true)))
+    {
+        let x = 0.5 * (rect.left().max(hovered.left()) + rect.right().min(hovered.right()));
+        let (y0, y1) = if is_below {
+            (hovered.bottom(), rect.top())
+        } else {
+            (rect.bottom(), hovered.top())
+        };
+        gaps.push([pos2(x, y0), pos2(x, y1)]);
+    }
+
+    for [a, b] in gaps {
+        painter.line_segment([a, b], (1.0, MEASURE_COLOR));
+        painter.text(
+            a + 0.5 * (b - a),
+            Align2::LEFT_CENTER,
+            format!(" {:.1}", (b - a).length()),
+            TextStyle::Small,
+            MEASURE_COLOR,
+        );
+    }
+}
+
+/// The rect with the smallest `distance`, ignoring those where it is `None`.
+fn closest(rects: &[Rect], distance: impl Fn(&Rect) -> Option<f32>) -> Option<Rect> {
+    rects
+        .iter()
+        .filter_map(|rect| distance(rect).map(|d| (d, *rect)))
+        .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(_, rect)| rect)
+}
@@ -0,0 +1,188 @@
+//! Checking what the user entered, see [`Form`].
+//!
+//! The functions in this module are common checks, to pass to [`Form::check`].
+//! Combine them with [`Result::and`] or [`Result::and_then`].
+
+use crate::*;
+
+/// Whether the user has edited a field yet.
+#[derive(Clone, Copy, Debug, Default)]
+struct FieldState {
+    touched: bool,
+}
+
+/// Collects validation errors for the fields of a form during a frame.
+///
+/// Call [`Self::check`] after each widget. Once the user has edited it (or moved focus away from it),
+/// an invalid widget gets a red outline and the error message is shown below it.
+/// Use [`Self::is_valid`] to only enable the submit button when everything checks out,
+/// and [`Self::show_errors`] to list what is left to fix.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut name = String::new();
+/// # let mut age = 0;
+/// use egui::form;
+///
+/// let mut form = egui::Form::new(ui, "signup");
+///
+/// let response = ui.text_edit_singleline(&mut name);
+/// form.check(ui, &response, "Name", form::required(&name).and(form::max_length(&name, 32)));
+///
+/// let response = ui.add(egui::DragValue::new(&mut age));
+/// form.check(ui, &response, "Age", form::in_range(&age, 18..=150));
+///
+/// form.show_errors(ui);
+/// if ui.add(egui::Button::new("Sign up").enabled(form.is_valid())).clicked() {
+///     // …
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Form {
+    id: Id,
+    /// The field names and messages of the failed checks.
+    errors: Vec<(String, String)>,
+}
+
+impl Form {
+    /// Start a form for this frame.
+    pub fn new(ui: &Ui, id_source: impl std::hash::Hash + std::fmt::Debug) -> Self {
+        Self {
+            id: ui.make_persistent_id(id_source),
+            errors: Default::default(),
+        }
+    }
+
+    /// Record the outcome of validating the field that `response` is for.
+    ///
+    /// `field` is the name of the field, used in [`Self::show_errors`] (and to tell the fields apart).
+    ///
+    /// Returns `true` if the field is valid.
+    pub fn check(
+        &mut self,
+        ui: &mut Ui,
+        response: &Response,
+        field: &str,
+        result: Result<(), String>,
+    ) -> bool {
+        let field_id = self.id.with(field);
+        let mut state = *ui
+            .memory()
+            .id_data_temp
+            .get_or_default::<FieldState>(field_id);
+        state.touched |= response.changed() || response.lost_focus();
+        ui.memory().id_data_temp.insert(field_id, state);
+
+        match result {
+            Ok(()) => true,
+            Err(message) => {
+                if state.touched {
+                    let visuals = ui.visuals();
+                    let corner_radius = visuals.widgets.inactive.corner_radius;
+                    let stroke = Stroke::new(1.0, visuals.error_fg_color);
+                    ui.painter()
+                        .rect_stroke(response.rect.expand(1.0), corner_radius, stroke);
+                    ui.colored_label(ui.visuals().error_fg_color, &message);
+                }
+                self.errors.push((field.to_owned(), message));
+                false
+            }
+        }
+    }
+
+    /// Did all checks so far pass?
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// The field names and messages of the checks that failed so far.
+    pub fn errors(&self) -> &[(String, String)] {
+        &self.errors
+    }
+
+    /// List the errors, e.g. next to the submit button. Shows nothing if there are none.
+    pub fn show_errors(&self, ui: &mut Ui) {
+        let color = ui.visuals().error_fg_color;
+        for (field, message) in &self.errors {
+            ui.colored_label(color, format!("{}: {}", field, message));
+        }
+    }
+
+    /// Show the messages of all fields, even those the user hasn't edited yet.
+    ///
+    /// Useful when the user tries to submit an incomplete form.
+    pub fn touch_all(&self, ctx: &CtxRef) {
+        let mut memory = ctx.memory();
+        for (field, _) in &self.errors {
+            memory
+                .id_data_temp
+                .insert(self.id.with(field), FieldState { touched: true });
+        }
+    }
+}
+
+/// Fails if `text` is empty or only whitespace.
+///
+/// # Errors
+/// "Required".
+pub fn required(text: &str) -> Result<(), String> {
+    if text.trim().is_empty() {
+        Err("Required".to_owned())
+    } else {
+        Ok(())
+    }
+}
+
+/// Fails if `text` has more than `max` characters.
+///
+/// # Errors
+/// Says how many characters are allowed.
+pub fn max_length(text: &str, max: usize) -> Result<(), String> {
+    if text.chars().count() > max {
+        Err(format!("At most {} characters", max))
+    } else {
+        Ok(())
+    }
+}
+
+/// Fails if `value` is outside of `range`.
+///
+/// # Errors
+/// Says what the range is.
+pub fn in_range<T: PartialOrd + std::fmt::Display>(
+    value: &T,
+    range: std::ops::RangeInclusive<T>,
+) -> Result<(), String> {
+    if range.contains(value) {
+        Ok(())
+    } else {
+        Err(format!(
+            "Must be between {} and {}",
+            range.start(),
+            range.end()
+        ))
+    }
+}
+
+/// Fails if `text` can't be parsed as a `T`, e.g. a number.
+///
+/// # Errors
+/// "Invalid value".
+pub fn parses<T: std::str::FromStr>(text: &str) -> Result<(), String> {
+    match text.trim().parse::<T>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err("Invalid value".to_owned()),
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn validators() {
+    assert!(required("  ").is_err());
+    assert!(required("a").is_ok());
+    assert!(max_length("abc", 2).is_err());
+    assert!(in_range(&5, 1..=10).is_ok());
+    assert!(in_range(&0.5, 1.0..=10.0).is_err());
+    assert!(parses::<f32>("1.5").is_ok());
+    assert!(parses::<u8>("-1").is_err());
+}
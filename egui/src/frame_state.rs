@@ -1,3 +1,5 @@
+use std::ops::RangeInclusive;
+
 use crate::*;
 use epaint::ahash;
 
@@ -9,6 +11,10 @@ pub(crate) struct FrameState {
     /// Used to debug `Id` clashes of widgets.
     pub(crate) used_ids: ahash::AHashMap<Id, Pos2>,
 
+    /// The final interact rects of all widgets that sense clicks or drags, in the order they were added.
+    /// Used by `Options::interact_with_last_frame_rects`.
+    pub(crate) interact_rects: Vec<(LayerId, Id, Rect)>,
+
     /// Starts off as the screen_rect, shrinks as panels are added.
     /// The `CentralPanel` does not change this.
     /// This is the area available to Window's.
@@ -21,26 +27,75 @@ pub(crate) struct FrameState {
     /// How much space is used by panels.
     pub(crate) used_by_panels: Rect,
 
+    /// Has the `CentralPanel` been added this frame?
+    /// Used to warn about panels that are added after it.
+    pub(crate) central_panel_added: bool,
+
     /// If a tooltip has been shown this frame, where was it?
     /// This is used to prevent multiple tooltips to cover each other.
     /// Initialized to `None` at the start of each frame.
     pub(crate) tooltip_rect: Option<(Id, Rect)>,
 
+    /// Set by [`Ui::close_menu`], and cleared by the menu or popup that it was called in.
+    pub(crate) close_menu: bool,
+
+    /// The menus being shown right now, innermost last, so [`crate::menu::submenu`] knows where it is.
+    /// Each is the `Id` of the menu the submenus belong to, and how deep in it we are.
+    pub(crate) menu_stack: Vec<(Id, usize)>,
+
+    /// The shortcuts that have been used by [`crate::Context::consume_shortcut`] this frame.
+    pub(crate) consumed_shortcuts: Vec<KeyboardShortcut>,
+
+    /// Collected by [`Response::widget_info`] when `Options::record_widget_info` is on.
+    pub(crate) widget_records: Vec<WidgetRecord>,
+    /// The `widget_records` of the previous (complete) frame.
+    pub(crate) widget_records_last_frame: Vec<WidgetRecord>,
+
+    /// The widgets that areas are anchored to with [`crate::Area::anchor_to_widget`].
+    /// An `Id` is tracked for one frame after it was last asked for,
+    /// so it doesn't matter if the area is shown before or after the widget.
+    pub(crate) tracked_widgets: ahash::AHashSet<Id>,
+    pub(crate) tracked_widgets_last_frame: ahash::AHashSet<Id>,
+    /// The rects of the `tracked_widgets` shown so far this frame.
+    pub(crate) tracked_rects: ahash::AHashMap<Id, Rect>,
+    /// The `tracked_rects` of the previous (complete) frame.
+    pub(crate) tracked_rects_last_frame: ahash::AHashMap<Id, Rect>,
+
+    /// Widgets that didn't fit, collected when `DebugOptions::show_overflow` is on.
+    pub(crate) overflow_warnings: Vec<String>,
+    /// The `overflow_warnings` of the previous (complete) frame.
+    pub(crate) overflow_warnings_last_frame: Vec<String>,
+
     /// Cleared by the first `ScrollArea` that makes use of it.
     pub(crate) scroll_delta: Vec2,
-    pub(crate) scroll_target: Option<(f32, Align)>,
+    /// Horizontal and vertical range to scroll into view, and how to align it.
+    /// Each axis is taken by the first `ScrollArea` that scrolls along it.
+    pub(crate) scroll_target: [Option<(RangeInclusive<f32>, Option<Align>)>; 2],
 }
 
 impl Default for FrameState {
     fn default() -> Self {
         Self {
             used_ids: Default::default(),
+            interact_rects: Default::default(),
             available_rect: Rect::NAN,
             unused_rect: Rect::NAN,
             used_by_panels: Rect::NAN,
+            central_panel_added: false,
             tooltip_rect: None,
+            close_menu: false,
+            menu_stack: Default::default(),
+            consumed_shortcuts: Default::default(),
+            widget_records: Default::default(),
+            widget_records_last_frame: Default::default(),
+            tracked_widgets: Default::default(),
+            tracked_widgets_last_frame: Default::default(),
+            tracked_rects: Default::default(),
+            tracked_rects_last_frame: Default::default(),
+            overflow_warnings: Default::default(),
+            overflow_warnings_last_frame: Default::default(),
             scroll_delta: Vec2::ZERO,
-            scroll_target: None,
+            scroll_target: [None, None],
         }
     }
 }
@@ -49,21 +104,63 @@ impl FrameState {
     pub(crate) fn begin_frame(&mut self, input: &InputState) {
         let Self {
             used_ids,
+            interact_rects,
             available_rect,
             unused_rect,
             used_by_panels,
+            central_panel_added,
             tooltip_rect,
+            close_menu,
+            menu_stack,
+            consumed_shortcuts,
+            widget_records,
+            widget_records_last_frame,
+            tracked_widgets,
+            tracked_widgets_last_frame,
+            tracked_rects,
+            tracked_rects_last_frame,
+            overflow_warnings,
+            overflow_warnings_last_frame,
             scroll_delta,
             scroll_target,
         } = self;
 
         used_ids.clear();
+        interact_rects.clear();
         *available_rect = input.screen_rect();
         *unused_rect = input.screen_rect();
         *used_by_panels = Rect::NOTHING;
+        *central_panel_added = false;
         *tooltip_rect = None;
+        *close_menu = false;
+        menu_stack.clear();
+        consumed_shortcuts.clear();
+        *widget_records_last_frame = std::mem::take(widget_records);
+        *tracked_widgets_last_frame = std::mem::take(tracked_widgets);
+        *tracked_rects_last_frame = std::mem::take(tracked_rects);
+        *overflow_warnings_last_frame = std::mem::take(overflow_warnings);
         *scroll_delta = input.scroll_delta;
-        *scroll_target = None;
+        *scroll_target = [None, None];
+    }
+
+    /// Where the widget with the given `Id` is this frame, if it has been shown already, else where it was last frame.
+    /// Starts tracking the widget, so call this every frame.
+    pub(crate) fn tracked_widget_rect(&mut self, id: Id) -> Option<Rect> {
+        self.tracked_widgets.insert(id);
+        self.tracked_rects
+            .get(&id)
+            .or_else(|| self.tracked_rects_last_frame.get(&id))
+            .copied()
+    }
+
+    /// Record where a widget is, if it is being tracked.
+    /// Returns `true` if it moved since last frame.
+    pub(crate) fn track_widget_rect(&mut self, id: Id, rect: Rect) -> bool {
+        if !self.tracked_widgets.contains(&id) && !self.tracked_widgets_last_frame.contains(&id) {
+            return false;
+        }
+        self.tracked_rects.insert(id, rect);
+        self.tracked_rects_last_frame.get(&id) != Some(&rect)
     }
 
     /// How much space is still available after panels has been added.
@@ -88,6 +185,20 @@ impl FrameState {
         self.used_by_panels = self.used_by_panels.union(panel_rect);
     }
 
+    /// Shrink `available_rect`.
+    pub(crate) fn allocate_right_panel(&mut self, panel_rect: Rect) {
+        crate::egui_assert!(
+            panel_rect
+                .right_top()
+                .distance(self.available_rect.right_top())
+                < 0.1,
+            "Mismatching right panel. You must not create a panel from within another panel."
+        );
+        self.available_rect.max.x = panel_rect.min.x;
+        self.unused_rect.max.x = panel_rect.min.x;
+        self.used_by_panels = self.used_by_panels.union(panel_rect);
+    }
+
     /// Shrink `available_rect`.
     pub(crate) fn allocate_top_panel(&mut self, panel_rect: Rect) {
         crate::egui_assert!(
@@ -99,10 +210,26 @@ impl FrameState {
         self.used_by_panels = self.used_by_panels.union(panel_rect);
     }
 
-    pub(crate) fn allocate_central_panel(&mut self, panel_rect: Rect) {
+    /// Shrink `available_rect`.
+    pub(crate) fn allocate_bottom_panel(&mut self, panel_rect: Rect) {
+        crate::egui_assert!(
+            (panel_rect.left() - self.available_rect.left()).abs() < 0.1
+                && panel_rect.bottom() >= self.available_rect.bottom() - 0.1,
+            "Mismatching bottom panel. You must not create a panel from within another panel."
+        );
+        self.available_rect.max.y = panel_rect.min.y;
+        self.unused_rect.max.y = panel_rect.min.y;
+        self.used_by_panels = self.used_by_panels.union(panel_rect);
+    }
+
+    /// `content_rect` is the part of `panel_rect` that is actually used by contents.
+    pub(crate) fn allocate_central_panel(&mut self, panel_rect: Rect, content_rect: Rect) {
         // Note: we do not shrink `available_rect`, because
         // we allow windows to cover the CentralPanel.
         self.unused_rect = Rect::NOTHING; // Nothing left unused after this
-        self.used_by_panels = self.used_by_panels.union(panel_rect);
+        self.central_panel_added = true;
+        self.used_by_panels = self
+            .used_by_panels
+            .union(content_rect.intersect(panel_rect));
     }
 }
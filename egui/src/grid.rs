@@ -1,4 +1,4 @@
-use crate::*;
+use crate::{layers::ShapeIdx, *};
 
 #[derive(Clone, Debug, Default, PartialEq)]
 #[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
@@ -39,7 +39,9 @@ impl State {
 pub(crate) struct GridLayout {
     ctx: CtxRef,
     style: std::sync::Arc<Style>,
+    painter: Painter,
     id: Id,
+    enabled: bool,
 
     /// State previous frame (if any).
     /// This can be used to predict future sizes of cells.
@@ -50,11 +52,22 @@ pub(crate) struct GridLayout {
     spacing: Vec2,
 
     striped: bool,
+    highlight_hovered_row: bool,
+    clickable_rows: bool,
     initial_x: f32,
     min_cell_size: Vec2,
     max_cell_size: Vec2,
+    /// Fixed widths of some columns, indexed by column.
+    fixed_col_widths: Vec<Option<f32>>,
+    /// Horizontal alignment of some columns, indexed by column.
+    col_aligns: Vec<Option<Align>>,
     col: usize,
     row: usize,
+    /// Top of the current row.
+    row_top: f32,
+    /// Placeholder for the background of the current row, painted when the row ends.
+    row_background: Option<ShapeIdx>,
+    clicked_row: Option<usize>,
 }
 
 impl GridLayout {
@@ -73,25 +86,48 @@ impl GridLayout {
         Self {
             ctx: ui.ctx().clone(),
             style: ui.style().clone(),
+            painter: ui.painter().clone(),
             id,
+            enabled: ui.enabled(),
             prev_state,
             curr_state: State::default(),
             spacing: ui.spacing().item_spacing,
             striped: false,
+            highlight_hovered_row: false,
+            clickable_rows: false,
             initial_x,
             min_cell_size: ui.spacing().interact_size,
             max_cell_size: Vec2::INFINITY,
+            fixed_col_widths: Vec::new(),
+            col_aligns: Vec::new(),
             col: 0,
             row: 0,
+            row_top: available.min.y,
+            row_background: None,
+            clicked_row: None,
         }
     }
 }
 
 impl GridLayout {
+    fn fixed_col_width(&self, col: usize) -> Option<f32> {
+        self.fixed_col_widths.get(col).copied().flatten()
+    }
+
+    fn col_align(&self, col: usize) -> Align {
+        self.col_aligns
+            .get(col)
+            .copied()
+            .flatten()
+            .unwrap_or(Align::LEFT)
+    }
+
     fn prev_col_width(&self, col: usize) -> f32 {
-        self.prev_state
-            .col_width(col)
-            .unwrap_or(self.min_cell_size.x)
+        self.fixed_col_width(col).unwrap_or_else(|| {
+            self.prev_state
+                .col_width(col)
+                .unwrap_or(self.min_cell_size.x)
+        })
     }
     fn prev_row_height(&self, row: usize) -> f32 {
         self.prev_state
@@ -100,7 +136,7 @@ impl GridLayout {
     }
 
     pub(crate) fn wrap_text(&self) -> bool {
-        self.max_cell_size.x.is_finite()
+        self.max_cell_size.x.is_finite() || self.fixed_col_width(self.col).is_some()
     }
 
     pub(crate) fn available_rect(&self, region: &Region) -> Rect {
@@ -109,7 +145,9 @@ impl GridLayout {
     }
 
     pub(crate) fn available_rect_finite(&self, region: &Region) -> Rect {
-        let width = if self.max_cell_size.x.is_finite() {
+        let width = if let Some(width) = self.fixed_col_width(self.col) {
+            width
+        } else if self.max_cell_size.x.is_finite() {
             // TODO: should probably heed `prev_state` here too
             self.max_cell_size.x
         } else {
@@ -132,16 +170,17 @@ impl GridLayout {
     }
 
     pub(crate) fn next_cell(&self, cursor: Rect, child_size: Vec2) -> Rect {
-        let width = self.prev_state.col_width(self.col).unwrap_or(0.0);
+        let width = self
+            .fixed_col_width(self.col)
+            .or_else(|| self.prev_state.col_width(self.col))
+            .unwrap_or(0.0);
         let height = self.prev_row_height(self.row);
         let size = child_size.max(vec2(width, height));
         Rect::from_min_size(cursor.min, size)
     }
 
-    #[allow(clippy::unused_self)]
     pub(crate) fn align_size_within_rect(&self, size: Vec2, frame: Rect) -> Rect {
-        // TODO: allow this alignment to be customized
-        Align2::LEFT_CENTER.align_size_within_rect(size, frame)
+        Align2([self.col_align(self.col), Align::Center]).align_size_within_rect(size, frame)
     }
 
     pub(crate) fn justify_and_align(&self, frame: Rect, size: Vec2) -> Rect {
@@ -171,44 +210,108 @@ impl GridLayout {
             }
         }
 
-        self.curr_state
-            .set_min_col_width(self.col, widget_rect.width().at_least(self.min_cell_size.x));
+        let col_width = self
+            .fixed_col_width(self.col)
+            .unwrap_or_else(|| widget_rect.width().at_least(self.min_cell_size.x));
+        self.curr_state.set_min_col_width(self.col, col_width);
         self.curr_state.set_min_row_height(
             self.row,
             widget_rect.height().at_least(self.min_cell_size.y),
         );
 
+        let frame_width = self
+            .fixed_col_width(self.col)
+            .unwrap_or_else(|| frame_rect.width());
         self.col += 1;
-        cursor.min.x += frame_rect.width() + self.spacing.x;
+        cursor.min.x += frame_width + self.spacing.x;
     }
 
-    pub(crate) fn end_row(&mut self, cursor: &mut Rect, painter: &Painter) {
+    pub(crate) fn end_row(&mut self, cursor: &mut Rect) {
+        self.finish_row();
+
         let row_height = self.prev_row_height(self.row);
 
         cursor.min.x = self.initial_x;
         cursor.min.y += row_height + self.spacing.y;
         self.col = 0;
         self.row += 1;
+        self.row_top = cursor.min.y;
 
-        if self.striped && self.row % 2 == 1 {
-            if let Some(height) = self.prev_state.row_height(self.row) {
-                // Paint background for coming row:
-                let size = Vec2::new(self.prev_state.full_width(self.spacing.x), height);
-                let rect = Rect::from_min_size(cursor.min, size);
-                let rect = rect.expand2(0.5 * self.spacing.y * Vec2::Y);
-                let rect = rect.expand2(2.0 * Vec2::X); // HACK: just looks better with some spacing on the sides
-
-                let color = if self.style.visuals.dark_mode {
-                    Rgba::from_white_alpha(0.0075)
-                } else {
-                    Rgba::from_black_alpha(0.075)
-                };
-                painter.rect_filled(rect, 2.0, color);
+        self.begin_row();
+    }
+
+    /// Reserve space for the background of the coming row, so that it ends up behind its cells.
+    fn begin_row(&mut self) {
+        if self.striped || self.highlight_hovered_row || self.clickable_rows {
+            self.row_background = Some(self.painter.add(Shape::Noop));
+        }
+    }
+
+    /// Sense hovers and clicks on the row that just ended, and paint its background.
+    fn finish_row(&mut self) {
+        let background = if let Some(background) = self.row_background.take() {
+            background
+        } else {
+            return;
+        };
+
+        let height = self
+            .curr_state
+            .row_height(self.row)
+            .unwrap_or(self.min_cell_size.y);
+        let width = self
+            .prev_state
+            .full_width(self.spacing.x)
+            .max(self.curr_state.full_width(self.spacing.x));
+        let rect = Rect::from_min_size(pos2(self.initial_x, self.row_top), vec2(width, height));
+
+        let mut hovered = false;
+        if self.highlight_hovered_row || self.clickable_rows {
+            let sense = if self.clickable_rows {
+                Sense::click()
+            } else {
+                Sense::hover()
+            };
+            // The cells have already been added, so they get to claim clicks before the row does.
+            let response = self.ctx.interact(
+                self.painter.clip_rect(),
+                self.spacing,
+                self.painter.layer_id(),
+                self.id.with("row").with(self.row),
+                rect,
+                sense,
+                self.enabled,
+            );
+            hovered = response.hovered();
+            if response.clicked() {
+                self.clicked_row = Some(self.row);
             }
         }
+
+        let color = if hovered {
+            self.style.visuals.widgets.hovered.bg_fill
+        } else if self.striped && self.row % 2 == 1 {
+            if self.style.visuals.dark_mode {
+                Rgba::from_white_alpha(0.0075).into()
+            } else {
+                Rgba::from_black_alpha(0.075).into()
+            }
+        } else {
+            return;
+        };
+
+        let rect = rect.expand2(0.5 * self.spacing.y * Vec2::Y);
+        let rect = rect.expand2(2.0 * Vec2::X); // HACK: just looks better with some spacing on the sides
+        self.painter
+            .set(background, Shape::rect_filled(rect, 2.0, color));
     }
 
-    pub(crate) fn save(&self) {
+    pub(crate) fn save(&mut self) {
+        if self.col > 0 {
+            // The last row was not ended with `end_row`:
+            self.finish_row();
+        }
+
         if self.curr_state != self.prev_state {
             self.ctx
                 .memory()
@@ -224,11 +327,14 @@ impl GridLayout {
 /// A simple grid layout.
 ///
 /// The cells are always layed out left to right, top-down.
-/// The contents of each cell will be aligned to the left and center.
+/// The contents of each cell will be aligned to the left and center,
+/// unless another alignment is set with [`Self::col_align`].
 ///
 /// If you want to add multiple widgets to a cell you need to group them with
 /// [`Ui::horizontal`], [`Ui::vertical`] etc.
 ///
+/// For a simple list where whole rows can be clicked, use [`Self::show_clickable`].
+///
 /// ```
 /// # let ui = &mut egui::Ui::__test();
 /// egui::Grid::new("some_unique_id").show(ui, |ui| {
@@ -250,9 +356,12 @@ impl GridLayout {
 pub struct Grid {
     id_source: Id,
     striped: bool,
+    highlight_hovered_row: bool,
     min_col_width: Option<f32>,
     min_row_height: Option<f32>,
     max_cell_size: Vec2,
+    fixed_col_widths: Vec<Option<f32>>,
+    col_aligns: Vec<Option<Align>>,
     spacing: Option<Vec2>,
 }
 
@@ -262,9 +371,12 @@ impl Grid {
         Self {
             id_source: Id::new(id_source),
             striped: false,
+            highlight_hovered_row: false,
             min_col_width: None,
             min_row_height: None,
             max_cell_size: Vec2::INFINITY,
+            fixed_col_widths: Vec::new(),
+            col_aligns: Vec::new(),
             spacing: None,
         }
     }
@@ -278,6 +390,13 @@ impl Grid {
         self
     }
 
+    /// If `true`, highlight the row under the mouse pointer.
+    /// Default: `false`.
+    pub fn highlight_hovered_row(mut self, highlight_hovered_row: bool) -> Self {
+        self.highlight_hovered_row = highlight_hovered_row;
+        self
+    }
+
     /// Set minimum width of each column.
     /// Default: [`crate::style::Spacing::interact_size`]`.x`.
     pub fn min_col_width(mut self, min_col_width: f32) -> Self {
@@ -298,6 +417,21 @@ impl Grid {
         self
     }
 
+    /// Give the column with the given index a fixed width, regardless of its contents.
+    /// Text in the column wraps to fit.
+    pub fn col_width(mut self, col: usize, width: f32) -> Self {
+        set_col(&mut self.fixed_col_widths, col, width);
+        self
+    }
+
+    /// Horizontally align the contents of the column with the given index,
+    /// e.g. [`Align::RIGHT`] for a column of numbers.
+    /// Default: [`Align::LEFT`].
+    pub fn col_align(mut self, col: usize, align: Align) -> Self {
+        set_col(&mut self.col_aligns, col, align);
+        self
+    }
+
     /// Set spacing between columns/rows.
     /// Default: [`crate::style::Spacing::item_spacing`].
     pub fn spacing(mut self, spacing: impl Into<Vec2>) -> Self {
@@ -308,36 +442,89 @@ impl Grid {
 
 impl Grid {
     pub fn show<R>(self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        self.show_impl(ui, false, add_contents).0
+    }
+
+    /// Like [`Self::show`], but whole rows can be clicked, and they are highlighted when hovered.
+    ///
+    /// Also returns the index of the row that was clicked, if any.
+    /// Clicks on interactive widgets inside a row go to the widget, not to the row.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// let files = ["notes.txt", "photo.png", "song.mp3"];
+    /// let (_, clicked_row) = egui::Grid::new("files").striped(true).show_clickable(ui, |ui| {
+    ///     for file in &files {
+    ///         ui.label(*file);
+    ///         ui.end_row();
+    ///     }
+    /// });
+    /// if let Some(row) = clicked_row {
+    ///     println!("Clicked {}", files[row]);
+    /// }
+    /// ```
+    pub fn show_clickable<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> (InnerResponse<R>, Option<usize>) {
+        self.show_impl(ui, true, add_contents)
+    }
+
+    fn show_impl<R>(
+        self,
+        ui: &mut Ui,
+        clickable_rows: bool,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> (InnerResponse<R>, Option<usize>) {
         let Self {
             id_source,
             striped,
+            highlight_hovered_row,
             min_col_width,
             min_row_height,
             max_cell_size,
+            fixed_col_widths,
+            col_aligns,
             spacing,
         } = self;
         let min_col_width = min_col_width.unwrap_or_else(|| ui.spacing().interact_size.x);
         let min_row_height = min_row_height.unwrap_or_else(|| ui.spacing().interact_size.y);
         let spacing = spacing.unwrap_or_else(|| ui.spacing().item_spacing);
 
-        // Each grid cell is aligned LEFT_CENTER.
+        // Each grid cell is centered vertically, and aligned horizontally by `col_align` (left by default).
         // If somebody wants to wrap more things inside a cell,
-        // then we should pick a default layout that matches that alignment,
+        // then we should pick a default layout that matches the default alignment,
         // which we do here:
-        ui.horizontal(|ui| {
+        let mut clicked_row = None;
+        let response = ui.horizontal(|ui| {
             let id = ui.make_persistent_id(id_source);
-            let grid = GridLayout {
+            let mut grid = GridLayout {
                 striped,
+                highlight_hovered_row,
+                clickable_rows,
                 spacing,
                 min_cell_size: vec2(min_col_width, min_row_height),
                 max_cell_size,
+                fixed_col_widths,
+                col_aligns,
                 ..GridLayout::new(ui, id)
             };
+            grid.begin_row();
 
             ui.set_grid(grid);
             let r = add_contents(ui);
             ui.save_grid();
+            clicked_row = ui.grid().and_then(|grid| grid.clicked_row);
             r
-        })
+        });
+        (response, clicked_row)
+    }
+}
+
+fn set_col<T>(cols: &mut Vec<Option<T>>, col: usize, value: T) {
+    if cols.len() <= col {
+        cols.resize_with(col + 1, || None);
     }
+    cols[col] = Some(value);
 }
@@ -8,8 +8,6 @@ pub use crate::data::input::Key;
 pub use touch_state::MultiTouchInfo;
 use touch_state::TouchState;
 
-/// If the pointer moves more than this, it is no longer a click (but maybe a drag)
-const MAX_CLICK_DIST: f32 = 6.0; // TODO: move to settings
 /// The new pointer press must come within this many seconds from previous pointer release
 const MAX_CLICK_DELAY: f64 = 0.3; // TODO: move to settings
 
@@ -30,8 +28,13 @@ pub struct InputState {
     touch_states: BTreeMap<TouchDeviceId, TouchState>,
 
     /// How many pixels the user scrolled.
+    ///
+    /// This is zero when the user is zooming with ctrl/cmd + scroll wheel (see [`Self::zoom_delta`]).
     pub scroll_delta: Vec2,
 
+    /// Zoom scale factor this frame, combining [`RawInput::zoom_delta`] with ctrl/cmd + scroll wheel.
+    zoom_factor_delta: f32,
+
     /// Position and size of the egui area.
     pub screen_rect: Rect,
 
@@ -68,6 +71,7 @@ impl Default for InputState {
             pointer: Default::default(),
             touch_states: Default::default(),
             scroll_delta: Default::default(),
+            zoom_factor_delta: 1.0,
             screen_rect: Rect::from_min_size(Default::default(), vec2(10_000.0, 10_000.0)),
             pixels_per_point: 1.0,
             time: 0.0,
@@ -101,6 +105,15 @@ impl InputState {
             touch_state.begin_frame(time, &new, self.pointer.interact_pos);
         }
         let pointer = self.pointer.begin_frame(time, &new);
+
+        let mut scroll_delta = new.scroll_delta;
+        let mut zoom_factor_delta = new.zoom_delta;
+        if new.modifiers.ctrl || new.modifiers.command {
+            // Scrolling while holding ctrl (or cmd on Mac) is the conventional way to zoom:
+            zoom_factor_delta *= (scroll_delta.y / 200.0).exp();
+            scroll_delta = Vec2::ZERO;
+        }
+
         let mut keys_down = self.keys_down;
         for event in &new.events {
             if let Event::Key { key, pressed, .. } = event {
@@ -114,7 +127,8 @@ impl InputState {
         InputState {
             pointer,
             touch_states: self.touch_states,
-            scroll_delta: new.scroll_delta,
+            scroll_delta,
+            zoom_factor_delta,
             screen_rect,
             pixels_per_point: new.pixels_per_point.unwrap_or(self.pixels_per_point),
             time,
@@ -136,6 +150,10 @@ impl InputState {
     /// * `zoom = 1`: no change
     /// * `zoom < 1`: pinch together
     /// * `zoom > 1`: pinch spread
+    ///
+    /// This combines multi-touch pinches, [`RawInput::zoom_delta`] from the integration,
+    /// and scrolling while holding ctrl (or cmd on Mac),
+    /// so this is the only thing you need to check to implement zooming.
     #[inline(always)]
     pub fn zoom_delta(&self) -> f32 {
         // If a multi touch gesture is detected, it measures the exact and linear proportions of
@@ -144,7 +162,7 @@ impl InputState {
         // synthesized from an original touch gesture.
         self.multi_touch()
             .map(|touch| touch.zoom_delta)
-            .unwrap_or(self.raw.zoom_delta)
+            .unwrap_or(self.zoom_factor_delta)
     }
 
     /// 2D non-proportional zoom scale factor this frame (e.g. from ctrl-scroll or pinch gesture).
@@ -168,11 +186,14 @@ impl InputState {
         // synthesized from an original touch gesture.
         self.multi_touch()
             .map(|touch| touch.zoom_delta_2d)
-            .unwrap_or_else(|| Vec2::splat(self.raw.zoom_delta))
+            .unwrap_or_else(|| Vec2::splat(self.zoom_factor_delta))
     }
 
     pub fn wants_repaint(&self) -> bool {
-        self.pointer.wants_repaint() || self.scroll_delta != Vec2::ZERO || !self.events.is_empty()
+        self.pointer.wants_repaint()
+            || self.scroll_delta != Vec2::ZERO
+            || self.zoom_factor_delta != 1.0
+            || !self.events.is_empty()
     }
 
     /// Was the given key pressed this frame?
@@ -359,6 +380,13 @@ pub struct PointerState {
     /// Set to true on pointer button down, set to false when pointer button moves too much.
     could_be_click: bool,
 
+    /// If the pointer moves more than this while a button is down, it is no longer a click.
+    /// Set from [`crate::style::Interaction::drag_threshold`] each frame.
+    pub(crate) max_click_dist: f32,
+
+    /// Did the pointer move far enough this frame to turn a press into a drag?
+    started_decidedly_dragging: bool,
+
     /// When did the pointer get click last?
     /// Used to check for double-clicks.
     last_click_time: f64,
@@ -378,6 +406,8 @@ impl Default for PointerState {
             down: Default::default(),
             press_origin: None,
             could_be_click: false,
+            max_click_dist: crate::style::Interaction::default().drag_threshold,
+            started_decidedly_dragging: false,
             last_click_time: std::f64::NEG_INFINITY,
             pointer_events: vec![],
         }
@@ -389,6 +419,7 @@ impl PointerState {
     pub(crate) fn begin_frame(mut self, time: f64, new: &RawInput) -> PointerState {
         self.pointer_events.clear();
 
+        let was_decidedly_dragging = self.is_decidedly_dragging();
         let old_pos = self.latest_pos;
         self.interact_pos = self.latest_pos;
 
@@ -401,7 +432,7 @@ impl PointerState {
                     self.interact_pos = Some(pos);
 
                     if let Some(press_origin) = &mut self.press_origin {
-                        self.could_be_click &= press_origin.distance(pos) < MAX_CLICK_DIST;
+                        self.could_be_click &= press_origin.distance(pos) < self.max_click_dist;
                     } else {
                         self.could_be_click = false;
                     }
@@ -489,6 +520,8 @@ impl PointerState {
             Vec2::default()
         };
 
+        self.started_decidedly_dragging = !was_decidedly_dragging && self.is_decidedly_dragging();
+
         self
     }
 
@@ -581,6 +614,13 @@ impl PointerState {
         self.pointer_events.iter().any(|event| event.is_click())
     }
 
+    /// Was there a double-click with any pointer button this frame?
+    pub fn any_double_click(&self) -> bool {
+        self.pointer_events
+            .iter()
+            .any(|event| matches!(event, PointerEvent::Released(Some(click)) if click.is_double()))
+    }
+
     // /// Was this button pressed (`!down -> down`) this frame?
     // /// This can sometimes return `true` even if `any_down() == false`
     // /// because a press can be shorted than one frame.
@@ -603,6 +643,20 @@ impl PointerState {
     pub(crate) fn could_any_button_be_click(&self) -> bool {
         self.could_be_click
     }
+
+    /// Is a button down and has the pointer moved far enough to no longer be a click?
+    ///
+    /// See [`crate::style::Interaction::drag_threshold`].
+    #[inline(always)]
+    pub fn is_decidedly_dragging(&self) -> bool {
+        self.any_down() && !self.could_be_click
+    }
+
+    /// Did [`Self::is_decidedly_dragging`] become true this frame?
+    #[inline(always)]
+    pub fn started_decidedly_dragging(&self) -> bool {
+        self.started_decidedly_dragging
+    }
 }
 
 impl InputState {
@@ -612,6 +666,7 @@ impl InputState {
             pointer,
             touch_states,
             scroll_delta,
+            zoom_factor_delta,
             screen_rect,
             pixels_per_point,
             time,
@@ -638,6 +693,7 @@ impl InputState {
         }
 
         ui.label(format!("scroll_delta: {:?} points", scroll_delta));
+        ui.label(format!("zoom_factor_delta: {:4.2}x", zoom_factor_delta));
         ui.label(format!("screen_rect: {:?} points", screen_rect));
         ui.label(format!(
             "{:?} physical pixels for each logical point",
@@ -667,6 +723,8 @@ impl PointerState {
             down,
             press_origin,
             could_be_click,
+            max_click_dist,
+            started_decidedly_dragging,
             last_click_time,
             pointer_events,
         } = self;
@@ -681,6 +739,11 @@ impl PointerState {
         ui.label(format!("down: {:#?}", down));
         ui.label(format!("press_origin: {:?}", press_origin));
         ui.label(format!("could_be_click: {:#?}", could_be_click));
+        ui.label(format!("max_click_dist: {:#?}", max_click_dist));
+        ui.label(format!(
+            "started_decidedly_dragging: {:#?}",
+            started_decidedly_dragging
+        ));
         ui.label(format!("last_click_time: {:#?}", last_click_time));
         ui.label(format!("pointer_events: {:?}", pointer_events));
     }
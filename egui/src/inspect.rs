@@ -0,0 +1,162 @@
+//! Editors for plain data, for quickly making debug and tweak panels.
+//!
+//! Implement [`Inspect`] for your structs with [`crate::impl_inspect_struct`]
+//! and for your enums with [`crate::impl_inspect_enum`], then show them with [`inspector`]:
+//!
+//! ```
+//! # let ui = &mut egui::Ui::__test();
+//! #[derive(PartialEq)]
+//! enum Shape {
+//!     Circle,
+//!     Square,
+//! }
+//! egui::impl_inspect_enum!(Shape { Circle, Square });
+//!
+//! struct Particle {
+//!     shape: Shape,
+//!     size: f32,
+//!     count: usize,
+//!     visible: bool,
+//! }
+//! egui::impl_inspect_struct!(Particle { shape, size, count, visible });
+//!
+//! # let mut particle = Particle { shape: Shape::Circle, size: 1.0, count: 10, visible: true };
+//! egui::inspect::inspector(ui, "Particle", &mut particle);
+//! ```
+//!
+//! Numbers get a [`DragValue`], `bool`s a checkbox, enums a [`ComboBox`],
+//! and nested structs and `Vec`s a collapsible group.
+
+use crate::*;
+
+/// Something that can be edited in a [`PropertyGrid`].
+///
+/// See the [module level docs](crate::inspect) for how to implement this for your own types.
+pub trait Inspect {
+    /// Add the row (or group of rows) for editing this value.
+    fn inspect(&mut self, grid: &mut PropertyGridUi<'_>, label: &str);
+}
+
+/// Show an editor for `value` in a [`PropertyGrid`].
+///
+/// `label` is the name of the value, and also used as the id of the grid.
+pub fn inspector(ui: &mut Ui, label: &str, value: &mut impl Inspect) -> Response {
+    PropertyGrid::new(label)
+        .show(ui, |grid| value.inspect(grid, label))
+        .response
+}
+
+macro_rules! impl_inspect_numeric {
+    ($($t:ty),*) => {
+        $(
+            impl Inspect for $t {
+                fn inspect(&mut self, grid: &mut PropertyGridUi<'_>, label: &str) {
+                    grid.row(label, |ui| ui.add(DragValue::new(self)));
+                }
+            }
+        )*
+    };
+}
+
+impl_inspect_numeric!(f32, f64, i8, u8, i16, u16, i32, u32, i64, u64, isize, usize);
+
+impl Inspect for bool {
+    fn inspect(&mut self, grid: &mut PropertyGridUi<'_>, label: &str) {
+        grid.row(label, |ui| ui.checkbox(self, ""));
+    }
+}
+
+impl Inspect for String {
+    fn inspect(&mut self, grid: &mut PropertyGridUi<'_>, label: &str) {
+        grid.row(label, |ui| ui.text_edit_singleline(self));
+    }
+}
+
+impl Inspect for Vec2 {
+    fn inspect(&mut self, grid: &mut PropertyGridUi<'_>, label: &str) {
+        grid.row(label, |ui| {
+            ui.add(DragValue::new(&mut self.x).prefix("x: "));
+            ui.add(DragValue::new(&mut self.y).prefix("y: "));
+        });
+    }
+}
+
+impl Inspect for Color32 {
+    fn inspect(&mut self, grid: &mut PropertyGridUi<'_>, label: &str) {
+        grid.row(label, |ui| ui.color_edit_button_srgba(self));
+    }
+}
+
+impl<T: Inspect> Inspect for Vec<T> {
+    fn inspect(&mut self, grid: &mut PropertyGridUi<'_>, label: &str) {
+        grid.group(label, |grid| {
+            for (i, item) in self.iter_mut().enumerate() {
+                item.inspect(grid, &i.to_string());
+            }
+        });
+    }
+}
+
+/// Implement [`inspect::Inspect`] for a struct, showing the given fields in a collapsible group.
+///
+/// All the fields must implement [`inspect::Inspect`] themselves.
+///
+/// ```
+/// struct Camera {
+///     fov: f32,
+///     near: f32,
+///     far: f32,
+/// }
+/// egui::impl_inspect_struct!(Camera { fov, near, far });
+/// ```
+#[macro_export]
+macro_rules! impl_inspect_struct {
+    ($ty:ty { $($field:ident),* $(,)? }) => {
+        impl $crate::inspect::Inspect for $ty {
+            fn inspect(&mut self, grid: &mut $crate::PropertyGridUi<'_>, label: &str) {
+                grid.group(label, |grid| {
+                    $(
+                        $crate::inspect::Inspect::inspect(&mut self.$field, grid, stringify!($field));
+                    )*
+                });
+            }
+        }
+    };
+}
+
+/// Implement [`inspect::Inspect`] for an enum without fields, showing a [`ComboBox`] of the given variants.
+///
+/// The enum must implement `PartialEq`.
+///
+/// ```
+/// #[derive(PartialEq)]
+/// enum Quality {
+///     Low,
+///     Medium,
+///     High,
+/// }
+/// egui::impl_inspect_enum!(Quality { Low, Medium, High });
+/// ```
+#[macro_export]
+macro_rules! impl_inspect_enum {
+    ($ty:ty { $($variant:ident),* $(,)? }) => {
+        impl $crate::inspect::Inspect for $ty {
+            fn inspect(&mut self, grid: &mut $crate::PropertyGridUi<'_>, label: &str) {
+                let selected_text = match self {
+                    $(Self::$variant => stringify!($variant),)*
+                };
+                // The same label can be used in many groups, so base the id on the group too:
+                let id_source = grid.id().with(label);
+                grid.row(label, |ui| {
+                    $crate::ComboBox::from_id_source(id_source)
+                        .selected_text(selected_text)
+                        .show_ui(ui, |ui| {
+                            $(
+                                ui.selectable_value(self, Self::$variant, stringify!($variant));
+                            )*
+                        })
+                });
+            }
+        }
+    };
+}
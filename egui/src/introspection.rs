@@ -92,6 +92,7 @@ impl Widget for &epaint::stats::PaintStats {
                 clipped_meshes,
                 vertices,
                 indices,
+                texture_uploads,
             } = self;
 
             ui.label("Intermediate:");
@@ -107,6 +108,10 @@ impl Widget for &epaint::stats::PaintStats {
                 .on_hover_text("Number of separate clip rectangles");
             label(ui, vertices, "vertices");
             label(ui, indices, "indices").on_hover_text("Three 32-bit indices per triangles");
+            ui.add(Label::new(format!("{} texture uploads", texture_uploads)).wrap(false))
+                .on_hover_text(
+                    "The font texture changed (e.g. new glyphs) and needs to be uploaded",
+                );
             ui.add_space(10.0);
 
             // ui.label("Total:");
@@ -116,6 +121,37 @@ impl Widget for &epaint::stats::PaintStats {
     }
 }
 
+impl Widget for &RepaintStats {
+    fn ui(self, ui: &mut Ui) -> Response {
+        ui.vertical(|ui| {
+            ui.label(
+                "How many times egui asked for a repaint during the last second, and why. \
+            When nothing is happening this should be zero.",
+            );
+            ui.add_space(10.0);
+
+            ui.style_mut().body_text_style = TextStyle::Monospace;
+
+            let RepaintStats {
+                input,
+                animation,
+                request,
+                last_cause,
+                idle_time,
+            } = self;
+
+            ui.label(format!("{:>4} input", input))
+                .on_hover_text("The mouse moved, a key was pressed, etc");
+            ui.label(format!("{:>4} animation", animation));
+            ui.label(format!("{:>4} request", request))
+                .on_hover_text("Calls to Context::request_repaint");
+            ui.label(format!("Last cause: {:?}", last_cause));
+            ui.label(format!("Idle for {:.1} s", idle_time));
+        })
+        .response
+    }
+}
+
 pub fn label(ui: &mut Ui, alloc_info: &epaint::stats::AllocInfo, what: &str) -> Response {
     ui.add(Label::new(alloc_info.format(what)).wrap(false))
 }
@@ -153,6 +189,10 @@ impl Widget for &memory::Interaction {
             ui.label(format!("drag_is_window: {:?}", self.drag_is_window));
             ui.label(format!("click_interest: {:?}", self.click_interest));
             ui.label(format!("drag_interest: {:?}", self.drag_interest));
+            ui.label(format!(
+                "hovered_last_frame_rect: {:?}",
+                self.hovered_last_frame_rect
+            ));
         })
         .response
     }
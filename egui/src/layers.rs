@@ -116,6 +116,43 @@ impl PaintList {
         self.0[idx.0] = ClippedShape(clip_rect, shape);
     }
 
+    pub(crate) fn clipped_shapes(&self) -> &[ClippedShape] {
+        &self.0
+    }
+
+    pub(crate) fn extend_clipped(&mut self, shapes: impl IntoIterator<Item = ClippedShape>) {
+        self.0.extend(shapes)
+    }
+
+    /// Tessellate each [`Shape`] and scale it and its clip rectangle around `origin`, in-place.
+    ///
+    /// Used to render a layer at a different scale than the rest (see [`crate::Area::scale`]).
+    /// The `options` should have the `pixels_per_point` of the scaled layer,
+    /// so that the feathering and pixel rounding is correct after scaling.
+    pub(crate) fn tessellate_and_scale(
+        &mut self,
+        origin: Pos2,
+        scale: f32,
+        options: epaint::TessellationOptions,
+        tex_size: [usize; 2],
+    ) {
+        let scale_pos = |pos: Pos2| origin + scale * (pos - origin);
+        let mut tessellator = epaint::Tessellator::from_options(options);
+        for ClippedShape(clip_rect, shape) in &mut self.0 {
+            let mut mesh = epaint::Mesh::default();
+            tessellator.tessellate_shape(
+                tex_size,
+                std::mem::replace(shape, Shape::Noop),
+                &mut mesh,
+            );
+            for vertex in &mut mesh.vertices {
+                vertex.pos = scale_pos(vertex.pos);
+            }
+            *clip_rect = Rect::from_min_max(scale_pos(clip_rect.min), scale_pos(clip_rect.max));
+            *shape = Shape::mesh(mesh);
+        }
+    }
+
     /// Translate each [`Shape`] and clip rectangle by this much, in-place
     pub fn translate(&mut self, delta: Vec2) {
         for ClippedShape(clip_rect, shape) in &mut self.0 {
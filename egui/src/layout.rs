@@ -69,6 +69,29 @@ impl Region {
         self.max_rect = self.max_rect.union(rect);
     }
 
+    /// Only place new widgets within `rect` from now on,
+    /// e.g. to make room for a panel along one of the edges.
+    ///
+    /// Unlike most other operations, this can leave `min_rect` outside of `max_rect`.
+    pub fn cut_to(&mut self, rect: Rect) {
+        self.max_rect = self.max_rect.intersect(rect);
+
+        // The side of the cursor facing the layout direction is infinite, and should stay so:
+        let cursor = &mut self.cursor;
+        if cursor.min.x.is_finite() {
+            cursor.min.x = cursor.min.x.max(rect.min.x);
+        }
+        if cursor.min.y.is_finite() {
+            cursor.min.y = cursor.min.y.max(rect.min.y);
+        }
+        if cursor.max.x.is_finite() {
+            cursor.max.x = cursor.max.x.min(rect.max.x);
+        }
+        if cursor.max.y.is_finite() {
+            cursor.max.y = cursor.max.y.min(rect.max.y);
+        }
+    }
+
     /// Ensure we are big enough to contain the given X-coordinate.
     /// This is sometimes useful to expand an ui to stretch to a certain place.
     pub fn expand_to_include_x(&mut self, x: f32) {
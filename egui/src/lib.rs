@@ -321,20 +321,26 @@ pub mod any;
 pub mod containers;
 mod context;
 mod data;
+mod design_overlay;
+pub mod form;
 mod frame_state;
 pub(crate) mod grid;
 mod id;
 mod input_state;
+pub mod inspect;
 mod introspection;
 pub mod layers;
 mod layout;
+mod localization;
 mod memory;
 pub mod menu;
 mod painter;
 pub(crate) mod placer;
+mod repaint_stats;
 mod response;
 mod sense;
 pub mod style;
+pub mod task;
 mod ui;
 pub mod util;
 pub mod widgets;
@@ -360,18 +366,23 @@ pub use {
     context::{Context, CtxRef},
     data::{
         input::*,
-        output::{self, CursorIcon, Output, WidgetInfo, WidgetType},
+        output::{self, CursorIcon, Output, WidgetInfo, WidgetRecord, WidgetType},
     },
+    form::Form,
     grid::Grid,
     id::Id,
     input_state::{InputState, MultiTouchInfo, PointerState},
+    inspect::Inspect,
     layers::{LayerId, Order},
     layout::*,
-    memory::Memory,
+    localization::Localization,
+    memory::{Memory, PaintBudget},
     painter::Painter,
+    repaint_stats::{RepaintCause, RepaintStats},
     response::{InnerResponse, Response},
     sense::Sense,
     style::{Style, Visuals},
+    task::{Task, TaskReporter},
     ui::Ui,
     widgets::*,
 };
@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+/// Translates the text of egui's built-in widgets (e.g. the labels in the color picker),
+/// and formats numbers (e.g. in a [`crate::DragValue`] or [`crate::Slider`]) for the user's locale.
+///
+/// The English text is used as the key for the translation.
+/// Return `None` for text you don't have a translation for, and it is shown in English.
+///
+/// ```
+/// # let ctx = egui::CtxRef::default();
+/// ctx.set_localization(
+///     egui::Localization::default()
+///         .decimal_separator(',')
+///         .translate(|text| match text {
+///             "Hue" => Some("Farbton".to_owned()),
+///             "Saturation" => Some("Sättigung".to_owned()),
+///             _ => None,
+///         }),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct Localization {
+    decimal_separator: char,
+    translate: Option<Arc<TranslateFn>>,
+}
+
+/// See [`Localization::translate`].
+type TranslateFn = dyn Fn(&str) -> Option<String> + Send + Sync;
+
+impl Default for Localization {
+    fn default() -> Self {
+        Self {
+            decimal_separator: '.',
+            translate: None,
+        }
+    }
+}
+
+impl std::fmt::Debug for Localization {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Localization")
+            .field("decimal_separator", &self.decimal_separator)
+            .field("translate", &self.translate.is_some())
+            .finish()
+    }
+}
+
+impl Localization {
+    /// The character between the integer and the fraction of a number. Default: `'.'`.
+    pub fn decimal_separator(mut self, decimal_separator: char) -> Self {
+        self.decimal_separator = decimal_separator;
+        self
+    }
+
+    /// Translate the English text of the built-in widgets.
+    pub fn translate(
+        mut self,
+        translate: impl Fn(&str) -> Option<String> + Send + Sync + 'static,
+    ) -> Self {
+        self.translate = Some(Arc::new(translate));
+        self
+    }
+
+    /// Translate some English text, or return it as is if there is no translation.
+    pub fn tr(&self, text: &str) -> String {
+        self.translate
+            .as_ref()
+            .and_then(|translate| translate(text))
+            .unwrap_or_else(|| text.to_owned())
+    }
+
+    /// Replace the `.` in a formatted number with [`Self::decimal_separator`].
+    pub fn format_number(&self, text: String) -> String {
+        if self.decimal_separator == '.' {
+            text
+        } else {
+            text.replace('.', &self.decimal_separator.to_string())
+        }
+    }
+
+    /// Parse a number written by the user, with either [`Self::decimal_separator`] or `.`.
+    pub fn parse_number(&self, text: &str) -> Option<f64> {
+        let text = text.trim();
+        text.parse()
+            .ok()
+            .or_else(|| text.replace(self.decimal_separator, ".").parse().ok())
+    }
+}
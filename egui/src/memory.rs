@@ -90,6 +90,88 @@ pub struct Options {
     /// but is a signal to any backend that we want the [`crate::Output::events`] read out loud.
     /// Screen readers is an experimental feature of egui, and not supported on all platforms.
     pub screen_reader: bool,
+
+    /// If `true`, clicks and hovers are resolved against the final rectangles
+    /// that widgets had at the end of the *previous* frame.
+    ///
+    /// Normally a widget checks if it is hovered using the rectangle it gets *this* frame,
+    /// while the user clicked on what was painted *last* frame.
+    /// If the layout changes between frames (e.g. a label above a button changes height)
+    /// the click may land on a different widget than the one the user saw under the pointer.
+    ///
+    /// With this option on, at the start of each frame egui finds the top-most layer under
+    /// the pointer and, within that layer, the last-added widget whose rectangle (from last frame)
+    /// contains the pointer. Only that widget will be hovered (and can be clicked).
+    /// Widgets that did not exist last frame fall back to using their current rectangle.
+    ///
+    /// Default: `false`.
+    pub interact_with_last_frame_rects: bool,
+
+    /// If `true`, the [`crate::WidgetInfo`] of every widget is recorded each frame,
+    /// and can be read with [`crate::Context::widget_records`].
+    ///
+    /// This is always done when [`Self::screen_reader`] is on.
+    ///
+    /// Default: `false`.
+    pub record_widget_info: bool,
+
+    /// If set, egui checks the [`crate::epaint::stats::PaintStats`] of each frame against these limits.
+    ///
+    /// Use [`crate::Context::paint_budget_warnings`] to log the warnings.
+    ///
+    /// Default: `None`.
+    pub paint_budget: Option<PaintBudget>,
+}
+
+/// Limits on how much is painted each frame, to catch mistakes like a million-vertex plot.
+/// See [`Options::paint_budget`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+pub struct PaintBudget {
+    /// Maximum number of shapes (including nested shapes).
+    pub max_shapes: usize,
+    /// Maximum number of separate clip rectangles.
+    pub max_clipped_meshes: usize,
+    pub max_vertices: usize,
+    pub max_indices: usize,
+    /// Paint the warnings on top of everything else.
+    pub show_overlay: bool,
+}
+
+impl Default for PaintBudget {
+    fn default() -> Self {
+        Self {
+            max_shapes: 100_000,
+            max_clipped_meshes: 1_000,
+            max_vertices: 1_000_000,
+            max_indices: 3_000_000,
+            show_overlay: true,
+        }
+    }
+}
+
+impl PaintBudget {
+    /// One warning for each limit that `stats` exceeds.
+    pub fn check(&self, stats: &epaint::stats::PaintStats) -> Vec<String> {
+        let checks = [
+            ("shapes", stats.shapes.num_elements(), self.max_shapes),
+            (
+                "clipped meshes",
+                stats.clipped_meshes.num_elements(),
+                self.max_clipped_meshes,
+            ),
+            ("vertices", stats.vertices.num_elements(), self.max_vertices),
+            ("indices", stats.indices.num_elements(), self.max_indices),
+        ];
+        checks
+            .iter()
+            .filter(|(_, count, max)| count > max)
+            .map(|(what, count, max)| {
+                format!("Paint budget exceeded: {} {} (max {})", count, what, max)
+            })
+            .collect()
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -125,6 +207,14 @@ pub(crate) struct Interaction {
     /// Any interest in catching clicks this frame?
     /// Cleared to false at start of each frame.
     pub drag_interest: bool,
+
+    /// The layer of each interactive widget last frame.
+    /// Used by [`Options::interact_with_last_frame_rects`].
+    pub rects_last_frame: epaint::ahash::AHashMap<Id, LayerId>,
+
+    /// The widget under the pointer according to the rectangles of last frame.
+    /// Used by [`Options::interact_with_last_frame_rects`].
+    pub hovered_last_frame_rect: Option<Id>,
 }
 
 /// Keeps tracks of what widget has keyboard focus
@@ -162,6 +252,40 @@ impl Interaction {
         self.click_id.is_some() || self.drag_id.is_some()
     }
 
+    /// Remember the final widget rectangles of last frame,
+    /// and find which widget is under the pointer according to them.
+    pub(crate) fn set_rects_last_frame(
+        &mut self,
+        interact_rects: &[(LayerId, Id, Rect)],
+        top_layer: Option<LayerId>,
+        pointer_pos: Option<Pos2>,
+    ) {
+        self.rects_last_frame = interact_rects
+            .iter()
+            .map(|(layer_id, id, _)| (*id, *layer_id))
+            .collect();
+
+        self.hovered_last_frame_rect = None;
+        if let (Some(top_layer), Some(pointer_pos)) = (top_layer, pointer_pos) {
+            // Last added is painted on top:
+            self.hovered_last_frame_rect = interact_rects
+                .iter()
+                .rev()
+                .find(|(layer_id, _, rect)| *layer_id == top_layer && rect.contains(pointer_pos))
+                .map(|(_, id, _)| *id);
+        }
+    }
+
+    /// If the widget existed last frame, is it hovered according to last frame's rectangles?
+    /// Returns `None` for new widgets.
+    pub(crate) fn hovered_by_last_frame_rect(&self, id: Id) -> Option<bool> {
+        if self.rects_last_frame.contains_key(&id) {
+            Some(self.hovered_last_frame_rect == Some(id))
+        } else {
+            None
+        }
+    }
+
     fn begin_frame(
         &mut self,
         prev_input: &crate::input_state::InputState,
@@ -174,8 +298,11 @@ impl Interaction {
             self.click_id = None;
         }
 
-        if !prev_input.pointer.any_down() || prev_input.pointer.latest_pos().is_none() {
-            // pointer button was not down last frame
+        if !prev_input.pointer.any_down() {
+            // pointer button was not down last frame.
+            // A drag continues outside the window until the button is released.
+            // Integrations that can't see that release (e.g. on the web) release the buttons
+            // when the pointer leaves.
             self.click_id = None;
             self.drag_id = None;
         }
@@ -294,17 +421,18 @@ impl Memory {
         self.areas.layer_id_at(pos, resize_interact_radius_side)
     }
 
-    pub(crate) fn had_focus_last_frame(&self, id: Id) -> bool {
+    /// Did this widget have keyboard focus last frame?
+    pub fn had_focus_last_frame(&self, id: Id) -> bool {
         self.interaction.focus.id_previous_frame == Some(id)
     }
 
     /// True if the given widget had keyboard focus last frame, but not this one.
-    pub(crate) fn lost_focus(&self, id: Id) -> bool {
+    pub fn lost_focus(&self, id: Id) -> bool {
         self.had_focus_last_frame(id) && !self.has_focus(id)
     }
 
     /// True if the given widget has keyboard focus this frame, but didn't last frame.
-    pub(crate) fn gained_focus(&self, id: Id) -> bool {
+    pub fn gained_focus(&self, id: Id) -> bool {
         !self.had_focus_last_frame(id) && self.has_focus(id)
     }
 
@@ -346,6 +474,23 @@ impl Memory {
         }
     }
 
+    /// Move keyboard focus to the next (or previous) widget, as if Tab (or Shift+Tab) was pressed.
+    /// This only works if called before the widget with focus is added this frame.
+    pub(crate) fn move_focus(&mut self, forward: bool) {
+        let focus = &mut self.interaction.focus;
+        if forward {
+            focus.pressed_tab = true;
+        } else {
+            focus.pressed_shift_tab = true;
+        }
+    }
+
+    /// Give keyboard focus to the next widget added that is interested in it.
+    pub(crate) fn focus_next_widget(&mut self) {
+        self.interaction.focus.id = None;
+        self.interaction.focus.give_to_next = true;
+    }
+
     /// Register this widget as being interested in getting keyboard focus.
     /// This will allow the user to select it with tab and shift-tab.
     #[inline(always)]
@@ -369,6 +514,41 @@ impl Memory {
         self.interaction.drag_id == Some(id)
     }
 
+    /// Let the given widget capture the pointer.
+    ///
+    /// While captured, the widget will be reported as dragged (see [`crate::Response::dragged`])
+    /// even when the pointer leaves its rectangle,
+    /// and no other widget will start a drag.
+    /// The capture is released automatically when all pointer buttons are released,
+    /// also if that happens outside the window,
+    /// or manually with [`Self::release_pointer`].
+    ///
+    /// Widgets that sense drags capture the pointer automatically when pressed.
+    pub fn capture_pointer(&mut self, id: Id) {
+        self.interaction.drag_id = Some(id);
+        self.interaction.drag_is_window = false;
+        self.window_interaction = None;
+    }
+
+    /// Release the pointer capture of the given widget, if it has it.
+    pub fn release_pointer(&mut self, id: Id) {
+        if self.interaction.drag_id == Some(id) {
+            self.interaction.drag_id = None;
+        }
+    }
+
+    /// Which widget has captured the pointer (if any)?
+    #[inline(always)]
+    pub fn pointer_capture(&self) -> Option<Id> {
+        self.interaction.drag_id
+    }
+
+    /// Has the given widget captured the pointer?
+    #[inline(always)]
+    pub fn has_pointer_capture(&self, id: Id) -> bool {
+        self.interaction.drag_id == Some(id)
+    }
+
     /// Forget window positions, sizes etc.
     /// Can be used to auto-layout windows.
     pub fn reset_areas(&mut self) {
@@ -440,6 +620,26 @@ pub struct Areas {
     /// So if you close three windows and then reopen them all in one frame,
     /// they will all be sent to the top, but keep their previous internal order.
     wants_to_be_on_top: HashSet<LayerId>,
+
+    /// Extra rectangles that block the pointer from reaching the layers below,
+    /// e.g. the dimmed background of a modal dialog.
+    /// See [`crate::Context::register_opaque_rect`].
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    opaque_rects_last_frame: HashMap<LayerId, Vec<Rect>>,
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    opaque_rects_current_frame: HashMap<LayerId, Vec<Rect>>,
+
+    /// The layer of the modal window, if any.
+    /// All layers below it are blocked from interaction.
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    modal_last_frame: Option<LayerId>,
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    modal_current_frame: Option<LayerId>,
+
+    /// Layers rendered at a different scale, see [`crate::Area::scale`].
+    /// Maps the layer to the point it is scaled around, and the scale factor.
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    layer_scales: HashMap<LayerId, (Pos2, f32)>,
 }
 
 impl Areas {
@@ -451,6 +651,10 @@ impl Areas {
         self.areas.get(&id)
     }
 
+    pub(crate) fn get_mut(&mut self, id: Id) -> Option<&mut area::State> {
+        self.areas.get_mut(&id)
+    }
+
     pub(crate) fn order(&self) -> &[LayerId] {
         &self.order
     }
@@ -458,29 +662,112 @@ impl Areas {
     pub(crate) fn set_state(&mut self, layer_id: LayerId, state: area::State) {
         self.visible_current_frame.insert(layer_id);
         self.areas.insert(layer_id.id, state);
-        if self.order.iter().find(|x| **x == layer_id).is_none() {
+        if !self.order.contains(&layer_id) {
             self.order.push(layer_id);
         }
     }
 
+    /// Which layer is the pointer over?
+    ///
+    /// Returns `None` if the top-most layer at `pos` is not interactable,
+    /// so that widgets behind e.g. a non-interactable window are not hovered.
+    /// Layers that never allow interaction (tooltips) are transparent to the pointer.
     pub fn layer_id_at(&self, pos: Pos2, resize_interact_radius_side: f32) -> Option<LayerId> {
         for layer in self.order.iter().rev() {
-            if self.is_visible(layer) {
-                if let Some(state) = self.areas.get(&layer.id) {
-                    if state.interactable {
-                        let rect = Rect::from_min_size(state.pos, state.size);
-                        // Allow us to resize by dragging just outside the window:
-                        let rect = rect.expand(resize_interact_radius_side);
-                        if rect.contains(pos) {
-                            return Some(*layer);
-                        }
+            if !self.is_visible(layer) || !layer.order.allow_interaction() {
+                continue;
+            }
+
+            let layer_pos = self.screen_to_layer_pos(layer, pos);
+
+            if self.is_opaque_at(layer, layer_pos) {
+                return Some(*layer);
+            }
+
+            if let Some(state) = self.areas.get(&layer.id) {
+                let rect = Rect::from_min_size(state.pos, state.size);
+                if state.interactable {
+                    // Allow us to resize by dragging just outside the window:
+                    let rect = rect.expand(resize_interact_radius_side);
+                    if rect.contains(layer_pos) {
+                        return Some(*layer);
                     }
+                } else if rect.contains(layer_pos) {
+                    // Occludes everything behind it:
+                    return None;
                 }
             }
         }
         None
     }
 
+    fn is_opaque_at(&self, layer_id: &LayerId, pos: Pos2) -> bool {
+        let contains = |rects: Option<&Vec<Rect>>| {
+            rects.map_or(false, |rects| rects.iter().any(|rect| rect.contains(pos)))
+        };
+        contains(self.opaque_rects_last_frame.get(layer_id))
+            || contains(self.opaque_rects_current_frame.get(layer_id))
+    }
+
+    /// Block the pointer from reaching any layer below `layer_id` within `rect`.
+    pub(crate) fn register_opaque_rect(&mut self, layer_id: LayerId, rect: Rect) {
+        self.opaque_rects_current_frame
+            .entry(layer_id)
+            .or_default()
+            .push(rect);
+
+        // Layers that are not areas (e.g. overlay panels) must also be known to `layer_id_at`:
+        self.visible_current_frame.insert(layer_id);
+        if !self.order.contains(&layer_id) {
+            self.order.push(layer_id);
+        }
+    }
+
+    /// Render `layer_id` scaled by `scale` around `origin`. A scale of `1.0` removes the scaling.
+    pub(crate) fn set_layer_scale(&mut self, layer_id: LayerId, origin: Pos2, scale: f32) {
+        // The pointer is mapped into the layer by dividing by the scale:
+        let scale = scale.max(0.01);
+        if scale == 1.0 {
+            self.layer_scales.remove(&layer_id);
+        } else {
+            self.layer_scales.insert(layer_id, (origin, scale));
+        }
+    }
+
+    pub(crate) fn layer_scales(&self) -> &HashMap<LayerId, (Pos2, f32)> {
+        &self.layer_scales
+    }
+
+    /// Transform a position on screen into the coordinates the given layer is laid out in,
+    /// undoing any [`crate::Area::scale`].
+    pub fn screen_to_layer_pos(&self, layer_id: &LayerId, pos: Pos2) -> Pos2 {
+        match self.layer_scales.get(layer_id) {
+            Some(&(origin, scale)) => origin + (pos - origin) / scale,
+            None => pos,
+        }
+    }
+
+    /// Make `layer_id` modal for this frame: it is kept on top,
+    /// and nothing below it can be interacted with.
+    pub(crate) fn set_modal(&mut self, layer_id: LayerId) {
+        self.modal_current_frame = Some(layer_id);
+        self.move_to_top(layer_id);
+    }
+
+    /// The layer of the modal window shown this frame or last frame, if any.
+    pub fn modal_layer(&self) -> Option<LayerId> {
+        self.modal_current_frame.or(self.modal_last_frame)
+    }
+
+    /// Is there a modal layer above `layer_id` that swallows all its input?
+    ///
+    /// Layers in a higher [`crate::Order`] than the modal (e.g. popups opened from within it) are not blocked.
+    pub fn is_blocked_by_modal(&self, layer_id: &LayerId) -> bool {
+        self.modal_layer().map_or(false, |modal| {
+            modal != *layer_id && layer_id.order <= modal.order
+        })
+    }
+
     pub fn visible_last_frame(&self, layer_id: &LayerId) -> bool {
         self.visible_last_frame.contains(layer_id)
     }
@@ -509,22 +796,58 @@ impl Areas {
         self.visible_current_frame.insert(layer_id);
         self.wants_to_be_on_top.insert(layer_id);
 
-        if self.order.iter().find(|x| **x == layer_id).is_none() {
+        if !self.order.contains(&layer_id) {
             self.order.push(layer_id);
         }
     }
 
+    /// Put `layer_id` directly behind `other`.
+    ///
+    /// Only layers of the same [`crate::Order`] can be reordered,
+    /// so this does nothing if they differ, or if `other` is not known.
+    pub fn move_behind(&mut self, layer_id: LayerId, other: LayerId) {
+        if layer_id == other || layer_id.order != other.order || !self.order.contains(&other) {
+            return;
+        }
+        self.order.retain(|x| *x != layer_id);
+        if let Some(index) = self.order.iter().position(|x| *x == other) {
+            self.order.insert(index, layer_id);
+        }
+
+        // Stay directly behind `other` when the layers are sorted at the end of the frame:
+        if self.wants_to_be_on_top.contains(&other) {
+            self.wants_to_be_on_top.insert(layer_id);
+        } else {
+            self.wants_to_be_on_top.remove(&layer_id);
+        }
+    }
+
     pub(crate) fn end_frame(&mut self) {
         let Self {
             visible_last_frame,
             visible_current_frame,
             order,
             wants_to_be_on_top,
+            opaque_rects_last_frame,
+            opaque_rects_current_frame,
+            modal_last_frame,
+            modal_current_frame,
+            layer_scales,
             ..
         } = self;
 
         *visible_last_frame = std::mem::take(visible_current_frame);
-        order.sort_by_key(|layer| (layer.order, wants_to_be_on_top.contains(layer)));
+        *opaque_rects_last_frame = std::mem::take(opaque_rects_current_frame);
+        *modal_last_frame = modal_current_frame.take();
+        layer_scales.retain(|layer, _| visible_last_frame.contains(layer));
+        let modal = *modal_last_frame;
+        order.sort_by_key(|layer| {
+            (
+                layer.order,
+                Some(*layer) == modal,
+                wants_to_be_on_top.contains(layer),
+            )
+        });
         wants_to_be_on_top.clear();
     }
 }
@@ -537,3 +860,23 @@ fn memory_impl_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
     assert_send_sync::<Memory>();
 }
+
+#[cfg(test)]
+#[test]
+fn areas_move_behind() {
+    let layer = |name: &str| LayerId::new(crate::Order::Middle, Id::new(name));
+    let mut areas = Areas::default();
+    for &name in &["a", "b", "c"] {
+        areas.move_to_top(layer(name));
+    }
+    areas.end_frame();
+
+    areas.move_behind(layer("c"), layer("a"));
+    areas.end_frame();
+    assert_eq!(areas.order(), &[layer("c"), layer("a"), layer("b")]);
+
+    // Behind an unknown layer: nothing changes.
+    areas.move_behind(layer("a"), layer("unknown"));
+    areas.end_frame();
+    assert_eq!(areas.order(), &[layer("c"), layer("a"), layer("b")]);
+}
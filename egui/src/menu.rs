@@ -1,4 +1,4 @@
-//! Menu bar functionality (very basic so far).
+//! Menu bars, context menus and submenus.
 //!
 //! Usage:
 //! ```
@@ -14,6 +14,8 @@
 //!     });
 //! }
 //! ```
+//!
+//! See [`crate::KeyboardShortcut`] for how to give menu items keyboard shortcuts.
 
 use crate::{widgets::*, *};
 use epaint::Stroke;
@@ -24,6 +26,8 @@ use epaint::Stroke;
 #[cfg_attr(feature = "persistence", serde(default))]
 pub(crate) struct BarState {
     open_menu: Option<Id>,
+    /// When the pointer left the open menu, for [`crate::style::MenuClosePolicy::close_after_leaving`].
+    pointer_left_time: Option<f64>,
 }
 
 impl BarState {
@@ -36,20 +40,23 @@ impl BarState {
     }
 }
 
+/// The buttons in menus have no frame until hovered.
+fn set_menu_style(style: &mut Style) {
+    style.spacing.button_padding = vec2(2.0, 0.0);
+    // style.visuals.widgets.active.bg_fill = Color32::TRANSPARENT;
+    style.visuals.widgets.active.bg_stroke = Stroke::none();
+    // style.visuals.widgets.hovered.bg_fill = Color32::TRANSPARENT;
+    style.visuals.widgets.hovered.bg_stroke = Stroke::none();
+    style.visuals.widgets.inactive.bg_fill = Color32::TRANSPARENT;
+    style.visuals.widgets.inactive.bg_stroke = Stroke::none();
+}
+
 /// The menu bar goes well in `TopPanel`,
 /// but can also be placed in a `Window`.
 /// In the latter case you may want to wrap it in `Frame`.
 pub fn bar<R>(ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
     ui.horizontal(|ui| {
-        let mut style = (**ui.style()).clone();
-        style.spacing.button_padding = vec2(2.0, 0.0);
-        // style.visuals.widgets.active.bg_fill = Color32::TRANSPARENT;
-        style.visuals.widgets.active.bg_stroke = Stroke::none();
-        // style.visuals.widgets.hovered.bg_fill = Color32::TRANSPARENT;
-        style.visuals.widgets.hovered.bg_stroke = Stroke::none();
-        style.visuals.widgets.inactive.bg_fill = Color32::TRANSPARENT;
-        style.visuals.widgets.inactive.bg_stroke = Stroke::none();
-        ui.set_style(style);
+        set_menu_style(ui.style_mut());
 
         // Take full width and fixed height:
         let height = ui.spacing().interact_size.y;
@@ -60,62 +67,516 @@ pub fn bar<R>(ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResp
 }
 
 /// Construct a top level menu in a menu bar. This would be e.g. "File", "Edit" etc.
-pub fn menu(ui: &mut Ui, title: impl ToString, add_contents: impl FnOnce(&mut Ui)) {
+///
+/// When the menu closes is controlled by [`crate::style::MenuClosePolicy`].
+pub fn menu(ui: &mut Ui, title: impl Into<WidgetText>, add_contents: impl FnOnce(&mut Ui)) {
     menu_impl(ui, title, Box::new(add_contents))
 }
 
-#[allow(clippy::needless_pass_by_value)]
-fn menu_impl<'c>(ui: &mut Ui, title: impl ToString, add_contents: Box<dyn FnOnce(&mut Ui) + 'c>) {
-    let title = title.to_string();
+fn menu_impl<'c>(
+    ui: &mut Ui,
+    title: impl Into<WidgetText>,
+    add_contents: Box<dyn FnOnce(&mut Ui) + 'c>,
+) {
+    let title = title.into();
     let bar_id = ui.id();
-    let menu_id = bar_id.with(&title);
+    let menu_id = bar_id.with(title.text());
 
     let mut bar_state = BarState::load(ui.ctx(), &bar_id);
+    let was_open = bar_state.open_menu == Some(menu_id);
 
     let mut button = Button::new(title);
 
-    if bar_state.open_menu == Some(menu_id) {
+    if was_open {
         button = button.fill(Some(ui.visuals().selection.bg_fill));
     }
 
+    let close_policy = ui.style().interaction.menu_close_policy;
+
     let button_response = ui.add(button);
     if button_response.clicked() {
-        // Toggle
-        if bar_state.open_menu == Some(menu_id) {
-            bar_state.open_menu = None;
-        } else {
+        if !was_open {
             bar_state.open_menu = Some(menu_id);
+        } else if close_policy.click_button_to_close {
+            bar_state.open_menu = None;
         }
     } else if button_response.hovered() && bar_state.open_menu.is_some() {
         bar_state.open_menu = Some(menu_id);
     }
 
+    // Arrow down (or Enter) on the focused button moves the focus into the menu:
+    let keyboard_click = button_response.clicked() && !ui.input().pointer.any_click();
+    let open_with_keyboard = (button_response.has_focus()
+        && ui.input().key_pressed(Key::ArrowDown))
+        || (keyboard_click && !was_open);
+    if open_with_keyboard {
+        bar_state.open_menu = Some(menu_id);
+    }
+
+    if bar_state.open_menu == Some(menu_id) {
+        let mut state = if was_open {
+            MenuState::load(ui.ctx(), menu_id)
+        } else {
+            MenuState::default()
+        };
+        if open_with_keyboard {
+            state.focus_first = Some(menu_id);
+        }
+        state.save(ui.ctx(), menu_id);
+    }
+
     if bar_state.open_menu == Some(menu_id) || ui.ctx().memory().everything_is_visible() {
-        let area = Area::new(menu_id)
-            .order(Order::Foreground)
-            .fixed_pos(button_response.rect.left_bottom());
-        let frame = Frame::menu(ui.style());
+        let area = Area::new(menu_id).order(Order::Foreground);
+        let size = crate::popup::last_popup_size(ui.ctx(), &area);
+        let (pos, max_height) =
+            crate::popup::popup_pos_on_screen(ui.ctx(), button_response.rect, size);
+        let area_response = menu_popup(
+            ui.ctx(),
+            area.fixed_pos(pos),
+            menu_id,
+            0,
+            max_height,
+            add_contents,
+        );
+
+        // Submenus may have changed the state:
+        let mut state = MenuState::load(ui.ctx(), menu_id);
+        let submenu_clicked = std::mem::take(&mut state.submenu_clicked);
+
+        let pointer_pos = ui.input().pointer.interact_pos();
+        let in_menu =
+            |pos: Pos2| area_response.rect.contains(pos) || state.submenus_contain(ui.ctx(), pos);
+        let in_menu_or_button = |pos: Pos2| in_menu(pos) || button_response.rect.contains(pos);
+
+        let close_requested = std::mem::take(&mut ui.ctx().frame_state().close_menu);
+        let mut close = close_requested || ui.input().key_pressed(Key::Escape);
+        if button_response.clicked_elsewhere() {
+            let clicked_in_menu = pointer_pos.map_or(false, in_menu);
+            close |= !clicked_in_menu || (close_policy.close_on_click_inside && !submenu_clicked);
+        }
+
+        if let Some(delay) = close_policy.close_after_leaving {
+            let time = ui.input().time;
+            if pointer_pos.map_or(false, in_menu_or_button) {
+                bar_state.pointer_left_time = None;
+            } else {
+                let left_time = *bar_state.pointer_left_time.get_or_insert(time);
+                if time - left_time >= delay as f64 {
+                    close = true;
+                } else {
+                    ui.ctx().request_repaint(); // Close on time even if nothing happens
+                }
+            }
+        }
+
+        state.end_frame(ui.ctx());
+        if close {
+            bar_state.open_menu = None;
+            ui.ctx().memory().id_data_temp.remove(&menu_id);
+        } else {
+            state.save(ui.ctx(), menu_id);
+        }
+    }
+
+    if bar_state.open_menu.is_none() {
+        bar_state.pointer_left_time = None;
+    }
+
+    bar_state.save(ui.ctx(), bar_id);
+}
+
+// ----------------------------------------------------------------------------
+
+/// How long the pointer must rest on a submenu button before the submenu opens,
+/// or on another item before it closes again.
+const SUBMENU_DELAY: f64 = 0.2;
+
+/// An open submenu.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct OpenSubmenu {
+    id: Id,
+    /// The button that opened it.
+    button_id: Id,
+    /// Where the pointer was last seen on the button.
+    /// The pointer can cross other items on its way from there to the submenu without closing it.
+    corridor_start: Option<Pos2>,
+}
+
+/// A submenu that will be opened (or closed, if `None`) if the pointer keeps resting where it is.
+#[derive(Clone, Copy, Debug)]
+struct PendingSubmenu {
+    depth: usize,
+    submenu: Option<OpenSubmenu>,
+    since: f64,
+}
+
+/// What an open menu (from the menu bar, or a context menu) remembers between frames,
+/// mostly about its submenus.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct MenuState {
+    /// Where a context menu was opened.
+    pos: Pos2,
+    /// The open submenus, outermost first.
+    open_submenus: Vec<OpenSubmenu>,
+    pending: Option<PendingSubmenu>,
+    /// If `pending` isn't asked for again each frame, the pointer has moved on and it is dropped.
+    pending_requested: bool,
+    /// Set when a submenu button is clicked, so that the click doesn't close the menu.
+    submenu_clicked: bool,
+    /// How many submenus deep the widget with keyboard focus is, if it is in the menu.
+    focus_depth: Option<usize>,
+    focus_depth_this_frame: Option<usize>,
+    /// Give keyboard focus to the first item of this menu or submenu when it is shown.
+    focus_first: Option<Id>,
+}
+
+impl MenuState {
+    fn load(ctx: &Context, menu_id: Id) -> Self {
+        ctx.memory()
+            .id_data_temp
+            .get_or_default::<Self>(menu_id)
+            .clone()
+    }
+
+    fn save(self, ctx: &Context, menu_id: Id) {
+        ctx.memory().id_data_temp.insert(menu_id, self);
+    }
+
+    fn is_open(&self, depth: usize, submenu_id: Id) -> bool {
+        self.open_submenus.get(depth).map(|submenu| submenu.id) == Some(submenu_id)
+    }
+
+    /// Open a submenu right away, closing any other at the same depth.
+    fn open(&mut self, depth: usize, submenu: OpenSubmenu) {
+        self.open_submenus.truncate(depth);
+        self.open_submenus.push(submenu);
+        self.pending = None;
+    }
+
+    /// Open (or close) a submenu after [`SUBMENU_DELAY`], if this is asked for every frame until then.
+    fn request(&mut self, depth: usize, submenu: Option<OpenSubmenu>, time: f64) {
+        if self.pending_requested && submenu.is_none() {
+            return; // Opening another submenu wins over closing.
+        }
+        self.pending_requested = true;
+
+        let submenu_id = submenu.map(|submenu| submenu.id);
+        let same = self.pending.map_or(false, |pending| {
+            pending.depth == depth && pending.submenu.map(|submenu| submenu.id) == submenu_id
+        });
+        if !same {
+            self.pending = Some(PendingSubmenu {
+                depth,
+                submenu,
+                since: time,
+            });
+        }
+    }
+
+    /// Is `pos` in one of the open submenus?
+    fn submenus_contain(&self, ctx: &Context, pos: Pos2) -> bool {
+        self.open_submenus.iter().any(|submenu| {
+            ctx.memory()
+                .areas
+                .get(submenu.id)
+                .map_or(false, |area| area.rect().contains(pos))
+        })
+    }
+
+    /// Is `pos` on the way from the button of the submenu open at `depth` to the submenu itself?
+    fn in_corridor(&self, ctx: &Context, depth: usize, pos: Pos2) -> bool {
+        let submenu = match self.open_submenus.get(depth) {
+            Some(submenu) => submenu,
+            None => return false,
+        };
+        let rect = ctx.memory().areas.get(submenu.id).map(|area| area.rect());
+        if let (Some(start), Some(rect)) = (submenu.corridor_start, rect) {
+            let x = if rect.center().x > start.x {
+                rect.left()
+            } else {
+                rect.right()
+            };
+            triangle_contains([start, pos2(x, rect.top()), pos2(x, rect.bottom())], pos)
+        } else {
+            false
+        }
+    }
+
+    /// Arrow keys move the focus between the items of the menu at `depth`,
+    /// and left arrow goes back out of a submenu.
+    fn keyboard_navigation(&mut self, ctx: &CtxRef, depth: usize) {
+        let input = ctx.input();
+        if input.key_pressed(Key::ArrowDown) {
+            ctx.memory().move_focus(true);
+        } else if input.key_pressed(Key::ArrowUp) {
+            ctx.memory().move_focus(false);
+        } else if input.key_pressed(Key::ArrowLeft) && depth > 0 {
+            if let Some(submenu) = self.open_submenus.get(depth - 1).copied() {
+                self.open_submenus.truncate(depth - 1);
+                ctx.memory().request_focus(submenu.button_id);
+            }
+        }
+    }
 
-        area.show(ui.ctx(), |ui| {
-            frame.show(ui, |ui| {
-                let mut style = (**ui.style()).clone();
-                style.spacing.button_padding = vec2(2.0, 0.0);
-                // style.visuals.widgets.active.bg_fill = Color32::TRANSPARENT;
-                style.visuals.widgets.active.bg_stroke = Stroke::none();
-                // style.visuals.widgets.hovered.bg_fill = Color32::TRANSPARENT;
-                style.visuals.widgets.hovered.bg_stroke = Stroke::none();
-                style.visuals.widgets.inactive.bg_fill = Color32::TRANSPARENT;
-                style.visuals.widgets.inactive.bg_stroke = Stroke::none();
-                ui.set_style(style);
+    /// Called by the menu after its contents (and so all submenus) have been shown.
+    fn end_frame(&mut self, ctx: &CtxRef) {
+        self.focus_depth = self.focus_depth_this_frame.take();
+
+        if !std::mem::take(&mut self.pending_requested) {
+            self.pending = None;
+        }
+        if let Some(pending) = self.pending {
+            if ctx.input().time - pending.since >= SUBMENU_DELAY {
+                self.open_submenus.truncate(pending.depth);
+                self.open_submenus.extend(pending.submenu);
+                self.pending = None;
+            } else {
+                ctx.request_repaint(); // Open on time even if nothing happens
+            }
+        }
+    }
+}
+
+fn triangle_contains(triangle: [Pos2; 3], pos: Pos2) -> bool {
+    let side = |a: Pos2, b: Pos2| (b - a).x * (pos - a).y - (b - a).y * (pos - a).x;
+    let [a, b, c] = triangle;
+    let sides = [side(a, b), side(b, c), side(c, a)];
+    sides.iter().all(|&s| s >= 0.0) || sides.iter().all(|&s| s <= 0.0)
+}
+
+/// Show the popup of a menu, or of a submenu `depth` levels into the menu with `menu_id`.
+fn menu_popup(
+    ctx: &CtxRef,
+    area: Area,
+    menu_id: Id,
+    depth: usize,
+    max_height: f32,
+    add_contents: impl FnOnce(&mut Ui),
+) -> Response {
+    let layer_id = area.layer();
+
+    let mut state = MenuState::load(ctx, menu_id);
+    if state.focus_first == Some(layer_id.id) {
+        state.focus_first = None;
+        ctx.memory().focus_next_widget();
+    } else if state.focus_depth == Some(depth) {
+        state.keyboard_navigation(ctx, depth);
+    }
+    state.save(ctx, menu_id);
+
+    ctx.frame_state().menu_stack.push((menu_id, depth));
+    let response = area.show(ctx, |ui| {
+        let frame = Frame::menu(ui.style());
+        let max_height = (max_height - 2.0 * frame.margin.y).at_least(0.0);
+        frame.show(ui, |ui| {
+            set_menu_style(ui.style_mut());
+            ScrollArea::from_max_height(max_height).show(ui, |ui| {
                 ui.with_layout(Layout::top_down_justified(Align::LEFT), add_contents);
             });
         });
+    });
+    ctx.frame_state().menu_stack.pop();
 
-        // TODO: this prevents sub-menus in menus. We should fix that.
-        if ui.input().key_pressed(Key::Escape) || button_response.clicked_elsewhere() {
-            bar_state.open_menu = None;
+    let focused = ctx.memory().interaction.focus.focused();
+    let has_focus = focused.map_or(false, |focused| {
+        ctx.frame_state()
+            .interact_rects
+            .iter()
+            .any(|(layer, id, _)| *layer == layer_id && *id == focused)
+    });
+    if has_focus {
+        let mut state = MenuState::load(ctx, menu_id);
+        state.focus_depth_this_frame = Some(depth);
+        state.save(ctx, menu_id);
+    }
+
+    response
+}
+
+/// Show a context menu at the pointer when `response` is right-clicked.
+/// See [`Response::context_menu`].
+pub(crate) fn context_menu(response: &Response, add_contents: Box<dyn FnOnce(&mut Ui) + '_>) {
+    let ctx = &response.ctx;
+    let menu_id = response.id.with("__context_menu");
+
+    let opened = response.secondary_clicked();
+    if opened {
+        if let Some(pos) = ctx.input().pointer.interact_pos() {
+            let state = MenuState {
+                pos,
+                ..Default::default()
+            };
+            state.save(ctx, menu_id);
+            ctx.memory().open_popup(menu_id);
         }
     }
 
-    bar_state.save(ui.ctx(), bar_id);
+    if !ctx.memory().is_popup_open(menu_id) {
+        return;
+    }
+
+    let area = Area::new(menu_id).order(Order::Foreground);
+    let size = crate::popup::last_popup_size(ctx, &area);
+    let screen_rect = ctx.input().screen_rect();
+    let pos = MenuState::load(ctx, menu_id).pos;
+    let pos = pos2(
+        pos.x.at_most(screen_rect.right() - size.x),
+        pos.y.at_most(screen_rect.bottom() - size.y),
+    )
+    .max(screen_rect.min);
+
+    let max_height = screen_rect.bottom() - pos.y;
+    let area_response = menu_popup(
+        ctx,
+        area.fixed_pos(pos),
+        menu_id,
+        0,
+        max_height,
+        add_contents,
+    );
+
+    // Submenus may have changed the state:
+    let mut state = MenuState::load(ctx, menu_id);
+    let submenu_clicked = std::mem::take(&mut state.submenu_clicked);
+
+    let close_requested = std::mem::take(&mut ctx.frame_state().close_menu);
+    let mut close = close_requested || ctx.input().key_pressed(Key::Escape);
+    let pointer = &ctx.input().pointer;
+    if pointer.any_click() && !opened {
+        let in_menu =
+            |pos: Pos2| area_response.rect.contains(pos) || state.submenus_contain(ctx, pos);
+        let clicked_in_menu = pointer.interact_pos().map_or(false, in_menu);
+        let close_policy = ctx.style().interaction.menu_close_policy;
+        close |= !clicked_in_menu || (close_policy.close_on_click_inside && !submenu_clicked);
+    }
+
+    state.end_frame(ctx);
+    if close {
+        ctx.memory().close_popup();
+        ctx.memory().id_data_temp.remove(&menu_id);
+    } else {
+        state.save(ctx, menu_id);
+    }
+}
+
+/// A button in a menu that opens another menu to the side of it.
+///
+/// The submenu opens when the pointer rests on the button, when it is clicked,
+/// or with the right arrow key. The arrow keys move between the items, and left arrow goes back.
+///
+/// This works in [`menu`] and [`Response::context_menu`], and in other submenus.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// egui::menu::bar(ui, |ui| {
+///     egui::menu::menu(ui, "File", |ui| {
+///         if ui.button("Open").clicked() {
+///             // …
+///         }
+///         egui::menu::submenu(ui, "Export as", |ui| {
+///             if ui.button("PNG").clicked() {
+///                 // …
+///             }
+///             if ui.button("SVG").clicked() {
+///                 // …
+///             }
+///         });
+///     });
+/// });
+/// ```
+pub fn submenu(ui: &mut Ui, title: impl Into<WidgetText>, add_contents: impl FnOnce(&mut Ui)) {
+    submenu_impl(ui, title, Box::new(add_contents))
+}
+
+fn submenu_impl<'c>(
+    ui: &mut Ui,
+    title: impl Into<WidgetText>,
+    add_contents: Box<dyn FnOnce(&mut Ui) + 'c>,
+) {
+    let title = title.into();
+    let submenu_id = ui.make_persistent_id(title.text());
+    let level = ui.ctx().frame_state().menu_stack.last().copied();
+    let (menu_id, depth) = if let Some(level) = level {
+        level
+    } else {
+        // Not in a menu, so there is nowhere to keep track of it being open:
+        ui.add(Button::new(title).enabled(false));
+        return;
+    };
+
+    let ctx = ui.ctx().clone();
+    let mut state = MenuState::load(&ctx, menu_id);
+    let was_open = state.is_open(depth, submenu_id);
+
+    let mut button = Button::new(title).shortcut_text("⏵");
+    if was_open {
+        button = button.fill(Some(ui.visuals().selection.bg_fill));
+    }
+    let button_response = ui.add(button);
+    let this = OpenSubmenu {
+        id: submenu_id,
+        button_id: button_response.id,
+        corridor_start: None,
+    };
+
+    let time = ctx.input().time;
+    let pointer_pos = ctx.input().pointer.hover_pos();
+    let keyboard_click = button_response.clicked() && !ctx.input().pointer.any_click();
+    if button_response.clicked() {
+        state.submenu_clicked = true;
+    }
+
+    if (button_response.has_focus() && ctx.input().key_pressed(Key::ArrowRight)) || keyboard_click {
+        state.open(depth, this);
+        state.focus_first = Some(submenu_id);
+    } else if button_response.clicked() && !was_open {
+        state.open(depth, this);
+    } else if button_response.hovered() {
+        if was_open {
+            state.open_submenus[depth].corridor_start = pointer_pos;
+        } else if !pointer_pos.map_or(false, |pos| state.in_corridor(&ctx, depth, pos)) {
+            state.request(depth, Some(this), time);
+        }
+    } else if was_open {
+        // Close it when the pointer rests on another item:
+        let parent_rect = ctx
+            .memory()
+            .areas
+            .get(ui.layer_id().id)
+            .map(|area| area.rect());
+        if let (Some(pos), Some(parent_rect)) = (pointer_pos, parent_rect) {
+            if parent_rect.contains(pos) && !state.in_corridor(&ctx, depth, pos) {
+                state.request(depth, None, time);
+            }
+        }
+    }
+
+    let is_open = state.is_open(depth, submenu_id);
+    state.save(&ctx, menu_id);
+
+    if is_open {
+        let area = Area::new(submenu_id).order(Order::Foreground);
+        let size = crate::popup::last_popup_size(&ctx, &area);
+        let screen_rect = ctx.input().screen_rect();
+        let margin = Frame::menu(ui.style()).margin;
+
+        // To the right of the button, unless there's only room on the left:
+        let rect = button_response.rect;
+        let mut pos = pos2(rect.right() + margin.x, rect.top() - margin.y);
+        if pos.x + size.x > screen_rect.right() {
+            pos.x = rect.left() - margin.x - size.x;
+        }
+        pos.y = pos.y.at_most(screen_rect.bottom() - size.y);
+        let pos = pos.max(screen_rect.min);
+
+        let max_height = screen_rect.bottom() - pos.y;
+        menu_popup(
+            &ctx,
+            area.fixed_pos(pos),
+            menu_id,
+            depth + 1,
+            max_height,
+            add_contents,
+        );
+    }
 }
@@ -323,7 +323,11 @@ impl Painter {
     /// Paint the results with [`Self::galley`].
     /// Always returns at least one row.
     #[inline(always)]
-    pub fn layout_no_wrap(&self, text_style: TextStyle, text: String) -> std::sync::Arc<Galley> {
+    pub fn layout_no_wrap(
+        &self,
+        text_style: TextStyle,
+        text: impl AsRef<str>,
+    ) -> std::sync::Arc<Galley> {
         self.layout_multiline(text_style, text, f32::INFINITY)
     }
 
@@ -335,7 +339,7 @@ impl Painter {
     pub fn layout_multiline(
         &self,
         text_style: TextStyle,
-        text: String,
+        text: impl AsRef<str>,
         max_width_in_points: f32,
     ) -> std::sync::Arc<Galley> {
         self.fonts()
@@ -180,9 +180,9 @@ impl Placer {
 
     /// Move to the next row in a grid layout or wrapping layout.
     /// Otherwise does nothing.
-    pub(crate) fn end_row(&mut self, item_spacing: Vec2, painter: &Painter) {
+    pub(crate) fn end_row(&mut self, item_spacing: Vec2) {
         if let Some(grid) = &mut self.grid {
-            grid.end_row(&mut self.region.cursor, painter)
+            grid.end_row(&mut self.region.cursor)
         } else {
             self.layout.end_row(&mut self.region, item_spacing)
         }
@@ -200,6 +200,11 @@ impl Placer {
         self.region.expand_to_include_rect(rect);
     }
 
+    /// Only place new widgets within `rect` from now on.
+    pub(crate) fn cut_to(&mut self, rect: Rect) {
+        self.region.cut_to(rect);
+    }
+
     /// Expand the `min_rect` and `max_rect` of this ui to include a child at the given x-coordinate.
     pub(crate) fn expand_to_include_x(&mut self, x: f32) {
         self.region.expand_to_include_x(x);
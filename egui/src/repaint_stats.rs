@@ -0,0 +1,110 @@
+use crate::util::History;
+
+/// Why egui asked the backend for another frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RepaintCause {
+    /// There was input, e.g. the mouse moved or a key was pressed.
+    Input,
+    /// Something is animating, e.g. a [`crate::CollapsingHeader`] opening.
+    Animation,
+    /// Someone called [`crate::Context::request_repaint`], e.g. a widget that needs one more frame to settle,
+    /// or your own code showing a continuous animation.
+    Request,
+}
+
+/// How often egui asked for a repaint during the last second, and why.
+///
+/// A well-behaved app should be idle (not repainting) when nothing is happening,
+/// which is important for battery life. Use this to check that yours is:
+///
+/// ```
+/// # let ctx = egui::CtxRef::default();
+/// let stats = ctx.repaint_stats();
+/// if !stats.is_idle() {
+///     eprintln!("Repainting {} times per second: {:?}", stats.repaints_per_second(), stats);
+/// }
+/// ```
+///
+/// Delayed repaints requested with [`crate::Context::request_repaint_after`] are not counted.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RepaintStats {
+    /// Repaints requested because of input during the last second.
+    pub input: usize,
+    /// Repaints requested because of animations during the last second.
+    pub animation: usize,
+    /// Repaints requested with [`crate::Context::request_repaint`] during the last second.
+    pub request: usize,
+    /// Why the last repaint was requested, if any has been.
+    pub last_cause: Option<RepaintCause>,
+    /// Seconds since egui last asked for a repaint.
+    pub idle_time: f64,
+}
+
+impl RepaintStats {
+    /// Total number of repaints requested during the last second.
+    pub fn repaints_per_second(&self) -> usize {
+        self.input + self.animation + self.request
+    }
+
+    /// `true` if egui has not asked for a repaint during the last second.
+    pub fn is_idle(&self) -> bool {
+        self.repaints_per_second() == 0
+    }
+}
+
+/// Kept by the [`crate::Context`].
+pub(crate) struct RepaintHistory {
+    /// The cause of the pending `repaint_requests`.
+    pub pending_cause: RepaintCause,
+    /// When egui asked for a repaint, and why.
+    history: History<RepaintCause>,
+    /// The last repaint request, which may be older than anything in `history`.
+    last_request: Option<(f64, RepaintCause)>,
+    /// Did the last frame ask for a repaint?
+    pub last_frame_requested_repaint: bool,
+}
+
+impl Default for RepaintHistory {
+    fn default() -> Self {
+        Self {
+            pending_cause: RepaintCause::Request,
+            history: History::new(1000, 1.0),
+            last_request: None,
+            last_frame_requested_repaint: true,
+        }
+    }
+}
+
+impl RepaintHistory {
+    /// Call at the end of each frame.
+    pub fn end_frame(&mut self, now: f64, cause: Option<RepaintCause>) {
+        self.last_frame_requested_repaint = cause.is_some();
+        if let Some(cause) = cause {
+            self.history.add(now, cause);
+            self.last_request = Some((now, cause));
+        } else {
+            self.history.flush(now);
+        }
+    }
+
+    pub fn stats(&self, now: f64) -> RepaintStats {
+        let mut stats = RepaintStats {
+            last_cause: self.last_request.map(|(_, cause)| cause),
+            idle_time: self
+                .last_request
+                .map_or(now, |(time, _)| now - time)
+                .max(0.0),
+            ..Default::default()
+        };
+        for (time, cause) in self.history.iter() {
+            if now - time <= self.history.max_age() as f64 {
+                match cause {
+                    RepaintCause::Input => stats.input += 1,
+                    RepaintCause::Animation => stats.animation += 1,
+                    RepaintCause::Request => stats.request += 1,
+                }
+            }
+        }
+        stats
+    }
+}
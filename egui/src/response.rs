@@ -1,5 +1,5 @@
 use crate::{
-    emath::{lerp, Align, Pos2, Rect, Vec2},
+    emath::{Align, Pos2, Rect, Vec2},
     CursorIcon, PointerButton, NUM_POINTER_BUTTONS,
 };
 use crate::{CtxRef, Id, LayerId, Sense, Ui};
@@ -209,6 +209,23 @@ impl Response {
         self.ctx.memory().surrender_focus(self.id)
     }
 
+    /// Capture the pointer, so that this widget keeps getting dragged
+    /// even if the pointer leaves it, until all pointer buttons are released.
+    /// See [`crate::Memory::capture_pointer`].
+    pub fn capture_pointer(&self) {
+        self.ctx.memory().capture_pointer(self.id)
+    }
+
+    /// Release the pointer capture (if this widget has it).
+    pub fn release_pointer(&self) {
+        self.ctx.memory().release_pointer(self.id)
+    }
+
+    /// Has this widget captured the pointer?
+    pub fn has_pointer_capture(&self) -> bool {
+        self.ctx.memory().has_pointer_capture(self.id)
+    }
+
     /// The widgets is being dragged.
     ///
     /// To find out which button(s), query [`crate::PointerState::button_down`]
@@ -228,22 +245,41 @@ impl Response {
     }
 
     /// Did a drag on this widgets begin this frame?
+    ///
+    /// For widgets that sense both clicks and drags, the drag does not start until the pointer
+    /// has moved more than [`crate::style::Interaction::drag_threshold`].
     pub fn drag_started(&self) -> bool {
-        self.dragged && self.ctx.input().pointer.any_pressed()
+        if !self.dragged {
+            false
+        } else if self.sense.click {
+            self.ctx.input().pointer.started_decidedly_dragging()
+        } else {
+            self.ctx.input().pointer.any_pressed()
+        }
     }
 
     /// The widget was being dragged, but now it has been released.
+    ///
+    /// This is never true on the same frame as [`Self::clicked`].
     pub fn drag_released(&self) -> bool {
         self.drag_released
     }
 
     /// If dragged, how many points were we dragged and in what direction?
     pub fn drag_delta(&self) -> Vec2 {
-        if self.dragged() {
-            self.ctx.input().pointer.delta()
-        } else {
-            Vec2::ZERO
+        if !self.dragged() {
+            return Vec2::ZERO;
         }
+        let pointer = &self.ctx.input().pointer;
+        if self.sense.click && pointer.started_decidedly_dragging() {
+            // Include the movement that happened before we knew this was a drag:
+            if let (Some(press_origin), Some(pos)) =
+                (pointer.press_origin(), pointer.interact_pos())
+            {
+                return pos - press_origin;
+            }
+        }
+        pointer.delta()
     }
 
     /// Where the pointer (mouse/touch) were when when this widget was clicked or dragged.
@@ -305,6 +341,30 @@ impl Response {
         self
     }
 
+    /// Show a menu at the pointer when this widget is right-clicked.
+    ///
+    /// The menu closes when the user clicks outside of it, presses Escape,
+    /// or clicks an item in it (see [`crate::style::MenuClosePolicy`]).
+    /// Use [`crate::menu::submenu`] for nested menus.
+    ///
+    /// The widget must sense clicks, see [`Self::interact`].
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// ui.button("Right-click me").context_menu(|ui| {
+    ///     if ui.button("Rename").clicked() {
+    ///         // …
+    ///     }
+    ///     if ui.button("Delete").clicked() {
+    ///         // …
+    ///     }
+    /// });
+    /// ```
+    pub fn context_menu(self, add_contents: impl FnOnce(&mut Ui)) -> Self {
+        crate::menu::context_menu(&self, Box::new(add_contents));
+        self
+    }
+
     /// Show this UI when hovering if the widget is disabled.
     pub fn on_disabled_hover_ui(self, add_contents: impl FnOnce(&mut Ui)) -> Self {
         if !self.enabled && self.ctx.rect_contains_pointer(self.layer_id, self.rect) {
@@ -405,6 +465,32 @@ impl Response {
         )
     }
 
+    /// Use this as the title bar of a native window without decorations
+    /// (see `decorated` in `epi::NativeOptions`):
+    /// dragging it moves the window, and double-clicking it maximizes or restores the window.
+    ///
+    /// This senses clicks and drags, so it is meant for the background of the title bar,
+    /// e.g. the response of a [`crate::TopPanel`] or of a label with the app name.
+    ///
+    /// ```
+    /// # let mut ui = &mut egui::Ui::__test();
+    /// ui.horizontal(|ui| {
+    ///     ui.label("My app");
+    /// })
+    /// .response
+    /// .native_title_bar();
+    /// ```
+    pub fn native_title_bar(self) -> Self {
+        let response = self.interact(Sense::click_and_drag());
+        if response.drag_started() && response.dragged_by(PointerButton::Primary) {
+            response.ctx.output().drag_window = true;
+        }
+        if response.double_clicked() {
+            response.ctx.output().toggle_maximized = true;
+        }
+        response
+    }
+
     /// Move the scroll to this UI with the specified alignment.
     ///
     /// ```
@@ -420,19 +506,70 @@ impl Response {
     /// });
     /// ```
     pub fn scroll_to_me(&self, align: Align) {
-        let scroll_target = lerp(self.rect.y_range(), align.to_factor());
-        self.ctx.frame_state().scroll_target = Some((scroll_target, align));
+        let mut frame_state = self.ctx.frame_state();
+        for d in 0..2 {
+            frame_state.scroll_target[d] = Some((self.rect.min[d]..=self.rect.max[d], Some(align)));
+        }
     }
 
-    /// For accessibility.
+    /// For accessibility, and for integrations that want to play sounds or give haptic feedback.
+    ///
+    /// Emits [`crate::output::OutputEvent`]:s describing what happened to the widget this frame,
+    /// and records the info if `ctx.memory().options.record_widget_info`
+    /// (or [`crate::style::DebugOptions::design_mode`]) is on.
     ///
     /// Call after interacting and potential calls to [`Self::mark_changed`].
     pub fn widget_info(&self, make_info: impl Fn() -> crate::WidgetInfo) {
+        use crate::output::{OutputEvent, WidgetEvent};
+
+        let record = {
+            let options = &self.ctx.memory().options;
+            options.record_widget_info || options.screen_reader
+        } || self.ctx.style().debug.design_mode;
+
+        let mut events = vec![];
         if self.gained_focus() {
-            use crate::output::{OutputEvent, WidgetEvent};
-            let widget_info = make_info();
-            let event = OutputEvent::WidgetEvent(WidgetEvent::Focus, widget_info);
-            self.ctx.output().events.push(event);
+            events.push(WidgetEvent::Focus);
+        }
+        if self.drag_started() {
+            events.push(WidgetEvent::DragStarted);
+        }
+        if self.drag_released() {
+            events.push(WidgetEvent::DragReleased);
+        }
+        if self.clicked() {
+            events.push(WidgetEvent::Clicked);
+        }
+        let changed = self.changed();
+        let lost_focus = self.lost_focus();
+        if events.is_empty() && !changed && !lost_focus && !record {
+            return; // Don't call `make_info` every frame
+        }
+
+        let widget_info = make_info();
+        if record {
+            self.ctx
+                .frame_state()
+                .widget_records
+                .push(crate::WidgetRecord {
+                    layer_id: self.layer_id,
+                    id: self.id,
+                    rect: self.rect,
+                    info: widget_info.clone(),
+                });
+        }
+        if changed && widget_info.selected.is_some() {
+            events.push(WidgetEvent::Toggled);
+        }
+        if lost_focus && widget_info.typ == crate::WidgetType::TextEdit {
+            events.push(WidgetEvent::TextCommitted);
+        }
+
+        let mut output = self.ctx.output();
+        for event in events {
+            output
+                .events
+                .push(OutputEvent::WidgetEvent(event, widget_info.clone()));
         }
     }
 }
@@ -149,6 +149,35 @@ pub struct Interaction {
 
     /// If `false`, tooltips will show up anytime you hover anything, even is mouse is still moving
     pub show_tooltips_only_when_still: bool,
+
+    /// If the pointer moves more than this many points while a button is down,
+    /// it is no longer a click but a drag.
+    pub drag_threshold: f32,
+
+    /// When an open [`crate::menu::menu`] closes.
+    pub menu_close_policy: MenuClosePolicy,
+}
+
+/// When an open [`crate::menu::menu`], context menu or popup (e.g. of a [`crate::ComboBox`]) closes.
+///
+/// A menu always closes when the user clicks outside of it, presses Escape,
+/// or when [`crate::Ui::close_menu`] is called.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+pub struct MenuClosePolicy {
+    /// Close the menu when the user clicks anything in it, e.g. a button. Default: `true`.
+    ///
+    /// Turn this off for menus with checkboxes, sliders etc,
+    /// and use [`crate::Ui::close_menu`] for the items that should close it.
+    pub close_on_click_inside: bool,
+
+    /// Close the menu when the pointer has been outside of it (and its button) for this many seconds.
+    /// Default: `None` (don't close on hover).
+    pub close_after_leaving: Option<f32>,
+
+    /// Close the menu when the user clicks its button again. Default: `true`.
+    pub click_button_to_close: bool,
 }
 
 /// Controls the visual style (colors etc) of egui.
@@ -188,6 +217,9 @@ pub struct Visuals {
 
     pub selection: Selection,
 
+    /// Visual style of the scroll bars of a [`crate::ScrollArea`].
+    pub scroll_bar: ScrollBarVisuals,
+
     /// Very dark or light color (for corresponding theme).
     /// Used as the background of text edits, scroll bars and others things
     /// that needs to look different from other interactive stuff.
@@ -199,8 +231,16 @@ pub struct Visuals {
     /// Background color behind code-styled monospaced labels.
     pub code_bg_color: Color32,
 
+    /// Used to mark invalid input, e.g. the frame of a [`crate::TextEdit`] with invalid contents.
+    pub error_fg_color: Color32,
+
+    /// Used for warnings.
+    pub warn_fg_color: Color32,
+
     pub window_corner_radius: f32,
     pub window_shadow: Shadow,
+    /// Dims the screen behind a modal [`crate::Window`].
+    pub window_modal_scrim: Color32,
 
     pub resize_corner_size: f32,
 
@@ -248,6 +288,23 @@ pub struct Selection {
     pub stroke: Stroke,
 }
 
+/// The look of the scroll bars of a [`crate::ScrollArea`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+pub struct ScrollBarVisuals {
+    /// Width of the scroll bar, not including the margin.
+    pub width: f32,
+    /// Corner radius of the track and the handle. Capped at half the width.
+    pub corner_radius: f32,
+    /// Background of the track the handle moves along.
+    /// The handle itself uses the colors of [`Widgets`].
+    pub track_fill: Color32,
+    /// If true, the scroll bars fade out after a moment of inactivity,
+    /// and fade back in when the scroll area is hovered or scrolled.
+    pub auto_hide: bool,
+}
+
 /// The visuals of widgets for different states of interaction.
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
@@ -319,6 +376,15 @@ pub struct DebugOptions {
     /// Show which widgets make their parent higher
     pub show_expand_height: bool,
     pub show_resize: bool,
+    /// Show which widgets didn't get all the space they asked for,
+    /// e.g. because the panel they are in is too small.
+    /// See also [`crate::Context::overflow_warnings`].
+    pub show_overflow: bool,
+    /// Design mode: show rulers along the screen edges, and the distances from the hovered widget
+    /// to its neighbors. Drag guide lines out of the rulers, and back onto them to remove them.
+    ///
+    /// Only widgets that describe themselves with [`crate::Response::widget_info`] are measured.
+    pub design_mode: bool,
 }
 
 // ----------------------------------------------------------------------------
@@ -361,6 +427,18 @@ impl Default for Interaction {
             resize_grab_radius_side: 5.0,
             resize_grab_radius_corner: 10.0,
             show_tooltips_only_when_still: false,
+            drag_threshold: 6.0,
+            menu_close_policy: Default::default(),
+        }
+    }
+}
+
+impl Default for MenuClosePolicy {
+    fn default() -> Self {
+        Self {
+            close_on_click_inside: true,
+            close_after_leaving: None,
+            click_button_to_close: true,
         }
     }
 }
@@ -373,11 +451,15 @@ impl Visuals {
             override_text_color: None,
             widgets: Widgets::default(),
             selection: Selection::default(),
+            scroll_bar: ScrollBarVisuals::default(),
             extreme_bg_color: Color32::from_gray(10),
             hyperlink_color: Color32::from_rgb(90, 170, 255),
             code_bg_color: Color32::from_gray(64),
+            error_fg_color: Color32::from_rgb(255, 80, 80),
+            warn_fg_color: Color32::from_rgb(255, 143, 0),
             window_corner_radius: 10.0,
             window_shadow: Shadow::big_dark(),
+            window_modal_scrim: Color32::from_black_alpha(140),
             resize_corner_size: 12.0,
             text_cursor_width: 2.0,
             text_cursor_preview: false,
@@ -391,10 +473,14 @@ impl Visuals {
             dark_mode: false,
             widgets: Widgets::light(),
             selection: Selection::light(),
+            scroll_bar: ScrollBarVisuals::light(),
             extreme_bg_color: Color32::from_gray(235), // TODO: rename
             hyperlink_color: Color32::from_rgb(0, 133, 218),
             code_bg_color: Color32::from_gray(200),
+            error_fg_color: Color32::from_rgb(220, 0, 0),
+            warn_fg_color: Color32::from_rgb(200, 100, 0),
             window_shadow: Shadow::big_light(),
+            window_modal_scrim: Color32::from_black_alpha(80),
             ..Self::dark()
         }
     }
@@ -427,6 +513,29 @@ impl Default for Selection {
     }
 }
 
+impl ScrollBarVisuals {
+    fn dark() -> Self {
+        Self {
+            width: 16.0,
+            corner_radius: 8.0,
+            track_fill: Color32::from_gray(10),
+            auto_hide: false,
+        }
+    }
+    fn light() -> Self {
+        Self {
+            track_fill: Color32::from_gray(235),
+            ..Self::dark()
+        }
+    }
+}
+
+impl Default for ScrollBarVisuals {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
 impl Widgets {
     pub fn dark() -> Self {
         Self {
@@ -591,6 +700,8 @@ impl Interaction {
             resize_grab_radius_side,
             resize_grab_radius_corner,
             show_tooltips_only_when_still,
+            drag_threshold,
+            menu_close_policy,
         } = self;
         ui.add(Slider::new(resize_grab_radius_side, 0.0..=20.0).text("resize_grab_radius_side"));
         ui.add(
@@ -600,11 +711,36 @@ impl Interaction {
             show_tooltips_only_when_still,
             "Only show tooltips if mouse is still",
         );
+        ui.add(Slider::new(drag_threshold, 0.0..=20.0).text("drag_threshold"))
+            .on_hover_text("How far the pointer must move before a press becomes a drag");
+        ui.collapsing("Menus", |ui| menu_close_policy.ui(ui));
 
         ui.vertical_centered(|ui| reset_button(ui, self));
     }
 }
 
+impl MenuClosePolicy {
+    pub fn ui(&mut self, ui: &mut crate::Ui) {
+        let Self {
+            close_on_click_inside,
+            close_after_leaving,
+            click_button_to_close,
+        } = self;
+        ui.checkbox(close_on_click_inside, "Close when clicking inside the menu");
+        ui.checkbox(click_button_to_close, "Close when clicking the menu button");
+        ui.horizontal(|ui| {
+            let mut close_on_leave = close_after_leaving.is_some();
+            ui.checkbox(&mut close_on_leave, "Close when the pointer leaves");
+            if close_on_leave {
+                let delay = close_after_leaving.get_or_insert(0.5);
+                ui.add(Slider::new(delay, 0.0..=2.0).text("seconds"));
+            } else {
+                *close_after_leaving = None;
+            }
+        });
+    }
+}
+
 impl Widgets {
     pub fn ui(&mut self, ui: &mut crate::Ui) {
         let Self {
@@ -644,6 +780,22 @@ impl Selection {
     }
 }
 
+impl ScrollBarVisuals {
+    pub fn ui(&mut self, ui: &mut crate::Ui) {
+        let Self {
+            width,
+            corner_radius,
+            track_fill,
+            auto_hide,
+        } = self;
+
+        ui.add(Slider::new(width, 2.0..=32.0).text("width"));
+        ui.add(Slider::new(corner_radius, 0.0..=16.0).text("corner_radius"));
+        ui_color(ui, track_fill, "track_fill");
+        ui.checkbox(auto_hide, "auto_hide");
+    }
+}
+
 impl WidgetVisuals {
     pub fn ui(&mut self, ui: &mut crate::Ui) {
         let Self {
@@ -703,11 +855,15 @@ impl Visuals {
             override_text_color: _,
             widgets,
             selection,
+            scroll_bar,
             extreme_bg_color,
             hyperlink_color,
             code_bg_color,
+            error_fg_color,
+            warn_fg_color,
             window_corner_radius,
             window_shadow,
+            window_modal_scrim,
             resize_corner_size,
             text_cursor_width,
             text_cursor_preview,
@@ -716,6 +872,7 @@ impl Visuals {
 
         ui.collapsing("widgets", |ui| widgets.ui(ui));
         ui.collapsing("selection", |ui| selection.ui(ui));
+        ui.collapsing("scroll_bar", |ui| scroll_bar.ui(ui));
 
         ui.group(|ui| {
             ui.label("Window");
@@ -724,6 +881,7 @@ impl Visuals {
             stroke_ui(ui, &mut widgets.noninteractive.bg_stroke, "Outline");
             ui.add(Slider::new(window_corner_radius, 0.0..=20.0).text("Corner Radius"));
             shadow_ui(ui, window_shadow, "Shadow");
+            ui_color(ui, window_modal_scrim, "Modal scrim");
         });
         ui_color(
             ui,
@@ -734,6 +892,8 @@ impl Visuals {
         ui_color(ui, extreme_bg_color, "extreme_bg_color");
         ui_color(ui, hyperlink_color, "hyperlink_color");
         ui_color(ui, code_bg_color, "code_bg_color");
+        ui_color(ui, error_fg_color, "error_fg_color");
+        ui_color(ui, warn_fg_color, "warn_fg_color");
         ui.add(Slider::new(resize_corner_size, 0.0..=20.0).text("resize_corner_size"));
         ui.add(Slider::new(text_cursor_width, 0.0..=2.0).text("text_cursor_width"));
         ui.checkbox(text_cursor_preview, "text_cursor_preview");
@@ -750,6 +910,8 @@ impl DebugOptions {
             show_expand_width: debug_expand_width,
             show_expand_height: debug_expand_height,
             show_resize: debug_resize,
+            show_overflow: debug_overflow,
+            design_mode,
         } = self;
 
         ui.checkbox(debug_widgets, "Show widget bounds on hover");
@@ -762,6 +924,11 @@ impl DebugOptions {
             "Show which widgets make their parent higher",
         );
         ui.checkbox(debug_resize, "Debug Resize");
+        ui.checkbox(
+            debug_overflow,
+            "Show widgets that didn't get the space they wanted",
+        );
+        ui.checkbox(design_mode, "Design mode: rulers, guides and distances");
 
         ui.vertical_centered(|ui| reset_button(ui, self));
     }
@@ -0,0 +1,167 @@
+//! Helpers for showing the progress of work done on another thread.
+//!
+//! ```
+//! # let ui = &mut egui::Ui::__test();
+//! # let request_repaint = || {};
+//! // Start the work once, and keep the `Task` in your app state:
+//! let task = egui::Task::spawn(request_repaint, |reporter| {
+//!     for i in 0..100 {
+//!         if reporter.is_cancelled() {
+//!             return;
+//!         }
+//!         reporter.set_message(format!("Processing item {}", i));
+//!         reporter.set_progress(i as f32 / 100.0);
+//!     }
+//! });
+//!
+//! // Then show it every frame:
+//! ui.add(egui::widgets::TaskProgress::new(&task));
+//! ```
+
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone, Debug, Default)]
+struct TaskState {
+    progress: f32,
+    message: String,
+    finished: bool,
+    cancelled: bool,
+}
+
+struct Shared {
+    state: Mutex<TaskState>,
+    request_repaint: Box<dyn Fn() + Send + Sync>,
+}
+
+impl Shared {
+    fn state(&self) -> std::sync::MutexGuard<'_, TaskState> {
+        // A panic in the other thread shouldn't also take down the ui:
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn update(&self, f: impl FnOnce(&mut TaskState)) {
+        f(&mut self.state());
+        (self.request_repaint)();
+    }
+}
+
+/// The ui side of some work done on another thread.
+///
+/// Keep this in your app state and show it with [`crate::widgets::TaskProgress`],
+/// or read the progress yourself.
+///
+/// Cloning gives another handle to the same task.
+#[derive(Clone)]
+pub struct Task {
+    shared: Arc<Shared>,
+}
+
+/// The worker side of a [`Task`], for reporting progress.
+///
+/// This is `Send`, so you can move it to another thread.
+/// Every report calls the `request_repaint` function given to [`Task::new`],
+/// which should wake up the ui (e.g. `epi::Frame::repaint_signal`),
+/// since egui won't repaint on its own while the user is idle.
+#[derive(Clone)]
+pub struct TaskReporter {
+    shared: Arc<Shared>,
+}
+
+impl Task {
+    /// Create a task and the reporter for it, for when you run the work yourself.
+    ///
+    /// `request_repaint` is called from the worker thread whenever something changes.
+    pub fn new(request_repaint: impl Fn() + Send + Sync + 'static) -> (Self, TaskReporter) {
+        let shared = Arc::new(Shared {
+            state: Default::default(),
+            request_repaint: Box::new(request_repaint),
+        });
+        let reporter = TaskReporter {
+            shared: shared.clone(),
+        };
+        (Self { shared }, reporter)
+    }
+
+    /// Run `work` on a new thread, and mark the task as finished when it returns.
+    ///
+    /// Threads are not available on the web, so there you need to use [`Self::new`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn spawn(
+        request_repaint: impl Fn() + Send + Sync + 'static,
+        work: impl FnOnce(&TaskReporter) + Send + 'static,
+    ) -> Self {
+        let (task, reporter) = Self::new(request_repaint);
+        std::thread::spawn(move || {
+            work(&reporter);
+            reporter.finish();
+        });
+        task
+    }
+
+    /// In the range `0.0..=1.0`.
+    pub fn progress(&self) -> f32 {
+        self.shared.state().progress
+    }
+
+    /// The last message from [`TaskReporter::set_message`].
+    pub fn message(&self) -> String {
+        self.shared.state().message.clone()
+    }
+
+    /// Has [`TaskReporter::finish`] been called?
+    pub fn is_finished(&self) -> bool {
+        self.shared.state().finished
+    }
+
+    /// Ask the worker to stop. It is up to the worker to check [`TaskReporter::is_cancelled`].
+    pub fn cancel(&self) {
+        self.shared.state().cancelled = true;
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.shared.state().cancelled
+    }
+}
+
+impl TaskReporter {
+    /// In the range `0.0..=1.0`.
+    pub fn set_progress(&self, progress: f32) {
+        self.shared
+            .update(|state| state.progress = progress.clamp(0.0, 1.0));
+    }
+
+    /// A short description of what is being done, e.g. `"Loading image 3 of 10"`.
+    pub fn set_message(&self, message: impl Into<String>) {
+        let message = message.into();
+        self.shared.update(|state| state.message = message);
+    }
+
+    /// The work is done. Called for you by [`Task::spawn`].
+    pub fn finish(&self) {
+        self.shared.update(|state| {
+            state.progress = 1.0;
+            state.finished = true;
+        });
+    }
+
+    /// Has the user asked to stop? Check this now and then, and return early if so.
+    pub fn is_cancelled(&self) -> bool {
+        self.shared.state().cancelled
+    }
+}
+
+impl std::fmt::Debug for Task {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Task").field(&*self.shared.state()).finish()
+    }
+}
+
+impl std::fmt::Debug for TaskReporter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("TaskReporter")
+            .field(&*self.shared.state())
+            .finish()
+    }
+}
@@ -451,6 +451,27 @@ impl Ui {
     pub fn available_rect_before_wrap_finite(&self) -> Rect {
         self.placer.available_rect_before_wrap_finite()
     }
+
+    /// The space that is left after the widgets added so far, i.e. where the next widget would go.
+    ///
+    /// Unlike [`Self::available_rect_before_wrap`] this is never infinite,
+    /// so you can use it to fill the rest of the `Ui` with something:
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// ui.label("Some text at the top");
+    /// let rest = ui.remaining_rect();
+    /// ui.allocate_rect(rest, egui::Sense::hover());
+    /// ui.painter().rect_filled(rest, 0.0, egui::Color32::GRAY);
+    /// ```
+    pub fn remaining_rect(&self) -> Rect {
+        self.available_rect_before_wrap_finite()
+    }
+
+    /// Size of [`Self::remaining_rect`].
+    pub fn remaining_size(&self) -> Vec2 {
+        self.remaining_rect().size()
+    }
 }
 
 /// # `Id` creation
@@ -463,11 +484,16 @@ impl Ui {
         self.id.with(&id_source)
     }
 
-    pub(crate) fn next_auto_id(&self) -> Id {
+    /// The `Id` the next widget added to this `Ui` will get, unless it is given one explicitly.
+    ///
+    /// Such ids are not stable if the widgets before it change, so don't use them for state
+    /// that should survive between frames (use [`Self::make_persistent_id`] for that).
+    pub fn next_auto_id(&self) -> Id {
         Id::new(self.next_auto_id_source)
     }
 
-    pub(crate) fn auto_id_with<IdSource>(&self, id_source: IdSource) -> Id
+    /// Same as `ui.next_auto_id().with(id_source)`.
+    pub fn auto_id_with<IdSource>(&self, id_source: IdSource) -> Id
     where
         IdSource: Hash + std::fmt::Debug,
     {
@@ -490,6 +516,39 @@ impl Ui {
         )
     }
 
+    /// Like [`Self::interact`], but you decide if the widget is hovered.
+    ///
+    /// Use this for widgets that are not rectangular, e.g. a round knob or a line in a node graph:
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// let (rect, _) = ui.allocate_exact_size(egui::vec2(32.0, 32.0), egui::Sense::hover());
+    /// let id = ui.make_persistent_id("my_knob");
+    /// let radius = rect.width() / 2.0;
+    /// let hovered = ui.rect_contains_pointer(rect)
+    ///     && ui.input().pointer.hover_pos().map_or(false, |pos| pos.distance(rect.center()) <= radius);
+    /// let response = ui.interact_with_hovered(rect, hovered, id, egui::Sense::drag());
+    /// ```
+    ///
+    /// `hovered` is ignored if this `Ui` is disabled.
+    pub fn interact_with_hovered(
+        &self,
+        rect: Rect,
+        hovered: bool,
+        id: Id,
+        sense: Sense,
+    ) -> Response {
+        self.ctx()
+            .interact_with_hovered(self.layer_id(), id, rect, sense, self.enabled, hovered)
+    }
+
+    /// Does the widget with this `Id` have keyboard focus?
+    ///
+    /// Same as `ui.memory().has_focus(id)`.
+    pub fn has_focus(&self, id: Id) -> bool {
+        self.memory().has_focus(id)
+    }
+
     /// Is the pointer (mouse/touch) above this rectangle in this `Ui`?
     ///
     /// The `clip_rect` and layer of this `Ui` will be respected, so, for instance,
@@ -552,6 +611,60 @@ impl Ui {
         self.interact(rect, id, sense)
     }
 
+    /// Like [`Self::allocate_response`], but also describes the widget for screen readers and the inspector
+    /// (see [`Response::widget_info`]).
+    ///
+    /// `make_info` is called after the interaction, and only when needed.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut volume = 0.5;
+    /// let response = ui.allocate_response_with_info(egui::vec2(100.0, 20.0), egui::Sense::drag(), || {
+    ///     egui::WidgetInfo::slider(volume, "Volume")
+    /// });
+    /// volume += response.drag_delta().x as f64 / 100.0;
+    /// ```
+    pub fn allocate_response_with_info(
+        &mut self,
+        desired_size: Vec2,
+        sense: Sense,
+        make_info: impl Fn() -> WidgetInfo,
+    ) -> Response {
+        let response = self.allocate_response(desired_size, sense);
+        response.widget_info(make_info);
+        response
+    }
+
+    /// Like [`Self::allocate_response`], but with an explicit `Id` instead of an automatic one.
+    ///
+    /// Use this when the widget needs to keep its `Id` even if its position in the `Ui` changes,
+    /// e.g. because some widget before it comes and goes.
+    pub fn allocate_response_with_id(
+        &mut self,
+        id: Id,
+        desired_size: Vec2,
+        sense: Sense,
+    ) -> Response {
+        let (_auto_id, rect) = self.allocate_space(desired_size);
+        self.interact(rect, id, sense)
+    }
+
+    /// [`Self::allocate_response`], but with an `Id` made from `id_source` if there is one.
+    pub(crate) fn allocate_response_with_id_source(
+        &mut self,
+        id_source: Option<Id>,
+        desired_size: Vec2,
+        sense: Sense,
+    ) -> Response {
+        match id_source {
+            Some(id_source) => {
+                let id = self.make_persistent_id(id_source);
+                self.allocate_response_with_id(id, desired_size, sense)
+            }
+            None => self.allocate_response(desired_size, sense),
+        }
+    }
+
     /// Returns a `Rect` with exactly what you asked for.
     ///
     /// The response rect will be larger if this is part of a justified layout or similar.
@@ -565,6 +678,34 @@ impl Ui {
         (rect, response)
     }
 
+    /// Like [`Self::allocate_exact_size`], but with an explicit `Id` instead of an automatic one.
+    pub fn allocate_exact_size_with_id(
+        &mut self,
+        id: Id,
+        desired_size: Vec2,
+        sense: Sense,
+    ) -> (Rect, Response) {
+        let response = self.allocate_response_with_id(id, desired_size, sense);
+        let rect = self
+            .placer
+            .align_size_within_rect(desired_size, response.rect);
+        (rect, response)
+    }
+
+    /// [`Self::allocate_exact_size`], but with an `Id` made from `id_source` if there is one.
+    pub(crate) fn allocate_exact_size_with_id_source(
+        &mut self,
+        id_source: Option<Id>,
+        desired_size: Vec2,
+        sense: Sense,
+    ) -> (Rect, Response) {
+        let response = self.allocate_response_with_id_source(id_source, desired_size, sense);
+        let rect = self
+            .placer
+            .align_size_within_rect(desired_size, response.rect);
+        (rect, response)
+    }
+
     /// Allocate at least as much space as needed, and interact with that rect.
     ///
     /// The returned `Rect` will be the same size as `Response::rect`.
@@ -637,9 +778,36 @@ impl Ui {
         let id = Id::new(self.next_auto_id_source);
         self.next_auto_id_source = self.next_auto_id_source.wrapping_add(1);
 
+        if self.style().debug.show_overflow && (too_wide || too_high) {
+            self.debug_overflow(id, desired_size, original_available, rect);
+        }
+
         (id, rect)
     }
 
+    /// Mark a widget that got less space than it wanted, and remember it for [`Context::overflow_warnings`].
+    fn debug_overflow(&self, id: Id, desired_size: Vec2, available: Vec2, rect: Rect) {
+        let warning = format!(
+            "Widget {:?} wanted {:.1}x{:.1} points, but only {:.1}x{:.1} was available",
+            id, desired_size.x, desired_size.y, available.x, available.y
+        );
+
+        // Paint on top of everything, unclipped, since the widget itself may be clipped:
+        let painter = self.ctx().debug_painter();
+        painter.rect_stroke(rect, 0.0, (1.0, Color32::RED));
+        painter.circle_filled(rect.right_top(), 3.0, Color32::RED);
+        let hovered = self
+            .input()
+            .pointer
+            .hover_pos()
+            .map_or(false, |pos| rect.contains(pos));
+        if hovered {
+            painter.error(rect.left_bottom(), &warning);
+        }
+
+        self.ctx().frame_state().overflow_warnings.push(warning);
+    }
+
     /// Reserve this much space and move the cursor.
     /// Returns where to put the widget.
     fn allocate_space_impl(&mut self, desired_size: Vec2) -> Rect {
@@ -685,6 +853,12 @@ impl Ui {
         self.placer.cursor()
     }
 
+    /// Only place new widgets within `rect` from now on.
+    /// Used to make room for panels inside of a [`Ui`].
+    pub(crate) fn cut_to(&mut self, rect: Rect) {
+        self.placer.cut_to(rect);
+    }
+
     /// Where do we expect a zero-sized widget to be placed?
     pub(crate) fn next_widget_position(&self) -> Pos2 {
         self.placer.next_widget_position()
@@ -798,6 +972,8 @@ impl Ui {
 
     /// Move the scroll to this cursor position with the specified alignment.
     ///
+    /// See also [`Self::scroll_to_rect`].
+    ///
     /// ```
     /// # use egui::Align;
     /// # let mut ui = &mut egui::Ui::__test();
@@ -814,7 +990,37 @@ impl Ui {
     /// ```
     pub fn scroll_to_cursor(&mut self, align: Align) {
         let target_y = self.next_widget_position().y;
-        self.ctx().frame_state().scroll_target = Some((target_y, align));
+        self.ctx().frame_state().scroll_target[1] = Some((target_y..=target_y, Some(align)));
+    }
+
+    /// Adjust the scroll position of any parent [`ScrollArea`] so that the given `Rect` becomes visible.
+    ///
+    /// If `align` is `None`, the parent scrolls just enough to bring the rect into view,
+    /// and not at all if it is already visible.
+    /// Otherwise the rect is aligned with the top/center/bottom (or left/center/right) of the visible area.
+    ///
+    /// ```
+    /// # use egui::Align;
+    /// # let mut ui = &mut egui::Ui::__test();
+    /// egui::ScrollArea::auto_sized().show(ui, |ui| {
+    ///     let mut selected_rect = None;
+    ///     for i in 0..1000 {
+    ///         let response = ui.label(format!("Item {}", i));
+    ///         if i == 500 {
+    ///             selected_rect = Some(response.rect);
+    ///         }
+    ///     }
+    ///
+    ///     if let Some(rect) = selected_rect {
+    ///         ui.scroll_to_rect(rect, None);
+    ///     }
+    /// });
+    /// ```
+    pub fn scroll_to_rect(&self, rect: Rect, align: Option<Align>) {
+        let mut frame_state = self.ctx().frame_state();
+        for d in 0..2 {
+            frame_state.scroll_target[d] = Some((rect.min[d]..=rect.max[d], align));
+        }
     }
 }
 
@@ -941,7 +1147,7 @@ impl Ui {
     /// ```
     ///
     /// See also [`Hyperlink`].
-    pub fn hyperlink_to(&mut self, label: impl ToString, url: impl ToString) -> Response {
+    pub fn hyperlink_to(&mut self, label: impl Into<WidgetText>, url: impl ToString) -> Response {
         Hyperlink::new(url).text(label).ui(self)
     }
 
@@ -986,7 +1192,7 @@ impl Ui {
     /// See also [`Button`].
     #[must_use = "You should check if the user clicked this with `if ui.button(…).clicked() { … } "]
     #[inline(always)]
-    pub fn button(&mut self, text: impl ToString) -> Response {
+    pub fn button(&mut self, text: impl Into<WidgetText>) -> Response {
         Button::new(text).ui(self)
     }
 
@@ -996,19 +1202,19 @@ impl Ui {
     ///
     /// Shortcut for `add(Button::new(text).small())`
     #[must_use = "You should check if the user clicked this with `if ui.small_button(…).clicked() { … } "]
-    pub fn small_button(&mut self, text: impl ToString) -> Response {
+    pub fn small_button(&mut self, text: impl Into<WidgetText>) -> Response {
         Button::new(text).small().ui(self)
     }
 
     /// Show a checkbox.
-    pub fn checkbox(&mut self, checked: &mut bool, text: impl ToString) -> Response {
+    pub fn checkbox(&mut self, checked: &mut bool, text: impl Into<WidgetText>) -> Response {
         Checkbox::new(checked, text).ui(self)
     }
 
     /// Show a [`RadioButton`].
     /// Often you want to use [`Self::radio_value`] instead.
     #[must_use = "You should check if the user clicked this with `if ui.radio(…).clicked() { … } "]
-    pub fn radio(&mut self, selected: bool, text: impl ToString) -> Response {
+    pub fn radio(&mut self, selected: bool, text: impl Into<WidgetText>) -> Response {
         RadioButton::new(selected, text).ui(self)
     }
 
@@ -1033,7 +1239,7 @@ impl Ui {
         &mut self,
         current_value: &mut Value,
         selected_value: Value,
-        text: impl ToString,
+        text: impl Into<WidgetText>,
     ) -> Response {
         let mut response = self.radio(*current_value == selected_value, text);
         if response.clicked() {
@@ -1047,7 +1253,7 @@ impl Ui {
     ///
     /// See also [`SelectableLabel`].
     #[must_use = "You should check if the user clicked this with `if ui.selectable_label(…).clicked() { … } "]
-    pub fn selectable_label(&mut self, checked: bool, text: impl ToString) -> Response {
+    pub fn selectable_label(&mut self, checked: bool, text: impl Into<WidgetText>) -> Response {
         SelectableLabel::new(checked, text).ui(self)
     }
 
@@ -1061,7 +1267,7 @@ impl Ui {
         &mut self,
         current_value: &mut Value,
         selected_value: Value,
-        text: impl ToString,
+        text: impl Into<WidgetText>,
     ) -> Response {
         let mut response = self.selectable_label(*current_value == selected_value, text);
         if response.clicked() {
@@ -1101,7 +1307,10 @@ impl Ui {
         let mut taus = *radians / TAU;
         let mut response = self
             .add(DragValue::new(&mut taus).speed(0.01).suffix("τ"))
-            .on_hover_text("1τ = one turn, 0.5τ = half a turn, etc. 0.25τ = 90°");
+            .on_hover_text(
+                self.ctx()
+                    .tr("1τ = one turn, 0.5τ = half a turn, etc. 0.25τ = 90°"),
+            );
 
         // only touch `*radians` if we actually changed the value
         if taus != *radians / TAU {
@@ -1220,6 +1429,36 @@ impl Ui {
         crate::Frame::group(self.style()).show(self, add_contents)
     }
 
+    /// Create a child ui whose widgets get `Id`s based on `id_source`.
+    ///
+    /// Widgets get automatic `Id`s from their position in the `Ui`, so a widget changes `Id`
+    /// if a widget before it comes or goes. That loses e.g. keyboard focus or an ongoing drag.
+    /// Wrap each row of a dynamic list in `push_id` to keep the `Id`s of its widgets stable
+    /// (or give a single widget an `id_source`, e.g. with [`crate::Button::id_source`]):
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut items = vec![("a".to_owned(), false), ("b".to_owned(), true)];
+    /// for (name, enabled) in &mut items {
+    ///     ui.push_id(name.as_str(), |ui| {
+    ///         ui.checkbox(enabled, "Enabled");
+    ///     });
+    /// }
+    /// ```
+    pub fn push_id<R>(
+        &mut self,
+        id_source: impl Hash,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let child_rect = self.available_rect_before_wrap();
+        let mut child_ui = self.child_ui(child_rect, *self.layout());
+        child_ui.id = self.id.with(id_source);
+        child_ui.next_auto_id_source = child_ui.id.with("auto").value();
+        let ret = add_contents(&mut child_ui);
+        let response = self.allocate_rect(child_ui.min_rect(), Sense::hover());
+        InnerResponse::new(ret, response)
+    }
+
     /// Create a scoped child ui.
     ///
     /// You can use this to temporarily change the [`Style`] of a sub-region, for instance:
@@ -1268,7 +1507,7 @@ impl Ui {
     /// A [`CollapsingHeader`] that starts out collapsed.
     pub fn collapsing<R>(
         &mut self,
-        heading: impl ToString,
+        heading: impl Into<WidgetText>,
         add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> CollapsingResponse<R> {
         CollapsingHeader::new(heading).show(self, add_contents)
@@ -1562,8 +1801,7 @@ impl Ui {
     /// Move to the next row in a grid layout or wrapping layout.
     /// Otherwise does nothing.
     pub fn end_row(&mut self) {
-        self.placer
-            .end_row(self.spacing().item_spacing, &self.painter().clone());
+        self.placer.end_row(self.spacing().item_spacing);
     }
 
     /// Set row height in horizontal wrapping layout.
@@ -1620,6 +1858,29 @@ impl Ui {
         self.advance_cursor_after_rect(Rect::from_min_size(top_left, size));
         result
     }
+
+    /// Close the [`crate::menu::menu`] or popup (e.g. [`crate::popup::popup_below_widget`]) that this `Ui` is in.
+    ///
+    /// Use this for the items that should close the menu when
+    /// [`crate::style::MenuClosePolicy::close_on_click_inside`] is off,
+    /// so that the menu can also hold checkboxes, sliders etc:
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut show_grid = true;
+    /// ui.style_mut().interaction.menu_close_policy.close_on_click_inside = false;
+    /// egui::menu::bar(ui, |ui| {
+    ///     egui::menu::menu(ui, "View", |ui| {
+    ///         ui.checkbox(&mut show_grid, "Show grid"); // Doesn't close the menu
+    ///         if ui.button("Reset view").clicked() {
+    ///             ui.close_menu();
+    ///         }
+    ///     });
+    /// });
+    /// ```
+    pub fn close_menu(&mut self) {
+        self.ctx().frame_state().close_menu = true;
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -13,6 +13,23 @@ use std::collections::VecDeque;
 /// This can be used for things like smoothed averages (for e.g. FPS)
 /// or for smoothed velocity (e.g. mouse pointer speed).
 /// All times are in seconds.
+///
+/// ```
+/// # let mut ctx = egui::CtxRef::default();
+/// # ctx.begin_frame(Default::default());
+/// use egui::util::History;
+///
+/// // Remember up to 300 frame times from the last second:
+/// let mut frame_times = History::new(300, 1.0);
+///
+/// // Each frame:
+/// let now = ctx.input().time;
+/// frame_times.add(now, ctx.input().unstable_dt);
+///
+/// if let (Some(average), Some(max)) = (frame_times.average(), frame_times.max()) {
+///     println!("Frame time: {:.1} ms on average, {:.1} ms at most", 1e3 * average, 1e3 * max);
+/// }
+/// ```
 #[derive(Clone, Debug)]
 pub struct History<T> {
     /// In elements, i.e. of `values.len()`
@@ -29,10 +46,7 @@ pub struct History<T> {
     values: VecDeque<(f64, T)>,
 }
 
-impl<T> History<T>
-where
-    T: Copy,
-{
+impl<T> History<T> {
     pub fn new(max_len: usize, max_age: f64) -> Self {
         Self::from_max_len_age(max_len, max_age)
     }
@@ -69,10 +83,6 @@ where
         self.total_count
     }
 
-    pub fn latest(&self) -> Option<T> {
-        self.values.back().map(|(_, value)| *value)
-    }
-
     pub fn latest_mut(&mut self) -> Option<&mut T> {
         self.values.back_mut().map(|(_, value)| value)
     }
@@ -86,15 +96,20 @@ where
         }
     }
 
-    /// `(time, value)` pairs
+    /// The time of the latest value.
+    pub fn latest_time(&self) -> Option<f64> {
+        self.values.back().map(|(time, _)| *time)
+    }
+
+    /// `(time, value)` pairs, by reference. Works for values that aren't `Copy`, e.g. log messages.
     /// Time difference between values can be zero, but never negative.
-    // TODO: impl IntoIter
-    pub fn iter(&'_ self) -> impl Iterator<Item = (f64, T)> + '_ {
-        self.values.iter().map(|(time, value)| (*time, *value))
+    pub fn iter_refs(&'_ self) -> impl DoubleEndedIterator<Item = (f64, &T)> + '_ {
+        self.values.iter().map(|(time, value)| (*time, value))
     }
 
-    pub fn values(&'_ self) -> impl Iterator<Item = T> + '_ {
-        self.values.iter().map(|(_time, value)| *value)
+    /// How many values were added per second, over the time span of the history (e.g. FPS).
+    pub fn rate(&self) -> Option<f32> {
+        self.mean_time_interval().map(|interval| 1.0 / interval)
     }
 
     pub fn clear(&mut self) {
@@ -140,6 +155,55 @@ where
     }
 }
 
+impl<T> History<T>
+where
+    T: Copy,
+{
+    pub fn latest(&self) -> Option<T> {
+        self.values.back().map(|(_, value)| *value)
+    }
+
+    /// `(time, value)` pairs
+    /// Time difference between values can be zero, but never negative.
+    // TODO: impl IntoIter
+    pub fn iter(&'_ self) -> impl Iterator<Item = (f64, T)> + '_ {
+        self.values.iter().map(|(time, value)| (*time, *value))
+    }
+
+    pub fn values(&'_ self) -> impl Iterator<Item = T> + '_ {
+        self.values.iter().map(|(_time, value)| *value)
+    }
+}
+
+impl<T> History<T>
+where
+    T: Copy + PartialOrd,
+{
+    /// The smallest value in the history. `NaN`s are ignored.
+    pub fn min(&self) -> Option<T> {
+        self.min_max().map(|(min, _)| min)
+    }
+
+    /// The largest value in the history. `NaN`s are ignored.
+    pub fn max(&self) -> Option<T> {
+        self.min_max().map(|(_, max)| max)
+    }
+
+    /// The smallest and largest value in the history, e.g. for the y-axis of a live plot.
+    pub fn min_max(&self) -> Option<(T, T)> {
+        let mut values = self
+            .values()
+            .filter(|value| value.partial_cmp(value).is_some()); // skip NaN
+        let first = values.next()?;
+        Some(values.fold((first, first), |(min, max), value| {
+            (
+                if value < min { value } else { min },
+                if value > max { value } else { max },
+            )
+        }))
+    }
+}
+
 impl<T> History<T>
 where
     T: Copy,
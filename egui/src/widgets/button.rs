@@ -13,7 +13,8 @@ use crate::*;
 /// ```
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 pub struct Button {
-    text: String,
+    text: WidgetText,
+    shortcut_text: Option<WidgetText>,
     text_color: Option<Color32>,
     text_style: Option<TextStyle>,
     /// None means default for interact
@@ -23,13 +24,14 @@ pub struct Button {
     frame: bool,
     wrap: Option<bool>,
     min_size: Vec2,
+    id_source: Option<Id>,
 }
 
 impl Button {
-    #[allow(clippy::needless_pass_by_value)]
-    pub fn new(text: impl ToString) -> Self {
+    pub fn new(text: impl Into<WidgetText>) -> Self {
         Self {
-            text: text.to_string(),
+            text: text.into(),
+            shortcut_text: None,
             text_color: None,
             text_style: None,
             fill: Default::default(),
@@ -38,6 +40,7 @@ impl Button {
             frame: true,
             wrap: None,
             min_size: Vec2::ZERO,
+            id_source: None,
         }
     }
 
@@ -103,6 +106,26 @@ impl Button {
         self
     }
 
+    /// Show some weak text on the right side of the button, usually a keyboard shortcut.
+    /// Use this for items in a menu, see [`crate::KeyboardShortcut`].
+    pub fn shortcut_text(mut self, shortcut_text: impl Into<WidgetText>) -> Self {
+        self.shortcut_text = Some(shortcut_text.into());
+        self
+    }
+
+    /// Base the `Id` on `id_source` instead of the position in the [`Ui`], see [`Ui::push_id`].
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
+    /// Like [`Self::id_source`], but without hashing it again,
+    /// so that the button gets the same `Id` as the widget it is part of.
+    pub(crate) fn id_source_id(mut self, id_source: Option<Id>) -> Self {
+        self.id_source = id_source;
+        self
+    }
+
     pub(crate) fn min_size(mut self, min_size: Vec2) -> Self {
         self.min_size = min_size;
         self
@@ -113,6 +136,7 @@ impl Button {
     fn enabled_ui(self, ui: &mut Ui) -> Response {
         let Button {
             text,
+            shortcut_text,
             text_color,
             text_style,
             fill,
@@ -121,6 +145,7 @@ impl Button {
             frame,
             wrap,
             min_size,
+            id_source,
         } = self;
 
         let text_style = text_style
@@ -133,28 +158,36 @@ impl Button {
         }
         let total_extra = button_padding + button_padding;
 
+        let shortcut_galley = shortcut_text
+            .as_ref()
+            .map(|shortcut_text| shortcut_text.layout(ui, text_style, None));
+        let shortcut_width = shortcut_galley
+            .as_ref()
+            .map_or(0.0, |galley| ui.spacing().item_spacing.x + galley.size.x);
+
         let wrap = wrap.unwrap_or_else(|| ui.wrap_text());
-        let galley = if wrap {
-            ui.fonts()
-                .layout_multiline(text_style, text, ui.available_width() - total_extra.x)
-        } else {
-            ui.fonts().layout_no_wrap(text_style, text)
-        };
+        let wrap_width =
+            Some(ui.available_width() - total_extra.x - shortcut_width).filter(|_| wrap);
+        let galley = text.layout(ui, text_style, wrap_width);
 
         let mut desired_size = galley.size + 2.0 * button_padding;
+        desired_size.x += shortcut_width;
         if !small {
             desired_size.y = desired_size.y.at_least(ui.spacing().interact_size.y);
         }
         desired_size = desired_size.at_least(min_size);
 
-        let (rect, response) = ui.allocate_at_least(desired_size, sense);
+        let response = ui.allocate_response_with_id_source(id_source, desired_size, sense);
+        let rect = response.rect;
         response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, &galley.text));
 
         if ui.clip_rect().intersects(rect) {
             let visuals = ui.style().interact(&response);
+            let mut text_rect = rect.shrink2(button_padding);
+            text_rect.max.x -= shortcut_width;
             let text_pos = ui
                 .layout()
-                .align_size_within_rect(galley.size, rect.shrink2(button_padding))
+                .align_size_within_rect(galley.size, text_rect)
                 .min;
 
             if frame {
@@ -170,7 +203,16 @@ impl Button {
             let text_color = text_color
                 .or(ui.visuals().override_text_color)
                 .unwrap_or_else(|| visuals.text_color());
-            ui.painter().galley(text_pos, galley, text_color);
+            text.paint_galley(ui, text_pos, galley, text_color);
+
+            if let (Some(shortcut_text), Some(shortcut_galley)) = (shortcut_text, shortcut_galley) {
+                let shortcut_pos = pos2(
+                    rect.right() - button_padding.x - shortcut_galley.size.x,
+                    rect.center().y - 0.5 * shortcut_galley.size.y,
+                );
+                let weak_color = ui.visuals().weak_text_color();
+                shortcut_text.paint_galley(ui, shortcut_pos, shortcut_galley, weak_color);
+            }
         }
 
         response
@@ -211,19 +253,20 @@ impl Widget for Button {
 #[derive(Debug)]
 pub struct Checkbox<'a> {
     checked: &'a mut bool,
-    text: String,
+    text: WidgetText,
     text_color: Option<Color32>,
     text_style: Option<TextStyle>,
+    id_source: Option<Id>,
 }
 
 impl<'a> Checkbox<'a> {
-    #[allow(clippy::needless_pass_by_value)]
-    pub fn new(checked: &'a mut bool, text: impl ToString) -> Self {
+    pub fn new(checked: &'a mut bool, text: impl Into<WidgetText>) -> Self {
         Checkbox {
             checked,
-            text: text.to_string(),
+            text: text.into(),
             text_color: None,
             text_style: None,
+            id_source: None,
         }
     }
 
@@ -236,6 +279,12 @@ impl<'a> Checkbox<'a> {
         self.text_style = Some(text_style);
         self
     }
+
+    /// Base the `Id` on `id_source` instead of the position in the [`Ui`], see [`Ui::push_id`].
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
 }
 
 impl<'a> Widget for Checkbox<'a> {
@@ -245,6 +294,7 @@ impl<'a> Widget for Checkbox<'a> {
             text,
             text_color,
             text_style,
+            id_source,
         } = self;
 
         let text_style = text_style
@@ -257,17 +307,14 @@ impl<'a> Widget for Checkbox<'a> {
         let button_padding = spacing.button_padding;
         let total_extra = button_padding + vec2(icon_width + icon_spacing, 0.0) + button_padding;
 
-        let galley = if ui.wrap_text() {
-            ui.fonts()
-                .layout_multiline(text_style, text, ui.available_width() - total_extra.x)
-        } else {
-            ui.fonts().layout_no_wrap(text_style, text)
-        };
+        let wrap_width = Some(ui.available_width() - total_extra.x).filter(|_| ui.wrap_text());
+        let galley = text.layout(ui, text_style, wrap_width);
 
         let mut desired_size = total_extra + galley.size;
         desired_size = desired_size.at_least(spacing.interact_size);
         desired_size.y = desired_size.y.max(icon_width);
-        let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::click());
+        let (rect, mut response) =
+            ui.allocate_exact_size_with_id_source(id_source, desired_size, Sense::click());
 
         if response.clicked() {
             *checked = !*checked;
@@ -304,7 +351,7 @@ impl<'a> Widget for Checkbox<'a> {
         let text_color = text_color
             .or(ui.visuals().override_text_color)
             .unwrap_or_else(|| visuals.text_color());
-        ui.painter().galley(text_pos, galley, text_color);
+        text.paint_galley(ui, text_pos, galley, text_color);
         response
     }
 }
@@ -333,19 +380,20 @@ impl<'a> Widget for Checkbox<'a> {
 #[derive(Debug)]
 pub struct RadioButton {
     checked: bool,
-    text: String,
+    text: WidgetText,
     text_color: Option<Color32>,
     text_style: Option<TextStyle>,
+    id_source: Option<Id>,
 }
 
 impl RadioButton {
-    #[allow(clippy::needless_pass_by_value)]
-    pub fn new(checked: bool, text: impl ToString) -> Self {
+    pub fn new(checked: bool, text: impl Into<WidgetText>) -> Self {
         Self {
             checked,
-            text: text.to_string(),
+            text: text.into(),
             text_color: None,
             text_style: None,
+            id_source: None,
         }
     }
 
@@ -358,6 +406,12 @@ impl RadioButton {
         self.text_style = Some(text_style);
         self
     }
+
+    /// Base the `Id` on `id_source` instead of the position in the [`Ui`], see [`Ui::push_id`].
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
 }
 
 impl Widget for RadioButton {
@@ -367,6 +421,7 @@ impl Widget for RadioButton {
             text,
             text_color,
             text_style,
+            id_source,
         } = self;
 
         let text_style = text_style
@@ -378,17 +433,14 @@ impl Widget for RadioButton {
         let button_padding = ui.spacing().button_padding;
         let total_extra = button_padding + vec2(icon_width + icon_spacing, 0.0) + button_padding;
 
-        let galley = if ui.wrap_text() {
-            ui.fonts()
-                .layout_multiline(text_style, text, ui.available_width() - total_extra.x)
-        } else {
-            ui.fonts().layout_no_wrap(text_style, text)
-        };
+        let wrap_width = Some(ui.available_width() - total_extra.x).filter(|_| ui.wrap_text());
+        let galley = text.layout(ui, text_style, wrap_width);
 
         let mut desired_size = total_extra + galley.size;
         desired_size = desired_size.at_least(ui.spacing().interact_size);
         desired_size.y = desired_size.y.max(icon_width);
-        let (rect, response) = ui.allocate_exact_size(desired_size, Sense::click());
+        let (rect, response) =
+            ui.allocate_exact_size_with_id_source(id_source, desired_size, Sense::click());
         response
             .widget_info(|| WidgetInfo::selected(WidgetType::RadioButton, checked, &galley.text));
 
@@ -424,7 +476,7 @@ impl Widget for RadioButton {
         let text_color = text_color
             .or(ui.visuals().override_text_color)
             .unwrap_or_else(|| visuals.text_color());
-        painter.galley(text_pos, galley, text_color);
+        text.paint_galley(ui, text_pos, galley, text_color);
         response
     }
 }
@@ -439,6 +491,7 @@ pub struct ImageButton {
     sense: Sense,
     frame: bool,
     selected: bool,
+    id_source: Option<Id>,
 }
 
 impl ImageButton {
@@ -448,6 +501,7 @@ impl ImageButton {
             sense: Sense::click(),
             frame: true,
             selected: false,
+            id_source: None,
         }
     }
 
@@ -481,6 +535,12 @@ impl ImageButton {
         self.sense = sense;
         self
     }
+
+    /// Base the `Id` on `id_source` instead of the position in the [`Ui`], see [`Ui::push_id`].
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
 }
 
 impl Widget for ImageButton {
@@ -490,11 +550,12 @@ impl Widget for ImageButton {
             sense,
             frame,
             selected,
+            id_source,
         } = self;
 
         let button_padding = ui.spacing().button_padding;
         let size = image.size() + 2.0 * button_padding;
-        let (rect, response) = ui.allocate_exact_size(size, sense);
+        let (rect, response) = ui.allocate_exact_size_with_id_source(id_source, size, sense);
         response.widget_info(|| WidgetInfo::new(WidgetType::ImageButton));
 
         if ui.clip_rect().intersects(rect) {
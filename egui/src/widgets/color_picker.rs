@@ -216,7 +216,11 @@ fn color_text_ui(ui: &mut Ui, color: impl Into<Color32>) {
             r, g, b, a
         ));
 
-        if ui.button("📋").on_hover_text("Click to copy").clicked() {
+        if ui
+            .button("📋")
+            .on_hover_text(ui.ctx().tr("Click to copy"))
+            .clicked()
+        {
             ui.output().copied_text = format!("{}, {}, {}, {}", r, g, b, a);
         }
     });
@@ -230,9 +234,9 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsva: &mut HsvaGamma, alpha: Alpha) {
         let a = &mut hsva.a;
         let mut additive = *a < 0.0;
         ui.horizontal(|ui| {
-            ui.label("Blending:");
-            ui.radio_value(&mut additive, false, "Normal");
-            ui.radio_value(&mut additive, true, "Additive");
+            ui.label(ui.ctx().tr("Blending:"));
+            ui.radio_value(&mut additive, false, ui.ctx().tr("Normal"));
+            ui.radio_value(&mut additive, true, ui.ctx().tr("Additive"));
 
             if additive {
                 *a = -a.abs();
@@ -266,7 +270,7 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsva: &mut HsvaGamma, alpha: Alpha) {
         if alpha == Alpha::Opaque {
             hsva.a = 1.0;
             show_color(ui, *hsva, current_color_size);
-            ui.label("Selected color");
+            ui.label(ui.ctx().tr("Selected color"));
             ui.end_row();
         } else {
             let a = &mut hsva.a;
@@ -276,16 +280,16 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsva: &mut HsvaGamma, alpha: Alpha) {
                     *a = 0.5; // was additive, but isn't allowed to be
                 }
                 color_slider_1d(ui, a, |a| HsvaGamma { a, ..opaque }.into());
-                ui.label("Alpha");
+                ui.label(ui.ctx().tr("Alpha"));
                 ui.end_row();
             } else if !additive {
                 color_slider_1d(ui, a, |a| HsvaGamma { a, ..opaque }.into());
-                ui.label("Alpha");
+                ui.label(ui.ctx().tr("Alpha"));
                 ui.end_row();
             }
 
             show_color(ui, *hsva, current_color_size);
-            ui.label("Selected color");
+            ui.label(ui.ctx().tr("Selected color"));
             ui.end_row();
         }
 
@@ -303,19 +307,19 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsva: &mut HsvaGamma, alpha: Alpha) {
             }
             .into()
         });
-        ui.label("Hue");
+        ui.label(ui.ctx().tr("Hue"));
         ui.end_row();
 
         color_slider_1d(ui, s, |s| HsvaGamma { s, ..opaque }.into());
-        ui.label("Saturation");
+        ui.label(ui.ctx().tr("Saturation"));
         ui.end_row();
 
         color_slider_1d(ui, v, |v| HsvaGamma { v, ..opaque }.into());
-        ui.label("Value");
+        ui.label(ui.ctx().tr("Value"));
         ui.end_row();
 
         color_slider_2d(ui, v, s, |v, s| HsvaGamma { s, v, ..opaque }.into());
-        ui.label("Value / Saturation");
+        ui.label(ui.ctx().tr("Value / Saturation"));
         ui.end_row();
     });
 }
@@ -335,7 +339,8 @@ fn color_picker_hsva_2d(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> bool {
 
 pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Response {
     let pupup_id = ui.auto_id_with("popup");
-    let mut button_response = color_button(ui, (*hsva).into()).on_hover_text("Click to edit color");
+    let mut button_response =
+        color_button(ui, (*hsva).into()).on_hover_text(ui.ctx().tr("Click to edit color"));
 
     if button_response.clicked() {
         ui.memory().toggle_popup(pupup_id);
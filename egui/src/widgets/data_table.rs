@@ -0,0 +1,689 @@
+use crate::*;
+
+/// How the cells in a column of a [`DataTable`] are edited.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CellEditor {
+    /// The cells can't be edited.
+    ReadOnly,
+    /// Any text.
+    Text,
+    /// Text that parses as a number, e.g. `"-1.5"`. Anything else is not accepted.
+    Number,
+    /// One of these options, picked from a [`ComboBox`].
+    ComboBox(Vec<String>),
+}
+
+impl Default for CellEditor {
+    fn default() -> Self {
+        Self::Text
+    }
+}
+
+impl CellEditor {
+    fn accepts(&self, text: &str) -> bool {
+        match self {
+            Self::ReadOnly => false,
+            Self::Text => true,
+            Self::Number => text.trim().parse::<f64>().is_ok(),
+            Self::ComboBox(options) => options.iter().any(|option| option == text),
+        }
+    }
+}
+
+/// How [`DataTable`] copies the selected cells to the clipboard.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CopyFormat {
+    /// Tab-separated values. Pastes nicely into most spreadsheets.
+    Tsv,
+    /// Comma-separated values, with quotes around cells that need them.
+    Csv,
+}
+
+impl CopyFormat {
+    fn join(self, cells: &[String]) -> String {
+        match self {
+            Self::Tsv => {
+                let cells: Vec<String> = cells
+                    .iter()
+                    .map(|cell| cell.replace(|c: char| c == '\t' || c == '\n', " "))
+                    .collect();
+                cells.join("\t")
+            }
+            Self::Csv => {
+                let cells: Vec<String> = cells
+                    .iter()
+                    .map(|cell| {
+                        if cell.contains(|c: char| c == ',' || c == '"' || c == '\n') {
+                            format!("\"{}\"", cell.replace('"', "\"\""))
+                        } else {
+                            cell.clone()
+                        }
+                    })
+                    .collect();
+                cells.join(",")
+            }
+        }
+    }
+}
+
+/// What a [`DataTable`] remembers between frames.
+#[derive(Clone, Debug, Default)]
+struct State {
+    /// The selected cell, as `(row, column)`.
+    selected: Option<(usize, usize)>,
+    /// The opposite corner of the selected range, after Shift-clicking or Shift+arrows.
+    anchor: Option<(usize, usize)>,
+    /// The cell being edited, and the text in its editor.
+    editing: Option<((usize, usize), String)>,
+    /// Give keyboard focus to the editor that was just opened.
+    focus_editor: bool,
+    /// Scroll the selected cell into view, after moving the selection with the keyboard.
+    scroll_to_selected: bool,
+    /// The width of each column, measured last frame.
+    column_widths: Vec<f32>,
+}
+
+impl State {
+    /// The selected range of cells, as the `(row, column)` of the top-left and bottom-right cell.
+    fn selection(&self) -> Option<((usize, usize), (usize, usize))> {
+        let selected = self.selected?;
+        let anchor = self.anchor.unwrap_or(selected);
+        Some((
+            (selected.0.min(anchor.0), selected.1.min(anchor.1)),
+            (selected.0.max(anchor.0), selected.1.max(anchor.1)),
+        ))
+    }
+
+    fn is_selected(&self, (row, column): (usize, usize)) -> bool {
+        self.selection().map_or(false, |(min, max)| {
+            (min.0..=max.0).contains(&row) && (min.1..=max.1).contains(&column)
+        })
+    }
+
+    /// Select a cell, or extend the selection to it.
+    fn select(&mut self, cell: (usize, usize), extend: bool) {
+        self.anchor = if extend {
+            self.anchor.or(self.selected)
+        } else {
+            None
+        };
+        self.selected = Some(cell);
+    }
+
+    /// Keep the selection inside a table that may have shrunk since last frame,
+    /// and stop editing a cell that no longer exists.
+    fn clamp_to(&mut self, num_rows: usize, num_columns: usize) {
+        let clamp = |cell: Option<(usize, usize)>| {
+            let (row, column) = cell?;
+            if num_rows == 0 || num_columns == 0 {
+                None
+            } else {
+                Some((row.min(num_rows - 1), column.min(num_columns - 1)))
+            }
+        };
+        self.selected = clamp(self.selected);
+        self.anchor = clamp(self.anchor).filter(|_| self.selected.is_some());
+        if let Some(((row, column), _)) = &self.editing {
+            if *row >= num_rows || *column >= num_columns {
+                self.editing = None;
+            }
+        }
+    }
+}
+
+/// A table of text cells that can be edited in place, like a spreadsheet.
+///
+/// * Click a cell to select it, and move the selection with the arrow keys and Tab.
+/// * Shift-click or Shift+arrows to select a range of cells. Click a column title to select the whole column.
+/// * Copy the selected cells to the clipboard (see [`Self::copy_format`]).
+/// * Double-click a cell (or press Enter) to edit it. The editor depends on the [`CellEditor`] of the column.
+/// * Enter, Tab or clicking elsewhere commits the edit. Escape cancels it.
+///
+/// The response is marked as changed when a cell was edited.
+///
+/// With [`Self::max_height`], or when the table is wider than the available space, the table scrolls.
+/// The header then stays on top, and you can keep the leading columns in place with [`Self::frozen_columns`].
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// let mut rows = vec![
+///     vec!["Apples".to_owned(), "3".to_owned(), "Fruit".to_owned()],
+///     vec!["Carrots".to_owned(), "12".to_owned(), "Vegetable".to_owned()],
+/// ];
+/// let kinds = vec!["Fruit".to_owned(), "Vegetable".to_owned()];
+/// let response = ui.add(
+///     egui::DataTable::new("groceries", &mut rows)
+///         .header(&["Name", "Amount", "Kind"])
+///         .column_editor(1, egui::CellEditor::Number)
+///         .column_editor(2, egui::CellEditor::ComboBox(kinds)),
+/// );
+/// if response.changed() {
+///     // save the groceries
+/// }
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct DataTable<'a> {
+    id_source: Id,
+    rows: &'a mut [Vec<String>],
+    header: Vec<String>,
+    editors: Vec<CellEditor>,
+    striped: bool,
+    frozen_columns: usize,
+    max_height: f32,
+    copy_format: CopyFormat,
+    copy_formatters: Vec<Option<CopyFormatter<'a>>>,
+}
+
+/// See [`DataTable::copy_formatter`].
+type CopyFormatter<'a> = Box<dyn Fn(&str) -> String + 'a>;
+
+impl<'a> DataTable<'a> {
+    /// The `id_source` must be unique within the parent [`Ui`].
+    pub fn new(id_source: impl std::hash::Hash, rows: &'a mut [Vec<String>]) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            rows,
+            header: vec![],
+            editors: vec![],
+            striped: true,
+            frozen_columns: 0,
+            max_height: f32::INFINITY,
+            copy_format: CopyFormat::Tsv,
+            copy_formatters: vec![],
+        }
+    }
+
+    /// The column titles, shown above the rows. Default: none.
+    pub fn header(mut self, header: &[impl ToString]) -> Self {
+        self.header = header.iter().map(ToString::to_string).collect();
+        self
+    }
+
+    /// How to edit the cells of the given column. Default: [`CellEditor::Text`].
+    pub fn column_editor(mut self, column: usize, editor: CellEditor) -> Self {
+        if self.editors.len() <= column {
+            self.editors.resize(column + 1, CellEditor::default());
+        }
+        self.editors[column] = editor;
+        self
+    }
+
+    /// Paint every other row with a different background color. Default: `true`.
+    pub fn striped(mut self, striped: bool) -> Self {
+        self.striped = striped;
+        self
+    }
+
+    /// Keep this many leading columns in place when scrolling horizontally, e.g. a name column. Default: 0.
+    pub fn frozen_columns(mut self, frozen_columns: usize) -> Self {
+        self.frozen_columns = frozen_columns;
+        self
+    }
+
+    /// Scroll vertically if the table is taller than this. The header stays on top. Default: no limit.
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// How the selected cells are put on the clipboard when copied. Default: [`CopyFormat::Tsv`].
+    pub fn copy_format(mut self, copy_format: CopyFormat) -> Self {
+        self.copy_format = copy_format;
+        self
+    }
+
+    /// Change how the cells of a column are copied to the clipboard, e.g. to strip a unit:
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut rows = vec![vec!["Apples".to_owned(), "3 kg".to_owned()]];
+    /// ui.add(
+    ///     egui::DataTable::new("groceries", &mut rows)
+    ///         .copy_formatter(1, |cell| cell.trim_end_matches(" kg").to_owned()),
+    /// );
+    /// ```
+    pub fn copy_formatter(mut self, column: usize, format: impl Fn(&str) -> String + 'a) -> Self {
+        if self.copy_formatters.len() <= column {
+            self.copy_formatters.resize_with(column + 1, || None);
+        }
+        self.copy_formatters[column] = Some(Box::new(format));
+        self
+    }
+}
+
+impl<'a> Widget for DataTable<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let DataTable {
+            id_source,
+            rows,
+            header,
+            editors,
+            striped,
+            frozen_columns,
+            max_height,
+            copy_format,
+            copy_formatters,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source);
+        let editor_id = id.with("editor");
+        let mut state = ui.memory().id_data_temp.get_or_default::<State>(id).clone();
+
+        let num_rows = rows.len();
+        let num_columns = rows
+            .iter()
+            .map(Vec::len)
+            .max()
+            .unwrap_or(0)
+            .max(header.len());
+        state.clamp_to(num_rows, num_columns);
+
+        let text_editor = CellEditor::default();
+        let editor_of = |column: usize| editors.get(column).unwrap_or(&text_editor);
+
+        // Don't let Tab move the focus out of the table (Escape still does):
+        ui.memory().lock_focus(id, true);
+
+        let table_has_focus = ui.memory().has_focus(id);
+        let editor_has_focus = ui.memory().has_focus(editor_id);
+        let (escape, enter, tab, shift, copy) = {
+            let input = ui.input();
+            (
+                input.key_pressed(Key::Escape),
+                input.key_pressed(Key::Enter),
+                input.key_pressed(Key::Tab),
+                input.modifiers.shift,
+                input
+                    .events
+                    .iter()
+                    .any(|event| matches!(event, Event::Copy)),
+            )
+        };
+
+        // The edited cell to write back, as `(row, column, text)`:
+        let mut commit = None;
+        let mut stop_editing = |state: &mut State, accept: bool| {
+            if let Some(((row, column), text)) = state.editing.take() {
+                if accept && editor_of(column).accepts(&text) {
+                    commit = Some((row, column, text));
+                }
+            }
+        };
+
+        let next_cell = |(row, column): (usize, usize), shift: bool| {
+            if shift {
+                if column > 0 {
+                    (row, column - 1)
+                } else if row > 0 {
+                    (row - 1, num_columns - 1)
+                } else {
+                    (row, column)
+                }
+            } else if column + 1 < num_columns {
+                (row, column + 1)
+            } else if row + 1 < num_rows {
+                (row + 1, 0)
+            } else {
+                (row, column)
+            }
+        };
+
+        let start_editing = |state: &mut State, (row, column): (usize, usize)| {
+            if editor_of(column) != &CellEditor::ReadOnly {
+                let text = rows[row].get(column).cloned().unwrap_or_default();
+                state.editing = Some(((row, column), text));
+                state.focus_editor = true;
+            }
+        };
+
+        // Keyboard handling:
+        if let Some((cell, _)) = &state.editing {
+            let cell = *cell;
+            if escape {
+                stop_editing(&mut state, false);
+                ui.memory().request_focus(id);
+            } else if tab && editor_has_focus {
+                stop_editing(&mut state, true);
+                let next = next_cell(cell, shift);
+                state.scroll_to_selected = true;
+                state.select(next, false);
+                start_editing(&mut state, next);
+            }
+        } else if table_has_focus {
+            if let Some((row, column)) = state.selected {
+                let input = ui.input();
+                let arrow_pressed = [
+                    Key::ArrowUp,
+                    Key::ArrowDown,
+                    Key::ArrowLeft,
+                    Key::ArrowRight,
+                ]
+                .iter()
+                .any(|&key| input.key_pressed(key));
+                let selected = if tab {
+                    next_cell((row, column), shift)
+                } else if input.key_pressed(Key::ArrowUp) {
+                    (row.saturating_sub(1), column)
+                } else if input.key_pressed(Key::ArrowDown) {
+                    ((row + 1).min(num_rows.saturating_sub(1)), column)
+                } else if input.key_pressed(Key::ArrowLeft) {
+                    (row, column.saturating_sub(1))
+                } else if input.key_pressed(Key::ArrowRight) {
+                    (row, (column + 1).min(num_columns.saturating_sub(1)))
+                } else {
+                    (row, column)
+                };
+                if tab || arrow_pressed {
+                    state.scroll_to_selected |= state.selected != Some(selected);
+                    state.select(selected, arrow_pressed && shift);
+                }
+                if enter {
+                    state.anchor = None;
+                    start_editing(&mut state, selected);
+                }
+            }
+
+            if copy {
+                if let Some((min, max)) = state.selection() {
+                    let lines: Vec<String> = rows[min.0..=max.0]
+                        .iter()
+                        .map(|row| {
+                            let cells: Vec<String> = (min.1..=max.1)
+                                .map(|column| {
+                                    let cell = row.get(column).map_or("", String::as_str);
+                                    match copy_formatters.get(column) {
+                                        Some(Some(format)) => format(cell),
+                                        _ => cell.to_owned(),
+                                    }
+                                })
+                                .collect();
+                            copy_format.join(&cells)
+                        })
+                        .collect();
+                    ui.ctx().output().copied_text = lines.join("\n");
+                }
+            }
+        }
+
+        let focus_editor = std::mem::take(&mut state.focus_editor);
+
+        // Lay out the cells using the column widths of last frame:
+        let spacing = ui.spacing().item_spacing;
+        let row_height = ui.spacing().interact_size.y;
+        let min_column_width = ui.spacing().interact_size.x;
+        let mut column_x = Vec::with_capacity(num_columns + 1);
+        let mut x = 0.0;
+        for column in 0..num_columns {
+            column_x.push(x);
+            let width = state.column_widths.get(column).copied();
+            x += width.unwrap_or(min_column_width) + spacing.x;
+        }
+        column_x.push(x);
+        let header_rows = if header.is_empty() { 0 } else { 1 };
+        let row_y = |row: usize| row as f32 * (row_height + spacing.y);
+        let content_size = vec2(
+            column_x[num_columns] - spacing.x,
+            row_y(header_rows + num_rows) - spacing.y,
+        )
+        .at_least(Vec2::ZERO);
+
+        // The frozen part, that doesn't scroll (horizontally or vertically):
+        let frozen_columns = frozen_columns.min(num_columns);
+        let frozen_size = vec2(column_x[frozen_columns], row_y(header_rows));
+
+        let max_height = max_height.at_least(frozen_size.y + row_height);
+        // Only scroll vertically with a `max_height`, otherwise the table takes all the room it needs:
+        let has_bar = [true, max_height.is_finite()];
+        let bar_width = if has_bar[1] {
+            spacing.x + ui.visuals().scroll_bar.width
+        } else {
+            0.0
+        };
+        let scroll_area = ScrollArea::new(has_bar)
+            .id_source(id.with("scroll"))
+            .max_width(content_size.x + bar_width)
+            .max_height(max_height);
+
+        // Only the visible cells are shown and measured, so the columns only grow.
+        // Otherwise they would change width as wide cells are scrolled in and out of view.
+        let mut new_column_widths = state.column_widths.clone();
+        new_column_widths.resize(num_columns, min_column_width);
+
+        let mut response = ui
+            .scope(|ui| {
+                scroll_area.show(ui, |ui| {
+                    let (content_rect, _) = ui.allocate_exact_size(content_size, Sense::hover());
+                    // The part of the table in view. The header and the frozen columns stay in place there:
+                    let view = ui
+                        .clip_rect()
+                        .shrink(ui.visuals().clip_rect_margin)
+                        .intersect(content_rect);
+                    let offset = view.min - content_rect.min;
+                    let split = view.min + frozen_size;
+
+                    if let Some((row, column)) = state.selected.filter(|_| state.scroll_to_selected)
+                    {
+                        // Scroll the cell out from under the header and the frozen columns too:
+                        let x_range = if column < frozen_columns {
+                            view.x_range()
+                        } else {
+                            content_rect.left() + column_x[column] - frozen_size.x
+                                ..=content_rect.left() + column_x[column + 1] - spacing.x
+                        };
+                        let top = content_rect.top() + row_y(header_rows + row);
+                        let y_range = top - frozen_size.y..=top + row_height;
+                        ui.scroll_to_rect(Rect::from_x_y_ranges(x_range, y_range), None);
+                        ui.ctx().request_repaint();
+                    }
+                    state.scroll_to_selected = false;
+
+                    // Each cell is clipped to its region, so that the scrolled cells slide under the frozen ones:
+                    let region = |is_header: bool, is_frozen: bool| {
+                        let x_range = if is_frozen {
+                            view.left()..=split.x
+                        } else {
+                            split.x..=view.right()
+                        };
+                        let y_range = if is_header {
+                            view.top()..=split.y
+                        } else {
+                            split.y..=view.bottom()
+                        };
+                        Rect::from_x_y_ranges(x_range, y_range).intersect(view)
+                    };
+                    let stripe_color = if ui.visuals().dark_mode {
+                        Rgba::from_white_alpha(0.0075)
+                    } else {
+                        Rgba::from_black_alpha(0.075)
+                    };
+
+                    for row_nr in 0..header_rows + num_rows {
+                        let is_header = row_nr < header_rows;
+                        for column in 0..num_columns {
+                            let is_frozen = column < frozen_columns;
+                            let clip_rect = region(is_header, is_frozen);
+                            let mut pos = content_rect.min + vec2(column_x[column], row_y(row_nr));
+                            if is_frozen {
+                                pos.x += offset.x;
+                            }
+                            if is_header {
+                                pos.y += offset.y;
+                            }
+                            let width = column_x[column + 1] - column_x[column] - spacing.x;
+                            let cell_rect = Rect::from_min_size(pos, vec2(width, row_height));
+                            if !clip_rect.intersects(cell_rect.expand2(0.5 * spacing)) {
+                                continue;
+                            }
+
+                            if striped && !is_header && (row_nr - header_rows) % 2 == 1 {
+                                ui.painter().sub_region(clip_rect).rect_filled(
+                                    cell_rect.expand2(0.5 * spacing),
+                                    0.0,
+                                    stripe_color,
+                                );
+                            }
+
+                            let mut cell_ui = ui.child_ui(cell_rect, Layout::left_to_right());
+                            cell_ui.set_clip_rect(clip_rect);
+                            let cell_ui = &mut cell_ui;
+
+                            if is_header {
+                                // The header may have fewer titles than there are columns:
+                                let title = header.get(column).map_or("", String::as_str);
+                                let response =
+                                    cell_ui.add(Label::new(title).strong().sense(Sense::click()));
+                                if response.clicked() && num_rows > 0 {
+                                    // Select the whole column:
+                                    stop_editing(&mut state, true);
+                                    state.select((0, column), false);
+                                    state.select((num_rows - 1, column), true);
+                                    cell_ui.memory().request_focus(id);
+                                }
+                            } else {
+                                let cell = (row_nr - header_rows, column);
+                                let text = rows[cell.0].get(column).map_or("", String::as_str);
+
+                                let edited_text = match &mut state.editing {
+                                    Some((edited_cell, text)) if *edited_cell == cell => Some(text),
+                                    _ => None,
+                                };
+
+                                if let Some(edited_text) = edited_text {
+                                    let committed = cell_editor_ui(
+                                        cell_ui,
+                                        editor_id,
+                                        editor_of(column),
+                                        edited_text,
+                                    );
+                                    if focus_editor {
+                                        cell_ui.memory().request_focus(editor_id);
+                                    }
+                                    if committed {
+                                        stop_editing(&mut state, true);
+                                        cell_ui.memory().request_focus(id);
+                                    }
+                                } else {
+                                    let selected = state.is_selected(cell);
+                                    let response =
+                                        cell_ui.add(SelectableLabel::new(selected, text));
+                                    if response.clicked() {
+                                        // Clicking elsewhere commits the edit:
+                                        stop_editing(&mut state, true);
+                                        let shift = cell_ui.input().modifiers.shift;
+                                        state.select(cell, shift);
+                                        cell_ui.memory().request_focus(id);
+                                    }
+                                    if response.double_clicked() {
+                                        start_editing(&mut state, cell);
+                                    }
+                                }
+                            }
+                            new_column_widths[column] =
+                                new_column_widths[column].max(cell_ui.min_rect().width());
+                        }
+                    }
+
+                    // Show where the frozen part ends:
+                    let stroke = ui.visuals().widgets.noninteractive.bg_stroke;
+                    if frozen_columns > 0 && offset.x > 0.0 {
+                        let x = split.x - 0.5 * spacing.x;
+                        ui.painter()
+                            .sub_region(view)
+                            .line_segment([pos2(x, view.top()), pos2(x, view.bottom())], stroke);
+                    }
+                    if header_rows > 0 && offset.y > 0.0 {
+                        let y = split.y - 0.5 * spacing.y;
+                        ui.painter()
+                            .sub_region(view)
+                            .line_segment([pos2(view.left(), y), pos2(view.right(), y)], stroke);
+                    }
+                });
+            })
+            .response;
+
+        if new_column_widths != state.column_widths {
+            state.column_widths = new_column_widths;
+            ui.ctx().request_repaint();
+        }
+
+        if let Some((row, column, text)) = commit {
+            let row = &mut rows[row];
+            if row.len() <= column {
+                row.resize(column + 1, String::new());
+            }
+            if row[column] != text {
+                row[column] = text;
+                response.mark_changed();
+            }
+        }
+
+        ui.memory().id_data_temp.insert(id, state);
+        response
+    }
+}
+
+/// Returns `true` when the edit should be committed.
+fn cell_editor_ui(ui: &mut Ui, editor_id: Id, editor: &CellEditor, text: &mut String) -> bool {
+    match editor {
+        CellEditor::ReadOnly => true,
+        CellEditor::Text | CellEditor::Number => {
+            let text_color = if editor.accepts(text) {
+                None
+            } else {
+                Some(ui.visuals().error_fg_color)
+            };
+            let response = ui.add(
+                TextEdit::singleline(text)
+                    .id(editor_id)
+                    .lock_focus(true)
+                    .text_color_opt(text_color)
+                    .desired_width(ui.spacing().text_edit_width / 2.0),
+            );
+            // Enter and clicking elsewhere both take away the focus:
+            response.lost_focus() && !ui.input().key_pressed(Key::Escape)
+        }
+        CellEditor::ComboBox(options) => {
+            let mut committed = false;
+            ComboBox::from_id_source(editor_id)
+                .selected_text(text.as_str())
+                .show_ui(ui, |ui| {
+                    for option in options {
+                        if ui.selectable_value(text, option.clone(), option).clicked() {
+                            committed = true;
+                        }
+                    }
+                });
+            committed
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn copy_format_join() {
+    let cells = vec!["a".to_owned(), "b, c".to_owned(), "say \"hi\"".to_owned()];
+    assert_eq!(CopyFormat::Tsv.join(&cells), "a\tb, c\tsay \"hi\"");
+    assert_eq!(
+        CopyFormat::Csv.join(&cells),
+        "a,\"b, c\",\"say \"\"hi\"\"\""
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn clamp_state_to_smaller_table() {
+    let mut state = State {
+        selected: Some((4, 1)),
+        anchor: Some((2, 3)),
+        editing: Some(((4, 1), "x".to_owned())),
+        ..Default::default()
+    };
+    state.clamp_to(3, 2);
+    assert_eq!(state.selected, Some((2, 1)));
+    assert_eq!(state.anchor, Some((2, 1)));
+    assert!(state.editing.is_none());
+
+    state.clamp_to(0, 2);
+    assert_eq!(state.selected, None);
+    assert_eq!(state.anchor, None);
+}
@@ -0,0 +1,617 @@
+use std::{convert::TryFrom, ops::Range, sync::Arc};
+
+use crate::*;
+
+/// More edits than this and we give up on finding the shortest diff,
+/// and just show the differing part as removed and re-added.
+const MAX_EDITS: usize = 2000;
+
+/// Lines longer than this (in chars) don't get the changed characters highlighted.
+const MAX_HIGHLIGHT_LEN: usize = 500;
+
+/// How a [`DiffView`] shows the two texts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiffMode {
+    /// The old text on the left and the new text on the right.
+    SideBySide,
+    /// One column, with removed lines above the lines that replaced them.
+    Unified,
+}
+
+/// Shows the differences between two texts, line by line, using the monospace font.
+///
+/// Changed lines are shown next to the lines they were changed into (or in [`DiffMode::Unified`], after them),
+/// with the characters that differ highlighted.
+/// Long runs of unchanged lines are collapsed, and can be expanded by clicking them.
+///
+/// Both sides are in the same [`ScrollArea`], so they always scroll together.
+/// Lines that are too long for their column are clipped.
+///
+/// The diff is only recomputed when the texts change.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// let old = "fn main() {\n    println!(\"Hello\");\n}\n";
+/// let new = "fn main() {\n    println!(\"Hello world!\");\n}\n";
+/// ui.add(egui::DiffView::new("my_diff", old, new).max_height(300.0));
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct DiffView<'a> {
+    id_source: Id,
+    old: &'a str,
+    new: &'a str,
+    mode: DiffMode,
+    context_lines: usize,
+    max_height: f32,
+}
+
+impl<'a> DiffView<'a> {
+    pub fn new(id_source: impl std::hash::Hash, old: &'a str, new: &'a str) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            old,
+            new,
+            mode: DiffMode::SideBySide,
+            context_lines: 3,
+            max_height: f32::INFINITY,
+        }
+    }
+
+    /// Default: [`DiffMode::SideBySide`].
+    pub fn mode(mut self, mode: DiffMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// How many unchanged lines to show around each change. The rest are collapsed.
+    ///
+    /// Use `usize::MAX` to never collapse anything. Default: 3.
+    pub fn context_lines(mut self, context_lines: usize) -> Self {
+        self.context_lines = context_lines;
+        self
+    }
+
+    /// Scroll if the diff is higher than this. Default: fill the available height.
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+}
+
+/// Kept in [`Memory::id_data_temp`].
+#[derive(Clone, Debug)]
+struct State {
+    /// Hash of the texts [`Self::lines`] is for.
+    texts: Id,
+    lines: Arc<Vec<Line>>,
+    /// The first line of each run of unchanged lines the user has expanded.
+    expanded: Vec<usize>,
+}
+
+impl<'a> Widget for DiffView<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            id_source,
+            old,
+            new,
+            mode,
+            context_lines,
+            max_height,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source);
+        let old_lines: Vec<String> = old.lines().map(expand_tabs).collect();
+        let new_lines: Vec<String> = new.lines().map(expand_tabs).collect();
+
+        let texts = Id::new((old, new));
+        let state = ui.memory().id_data_temp.get::<State>(&id).cloned();
+        let mut state = match state {
+            Some(state) if state.texts == texts => state,
+            _ => State {
+                texts,
+                lines: Arc::new(diff_lines(&old_lines, &new_lines)),
+                expanded: vec![],
+            },
+        };
+
+        let rows = rows(&state.lines, context_lines, &state.expanded, mode);
+
+        let row_height = ui.fonts().row_height(TextStyle::Monospace);
+        let digit_width = ui.fonts().glyph_width(TextStyle::Monospace, '0');
+        let digits = old_lines
+            .len()
+            .max(new_lines.len())
+            .max(1)
+            .to_string()
+            .len();
+        let number_width = (digits + 1) as f32 * digit_width;
+
+        let response = ScrollArea::from_max_height(max_height)
+            .id_source(id)
+            .show(ui, |ui| {
+                let size = vec2(ui.available_width(), rows.len() as f32 * row_height);
+                let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+
+                // Only the visible rows are laid out and painted:
+                let clip_rect = ui.clip_rect().intersect(rect);
+                let first = ((clip_rect.top() - rect.top()) / row_height)
+                    .floor()
+                    .max(0.0) as usize;
+                let last = ((clip_rect.bottom() - rect.top()) / row_height)
+                    .ceil()
+                    .max(0.0) as usize;
+
+                let painter = ui.painter().sub_region(clip_rect);
+                let half_width = 0.5 * rect.width();
+                let divider_x = rect.left() + half_width;
+
+                for (row_nr, row) in rows.iter().enumerate().take(last).skip(first) {
+                    let row_rect = Rect::from_min_size(
+                        pos2(rect.left(), rect.top() + row_nr as f32 * row_height),
+                        vec2(rect.width(), row_height),
+                    );
+                    let left =
+                        Rect::from_x_y_ranges(row_rect.left()..=divider_x, row_rect.y_range());
+                    let right =
+                        Rect::from_x_y_ranges(divider_x..=row_rect.right(), row_rect.y_range());
+                    let old_line = |nr: usize| old_lines[nr].as_str();
+                    let new_line = |nr: usize| new_lines[nr].as_str();
+                    let pane = Pane::new(ui, &painter, number_width);
+
+                    match (*row, mode) {
+                        (Row::Same(o, n), DiffMode::SideBySide) => {
+                            pane.line(left, &[Some(o)], Edit::Same, old_line(o), None);
+                            pane.line(right, &[Some(n)], Edit::Same, new_line(n), None);
+                        }
+                        (Row::Same(o, n), DiffMode::Unified) => {
+                            pane.line(row_rect, &[Some(o), Some(n)], Edit::Same, old_line(o), None);
+                        }
+                        (Row::Changed(o, n), _) => {
+                            if let Some(o) = o {
+                                let partner = n.map(|n| (new_line(n), true));
+                                pane.line(left, &[Some(o)], Edit::Removed, old_line(o), partner);
+                            }
+                            if let Some(n) = n {
+                                let partner = o.map(|o| (old_line(o), false));
+                                pane.line(right, &[Some(n)], Edit::Added, new_line(n), partner);
+                            }
+                        }
+                        (Row::Removed(o, n), _) => {
+                            let partner = n.map(|n| (new_line(n), true));
+                            pane.line(
+                                row_rect,
+                                &[Some(o), None],
+                                Edit::Removed,
+                                old_line(o),
+                                partner,
+                            );
+                        }
+                        (Row::Added(n, o), _) => {
+                            let partner = o.map(|o| (old_line(o), false));
+                            pane.line(
+                                row_rect,
+                                &[None, Some(n)],
+                                Edit::Added,
+                                new_line(n),
+                                partner,
+                            );
+                        }
+                        (Row::Collapsed { start, count }, _) => {
+                            let response = ui
+                                .interact(row_rect, id.with(("collapsed", start)), Sense::click())
+                                .on_hover_text(ui.ctx().tr("Click to show"));
+                            if response.hovered() {
+                                let fill = ui.visuals().widgets.hovered.bg_fill;
+                                painter.rect_filled(row_rect, 0.0, fill);
+                            }
+                            let text = format!("⋯ {} unchanged lines", count);
+                            painter.text(
+                                row_rect.left_center() + vec2(number_width, 0.0),
+                                Align2::LEFT_CENTER,
+                                text,
+                                TextStyle::Monospace,
+                                ui.visuals().weak_text_color(),
+                            );
+                            if response.clicked() {
+                                state.expanded.push(start);
+                            }
+                        }
+                    }
+                }
+
+                if mode == DiffMode::SideBySide {
+                    let stroke = ui.visuals().widgets.noninteractive.bg_stroke;
+                    painter.line_segment(
+                        [
+                            pos2(divider_x, clip_rect.top()),
+                            pos2(divider_x, clip_rect.bottom()),
+                        ],
+                        stroke,
+                    );
+                }
+                response
+            });
+
+        ui.memory().id_data_temp.insert(id, state);
+        response
+    }
+}
+
+/// Paints a line of the diff.
+struct Pane<'u> {
+    ui: &'u Ui,
+    painter: &'u Painter,
+    number_width: f32,
+}
+
+impl<'u> Pane<'u> {
+    fn new(ui: &'u Ui, painter: &'u Painter, number_width: f32) -> Self {
+        Self {
+            ui,
+            painter,
+            number_width,
+        }
+    }
+
+    /// `numbers` are the (zero-based) line numbers to show in the gutter, one column each.
+    ///
+    /// `partner` is the line this one was changed from (or into), and whether `text` is the old one of the two.
+    fn line(
+        &self,
+        rect: Rect,
+        numbers: &[Option<usize>],
+        edit: Edit,
+        text: &str,
+        partner: Option<(&str, bool)>,
+    ) {
+        let painter = self
+            .painter
+            .sub_region(rect.intersect(self.painter.clip_rect()));
+        let visuals = self.ui.visuals();
+
+        let (fill, highlight, marker) = match edit {
+            Edit::Same => (Color32::TRANSPARENT, Color32::TRANSPARENT, ' '),
+            Edit::Removed => (
+                Color32::from_rgba_unmultiplied(255, 0, 0, 25),
+                Color32::from_rgba_unmultiplied(255, 0, 0, 70),
+                '-',
+            ),
+            Edit::Added => (
+                Color32::from_rgba_unmultiplied(0, 200, 0, 25),
+                Color32::from_rgba_unmultiplied(0, 200, 0, 70),
+                '+',
+            ),
+        };
+        painter.rect_filled(rect, 0.0, fill);
+
+        let mut x = rect.left();
+        for number in numbers {
+            if let Some(number) = number {
+                painter.text(
+                    pos2(x + self.number_width, rect.center().y),
+                    Align2::RIGHT_CENTER,
+                    number + 1,
+                    TextStyle::Monospace,
+                    visuals.weak_text_color(),
+                );
+            }
+            x += self.number_width;
+        }
+        let galley = painter.layout_no_wrap(TextStyle::Monospace, format!(" {} {}", marker, text));
+        let text_pos = pos2(x, rect.top());
+
+        // The marker and the spaces around it take up the first three chars:
+        if let (Some(row), Some((partner, is_old))) = (galley.rows.first(), partner) {
+            let (old, new) = if is_old {
+                (text, partner)
+            } else {
+                (partner, text)
+            };
+            for range in changed_chars(old, new, !is_old) {
+                let highlight_rect = Rect::from_x_y_ranges(
+                    text_pos.x + row.x_offset(range.start + 3)
+                        ..=text_pos.x + row.x_offset(range.end + 3),
+                    rect.y_range(),
+                );
+                painter.rect_filled(highlight_rect, 0.0, highlight);
+            }
+        }
+        painter.galley(text_pos, galley, visuals.text_color());
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// What happened to an element (line or char) of the old text to get the new one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Edit {
+    Same,
+    Removed,
+    Added,
+}
+
+/// A line of the diff, with the (zero-based) line numbers in the old and new text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Line {
+    Same(usize, usize),
+    /// A removed and/or an added line. When there are both, the new line replaced the old one.
+    Changed(Option<usize>, Option<usize>),
+}
+
+/// A row of the [`DiffView`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Row {
+    Same(usize, usize),
+    /// Side by side: the removed line on the left, the added line on the right.
+    Changed(Option<usize>, Option<usize>),
+    /// Unified: a removed line, and the line that replaced it.
+    Removed(usize, Option<usize>),
+    /// Unified: an added line, and the line it replaced.
+    Added(usize, Option<usize>),
+    /// The hidden middle of the run of unchanged lines that starts at this index into the [`Line`]s.
+    Collapsed {
+        start: usize,
+        count: usize,
+    },
+}
+
+fn expand_tabs(line: &str) -> String {
+    line.replace('\t', "    ")
+}
+
+fn diff_lines(old: &[String], new: &[String]) -> Vec<Line> {
+    fn pair_up(lines: &mut Vec<Line>, removed: &mut Vec<usize>, added: &mut Vec<usize>) {
+        for i in 0..removed.len().max(added.len()) {
+            lines.push(Line::Changed(
+                removed.get(i).copied(),
+                added.get(i).copied(),
+            ));
+        }
+        removed.clear();
+        added.clear();
+    }
+
+    let mut lines = vec![];
+    let (mut removed, mut added) = (vec![], vec![]);
+    let (mut old_nr, mut new_nr) = (0, 0);
+    for edit in diff(old, new, MAX_EDITS) {
+        match edit {
+            Edit::Same => {
+                pair_up(&mut lines, &mut removed, &mut added);
+                lines.push(Line::Same(old_nr, new_nr));
+                old_nr += 1;
+                new_nr += 1;
+            }
+            Edit::Removed => {
+                removed.push(old_nr);
+                old_nr += 1;
+            }
+            Edit::Added => {
+                added.push(new_nr);
+                new_nr += 1;
+            }
+        }
+    }
+    pair_up(&mut lines, &mut removed, &mut added);
+    lines
+}
+
+/// Collapse the long runs of unchanged lines (unless expanded), and split up changed lines in unified mode.
+fn rows(lines: &[Line], context_lines: usize, expanded: &[usize], mode: DiffMode) -> Vec<Row> {
+    let mut rows = vec![];
+    let mut i = 0;
+    while i < lines.len() {
+        match lines[i] {
+            Line::Same(..) => {
+                let start = i;
+                while i < lines.len() && matches!(lines[i], Line::Same(..)) {
+                    i += 1;
+                }
+                // Only show context on the sides where there is a change:
+                let before = if start == 0 { 0 } else { context_lines };
+                let after = if i == lines.len() { 0 } else { context_lines };
+                let count = i - start;
+                let same = |line: &Line| match *line {
+                    Line::Same(o, n) => Row::Same(o, n),
+                    Line::Changed(..) => unreachable!(),
+                };
+                if count > before.saturating_add(after).saturating_add(1)
+                    && !expanded.contains(&start)
+                {
+                    rows.extend(lines[start..start + before].iter().map(same));
+                    rows.push(Row::Collapsed {
+                        start,
+                        count: count - before - after,
+                    });
+                    rows.extend(lines[i - after..i].iter().map(same));
+                } else {
+                    rows.extend(lines[start..i].iter().map(same));
+                }
+            }
+            Line::Changed(..) => {
+                let start = i;
+                while i < lines.len() && matches!(lines[i], Line::Changed(..)) {
+                    i += 1;
+                }
+                let changed = lines[start..i].iter().map(|line| match *line {
+                    Line::Changed(o, n) => (o, n),
+                    Line::Same(..) => unreachable!(),
+                });
+                match mode {
+                    DiffMode::SideBySide => {
+                        rows.extend(changed.map(|(o, n)| Row::Changed(o, n)));
+                    }
+                    DiffMode::Unified => {
+                        let changed: Vec<_> = changed.collect();
+                        rows.extend(
+                            changed
+                                .iter()
+                                .filter_map(|&(o, n)| o.map(|o| Row::Removed(o, n))),
+                        );
+                        rows.extend(
+                            changed
+                                .iter()
+                                .filter_map(|&(o, n)| n.map(|n| Row::Added(n, o))),
+                        );
+                    }
+                }
+            }
+        }
+    }
+    rows
+}
+
+/// The char ranges of the old text that were removed (or if `in_new`, the ranges of the new text that were added).
+fn changed_chars(old: &str, new: &str, in_new: bool) -> Vec<Range<usize>> {
+    let old: Vec<char> = old.chars().collect();
+    let new: Vec<char> = new.chars().collect();
+    if old.len() > MAX_HIGHLIGHT_LEN || new.len() > MAX_HIGHLIGHT_LEN {
+        return vec![];
+    }
+
+    let (wanted, skipped) = if in_new {
+        (Edit::Added, Edit::Removed)
+    } else {
+        (Edit::Removed, Edit::Added)
+    };
+    let mut ranges: Vec<Range<usize>> = vec![];
+    let mut i = 0;
+    for edit in diff(&old, &new, MAX_HIGHLIGHT_LEN) {
+        if edit == skipped {
+            continue;
+        }
+        if edit == wanted {
+            match ranges.last_mut() {
+                Some(range) if range.end == i => range.end += 1,
+                _ => ranges.push(i..i + 1),
+            }
+        }
+        i += 1;
+    }
+    ranges
+}
+
+/// The shortest way of editing `a` into `b`.
+///
+/// If that takes more than `max_edits`, everything between the common start and end
+/// is removed and re-added instead.
+fn diff<T: PartialEq>(a: &[T], b: &[T], max_edits: usize) -> Vec<Edit> {
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let (a, b) = (&a[prefix..], &b[prefix..]);
+    let suffix = a
+        .iter()
+        .rev()
+        .zip(b.iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let (a, b) = (&a[..a.len() - suffix], &b[..b.len() - suffix]);
+
+    let mut edits = vec![Edit::Same; prefix];
+    match myers(a, b, max_edits) {
+        Some(middle) => edits.extend(middle),
+        None => {
+            edits.extend(std::iter::repeat(Edit::Removed).take(a.len()));
+            edits.extend(std::iter::repeat(Edit::Added).take(b.len()));
+        }
+    }
+    edits.extend(std::iter::repeat(Edit::Same).take(suffix));
+    edits
+}
+
+/// Myers' O(ND) diff algorithm. Returns `None` if it needs more than `max_edits`.
+fn myers<T: PartialEq>(a: &[T], b: &[T], max_edits: usize) -> Option<Vec<Edit>> {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let max_d = (n + m).min(isize::try_from(max_edits).unwrap_or(isize::MAX));
+    // `v[k + offset]` is the furthest x reached on diagonal k = x - y.
+    let offset = n + m + 1;
+    let mut v = vec![0_isize; 2 * offset as usize + 1];
+    // The part of `v` that can be needed for going back, at the start of each step:
+    let mut trace: Vec<Vec<isize>> = vec![];
+
+    for d in 0..=max_d {
+        trace.push(v[(offset - d) as usize..=(offset + d) as usize].to_vec());
+        for k in (-d..=d).step_by(2) {
+            let i = (k + offset) as usize;
+            let mut x = if k == -d || (k != d && v[i - 1] < v[i + 1]) {
+                v[i + 1] // Down, i.e. an insertion
+            } else {
+                v[i - 1] + 1 // Right, i.e. a deletion
+            };
+            let mut y = x - k;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[i] = x;
+            if x >= n && y >= m {
+                return Some(backtrack(&trace, n, m));
+            }
+        }
+    }
+    None
+}
+
+fn backtrack(trace: &[Vec<isize>], mut x: isize, mut y: isize) -> Vec<Edit> {
+    let mut edits = vec![];
+    for (d, v) in trace.iter().enumerate().skip(1).rev() {
+        let d = d as isize;
+        let k = x - y;
+        let get = |k: isize| v[(k + d) as usize];
+        let down = k == -d || (k != d && get(k - 1) < get(k + 1));
+        let prev_k = if down { k + 1 } else { k - 1 };
+        let prev_x = get(prev_k);
+        let prev_y = prev_x - prev_k;
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            edits.push(Edit::Same);
+        }
+        edits.push(if down { Edit::Added } else { Edit::Removed });
+        x = prev_x;
+        y = prev_y;
+    }
+    // What is left is the common start:
+    edits.extend(std::iter::repeat(Edit::Same).take(x as usize));
+    edits.reverse();
+    edits
+}
+
+#[cfg(test)]
+#[test]
+fn diff_view_diff() {
+    let count = |edits: &[Edit], edit: Edit| edits.iter().filter(|&&e| e == edit).count();
+
+    let a: Vec<char> = "ABCABBA".chars().collect();
+    let b: Vec<char> = "CBABAC".chars().collect();
+    let edits = diff(&a, &b, usize::MAX);
+    assert_eq!(count(&edits, Edit::Same), 4);
+    assert_eq!(count(&edits, Edit::Removed), 3);
+    assert_eq!(count(&edits, Edit::Added), 2);
+
+    let edits = diff(&a, &b, 1);
+    assert_eq!(count(&edits, Edit::Same), 0);
+
+    assert_eq!(changed_chars("Hello", "Hello world!", true), vec![5..12]);
+    assert_eq!(changed_chars("Hello", "Hello world!", false), vec![]);
+
+    let lines = |text: &str| text.lines().map(expand_tabs).collect::<Vec<_>>();
+    let old = lines("a\nb\nc\nd\ne\nf\ng");
+    let new = lines("a\nB\nc\nd\ne\nf\ng\nh");
+    let diffed = diff_lines(&old, &new);
+    assert_eq!(diffed[1], Line::Changed(Some(1), Some(1)));
+    assert_eq!(diffed[7], Line::Changed(None, Some(7)));
+
+    let rows = rows(&diffed, 1, &[], DiffMode::Unified);
+    assert_eq!(
+        rows,
+        vec![
+            Row::Same(0, 0),
+            Row::Removed(1, Some(1)),
+            Row::Added(1, Some(1)),
+            Row::Same(2, 2),
+            Row::Collapsed { start: 2, count: 3 },
+            Row::Same(6, 6),
+            Row::Added(7, None),
+        ]
+    );
+}
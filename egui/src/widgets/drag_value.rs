@@ -55,6 +55,7 @@ pub struct DragValue<'a> {
     clamp_range: RangeInclusive<f64>,
     min_decimals: usize,
     max_decimals: Option<usize>,
+    id_source: Option<Id>,
 }
 
 macro_rules! impl_integer_constructor {
@@ -132,6 +133,7 @@ impl<'a> DragValue<'a> {
             clamp_range: f64::NEG_INFINITY..=f64::INFINITY,
             min_decimals: 0,
             max_decimals: None,
+            id_source: None,
         }
     }
 
@@ -194,6 +196,12 @@ impl<'a> DragValue<'a> {
         self
     }
 
+    /// Base the `Id` on `id_source` instead of the position in the [`Ui`], see [`Ui::push_id`].
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
     /// Set an exact number of decimals to display.
     /// Values will also be rounded to this number of decimals.
     /// Normally you don't need to pick a precision, as the slider will intelligently pick a precision for you.
@@ -215,10 +223,14 @@ impl<'a> Widget for DragValue<'a> {
             suffix,
             min_decimals,
             max_decimals,
+            id_source,
         } = self;
 
-        let is_slow_speed =
-            ui.input().modifiers.shift_only() && ui.memory().is_being_dragged(ui.next_auto_id());
+        let id = id_source.map_or_else(
+            || ui.next_auto_id(),
+            |id_source| ui.make_persistent_id(id_source),
+        );
+        let is_slow_speed = ui.input().modifiers.shift_only() && ui.memory().is_being_dragged(id);
 
         let old_value = get(&mut get_set_value);
         let value = clamp_to_range(old_value, clamp_range.clone());
@@ -232,13 +244,17 @@ impl<'a> Widget for DragValue<'a> {
 
         let max_decimals = max_decimals.unwrap_or(auto_decimals + 2);
         let auto_decimals = auto_decimals.clamp(min_decimals, max_decimals);
+        let localization = ui.ctx().localization();
         let value_text = if value == 0.0 {
             "0".to_owned()
         } else {
-            emath::format_with_decimals_in_range(value, auto_decimals..=max_decimals)
+            localization.format_number(emath::format_with_decimals_in_range(
+                value,
+                auto_decimals..=max_decimals,
+            ))
         };
 
-        let kb_edit_id = ui.auto_id_with("edit");
+        let kb_edit_id = id.with("edit");
         let is_kb_editing = ui.memory().has_focus(kb_edit_id);
 
         let mut response = if is_kb_editing {
@@ -255,7 +271,7 @@ impl<'a> Widget for DragValue<'a> {
                     .desired_width(button_width)
                     .text_style(TextStyle::Monospace),
             );
-            if let Ok(parsed_value) = value_text.parse() {
+            if let Some(parsed_value) = localization.parse_number(&value_text) {
                 let parsed_value = clamp_to_range(parsed_value, clamp_range);
                 set(&mut get_set_value, parsed_value)
             }
@@ -271,16 +287,19 @@ impl<'a> Widget for DragValue<'a> {
                 .sense(Sense::click_and_drag())
                 .text_style(TextStyle::Monospace)
                 .wrap(false)
-                .min_size(ui.spacing().interact_size); // TODO: find some more generic solution to this
+                .min_size(ui.spacing().interact_size) // TODO: find some more generic solution to this
+                .id_source_id(id_source);
 
             let response = ui.add(button);
             let response = response
                 .on_hover_cursor(CursorIcon::ResizeHorizontal)
                 .on_hover_text(format!(
-                    "{}{}{}\nDrag to edit or click to enter a value.\nPress 'Shift' while dragging for better control.",
+                    "{}{}{}\n{}",
                     prefix,
-                    value as f32, // Show full precision value on-hover. TODO: figure out f64 vs f32
-                    suffix
+                    // Show full precision value on-hover. TODO: figure out f64 vs f32
+                    localization.format_number((value as f32).to_string()),
+                    suffix,
+                    localization.tr("Drag to edit or click to enter a value.\nPress 'Shift' while dragging for better control."),
                 ));
 
             if response.clicked() {
@@ -0,0 +1,471 @@
+use crate::*;
+
+enum Bytes<'a> {
+    ReadOnly(&'a [u8]),
+    Editable(&'a mut [u8]),
+}
+
+impl<'a> Bytes<'a> {
+    fn as_slice(&self) -> &[u8] {
+        match self {
+            Self::ReadOnly(bytes) => bytes,
+            Self::Editable(bytes) => bytes,
+        }
+    }
+}
+
+/// Kept in [`Memory::id_data_temp`].
+#[derive(Clone, Debug, Default)]
+struct State {
+    /// The offset of the byte with the cursor.
+    selected: Option<usize>,
+    /// Where the selection started, if more than one byte is selected.
+    anchor: Option<usize>,
+    /// The high nibble of the selected byte has been typed, and we are waiting for the low one.
+    half_typed: bool,
+    goto_text: String,
+}
+
+impl State {
+    fn selection(&self) -> Option<std::ops::RangeInclusive<usize>> {
+        let selected = self.selected?;
+        let anchor = self.anchor.unwrap_or(selected);
+        Some(selected.min(anchor)..=selected.max(anchor))
+    }
+
+    /// Select a byte, or extend the selection to it.
+    fn select(&mut self, offset: usize, extend: bool) {
+        self.anchor = if extend {
+            self.anchor.or(self.selected)
+        } else {
+            None
+        };
+        self.selected = Some(offset);
+        self.half_typed = false;
+    }
+}
+
+/// Where things go in a row, in chars: the offset, the bytes in hex, and the bytes as ASCII.
+struct Columns {
+    offset_digits: usize,
+    bytes_per_row: usize,
+}
+
+impl Columns {
+    /// The start of the hex digits of the byte at `index` in the row.
+    fn hex(&self, index: usize) -> usize {
+        // An extra space between each group of 8:
+        self.offset_digits + 2 + 3 * index + index / 8
+    }
+
+    fn ascii(&self, index: usize) -> usize {
+        self.hex(self.bytes_per_row) + 1 + index
+    }
+
+    fn width(&self) -> usize {
+        self.ascii(self.bytes_per_row)
+    }
+
+    /// The index in the row of the byte at this column, in either the hex or the ASCII part.
+    fn byte_at(&self, column: f32) -> Option<usize> {
+        let hex_start = self.hex(0) as f32;
+        let ascii_start = self.ascii(0) as f32;
+        if (hex_start..ascii_start).contains(&column) {
+            (0..self.bytes_per_row)
+                .rev()
+                .find(|&index| self.hex(index) as f32 <= column + 0.5)
+        } else if column >= ascii_start {
+            Some(((column - ascii_start) as usize).min(self.bytes_per_row - 1))
+        } else {
+            None
+        }
+    }
+}
+
+/// A hex dump of some bytes: the offset of each row, the bytes in hex, and the same bytes as ASCII.
+///
+/// * Click a byte to select it, and shift-click or drag to select a range. The arrow keys move the selection.
+/// * Copy the selected bytes as hex with the copy shortcut.
+/// * Use "Go to" in the toolbar to jump to an offset (in hex, or in decimal with a `#` in front).
+/// * With [`Self::editable`], type hex digits to change the selected byte.
+///
+/// Only the visible rows are painted, so this is fast for large buffers.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut bytes = vec![0_u8; 1024];
+/// ui.add(egui::HexView::new("dump", &bytes).max_height(300.0));
+///
+/// let response = ui.add(egui::HexView::editable("editor", &mut bytes));
+/// if response.changed() {
+///     // The bytes were edited
+/// }
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct HexView<'a> {
+    id_source: Id,
+    bytes: Bytes<'a>,
+    bytes_per_row: usize,
+    max_height: f32,
+}
+
+impl<'a> HexView<'a> {
+    /// Show the bytes, without editing.
+    pub fn new(id_source: impl std::hash::Hash, bytes: &'a [u8]) -> Self {
+        Self::from_bytes(id_source, Bytes::ReadOnly(bytes))
+    }
+
+    /// Show the bytes, and let the user edit them.
+    pub fn editable(id_source: impl std::hash::Hash, bytes: &'a mut [u8]) -> Self {
+        Self::from_bytes(id_source, Bytes::Editable(bytes))
+    }
+
+    fn from_bytes(id_source: impl std::hash::Hash, bytes: Bytes<'a>) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            bytes,
+            bytes_per_row: 16,
+            max_height: f32::INFINITY,
+        }
+    }
+
+    /// Default: 16.
+    pub fn bytes_per_row(mut self, bytes_per_row: usize) -> Self {
+        self.bytes_per_row = bytes_per_row.max(1);
+        self
+    }
+
+    /// Scroll if the rows don't fit in this height. Default: fill the available height.
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+}
+
+impl<'a> Widget for HexView<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            id_source,
+            mut bytes,
+            bytes_per_row,
+            max_height,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source);
+        let mut state = ui
+            .memory()
+            .id_data_temp
+            .get::<State>(&id)
+            .cloned()
+            .unwrap_or_default();
+
+        let len = bytes.as_slice().len();
+        let out_of_range = |offset: Option<usize>| offset.map_or(false, |offset| offset >= len);
+        if out_of_range(state.selected) || out_of_range(state.anchor) {
+            // The bytes got shorter:
+            state = State {
+                goto_text: state.goto_text,
+                ..Default::default()
+            };
+        }
+
+        let columns = Columns {
+            offset_digits: format!("{:X}", len.saturating_sub(1)).len().max(4),
+            bytes_per_row,
+        };
+        let num_rows = (len + bytes_per_row - 1) / bytes_per_row;
+        let mut scroll_to_selected = false;
+
+        ui.horizontal(|ui| {
+            let goto_text = ui.ctx().tr("Go to");
+            ui.label(goto_text);
+            let response = ui.add(
+                TextEdit::singleline(&mut state.goto_text)
+                    .hint_text("0x0")
+                    .desired_width(80.0),
+            );
+            if response.lost_focus() && ui.input().key_pressed(Key::Enter) {
+                if let Some(offset) = parse_offset(&state.goto_text).filter(|&o| o < len) {
+                    state.select(offset, false);
+                    scroll_to_selected = true;
+                    ui.memory().request_focus(id);
+                }
+            }
+            if let Some(selection) = state.selection() {
+                ui.label(format!(
+                    "0x{:X} ({} {})",
+                    selection.start(),
+                    selection.end() - selection.start() + 1,
+                    ui.ctx().tr("selected")
+                ));
+            }
+        });
+
+        let char_width = ui.fonts().glyph_width(TextStyle::Monospace, '0');
+        let row_height = ui.fonts().row_height(TextStyle::Monospace);
+        let mut changed = false;
+
+        let mut response = ScrollArea::from_max_height(max_height)
+            .id_source(id.with("scroll"))
+            .show(ui, |ui| {
+                let size = vec2(
+                    columns.width() as f32 * char_width,
+                    num_rows as f32 * row_height,
+                );
+                let (rect, _) = ui.allocate_exact_size(size, Sense::hover());
+                let response = ui.interact(rect, id, Sense::click_and_drag());
+
+                // Select with the pointer:
+                if let Some(pos) = response.interact_pointer_pos() {
+                    let row = ((pos.y - rect.top()) / row_height).floor().max(0.0) as usize;
+                    let column = (pos.x - rect.left()) / char_width;
+                    let offset = columns
+                        .byte_at(column)
+                        .map(|index| row * bytes_per_row + index)
+                        .filter(|&offset| offset < len);
+                    if let Some(offset) = offset {
+                        let input = ui.input();
+                        let extend = !input.pointer.any_pressed() || input.modifiers.shift;
+                        state.select(offset, extend);
+                    }
+                    response.request_focus();
+                }
+
+                if response.has_focus() {
+                    if let Some(selected) = state.selected {
+                        let input = ui.input();
+                        let shift = input.modifiers.shift;
+                        let moved = if input.key_pressed(Key::ArrowLeft) {
+                            Some(selected.saturating_sub(1))
+                        } else if input.key_pressed(Key::ArrowRight) {
+                            Some(selected + 1)
+                        } else if input.key_pressed(Key::ArrowUp) {
+                            selected.checked_sub(bytes_per_row)
+                        } else if input.key_pressed(Key::ArrowDown) {
+                            Some(selected + bytes_per_row)
+                        } else {
+                            None
+                        };
+                        if let Some(offset) = moved.filter(|&offset| offset < len) {
+                            state.select(offset, shift);
+                            scroll_to_selected = true;
+                        }
+
+                        let copy = input
+                            .events
+                            .iter()
+                            .any(|event| matches!(event, Event::Copy));
+                        if copy {
+                            let selection = state.selection();
+                            let selected_bytes =
+                                selection.and_then(|selection| bytes.as_slice().get(selection));
+                            if let Some(selected_bytes) = selected_bytes {
+                                let hex: Vec<String> = selected_bytes
+                                    .iter()
+                                    .map(|byte| format!("{:02X}", byte))
+                                    .collect();
+                                ui.ctx().output().copied_text = hex.join(" ");
+                            }
+                        }
+                    }
+
+                    if let Bytes::Editable(bytes) = &mut bytes {
+                        changed |= type_hex(ui, &mut state, bytes);
+                        scroll_to_selected |= changed;
+                    }
+                }
+
+                if scroll_to_selected {
+                    if let Some(selected) = state.selected {
+                        let y = rect.top() + (selected / bytes_per_row) as f32 * row_height;
+                        let row_rect = Rect::from_x_y_ranges(rect.x_range(), y..=y + row_height);
+                        ui.scroll_to_rect(row_rect, Some(Align::Center));
+                    }
+                }
+
+                paint_rows(
+                    ui,
+                    rect,
+                    &columns,
+                    bytes.as_slice(),
+                    &state,
+                    response.has_focus(),
+                );
+                response
+            });
+
+        ui.memory().id_data_temp.insert(id, state);
+        if changed {
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+/// Edit the selected byte with the typed hex digits. Returns `true` if a byte changed.
+fn type_hex(ui: &Ui, state: &mut State, bytes: &mut [u8]) -> bool {
+    let mut changed = false;
+    for event in &ui.input().events {
+        if let Event::Text(text) = event {
+            for digit in text.chars().filter_map(|c| c.to_digit(16)) {
+                let selected = match state.selected {
+                    Some(selected) if selected < bytes.len() => selected,
+                    _ => return changed,
+                };
+                let byte = &mut bytes[selected];
+                if state.half_typed {
+                    *byte = (*byte & 0xF0) | digit as u8;
+                    state.anchor = None;
+                    state.half_typed = false;
+                    if selected + 1 < bytes.len() {
+                        state.selected = Some(selected + 1);
+                    }
+                } else {
+                    *byte = (*byte & 0x0F) | ((digit as u8) << 4);
+                    state.anchor = None;
+                    state.half_typed = true;
+                }
+                changed = true;
+            }
+        }
+    }
+    changed
+}
+
+fn paint_rows(
+    ui: &Ui,
+    rect: Rect,
+    columns: &Columns,
+    bytes: &[u8],
+    state: &State,
+    has_focus: bool,
+) {
+    let char_width = ui.fonts().glyph_width(TextStyle::Monospace, '0');
+    let row_height = ui.fonts().row_height(TextStyle::Monospace);
+    let bytes_per_row = columns.bytes_per_row;
+    let visuals = ui.visuals();
+
+    // Only the visible rows:
+    let clip_rect = ui.clip_rect().intersect(rect);
+    let first = ((clip_rect.top() - rect.top()) / row_height)
+        .floor()
+        .max(0.0) as usize;
+    let last = ((clip_rect.bottom() - rect.top()) / row_height)
+        .ceil()
+        .max(0.0) as usize;
+    let painter = ui.painter().sub_region(clip_rect);
+
+    let selection = state.selection();
+    let char_rect = |row: usize, column: usize, chars: usize| {
+        Rect::from_min_size(
+            rect.min + vec2(column as f32 * char_width, row as f32 * row_height),
+            vec2(chars as f32 * char_width, row_height),
+        )
+    };
+
+    for (row, row_bytes) in bytes
+        .chunks(bytes_per_row)
+        .enumerate()
+        .take(last)
+        .skip(first)
+    {
+        let row_offset = row * bytes_per_row;
+
+        for index in 0..row_bytes.len() {
+            let offset = row_offset + index;
+            if selection.as_ref().map_or(false, |s| s.contains(&offset)) {
+                let fill = visuals.selection.bg_fill;
+                painter.rect_filled(char_rect(row, columns.hex(index), 2), 0.0, fill);
+                painter.rect_filled(char_rect(row, columns.ascii(index), 1), 0.0, fill);
+            }
+            if has_focus && state.selected == Some(offset) {
+                let stroke = visuals.selection.stroke;
+                // Show which of the hex digits is typed next:
+                let (column, chars) = if state.half_typed {
+                    (columns.hex(index) + 1, 1)
+                } else {
+                    (columns.hex(index), 2)
+                };
+                painter.rect_stroke(char_rect(row, column, chars), 0.0, stroke);
+            }
+        }
+
+        let offset_text = format!("{:0width$X}", row_offset, width = columns.offset_digits);
+        let mut hex = String::new();
+        let mut ascii = String::new();
+        for (index, byte) in row_bytes.iter().enumerate() {
+            if index > 0 {
+                hex.push(' ');
+                if index % 8 == 0 {
+                    hex.push(' ');
+                }
+            }
+            hex += &format!("{:02X}", byte);
+            ascii.push(if byte.is_ascii_graphic() || *byte == b' ' {
+                *byte as char
+            } else {
+                '.'
+            });
+        }
+
+        let text_pos =
+            |column: usize| rect.min + vec2(column as f32 * char_width, row as f32 * row_height);
+        let weak = visuals.weak_text_color();
+        let text_color = visuals.text_color();
+        painter.text(
+            text_pos(0),
+            Align2::LEFT_TOP,
+            offset_text,
+            TextStyle::Monospace,
+            weak,
+        );
+        painter.text(
+            text_pos(columns.hex(0)),
+            Align2::LEFT_TOP,
+            hex,
+            TextStyle::Monospace,
+            text_color,
+        );
+        painter.text(
+            text_pos(columns.ascii(0)),
+            Align2::LEFT_TOP,
+            ascii,
+            TextStyle::Monospace,
+            weak,
+        );
+    }
+}
+
+/// Hex (with or without `0x`), or decimal with a `#` in front.
+fn parse_offset(text: &str) -> Option<usize> {
+    let text = text.trim();
+    if let Some(decimal) = text.strip_prefix('#') {
+        decimal.trim().parse().ok()
+    } else {
+        let hex = text
+            .strip_prefix("0x")
+            .or_else(|| text.strip_prefix("0X"))
+            .unwrap_or(text);
+        usize::from_str_radix(hex, 16).ok()
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn hex_view() {
+    assert_eq!(parse_offset("0x1f"), Some(31));
+    assert_eq!(parse_offset("ff"), Some(255));
+    assert_eq!(parse_offset("#100"), Some(100));
+    assert_eq!(parse_offset("zz"), None);
+
+    let columns = Columns {
+        offset_digits: 4,
+        bytes_per_row: 16,
+    };
+    assert_eq!(columns.hex(0), 6);
+    assert_eq!(columns.hex(8), 6 + 24 + 1);
+    assert_eq!(columns.byte_at(6.0), Some(0));
+    assert_eq!(columns.byte_at(columns.hex(9) as f32 + 1.5), Some(9));
+    assert_eq!(columns.byte_at(columns.ascii(3) as f32 + 0.5), Some(3));
+    assert_eq!(columns.byte_at(1.0), None);
+}
@@ -13,6 +13,7 @@ use crate::*;
 pub struct Hyperlink {
     url: String,
     label: Label,
+    id_source: Option<Id>,
 }
 
 impl Hyperlink {
@@ -22,21 +23,26 @@ impl Hyperlink {
         Self {
             url: url.clone(),
             label: Label::new(url),
+            id_source: None,
         }
     }
 
     #[allow(clippy::needless_pass_by_value)]
-    pub fn from_label_and_url(label: impl Into<Label>, url: impl ToString) -> Self {
+    pub fn from_label_and_url(label: impl Into<WidgetText>, url: impl ToString) -> Self {
         Self {
             url: url.to_string(),
-            label: label.into(),
+            label: label.into().into(),
+            id_source: None,
         }
     }
 
     /// Show some other text than the url
-    #[allow(clippy::needless_pass_by_value)]
-    pub fn text(mut self, text: impl ToString) -> Self {
-        self.label.text = text.to_string();
+    pub fn text(mut self, text: impl Into<WidgetText>) -> Self {
+        let mut label = Label::from(text.into());
+        if label.text_style.is_none() {
+            label.text_style = self.label.text_style;
+        }
+        self.label = label;
         self
     }
 
@@ -49,13 +55,24 @@ impl Hyperlink {
     pub fn small(self) -> Self {
         self.text_style(TextStyle::Small)
     }
+
+    /// Base the `Id` on `id_source` instead of the position in the [`Ui`], see [`Ui::push_id`].
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
 }
 
 impl Widget for Hyperlink {
     fn ui(self, ui: &mut Ui) -> Response {
-        let Hyperlink { url, label } = self;
+        let Hyperlink {
+            url,
+            label,
+            id_source,
+        } = self;
         let galley = label.layout(ui);
-        let (rect, response) = ui.allocate_exact_size(galley.size, Sense::click());
+        let (rect, response) =
+            ui.allocate_exact_size_with_id_source(id_source, galley.size, Sense::click());
         response.widget_info(|| WidgetInfo::labeled(WidgetType::Hyperlink, &galley.text));
 
         if response.hovered() {
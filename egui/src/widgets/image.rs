@@ -100,3 +100,234 @@ impl Widget for Image {
         response
     }
 }
+
+// ----------------------------------------------------------------------------
+
+/// One frame of an [`AnimatedImage`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AnimationFrame {
+    pub texture_id: TextureId,
+    /// How long to show this frame, in seconds.
+    pub delay: f32,
+}
+
+/// Frames shorter than this are shown for [`DEFAULT_FRAME_DELAY`] instead, like web browsers do.
+const MIN_FRAME_DELAY: f32 = 0.02;
+const DEFAULT_FRAME_DELAY: f32 = 0.1;
+
+/// Plays back a sequence of frames, e.g. from an animated GIF.
+///
+/// The playback state (current frame, playing/paused, looping) is stored in [`Memory`],
+/// so you can turn on [`Self::show_controls`] and let the user pause the animation.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let frames = [egui::AnimationFrame { texture_id: egui::TextureId::User(0), delay: 0.1 }];
+/// ui.add(egui::AnimatedImage::new(&frames, [64.0, 64.0]).show_controls(true));
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+#[derive(Clone, Copy, Debug)]
+pub struct AnimatedImage<'a> {
+    frames: &'a [AnimationFrame],
+    size: Vec2,
+    id_source: Option<Id>,
+    autoplay: bool,
+    looping: bool,
+    show_controls: bool,
+    bg_fill: Color32,
+    tint: Color32,
+    sense: Sense,
+}
+
+impl<'a> AnimatedImage<'a> {
+    pub fn new(frames: &'a [AnimationFrame], size: impl Into<Vec2>) -> Self {
+        Self {
+            frames,
+            size: size.into(),
+            id_source: None,
+            autoplay: true,
+            looping: true,
+            show_controls: false,
+            bg_fill: Default::default(),
+            tint: Color32::WHITE,
+            sense: Sense::hover(),
+        }
+    }
+
+    /// A source for the unique `Id`, e.g. `.id_source("spinner")` or `.id_source(loop_index)`.
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
+    /// Start playing the first time the image is shown. Default: `true`.
+    pub fn autoplay(mut self, autoplay: bool) -> Self {
+        self.autoplay = autoplay;
+        self
+    }
+
+    /// Start over when reaching the last frame. Default: `true`.
+    ///
+    /// This is only the initial value if [`Self::show_controls`] is on.
+    pub fn looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    /// Show play/pause and loop buttons below the image. Default: `false`.
+    pub fn show_controls(mut self, show_controls: bool) -> Self {
+        self.show_controls = show_controls;
+        self
+    }
+
+    /// A solid color to put behind the image. Useful for transparent images.
+    pub fn bg_fill(mut self, bg_fill: impl Into<Color32>) -> Self {
+        self.bg_fill = bg_fill.into();
+        self
+    }
+
+    /// Multiply image color with this. Default is WHITE (no tint).
+    pub fn tint(mut self, tint: impl Into<Color32>) -> Self {
+        self.tint = tint.into();
+        self
+    }
+
+    /// Make the image respond to clicks and/or drags.
+    pub fn sense(mut self, sense: Sense) -> Self {
+        self.sense = sense;
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct PlaybackState {
+    frame_nr: usize,
+    /// When the current frame was first shown.
+    frame_start: f64,
+    playing: bool,
+    looping: bool,
+}
+
+impl PlaybackState {
+    /// Go to the frame we should be showing at `time`.
+    fn advance(&mut self, frames: &[AnimationFrame], time: f64) {
+        let total_duration: f64 = frames.iter().map(|f| frame_delay(f) as f64).sum();
+        if self.looping && time - self.frame_start > total_duration {
+            // We have been away for a while. Skip the full rounds:
+            let rounds = ((time - self.frame_start) / total_duration).floor();
+            self.frame_start += rounds * total_duration;
+        }
+
+        while self.playing {
+            let frame_end = self.frame_start + frame_delay(&frames[self.frame_nr]) as f64;
+            if time < frame_end {
+                break;
+            }
+            if self.frame_nr + 1 < frames.len() {
+                self.frame_nr += 1;
+            } else if self.looping {
+                self.frame_nr = 0;
+            } else {
+                self.playing = false;
+                break;
+            }
+            self.frame_start = frame_end;
+        }
+    }
+
+    fn is_finished(&self, frames: &[AnimationFrame]) -> bool {
+        !self.playing && !self.looping && self.frame_nr + 1 == frames.len()
+    }
+}
+
+fn frame_delay(frame: &AnimationFrame) -> f32 {
+    if frame.delay < MIN_FRAME_DELAY {
+        DEFAULT_FRAME_DELAY
+    } else {
+        frame.delay
+    }
+}
+
+impl<'a> Widget for AnimatedImage<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            frames,
+            size,
+            id_source,
+            autoplay,
+            looping,
+            show_controls,
+            bg_fill,
+            tint,
+            sense,
+        } = self;
+
+        if frames.is_empty() {
+            let (_, response) = ui.allocate_exact_size(size, sense);
+            return response;
+        }
+
+        let id = id_source.map_or_else(
+            || ui.next_auto_id(),
+            |id_source| ui.make_persistent_id(id_source),
+        );
+        let time = ui.input().time;
+        let mut state = *ui
+            .memory()
+            .id_data_temp
+            .get_or_insert_with(id, || PlaybackState {
+                frame_nr: 0,
+                frame_start: time,
+                playing: autoplay,
+                looping,
+            });
+        if !show_controls {
+            state.looping = looping;
+        }
+        state.frame_nr = state.frame_nr.min(frames.len() - 1);
+        state.advance(frames, time);
+
+        let frame = &frames[state.frame_nr];
+        let image = Image::new(frame.texture_id, size)
+            .bg_fill(bg_fill)
+            .tint(tint)
+            .sense(sense);
+
+        let response = if show_controls {
+            ui.vertical(|ui| {
+                let response = ui.add(image);
+                ui.horizontal(|ui| {
+                    let play_pause = if state.playing { "⏸" } else { "▶" };
+                    if ui.small_button(play_pause).clicked() {
+                        if state.is_finished(frames) {
+                            state.frame_nr = 0;
+                        }
+                        state.playing = !state.playing;
+                        state.frame_start = time;
+                    }
+                    if ui
+                        .selectable_label(state.looping, "🔁")
+                        .on_hover_text(ui.ctx().tr("Loop"))
+                        .clicked()
+                    {
+                        state.looping = !state.looping;
+                    }
+                    ui.label(format!("{}/{}", state.frame_nr + 1, frames.len()));
+                });
+                response
+            })
+            .inner
+        } else {
+            ui.add(image)
+        };
+
+        if state.playing && frames.len() > 1 {
+            let frame_end = state.frame_start + frame_delay(&frames[state.frame_nr]) as f64;
+            ui.ctx()
+                .request_repaint_after(((frame_end - time) as f32).max(0.0));
+        }
+
+        ui.memory().id_data_temp.insert(id, state);
+        response
+    }
+}
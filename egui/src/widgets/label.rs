@@ -1,6 +1,6 @@
 use crate::*;
 use epaint::Galley;
-use std::sync::Arc;
+use std::{borrow::Cow, sync::Arc};
 
 /// Static text.
 ///
@@ -10,10 +10,21 @@ use std::sync::Arc;
 /// ui.add(egui::Label::new("Equivalent"));
 /// ui.add(egui::Label::new("With Options").text_color(egui::Color32::RED));
 /// ```
+///
+/// If you show the same text every frame you can avoid allocating a new `String` for it
+/// by creating the label from an `Arc<str>` or a `Cow<'static, str>` instead:
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// let title: std::sync::Arc<str> = "Shared".into();
+/// ui.add(egui::Label::from(title.clone()));
+/// ui.add(egui::Label::from(std::borrow::Cow::Borrowed("Static")));
+/// ```
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+#[derive(Clone, Debug)]
 pub struct Label {
     // TODO: not pub
-    pub(crate) text: String,
+    pub(crate) text: LabelText,
     pub(crate) wrap: Option<bool>,
     pub(crate) text_style: Option<TextStyle>,
     pub(crate) background_color: Color32,
@@ -31,8 +42,12 @@ pub struct Label {
 impl Label {
     #[allow(clippy::needless_pass_by_value)]
     pub fn new(text: impl ToString) -> Self {
+        Self::from_text(LabelText::Owned(text.to_string()))
+    }
+
+    fn from_text(text: LabelText) -> Self {
         Self {
-            text: text.to_string(),
+            text,
             wrap: None,
             text_style: None,
             background_color: Color32::TRANSPARENT,
@@ -49,7 +64,7 @@ impl Label {
     }
 
     pub fn text(&self) -> &str {
-        &self.text
+        self.text.as_ref()
     }
 
     /// If `true`, the text will wrap at the `max_width`.
@@ -179,7 +194,7 @@ impl Label {
         };
         let galley = ui
             .fonts()
-            .layout_multiline(text_style, self.text.clone(), wrap_width); // TODO: avoid clone
+            .layout_multiline(text_style, &self.text, wrap_width);
         self.valign_galley(ui, text_style, galley)
     }
 
@@ -200,9 +215,9 @@ impl Label {
         self.paint_galley_impl(ui, pos, galley, false, ui.visuals().text_color())
     }
 
-    fn paint_galley_impl(
+    pub(crate) fn paint_galley_impl(
         &self,
-        ui: &mut Ui,
+        ui: &Ui,
         pos: Pos2,
         galley: Arc<Galley>,
         has_focus: bool,
@@ -333,7 +348,7 @@ impl Widget for Label {
             let text_style = self.text_style_or_default(ui.style());
             let galley = ui.fonts().layout_multiline_with_indentation_and_max_width(
                 text_style,
-                self.text.clone(),
+                &self.text,
                 first_row_indentation,
                 max_width,
             );
@@ -400,3 +415,40 @@ impl From<String> for Label {
         Label::new(s)
     }
 }
+
+impl From<Arc<str>> for Label {
+    fn from(text: Arc<str>) -> Self {
+        Self::from_text(LabelText::Shared(text))
+    }
+}
+
+impl From<Cow<'static, str>> for Label {
+    fn from(text: Cow<'static, str>) -> Self {
+        Self::from_text(match text {
+            Cow::Borrowed(text) => LabelText::Static(text),
+            Cow::Owned(text) => LabelText::Owned(text),
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// The text of a [`Label`].
+///
+/// Static and shared strings are kept as they are, so they don't need to be copied each frame.
+#[derive(Clone, Debug)]
+pub(crate) enum LabelText {
+    Owned(String),
+    Static(&'static str),
+    Shared(Arc<str>),
+}
+
+impl AsRef<str> for LabelText {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Owned(text) => text,
+            Self::Static(text) => text,
+            Self::Shared(text) => text,
+        }
+    }
+}
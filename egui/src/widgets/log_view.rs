@@ -0,0 +1,500 @@
+use std::collections::VecDeque;
+
+use crate::*;
+
+/// How important a [`LogLine`] is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl Default for LogLevel {
+    fn default() -> Self {
+        Self::Trace
+    }
+}
+
+impl LogLevel {
+    pub const ALL: [LogLevel; 5] = [
+        Self::Trace,
+        Self::Debug,
+        Self::Info,
+        Self::Warn,
+        Self::Error,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Trace => "TRACE",
+            Self::Debug => "DEBUG",
+            Self::Info => "INFO",
+            Self::Warn => "WARN",
+            Self::Error => "ERROR",
+        }
+    }
+
+    fn text_color(self, visuals: &Visuals) -> Color32 {
+        match self {
+            Self::Trace | Self::Debug => visuals.weak_text_color(),
+            Self::Info => visuals.text_color(),
+            Self::Warn => visuals.warn_fg_color,
+            Self::Error => visuals.error_fg_color,
+        }
+    }
+}
+
+/// A line in a [`LogBuffer`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogLine {
+    /// In seconds, e.g. since the app started or since the Unix epoch.
+    ///
+    /// Shown as `hh:mm:ss.mmm` (so for Unix time, as the time of day in UTC).
+    pub time: f64,
+    pub level: LogLevel,
+    pub text: String,
+}
+
+impl LogLine {
+    /// As shown by [`LogView`], and copied to the clipboard.
+    pub fn format(&self) -> String {
+        format!(
+            "{} {:5} {}",
+            format_time(self.time),
+            self.level.name(),
+            self.text
+        )
+    }
+}
+
+/// The lines shown by a [`LogView`]. Keep this in your app state and push lines to it as they come in.
+///
+/// When it is full, the oldest lines are dropped to make room for new ones.
+///
+/// ```
+/// let mut log = egui::LogBuffer::new(10_000);
+/// log.log(0.25, egui::LogLevel::Info, "Starting up");
+/// log.log(1.5, egui::LogLevel::Warn, "Config file not found, using defaults");
+/// assert_eq!(log.len(), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct LogBuffer {
+    lines: VecDeque<LogLine>,
+    capacity: usize,
+    /// How many lines have been dropped from the front.
+    ///
+    /// Lines are numbered from the first line ever pushed,
+    /// so that e.g. the selection in a [`LogView`] stays on the same lines when old ones are dropped.
+    dropped: u64,
+}
+
+impl LogBuffer {
+    /// Keep at most `capacity` lines.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            lines: VecDeque::with_capacity(capacity.min(1024)),
+            capacity: capacity.max(1),
+            dropped: 0,
+        }
+    }
+
+    pub fn push(&mut self, line: LogLine) {
+        if self.lines.len() == self.capacity {
+            self.lines.pop_front();
+            self.dropped += 1;
+        }
+        self.lines.push_back(line);
+    }
+
+    /// Push a new [`LogLine`].
+    pub fn log(&mut self, time: f64, level: LogLevel, text: impl Into<String>) {
+        self.push(LogLine {
+            time,
+            level,
+            text: text.into(),
+        });
+    }
+
+    pub fn clear(&mut self) {
+        self.dropped += self.lines.len() as u64;
+        self.lines.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Oldest first.
+    pub fn lines(&self) -> impl Iterator<Item = &LogLine> + '_ {
+        self.lines.iter()
+    }
+
+    /// The line number of the oldest line.
+    fn first_line(&self) -> u64 {
+        self.dropped
+    }
+
+    /// One past the line number of the newest line.
+    fn end_line(&self) -> u64 {
+        self.dropped + self.lines.len() as u64
+    }
+
+    fn get(&self, line_nr: u64) -> Option<&LogLine> {
+        let index = line_nr.checked_sub(self.dropped)?;
+        self.lines.get(index as usize)
+    }
+}
+
+/// Kept in [`Memory::id_data_temp`].
+#[derive(Clone, Debug, Default)]
+struct State {
+    filter: String,
+    min_level: LogLevel,
+    follow: bool,
+    /// The line numbers of where the selection started, and where it ends.
+    selection: Option<(u64, u64)>,
+
+    /// The line numbers of the lines that pass the filter, oldest first.
+    matches: Vec<u64>,
+    /// What [`Self::matches`] was found for.
+    matches_for: (String, LogLevel),
+    /// The lines before this have been checked against the filter.
+    checked_to: u64,
+}
+
+impl State {
+    fn is_filtering(&self) -> bool {
+        !self.filter.is_empty() || self.min_level != LogLevel::Trace
+    }
+
+    /// `filter` is [`Self::filter`] in lowercase.
+    fn passes(&self, line: &LogLine, filter: &str) -> bool {
+        line.level >= self.min_level && line.text.to_lowercase().contains(filter)
+    }
+
+    /// Only check the lines that are new since last frame, so that filtering stays fast for big logs.
+    fn update_matches(&mut self, buffer: &LogBuffer) {
+        let matches_for = (self.filter.clone(), self.min_level);
+        if self.matches_for != matches_for || self.checked_to > buffer.end_line() {
+            // New filter, or a different buffer:
+            self.matches.clear();
+            self.matches_for = matches_for;
+            self.checked_to = 0;
+        }
+
+        let dropped = self.matches.partition_point(|&nr| nr < buffer.first_line());
+        self.matches.drain(..dropped);
+
+        let filter = self.filter.to_lowercase();
+        for nr in self.checked_to.max(buffer.first_line())..buffer.end_line() {
+            let line = &buffer.lines[(nr - buffer.first_line()) as usize];
+            if self.passes(line, &filter) {
+                self.matches.push(nr);
+            }
+        }
+        self.checked_to = buffer.end_line();
+    }
+}
+
+/// Shows the lines of a [`LogBuffer`], with a toolbar for filtering them.
+///
+/// * Lines are colored by their [`LogLevel`].
+/// * With "Follow" checked, the view sticks to the bottom as new lines come in.
+///   Scrolling up turns it off, scrolling back to the bottom turns it on again.
+/// * Click a line to select it, shift-click to select a range,
+///   and copy the selected lines (that pass the filter) with the copy shortcut.
+/// * Only the visible lines are laid out and painted, and only new lines are checked against the filter,
+///   so this stays fast for logs of millions of lines.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut log = egui::LogBuffer::new(10_000);
+/// log.log(ui.input().time, egui::LogLevel::Info, "Frame started");
+/// ui.add(egui::LogView::new("my_log", &log).max_height(200.0));
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct LogView<'a> {
+    id_source: Id,
+    buffer: &'a LogBuffer,
+    max_height: f32,
+    show_time: bool,
+}
+
+impl<'a> LogView<'a> {
+    pub fn new(id_source: impl std::hash::Hash, buffer: &'a LogBuffer) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            buffer,
+            max_height: f32::INFINITY,
+            show_time: true,
+        }
+    }
+
+    /// Scroll if the lines don't fit in this height. Default: fill the available height.
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Show the time of each line. Default: `true`.
+    pub fn show_time(mut self, show_time: bool) -> Self {
+        self.show_time = show_time;
+        self
+    }
+}
+
+impl<'a> Widget for LogView<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            id_source,
+            buffer,
+            max_height,
+            show_time,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source);
+        // Taken out of memory while we use it, so the matches aren't copied every frame:
+        let state = ui
+            .memory()
+            .id_data_temp
+            .get_mut::<State>(&id)
+            .map(std::mem::take);
+        let mut state = state.unwrap_or_else(|| State {
+            follow: true,
+            ..Default::default()
+        });
+
+        let response = ui
+            .vertical(|ui| {
+                ui.horizontal(|ui| {
+                    let hint_text = ui.ctx().tr("Filter");
+                    ui.add(
+                        TextEdit::singleline(&mut state.filter)
+                            .hint_text(hint_text)
+                            .desired_width(160.0),
+                    );
+                    ComboBox::from_id_source(id.with("min_level"))
+                        .selected_text(state.min_level.name())
+                        .show_ui(ui, |ui| {
+                            for &level in &LogLevel::ALL {
+                                ui.selectable_value(&mut state.min_level, level, level.name());
+                            }
+                        });
+                    let follow_text = ui.ctx().tr("Follow");
+                    ui.checkbox(&mut state.follow, follow_text);
+                });
+
+                if state.is_filtering() {
+                    state.update_matches(buffer);
+                } else {
+                    state.matches.clear();
+                    state.checked_to = 0;
+                }
+                let num_rows = if state.is_filtering() {
+                    state.matches.len()
+                } else {
+                    buffer.len()
+                };
+
+                ScrollArea::from_max_height(max_height)
+                    .id_source(id.with("scroll"))
+                    .show(ui, |ui| {
+                        show_lines(ui, id, buffer, &mut state, num_rows, show_time)
+                    })
+            })
+            .inner;
+
+        ui.memory().id_data_temp.insert(id, state);
+        response
+    }
+}
+
+fn show_lines(
+    ui: &mut Ui,
+    id: Id,
+    buffer: &LogBuffer,
+    state: &mut State,
+    num_rows: usize,
+    show_time: bool,
+) -> Response {
+    let row_height = ui.fonts().row_height(TextStyle::Monospace);
+    let char_width = ui.fonts().glyph_width(TextStyle::Monospace, '0');
+    let size = vec2(ui.available_width(), num_rows as f32 * row_height);
+    let (rect, _) = ui.allocate_exact_size(size, Sense::hover());
+    let response = ui.interact(rect, id, Sense::click());
+
+    let line_at = |state: &State, row: usize| {
+        if state.is_filtering() {
+            state.matches.get(row).copied()
+        } else {
+            Some(buffer.first_line() + row as u64).filter(|&nr| nr < buffer.end_line())
+        }
+    };
+    // In `f64`, so that we still hit the right row far down a long log:
+    let row_at = |y: f32| {
+        let row = (f64::from(y) - f64::from(rect.top())) / f64::from(row_height);
+        row.floor().max(0.0) as usize
+    };
+
+    // Select:
+    if response.clicked() {
+        response.request_focus();
+        if let Some(pos) = response.interact_pointer_pos() {
+            if let Some(nr) = line_at(state, row_at(pos.y)) {
+                state.selection = match state.selection {
+                    Some((anchor, _)) if ui.input().modifiers.shift => Some((anchor, nr)),
+                    _ => Some((nr, nr)),
+                };
+            }
+        }
+    }
+
+    // Copy:
+    let copy = ui
+        .input()
+        .events
+        .iter()
+        .any(|event| matches!(event, Event::Copy));
+    if copy && response.has_focus() {
+        if let Some((anchor, end)) = state.selection {
+            let filter = state.filter.to_lowercase();
+            let lines: Vec<String> = (anchor.min(end)..=anchor.max(end))
+                .filter_map(|nr| buffer.get(nr))
+                .filter(|line| state.passes(line, &filter))
+                .map(LogLine::format)
+                .collect();
+            ui.ctx().output().copied_text = lines.join("\n");
+        }
+    }
+
+    // Follow:
+    let scroll_delta = ui.input().scroll_delta.y;
+    let clip_rect = ui.clip_rect().intersect(rect);
+    if ui.rect_contains_pointer(ui.clip_rect()) {
+        if scroll_delta > 0.0 {
+            state.follow = false;
+        } else if scroll_delta < 0.0 && clip_rect.bottom() >= rect.bottom() - row_height {
+            state.follow = true;
+        }
+    }
+    if state.follow {
+        response.scroll_to_me(Align::BOTTOM);
+    }
+
+    // Paint only the visible lines:
+    // Lay out from the first visible row, rather than from the (far away) top of the log:
+    let first = row_at(clip_rect.top());
+    let first_top = (f64::from(rect.top()) + first as f64 * f64::from(row_height)) as f32;
+    let last = first + (clip_rect.height() / row_height).ceil() as usize + 1;
+    let painter = ui.painter().sub_region(clip_rect);
+    let visuals = ui.visuals();
+    let selection = state
+        .selection
+        .map(|(anchor, end)| anchor.min(end)..=anchor.max(end));
+    for row in first..last.min(num_rows) {
+        let nr = match line_at(state, row) {
+            Some(nr) => nr,
+            None => break,
+        };
+        let line = match buffer.get(nr) {
+            Some(line) => line,
+            None => continue,
+        };
+        let row_rect = Rect::from_min_size(
+            pos2(rect.left(), first_top + (row - first) as f32 * row_height),
+            vec2(rect.width(), row_height),
+        );
+        if selection
+            .as_ref()
+            .map_or(false, |range| range.contains(&nr))
+        {
+            painter.rect_filled(row_rect, 0.0, visuals.selection.bg_fill);
+        }
+
+        let mut x = row_rect.left();
+        if show_time {
+            painter.text(
+                pos2(x, row_rect.top()),
+                Align2::LEFT_TOP,
+                format_time(line.time),
+                TextStyle::Monospace,
+                visuals.weak_text_color(),
+            );
+            x += 13.0 * char_width; // "hh:mm:ss.mmm "
+        }
+        let color = line.level.text_color(visuals);
+        painter.text(
+            pos2(x, row_rect.top()),
+            Align2::LEFT_TOP,
+            line.level.name(),
+            TextStyle::Monospace,
+            color,
+        );
+        x += 6.0 * char_width; // "ERROR "
+        painter.text(
+            pos2(x, row_rect.top()),
+            Align2::LEFT_TOP,
+            &line.text,
+            TextStyle::Monospace,
+            color,
+        );
+    }
+
+    response
+}
+
+/// `hh:mm:ss.mmm`, wrapping around every 24 hours.
+fn format_time(time: f64) -> String {
+    let ms = (time.rem_euclid(24.0 * 60.0 * 60.0) * 1000.0) as u64;
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        ms / 3_600_000,
+        ms / 60_000 % 60,
+        ms / 1000 % 60,
+        ms % 1000
+    )
+}
+
+#[cfg(test)]
+#[test]
+fn log_view() {
+    assert_eq!(format_time(3661.5), "01:01:01.500");
+    assert_eq!(format_time(86_400.0 + 1.0), "00:00:01.000");
+
+    let mut buffer = LogBuffer::new(3);
+    for i in 0..5 {
+        let level = if i % 2 == 0 {
+            LogLevel::Info
+        } else {
+            LogLevel::Error
+        };
+        buffer.log(i as f64, level, format!("line {}", i));
+    }
+    assert_eq!(buffer.len(), 3);
+    assert_eq!(buffer.first_line(), 2);
+    assert_eq!(buffer.get(4).unwrap().text, "line 4");
+    assert!(buffer.get(1).is_none());
+
+    let mut state = State {
+        min_level: LogLevel::Error,
+        ..Default::default()
+    };
+    state.update_matches(&buffer);
+    assert_eq!(state.matches, vec![3]);
+
+    buffer.log(5.0, LogLevel::Error, "line 5");
+    buffer.log(6.0, LogLevel::Info, "line 6");
+    state.update_matches(&buffer);
+    assert_eq!(state.matches, vec![5]);
+}
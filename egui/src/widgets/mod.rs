@@ -3,26 +3,67 @@
 //! Example widget uses:
 //! * `ui.add(Label::new("Text").text_color(color::red));`
 //! * `if ui.add(Button::new("Click me")).clicked() { ... }`
+//!
+//! # Writing your own widget
+//! A widget is a function (or a type implementing [`Widget`]) that does four things:
+//!
+//! 1. Decide how much space it needs, e.g. from [`Ui::spacing`] and [`Ui::available_size`].
+//! 2. Allocate that space and sense interaction with [`Ui::allocate_response`], [`Ui::allocate_exact_size`]
+//!    or [`Ui::allocate_painter`]. If you manage layout yourself, use [`Ui::allocate_rect`],
+//!    or [`Ui::interact`] / [`Ui::interact_with_hovered`] for regions you have already allocated.
+//! 3. React to the [`Response`]: [`Response::clicked`], [`Response::dragged`], [`Response::drag_delta`], [`Response::has_focus`] etc.
+//!    Call [`Response::mark_changed`] when the value changed, and [`Response::widget_info`] so screen readers know what it is.
+//! 4. Paint it with [`Ui::painter`], using [`Style::interact`] (via [`Ui::style`]) for the colors.
+//!
+//! Widgets that need an [`Id`] (e.g. to remember state between frames with [`Memory::id_data`], or to take keyboard focus)
+//! should get one from the user (hashed with [`Ui::make_persistent_id`]), or use [`Ui::next_auto_id`].
+//! Keyboard focus is managed with [`Memory::request_focus`], [`Memory::surrender_focus`],
+//! [`Memory::has_focus`], [`Memory::gained_focus`] and [`Memory::lost_focus`].
+//!
+//! The functions linked above are the supported API for widget crates, and follow semver.
+//! See the `toggle_switch.rs` example in `egui_demo_lib` for a complete widget.
 
 use crate::*;
 
 mod button;
 pub mod color_picker;
+mod data_table;
+mod diff_view;
 pub(crate) mod drag_value;
+mod hex_view;
 mod hyperlink;
 mod image;
 mod label;
+mod log_view;
+mod monospace_table;
 pub mod plot;
 mod selected_label;
 mod separator;
 mod slider;
+mod task_progress;
 pub(crate) mod text_edit;
+mod value_text_edit;
+mod widget_text;
 
 pub use hyperlink::*;
 pub use label::*;
 pub use selected_label::*;
 pub use separator::*;
-pub use {button::*, drag_value::DragValue, image::Image, slider::*, text_edit::*};
+pub use {
+    button::*,
+    data_table::{CellEditor, CopyFormat, DataTable},
+    diff_view::{DiffMode, DiffView},
+    drag_value::DragValue,
+    hex_view::HexView,
+    image::{AnimatedImage, AnimationFrame, Image},
+    log_view::{LogBuffer, LogLevel, LogLine, LogView},
+    monospace_table::MonospaceTable,
+    slider::*,
+    task_progress::TaskProgress,
+    text_edit::*,
+    value_text_edit::ValueTextEdit,
+    widget_text::WidgetText,
+};
 
 // ----------------------------------------------------------------------------
 
@@ -81,7 +122,7 @@ where
 pub fn reset_button<T: Default + PartialEq>(ui: &mut Ui, value: &mut T) {
     let def = T::default();
     if ui
-        .add(Button::new("Reset").enabled(*value != def))
+        .add(Button::new(ui.ctx().tr("Reset")).enabled(*value != def))
         .clicked()
     {
         *value = def;
@@ -94,7 +135,7 @@ pub fn stroke_ui(ui: &mut crate::Ui, stroke: &mut epaint::Stroke, text: &str) {
     let epaint::Stroke { width, color } = stroke;
     ui.horizontal(|ui| {
         ui.add(DragValue::new(width).speed(0.1).clamp_range(0.0..=5.0))
-            .on_hover_text("Width");
+            .on_hover_text(ui.ctx().tr("Width"));
         ui.color_edit_button_srgba(color);
         ui.label(text);
 
@@ -115,7 +156,7 @@ pub(crate) fn shadow_ui(ui: &mut Ui, shadow: &mut epaint::Shadow, text: &str) {
                 .speed(1.0)
                 .clamp_range(0.0..=100.0),
         )
-        .on_hover_text("Extrusion");
+        .on_hover_text(ui.ctx().tr("Extrusion"));
         ui.color_edit_button_srgba(color);
     });
 }
@@ -0,0 +1,123 @@
+use crate::*;
+
+/// Rows of text laid out with aligned columns, using the monospace font.
+///
+/// Useful for simple data dumps, where you would otherwise pad the text with spaces yourself.
+/// Columns where all cells are numbers are right-aligned (this can be turned off).
+/// The first row is allowed to be a header, i.e. it does not need to be numeric.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// ui.add(egui::MonospaceTable::from_tsv("name\tsize\nfoo.rs\t1024\nlib.rs\t42"));
+///
+/// ui.add(egui::MonospaceTable::new(vec![
+///     vec!["x", "y"],
+///     vec!["0.5", "-12.25"],
+/// ]));
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+#[derive(Clone, Debug)]
+pub struct MonospaceTable {
+    rows: Vec<Vec<String>>,
+    right_align_numbers: bool,
+    column_gap: usize,
+    text_color: Option<Color32>,
+}
+
+impl MonospaceTable {
+    pub fn new(rows: impl IntoIterator<Item = impl IntoIterator<Item = impl ToString>>) -> Self {
+        Self {
+            rows: rows
+                .into_iter()
+                .map(|row| row.into_iter().map(|cell| cell.to_string()).collect())
+                .collect(),
+            right_align_numbers: true,
+            column_gap: 2,
+            text_color: None,
+        }
+    }
+
+    /// Tab-separated values: one row per line, cells separated by `\t`.
+    pub fn from_tsv(text: &str) -> Self {
+        Self::new(text.lines().map(|line| line.split('\t')))
+    }
+
+    /// Right-align columns where all cells (except maybe the first) are numbers. Default: `true`.
+    pub fn right_align_numbers(mut self, right_align_numbers: bool) -> Self {
+        self.right_align_numbers = right_align_numbers;
+        self
+    }
+
+    /// Number of spaces between columns. Default: 2.
+    pub fn column_gap(mut self, column_gap: usize) -> Self {
+        self.column_gap = column_gap;
+        self
+    }
+
+    pub fn text_color(mut self, text_color: impl Into<Color32>) -> Self {
+        self.text_color = Some(text_color.into());
+        self
+    }
+
+    /// The table as text, with the cells padded with spaces.
+    pub fn to_aligned_string(&self) -> String {
+        let num_columns = self.rows.iter().map(Vec::len).max().unwrap_or(0);
+
+        let mut widths = vec![0; num_columns];
+        for row in &self.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let right_aligned: Vec<bool> = (0..num_columns)
+            .map(|column| self.right_align_numbers && self.is_numeric_column(column))
+            .collect();
+
+        let gap = " ".repeat(self.column_gap);
+        let mut text = String::new();
+        for (row_nr, row) in self.rows.iter().enumerate() {
+            if row_nr > 0 {
+                text.push('\n');
+            }
+            let mut line = String::new();
+            for (column, cell) in row.iter().enumerate() {
+                if column > 0 {
+                    line += &gap;
+                }
+                let padding = " ".repeat(widths[column] - cell.chars().count());
+                if right_aligned[column] {
+                    line += &padding;
+                    line += cell;
+                } else {
+                    line += cell;
+                    line += &padding;
+                }
+            }
+            text += line.trim_end();
+        }
+        text
+    }
+
+    fn is_numeric_column(&self, column: usize) -> bool {
+        let mut cells = self
+            .rows
+            .iter()
+            .skip(1) // Maybe a header
+            .filter_map(|row| row.get(column))
+            .map(|cell| cell.trim())
+            .filter(|cell| !cell.is_empty())
+            .peekable();
+        cells.peek().is_some() && cells.all(|cell| cell.parse::<f64>().is_ok())
+    }
+}
+
+impl Widget for MonospaceTable {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let mut label = Label::new(self.to_aligned_string()).monospace().wrap(false);
+        if let Some(text_color) = self.text_color {
+            label = label.text_color(text_color);
+        }
+        ui.add(label)
+    }
+}
@@ -1,6 +1,7 @@
 //! Contains items that can be added to a plot.
 
 use std::ops::RangeInclusive;
+use std::sync::Arc;
 
 use super::transform::Bounds;
 use crate::*;
@@ -62,6 +63,122 @@ impl VLine {
     }
 }
 
+/// A shaded band in a plot, covering a range of x values over the full height,
+/// or a range of y values over the full width, e.g. to mark an outage or a normal range.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// use egui::plot::{Arrow, HLine, Plot, Span, Text, Value};
+/// use egui::{Align2, Color32};
+/// let plot = Plot::new("Monitoring")
+///     .hline(HLine::new(90.0, (1.0, Color32::RED)))
+///     .span(Span::x(12.0, 14.0))
+///     .text(Text::new(Value::new(10.0, 95.0), "Deploy").anchor(Align2::RIGHT_CENTER))
+///     .arrow(Arrow::new(Value::new(10.0, 95.0), Value::new(12.0, 80.0)));
+/// ui.add(plot);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Span {
+    /// 0 for a range of x values, 1 for a range of y values.
+    pub(crate) axis: usize,
+    pub(crate) min: f64,
+    pub(crate) max: f64,
+    pub(crate) color: Color32,
+}
+
+impl Span {
+    /// Shade the x values from `min` to `max`.
+    pub fn x(min: impl Into<f64>, max: impl Into<f64>) -> Self {
+        Self::new(0, min.into(), max.into())
+    }
+
+    /// Shade the y values from `min` to `max`.
+    pub fn y(min: impl Into<f64>, max: impl Into<f64>) -> Self {
+        Self::new(1, min.into(), max.into())
+    }
+
+    fn new(axis: usize, min: f64, max: f64) -> Self {
+        Self {
+            axis,
+            min: min.min(max),
+            max: min.max(max),
+            color: Color32::TRANSPARENT,
+        }
+    }
+
+    /// Fill color, which should be quite transparent.
+    /// Default is `Color32::TRANSPARENT` which means a faint color will be auto-assigned.
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self
+    }
+}
+
+/// A text annotation at a position in the plot, e.g. to describe an event.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Text {
+    pub(crate) position: Value,
+    pub(crate) text: String,
+    pub(crate) anchor: Align2,
+    pub(crate) style: TextStyle,
+    pub(crate) color: Color32,
+}
+
+impl Text {
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(position: Value, text: impl ToString) -> Self {
+        Self {
+            position,
+            text: text.to_string(),
+            anchor: Align2::CENTER_CENTER,
+            style: TextStyle::Small,
+            color: Color32::TRANSPARENT,
+        }
+    }
+
+    /// Which part of the text is at the position. Default: the center.
+    pub fn anchor(mut self, anchor: Align2) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Default: [`TextStyle::Small`].
+    pub fn style(mut self, style: TextStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Default is `Color32::TRANSPARENT` which means the normal text color is used.
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self
+    }
+}
+
+/// An arrow between two positions in the plot, e.g. from a [`Text`] to what it describes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Arrow {
+    pub(crate) origin: Value,
+    pub(crate) tip: Value,
+    pub(crate) stroke: Stroke,
+}
+
+impl Arrow {
+    pub fn new(origin: Value, tip: Value) -> Self {
+        Self {
+            origin,
+            tip,
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+        }
+    }
+
+    /// Default is a thin line with `Color32::TRANSPARENT`, which means the normal text color is used.
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 /// Describes a function y = f(x) with an optional range for x and a number of points.
@@ -73,9 +190,113 @@ struct ExplicitGenerator {
 
 // ----------------------------------------------------------------------------
 
+/// A run of consecutive values of a [`ChunkedValues`] or a [`Curve`].
+#[derive(Clone)]
+struct ValueChunk {
+    values: Vec<Value>,
+    bounds: Bounds,
+    /// Are the x coordinates increasing (or equal)?
+    sorted: bool,
+}
+
+impl ValueChunk {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            values: Vec::with_capacity(capacity),
+            bounds: Bounds::NOTHING,
+            sorted: true,
+        }
+    }
+
+    fn new(values: Vec<Value>) -> Self {
+        let mut chunk = Self::with_capacity(values.len());
+        for value in values {
+            chunk.push(value);
+        }
+        chunk
+    }
+
+    fn push(&mut self, value: Value) {
+        if let Some(last) = self.values.last() {
+            self.sorted &= last.x <= value.x;
+        }
+        self.bounds.extend_with(&value);
+        self.values.push(value);
+    }
+}
+
+/// A growing series of values, e.g. a time series that is streamed in.
+///
+/// The values are stored in shared chunks,
+/// so [`Curve::from_chunked`] can show them every frame without copying them.
+///
+/// ```
+/// use egui::plot::{ChunkedValues, Curve, Value};
+/// let mut values = ChunkedValues::default();
+/// for i in 0..100_000 {
+///     values.push(Value::new(i as f64, (i as f64 / 1000.0).sin()));
+/// }
+/// let curve = Curve::from_chunked(&values);
+/// ```
+#[derive(Clone, Default)]
+pub struct ChunkedValues {
+    chunks: Vec<Arc<ValueChunk>>,
+}
+
+impl ChunkedValues {
+    /// The number of values in each chunk.
+    const CHUNK_SIZE: usize = 4096;
+
+    /// Add a value to the end.
+    pub fn push(&mut self, value: Value) {
+        match self.chunks.last_mut() {
+            Some(chunk) if chunk.values.len() < Self::CHUNK_SIZE => {
+                // Only copies the chunk if a curve from this frame is still holding on to it.
+                Arc::make_mut(chunk).push(value);
+            }
+            _ => {
+                let mut chunk = ValueChunk::with_capacity(Self::CHUNK_SIZE);
+                chunk.push(value);
+                self.chunks.push(Arc::new(chunk));
+            }
+        }
+    }
+
+    /// Add values to the end.
+    pub fn extend(&mut self, values: impl IntoIterator<Item = Value>) {
+        for value in values {
+            self.push(value);
+        }
+    }
+
+    /// The number of values.
+    pub fn len(&self) -> usize {
+        self.chunks.iter().map(|chunk| chunk.values.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.chunks.is_empty()
+    }
+
+    /// Remove all values.
+    pub fn clear(&mut self) {
+        self.chunks.clear();
+    }
+
+    /// Iterate over all values, in order.
+    pub fn iter(&self) -> impl Iterator<Item = &Value> {
+        self.chunks.iter().flat_map(|chunk| chunk.values.iter())
+    }
+}
+
 /// A series of values forming a path.
+///
+/// Curves with many values are downsampled to a few points per pixel column when drawn.
+/// If the x coordinates are increasing, as in a time series,
+/// only the visible part of the curve is processed at all.
+/// Together this makes it possible to show millions of values.
 pub struct Curve {
-    pub(crate) values: Vec<Value>,
+    chunks: Vec<Arc<ValueChunk>>,
     generator: Option<ExplicitGenerator>,
     pub(crate) bounds: Bounds,
     pub(crate) stroke: Stroke,
@@ -85,7 +306,7 @@ pub struct Curve {
 impl Curve {
     fn empty() -> Self {
         Self {
-            values: Vec::new(),
+            chunks: Vec::new(),
             generator: None,
             bounds: Bounds::NOTHING,
             stroke: Stroke::new(2.0, Color32::TRANSPARENT),
@@ -93,18 +314,27 @@ impl Curve {
         }
     }
 
-    pub fn from_values(values: Vec<Value>) -> Self {
+    fn from_chunks(chunks: Vec<Arc<ValueChunk>>) -> Self {
         let mut bounds = Bounds::NOTHING;
-        for value in &values {
-            bounds.extend_with(value);
+        for chunk in &chunks {
+            bounds.merge(&chunk.bounds);
         }
         Self {
-            values,
+            chunks,
             bounds,
             ..Self::empty()
         }
     }
 
+    pub fn from_values(values: Vec<Value>) -> Self {
+        Self::from_chunks(vec![Arc::new(ValueChunk::new(values))])
+    }
+
+    /// Show the values of a [`ChunkedValues`]. This is cheap, as the values are not copied.
+    pub fn from_chunked(values: &ChunkedValues) -> Self {
+        Self::from_chunks(values.chunks.clone())
+    }
+
     pub fn from_values_iter(iter: impl Iterator<Item = Value>) -> Self {
         Self::from_values(iter.collect())
     }
@@ -151,7 +381,56 @@ impl Curve {
 
     /// Returns true if there are no data points available and there is no function to generate any.
     pub(crate) fn no_data(&self) -> bool {
-        self.generator.is_none() && self.values.is_empty()
+        self.generator.is_none() && self.chunks.iter().all(|chunk| chunk.values.is_empty())
+    }
+
+    /// Are the x coordinates increasing (or equal) throughout the curve?
+    fn is_sorted(&self) -> bool {
+        self.chunks.iter().all(|chunk| chunk.sorted)
+            && self.chunks.windows(2).all(|pair| {
+                match (pair[0].values.last(), pair[1].values.first()) {
+                    (Some(last), Some(first)) => last.x <= first.x,
+                    _ => true,
+                }
+            })
+    }
+
+    /// The values needed to draw the curve within `x_range`, as consecutive slices.
+    ///
+    /// For a sorted curve this is the values in the range, plus the closest value on either side
+    /// so that the line continues out of the range. For an unsorted curve it is all values.
+    pub(crate) fn values_in(&self, x_range: RangeInclusive<f64>) -> Vec<&[Value]> {
+        if !self.is_sorted() {
+            return self.chunks.iter().map(|chunk| &chunk.values[..]).collect();
+        }
+
+        let (min, max) = (*x_range.start(), *x_range.end());
+        let mut slices = Vec::new();
+        let mut before: Option<&[Value]> = None; // The last value left of the range.
+        for chunk in &self.chunks {
+            let values = &chunk.values[..];
+            if values.is_empty() {
+                continue;
+            }
+            let mut start = values.partition_point(|value| value.x < min);
+            let end = values.partition_point(|value| value.x <= max);
+            if start == values.len() {
+                before = Some(&values[start - 1..]);
+                continue;
+            }
+            if start > 0 {
+                start -= 1;
+            } else if let Some(before) = before {
+                slices.push(before);
+            }
+            before = None;
+            if end < values.len() {
+                slices.push(&values[start..=end]);
+                break;
+            }
+            slices.push(&values[start..end]);
+        }
+        slices
     }
 
     /// Returns the intersection of two ranges if they intersect.
@@ -171,13 +450,14 @@ impl Curve {
             if let Some(intersection) = Self::range_intersection(&x_range, &generator.x_range) {
                 let increment =
                     (intersection.end() - intersection.start()) / (generator.points - 1) as f64;
-                self.values = (0..generator.points)
+                let values = (0..generator.points)
                     .map(|i| {
                         let x = intersection.start() + i as f64 * increment;
                         let y = (generator.function)(x);
                         Value { x, y }
                     })
                     .collect();
+                self.chunks = vec![Arc::new(ValueChunk::new(values))];
             }
         }
     }
@@ -224,3 +504,312 @@ impl Curve {
         self
     }
 }
+
+// ----------------------------------------------------------------------------
+
+/// A bar from `start` to `end` along the x axis, on one row of an [`Intervals`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Interval {
+    pub(crate) row: f64,
+    pub(crate) start: f64,
+    pub(crate) end: f64,
+    pub(crate) label: String,
+    pub(crate) color: Option<Color32>,
+}
+
+impl Interval {
+    /// `row` is the y value the bar is centered on.
+    pub fn new(row: impl Into<f64>, start: impl Into<f64>, end: impl Into<f64>) -> Self {
+        Self {
+            row: row.into(),
+            start: start.into(),
+            end: end.into(),
+            label: Default::default(),
+            color: None,
+        }
+    }
+
+    /// Shown on the bar (if it is wide enough) and when hovering it.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn label(mut self, label: impl ToString) -> Self {
+        self.label = label.to_string();
+        self
+    }
+
+    /// Use this color instead of the one of the [`Intervals`].
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+/// Horizontal bars on rows, e.g. the tasks of a Gantt chart or the scopes of a frame profiler.
+///
+/// Hover a bar to see its label, start, end and duration.
+/// Use [`super::Plot::allow_zoom_y`] to only zoom the time axis.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// use egui::plot::{Interval, Intervals, Plot};
+/// let tasks = Intervals::new(vec![
+///     Interval::new(0.0, 0.0, 3.0).label("Design"),
+///     Interval::new(1.0, 2.0, 7.0).label("Build"),
+///     Interval::new(2.0, 6.5, 9.0).label("Test"),
+/// ])
+/// .name("Tasks");
+/// ui.add(Plot::new("Schedule").intervals(tasks).allow_zoom_y(false).view_aspect(3.0));
+/// ```
+pub struct Intervals {
+    pub(crate) intervals: Vec<Interval>,
+    pub(crate) bounds: Bounds,
+    pub(crate) color: Color32,
+    /// How much of the height of a row the bars fill.
+    pub(crate) bar_height: f64,
+    pub(crate) name: String,
+}
+
+impl Intervals {
+    pub fn new(intervals: Vec<Interval>) -> Self {
+        let bar_height = 0.8;
+        let mut bounds = Bounds::NOTHING;
+        for interval in &intervals {
+            let half_height = 0.5 * bar_height;
+            bounds.extend_with(&Value::new(interval.start, interval.row - half_height));
+            bounds.extend_with(&Value::new(interval.end, interval.row + half_height));
+        }
+        Self {
+            intervals,
+            bounds,
+            color: Color32::TRANSPARENT,
+            bar_height,
+            name: Default::default(),
+        }
+    }
+
+    /// Color of the bars. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Name of this series of bars.
+    ///
+    /// If given a name it will show up in the plot legend
+    /// (if legends are turned on).
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// The value-space rectangle of a bar, as `(min, max)`.
+    pub(crate) fn bar(&self, interval: &Interval) -> (Value, Value) {
+        let half_height = 0.5 * self.bar_height;
+        (
+            Value::new(interval.start, interval.row - half_height),
+            Value::new(interval.end, interval.row + half_height),
+        )
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// One period of a [`Candles`] series: the opening, highest, lowest and closing price, and the traded volume.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Candle {
+    /// Where the period is centered on the x axis, e.g. a unix time.
+    pub x: f64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+}
+
+impl Candle {
+    pub fn new(
+        x: impl Into<f64>,
+        open: impl Into<f64>,
+        high: impl Into<f64>,
+        low: impl Into<f64>,
+        close: impl Into<f64>,
+    ) -> Self {
+        Self {
+            x: x.into(),
+            open: open.into(),
+            high: high.into(),
+            low: low.into(),
+            close: close.into(),
+            volume: 0.0,
+        }
+    }
+
+    /// The traded volume, shown below the candles if [`Candles::show_volume`] is on.
+    pub fn volume(mut self, volume: impl Into<f64>) -> Self {
+        self.volume = volume.into();
+        self
+    }
+
+    /// Did the price go up (or stay the same) during this period?
+    pub(crate) fn is_up(&self) -> bool {
+        self.close >= self.open
+    }
+
+    /// Extend this candle with the period following it.
+    pub(crate) fn merge(&mut self, next: &Candle) {
+        self.high = self.high.max(next.high);
+        self.low = self.low.min(next.low);
+        self.close = next.close;
+        self.volume += next.volume;
+    }
+}
+
+/// A candlestick (or OHLC bar) chart for trading UIs.
+///
+/// Only the candles in view are painted, and candles that end up on the same pixel column
+/// are merged, so it stays fast with tens of thousands of candles.
+/// Use [`super::Plot::crosshair`] to read off exact values.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// use egui::plot::{Candle, Candles, Plot};
+/// let candles = Candles::new(vec![
+///     Candle::new(0.0, 10.0, 12.0, 9.5, 11.0).volume(1200.0),
+///     Candle::new(1.0, 11.0, 11.5, 8.0, 8.5).volume(3400.0),
+///     Candle::new(2.0, 8.5, 10.0, 8.0, 9.5).volume(900.0),
+/// ])
+/// .show_volume(true)
+/// .name("ACME");
+/// ui.add(Plot::new("Prices").candles(candles).crosshair(true).view_aspect(2.0));
+/// ```
+pub struct Candles {
+    /// Sorted by x.
+    pub(crate) candles: Vec<Candle>,
+    pub(crate) bounds: Bounds,
+    /// Width of the candle bodies along the x axis.
+    pub(crate) width: f64,
+    pub(crate) up_color: Color32,
+    pub(crate) down_color: Color32,
+    pub(crate) ohlc: bool,
+    pub(crate) show_volume: bool,
+    pub(crate) name: String,
+}
+
+impl Candles {
+    /// The candles will be sorted by x.
+    pub fn new(mut candles: Vec<Candle>) -> Self {
+        candles.sort_by(|a, b| a.x.partial_cmp(&b.x).unwrap_or(std::cmp::Ordering::Equal));
+
+        // Default to a small gap between neighbors:
+        let min_spacing = candles
+            .windows(2)
+            .map(|pair| pair[1].x - pair[0].x)
+            .filter(|&spacing| spacing > 0.0)
+            .fold(f64::INFINITY, f64::min);
+        let width = if min_spacing.is_finite() {
+            0.8 * min_spacing
+        } else {
+            1.0
+        };
+
+        let mut candles = Self {
+            candles,
+            bounds: Bounds::NOTHING,
+            width,
+            up_color: Color32::from_rgb(38, 166, 91),
+            down_color: Color32::from_rgb(222, 60, 60),
+            ohlc: false,
+            show_volume: false,
+            name: Default::default(),
+        };
+        candles.update_bounds();
+        candles
+    }
+
+    fn update_bounds(&mut self) {
+        let half_width = 0.5 * self.width;
+        let mut bounds = Bounds::NOTHING;
+        for candle in &self.candles {
+            bounds.extend_with(&Value::new(candle.x - half_width, candle.low));
+            bounds.extend_with(&Value::new(candle.x + half_width, candle.high));
+        }
+        self.bounds = bounds;
+    }
+
+    /// Width of the candle bodies along the x axis.
+    /// Default: 80% of the smallest distance between two candles.
+    pub fn width(mut self, width: impl Into<f64>) -> Self {
+        self.width = width.into();
+        self.update_bounds();
+        self
+    }
+
+    /// Color of candles that closed at or above their opening price. Default: green.
+    pub fn up_color(mut self, color: impl Into<Color32>) -> Self {
+        self.up_color = color.into();
+        self
+    }
+
+    /// Color of candles that closed below their opening price. Default: red.
+    pub fn down_color(mut self, color: impl Into<Color32>) -> Self {
+        self.down_color = color.into();
+        self
+    }
+
+    /// Paint OHLC bars (with ticks for the open and close) instead of candlesticks.
+    pub fn ohlc(mut self, ohlc: bool) -> Self {
+        self.ohlc = ohlc;
+        self
+    }
+
+    /// Show the volume as bars along the bottom of the plot, scaled to the largest volume in view.
+    pub fn show_volume(mut self, show_volume: bool) -> Self {
+        self.show_volume = show_volume;
+        self
+    }
+
+    /// Name of this series of candles.
+    ///
+    /// If given a name it will show up in the plot legend
+    /// (if legends are turned on).
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    pub(crate) fn color(&self, candle: &Candle) -> Color32 {
+        if candle.is_up() {
+            self.up_color
+        } else {
+            self.down_color
+        }
+    }
+
+    /// The candles that are at least partially within the given x range.
+    pub(crate) fn visible(&self, x_range: RangeInclusive<f64>) -> &[Candle] {
+        let half_width = 0.5 * self.width;
+        let start = self
+            .candles
+            .partition_point(|candle| candle.x + half_width < *x_range.start());
+        let end = self
+            .candles
+            .partition_point(|candle| candle.x - half_width <= *x_range.end());
+        &self.candles[start..end.max(start)]
+    }
+
+    /// The candle closest to `x`, if it is at most `max_distance` away.
+    pub(crate) fn closest(&self, x: f64, max_distance: f64) -> Option<&Candle> {
+        let i = self.candles.partition_point(|candle| candle.x < x);
+        let before = i.checked_sub(1).and_then(|i| self.candles.get(i));
+        let after = self.candles.get(i);
+        before
+            .into_iter()
+            .chain(after)
+            .filter(|candle| (candle.x - x).abs() <= max_distance)
+            .min_by(|a, b| {
+                let (a, b) = ((a.x - x).abs(), (b.x - x).abs());
+                a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal)
+            })
+    }
+}
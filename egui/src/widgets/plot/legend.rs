@@ -2,6 +2,56 @@ use std::string::String;
 
 use crate::*;
 
+/// Which corner of the plot the [`Legend`] is shown in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Corner {
+    LeftTop,
+    RightTop,
+    LeftBottom,
+    RightBottom,
+}
+
+impl Corner {
+    pub(crate) fn is_bottom(self) -> bool {
+        matches!(self, Self::LeftBottom | Self::RightBottom)
+    }
+
+    /// Lays out the legend entries from this corner.
+    pub(crate) fn layout(self) -> Layout {
+        match self {
+            Self::LeftTop => Layout::top_down(Align::LEFT),
+            Self::RightTop => Layout::top_down(Align::RIGHT),
+            Self::LeftBottom => Layout::bottom_up(Align::LEFT),
+            Self::RightBottom => Layout::bottom_up(Align::RIGHT),
+        }
+    }
+}
+
+/// The legend of a [`super::Plot`], listing its named items.
+/// Click an entry to hide or show the items, hover it to highlight them.
+///
+/// To show the legend somewhere else than on top of the plot, see [`super::Plot::legend_ui`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Legend {
+    pub(crate) position: Corner,
+}
+
+impl Default for Legend {
+    fn default() -> Self {
+        Self {
+            position: Corner::LeftTop,
+        }
+    }
+}
+
+impl Legend {
+    /// Which corner of the plot to show the legend in. Default: [`Corner::LeftTop`].
+    pub fn position(mut self, corner: Corner) -> Self {
+        self.position = corner;
+        self
+    }
+}
+
 pub(crate) struct LegendEntry {
     pub text: String,
     pub color: Color32,
@@ -34,7 +84,7 @@ impl Widget for &mut LegendEntry {
         let total_extra = padding + vec2(icon_width + icon_spacing, 0.0) + padding;
 
         let text_style = TextStyle::Button;
-        let galley = ui.fonts().layout_no_wrap(text_style, text.clone());
+        let galley = ui.fonts().layout_no_wrap(text_style, text.as_str());
 
         let mut desired_size = total_extra + galley.size;
         desired_size = desired_size.at_least(ui.spacing().interact_size);
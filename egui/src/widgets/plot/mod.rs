@@ -2,12 +2,20 @@
 
 mod items;
 mod legend;
+mod pie;
+mod polar;
 mod transform;
 
 use std::collections::{BTreeMap, HashSet};
 
-pub use items::{Curve, Value};
+pub use items::{Arrow, Span, Text};
+pub use items::{Candle, Candles};
+pub use items::{ChunkedValues, Curve, Value};
 pub use items::{HLine, VLine};
+pub use items::{Interval, Intervals};
+pub use legend::{Corner, Legend};
+pub use pie::{PieChart, Slice};
+pub use polar::{PolarPlot, Radar};
 use transform::{Bounds, ScreenTransform};
 
 use crate::*;
@@ -26,6 +34,33 @@ struct PlotMemory {
     hidden_curves: HashSet<String>,
 }
 
+/// The state of a legend shown with [`Plot::legend_ui`].
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Default)]
+struct ExternalLegend {
+    hidden: HashSet<String>,
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    hovered: Option<String>,
+}
+
+/// External legends are shown in another [`Ui`] than the plot, so they are identified by the plot name only.
+fn external_legend_id(plot_name: &str) -> Id {
+    Id::new(plot_name).with("legend")
+}
+
+/// The bounds last shown by a group of plots with linked axes, see [`Plot::link_axes`].
+#[derive(Clone, Copy)]
+struct LinkedBounds(Bounds);
+
+// ----------------------------------------------------------------------------
+
+/// The color of the `i`:th automatically colored item, shared by all plot types.
+fn auto_color(i: usize) -> Color32 {
+    let golden_ratio = (5.0_f32.sqrt() - 1.0) / 2.0; // 0.61803398875
+    let h = i as f32 * golden_ratio;
+    Hsva::new(h, 0.85, 0.5, 1.0).into() // TODO: OkLab or some other perspective color space
+}
+
 // ----------------------------------------------------------------------------
 
 /// A 2D plot, e.g. a graph of a function.
@@ -51,10 +86,16 @@ pub struct Plot {
     curves: Vec<Curve>,
     hlines: Vec<HLine>,
     vlines: Vec<VLine>,
+    spans: Vec<Span>,
+    intervals: Vec<Intervals>,
+    candles: Vec<Candles>,
+    texts: Vec<Text>,
+    arrows: Vec<Arrow>,
 
     center_x_axis: bool,
     center_y_axis: bool,
     allow_zoom: bool,
+    allow_zoom_y: bool,
     allow_drag: bool,
     min_auto_bounds: Bounds,
     margin_fraction: Vec2,
@@ -67,7 +108,9 @@ pub struct Plot {
 
     show_x: bool,
     show_y: bool,
-    show_legend: bool,
+    legend: Option<Legend>,
+    crosshair: bool,
+    linked_axes: Option<(Id, [bool; 2])>,
 }
 
 impl Plot {
@@ -80,10 +123,16 @@ impl Plot {
             curves: Default::default(),
             hlines: Default::default(),
             vlines: Default::default(),
+            spans: Default::default(),
+            intervals: Default::default(),
+            candles: Default::default(),
+            texts: Default::default(),
+            arrows: Default::default(),
 
             center_x_axis: false,
             center_y_axis: false,
             allow_zoom: true,
+            allow_zoom_y: true,
             allow_drag: true,
             min_auto_bounds: Bounds::NOTHING,
             margin_fraction: Vec2::splat(0.05),
@@ -96,17 +145,16 @@ impl Plot {
 
             show_x: true,
             show_y: true,
-            show_legend: true,
+            legend: Some(Legend::default()),
+            crosshair: false,
+            linked_axes: None,
         }
     }
 
     fn auto_color(&mut self, color: &mut Color32) {
         if *color == Color32::TRANSPARENT {
-            let i = self.next_auto_color_idx;
+            *color = auto_color(self.next_auto_color_idx);
             self.next_auto_color_idx += 1;
-            let golden_ratio = (5.0_f32.sqrt() - 1.0) / 2.0; // 0.61803398875
-            let h = i as f32 * golden_ratio;
-            *color = Hsva::new(h, 0.85, 0.5, 1.0).into(); // TODO: OkLab or some other perspective color space
         }
     }
 
@@ -138,6 +186,48 @@ impl Plot {
         self
     }
 
+    /// Shade a range of x or y values, e.g. to mark a time period or the normal range of a value.
+    /// You can add multiple spans.
+    pub fn span(mut self, mut span: Span) -> Self {
+        if span.color == Color32::TRANSPARENT {
+            span.color = auto_color(self.next_auto_color_idx).linear_multiply(0.15);
+            self.next_auto_color_idx += 1;
+        }
+        self.spans.push(span);
+        self
+    }
+
+    /// Add a text annotation. It stays at the same position in the plot when panning and zooming.
+    pub fn text(mut self, text: Text) -> Self {
+        self.texts.push(text);
+        self
+    }
+
+    /// Add an arrow, e.g. from a [`Text`] to the value it describes.
+    pub fn arrow(mut self, arrow: Arrow) -> Self {
+        self.arrows.push(arrow);
+        self
+    }
+
+    /// Add horizontal bars, e.g. for a Gantt chart.
+    /// You can add multiple series of bars.
+    pub fn intervals(mut self, mut intervals: Intervals) -> Self {
+        if !intervals.intervals.is_empty() {
+            self.auto_color(&mut intervals.color);
+            self.intervals.push(intervals);
+        }
+        self
+    }
+
+    /// Add a candlestick chart, e.g. of stock prices.
+    /// You can add multiple series of candles.
+    pub fn candles(mut self, candles: Candles) -> Self {
+        if !candles.candles.is_empty() {
+            self.candles.push(candles);
+        }
+        self
+    }
+
     /// width / height ratio of the data.
     /// For instance, it can be useful to set this to `1.0` for when the two axes show the same
     /// unit.
@@ -218,6 +308,14 @@ impl Plot {
         self
     }
 
+    /// Whether zooming also changes the y axis. Default: `true`.
+    ///
+    /// Turn this off to only zoom the x axis, e.g. the time axis of a Gantt chart.
+    pub fn allow_zoom_y(mut self, on: bool) -> Self {
+        self.allow_zoom_y = on;
+        self
+    }
+
     /// Whether to allow dragging in the plot to move the bounds. Default: `true`.
     pub fn allow_drag(mut self, on: bool) -> Self {
         self.allow_drag = on;
@@ -240,7 +338,83 @@ impl Plot {
 
     /// Whether to show a legend including all named curves. Default: `true`.
     pub fn show_legend(mut self, show: bool) -> Self {
-        self.show_legend = show;
+        self.legend = show.then(Legend::default);
+        self
+    }
+
+    /// Show a legend with the given settings on top of the plot.
+    pub fn legend(mut self, legend: Legend) -> Self {
+        self.legend = Some(legend);
+        self
+    }
+
+    /// Show the legend of this plot in a [`Ui`] of your choice, e.g. a side panel.
+    ///
+    /// Call this before adding the plot, and use `.show_legend(false)` so that the plot
+    /// follows this legend instead of showing its own.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// use egui::plot::{Curve, Plot, Value};
+    /// let curve = Curve::from_values(vec![Value::new(0.0, 0.0), Value::new(1.0, 1.0)]).name("line");
+    /// let plot = Plot::new("Plot with external legend").curve(curve).show_legend(false);
+    /// ui.horizontal(|ui| {
+    ///     ui.vertical(|ui| plot.legend_ui(ui));
+    ///     ui.add(plot);
+    /// });
+    /// ```
+    pub fn legend_ui(&self, ui: &mut Ui) {
+        let id = external_legend_id(&self.name);
+        let mut state = ui
+            .memory()
+            .id_data
+            .get_or_default::<ExternalLegend>(id)
+            .clone();
+
+        let mut entries = legend_entries(
+            ui,
+            &self.curves,
+            &self.intervals,
+            &self.candles,
+            &state.hidden,
+        );
+        for entry in entries.values_mut() {
+            ui.add(entry);
+        }
+
+        state.hidden = entries
+            .values()
+            .filter(|entry| !entry.checked)
+            .map(|entry| entry.text.clone())
+            .collect();
+        state.hovered = entries
+            .values()
+            .find(|entry| entry.hovered)
+            .map(|entry| entry.text.clone());
+        ui.memory().id_data.insert(id, state);
+    }
+
+    /// Show a crosshair at the pointer, with the x and y values read off at the edges of the plot.
+    /// Default: `false`.
+    pub fn crosshair(mut self, crosshair: bool) -> Self {
+        self.crosshair = crosshair;
+        self
+    }
+
+    /// Link the x and/or y axis of all plots with the same `group_id_source`,
+    /// so that panning and zooming one of them pans and zooms the others, e.g. for stacked time series.
+    ///
+    /// Double-click a plot to fit it (and its linked axes) to its contents.
+    /// An axis that is not linked is fitted to the contents of each plot on its own.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// use egui::plot::Plot;
+    /// ui.add(Plot::new("Temperature").link_axes("dashboard", true, false).height(100.0));
+    /// ui.add(Plot::new("Humidity").link_axes("dashboard", true, false).height(100.0));
+    /// ```
+    pub fn link_axes(mut self, group_id_source: impl std::hash::Hash, x: bool, y: bool) -> Self {
+        self.linked_axes = Some((Id::new(group_id_source), [x, y]));
         self
     }
 }
@@ -253,9 +427,15 @@ impl Widget for Plot {
             mut curves,
             hlines,
             vlines,
+            spans,
+            mut intervals,
+            mut candles,
+            texts,
+            arrows,
             center_x_axis,
             center_y_axis,
             allow_zoom,
+            allow_zoom_y,
             allow_drag,
             min_auto_bounds,
             margin_fraction,
@@ -266,10 +446,12 @@ impl Widget for Plot {
             view_aspect,
             mut show_x,
             mut show_y,
-            show_legend,
+            legend,
+            crosshair,
+            linked_axes,
         } = self;
 
-        let plot_id = ui.make_persistent_id(name);
+        let plot_id = ui.make_persistent_id(&name);
         let memory = ui
             .memory()
             .id_data
@@ -323,35 +505,26 @@ impl Widget for Plot {
 
         // --- Legend ---
 
-        if show_legend {
-            // Collect the legend entries. If multiple curves have the same name, they share a
-            // checkbox. If their colors don't match, we pick a neutral color for the checkbox.
-            let mut legend_entries: BTreeMap<String, LegendEntry> = BTreeMap::new();
-            curves
-                .iter()
-                .filter(|curve| !curve.name.is_empty())
-                .for_each(|curve| {
-                    let checked = !hidden_curves.contains(&curve.name);
-                    let text = curve.name.clone();
-                    legend_entries
-                        .entry(curve.name.clone())
-                        .and_modify(|entry| {
-                            if entry.color != curve.stroke.color {
-                                entry.color = ui.visuals().noninteractive().fg_stroke.color
-                            }
-                        })
-                        .or_insert_with(|| LegendEntry::new(text, curve.stroke.color, checked));
-                });
+        let mut hovered_entry = None;
+        if let Some(legend) = legend {
+            let mut legend_entries =
+                legend_entries(ui, &curves, &intervals, &candles, &hidden_curves);
 
             // Show the legend.
-            let mut legend_ui = ui.child_ui(rect, Layout::top_down(Align::LEFT));
-            legend_entries.values_mut().for_each(|entry| {
+            let mut legend_ui = ui.child_ui(rect, legend.position.layout());
+            let mut add_entry = |entry: &mut LegendEntry| {
                 let response = legend_ui.add(entry);
                 if response.hovered() {
                     show_x = false;
                     show_y = false;
                 }
-            });
+            };
+            if legend.position.is_bottom() {
+                // Keep the order the same as in the top corners:
+                legend_entries.values_mut().rev().for_each(&mut add_entry);
+            } else {
+                legend_entries.values_mut().for_each(&mut add_entry);
+            }
 
             // Get the names of the hidden curves.
             hidden_curves = legend_entries
@@ -359,36 +532,88 @@ impl Widget for Plot {
                 .filter(|entry| !entry.checked)
                 .map(|entry| entry.text.clone())
                 .collect();
-
-            // Highlight the hovered curves.
-            legend_entries
+            hovered_entry = legend_entries
                 .values()
-                .filter(|entry| entry.hovered)
-                .for_each(|entry| {
-                    curves
-                        .iter_mut()
-                        .filter(|curve| curve.name == entry.text)
-                        .for_each(|curve| {
-                            curve.stroke.width *= 2.0;
-                        });
-                });
+                .find(|entry| entry.hovered)
+                .map(|entry| entry.text.clone());
+        } else if let Some(external) = ui
+            .memory()
+            .id_data
+            .get::<ExternalLegend>(&external_legend_id(&name))
+        {
+            hidden_curves = external.hidden.clone();
+            hovered_entry = external.hovered.clone();
+        }
 
-            // Remove deselected curves.
-            curves.retain(|curve| !hidden_curves.contains(&curve.name));
+        // Highlight the hovered curves.
+        if let Some(hovered_entry) = hovered_entry {
+            curves
+                .iter_mut()
+                .filter(|curve| curve.name == hovered_entry)
+                .for_each(|curve| {
+                    curve.stroke.width *= 2.0;
+                });
         }
 
+        // Remove deselected curves.
+        curves.retain(|curve| !hidden_curves.contains(&curve.name));
+        intervals.retain(|intervals| !hidden_curves.contains(&intervals.name));
+        candles.retain(|candles| !hidden_curves.contains(&candles.name));
+
         // ---
 
-        auto_bounds |= response.double_clicked_by(PointerButton::Primary);
+        let reset_bounds = response.double_clicked_by(PointerButton::Primary);
+        auto_bounds |= reset_bounds;
+
+        // Follow the other plots of the link group, unless we were just reset:
+        let linked_bounds = match linked_axes {
+            Some((link_id, axes)) if !reset_bounds => ui
+                .memory()
+                .id_data_temp
+                .get::<LinkedBounds>(&link_id)
+                .map(|linked| (linked.0, axes)),
+            _ => None,
+        };
 
         // Set bounds automatically based on content.
         if auto_bounds || !bounds.is_valid() {
             bounds = min_auto_bounds;
             hlines.iter().for_each(|line| bounds.extend_with_y(line.y));
             vlines.iter().for_each(|line| bounds.extend_with_x(line.x));
+            spans.iter().for_each(|span| {
+                for &value in &[span.min, span.max] {
+                    if span.axis == 0 {
+                        bounds.extend_with_x(value);
+                    } else {
+                        bounds.extend_with_y(value);
+                    }
+                }
+            });
+            texts
+                .iter()
+                .for_each(|text| bounds.extend_with(&text.position));
+            arrows.iter().for_each(|arrow| {
+                bounds.extend_with(&arrow.origin);
+                bounds.extend_with(&arrow.tip);
+            });
             curves.iter().for_each(|curve| bounds.merge(&curve.bounds));
+            intervals
+                .iter()
+                .for_each(|intervals| bounds.merge(&intervals.bounds));
+            candles
+                .iter()
+                .for_each(|candles| bounds.merge(&candles.bounds));
             bounds.add_relative_margin(margin_fraction);
         }
+        // Only the linked axes follow the group, the others still fit the contents of this plot:
+        if let Some((linked, axes)) = linked_bounds {
+            for (d, &is_linked) in axes.iter().enumerate() {
+                if is_linked {
+                    bounds.min[d] = linked.min[d];
+                    bounds.max[d] = linked.max[d];
+                }
+            }
+        }
         // Make sure they are not empty.
         if !bounds.is_valid() {
             bounds = Bounds::new_symmetrical(1.0);
@@ -409,6 +634,8 @@ impl Widget for Plot {
             transform.set_aspect(data_aspect as f64);
         }
 
+        let bounds_before_interaction = *transform.bounds();
+
         // Dragging
         if allow_drag && response.dragged_by(PointerButton::Primary) {
             transform.translate_bounds(-response.drag_delta());
@@ -418,11 +645,14 @@ impl Widget for Plot {
         // Zooming
         if allow_zoom {
             if let Some(hover_pos) = response.hover_pos() {
-                let zoom_factor = if data_aspect.is_some() {
+                let mut zoom_factor = if data_aspect.is_some() {
                     Vec2::splat(ui.input().zoom_delta())
                 } else {
                     ui.input().zoom_delta_2d()
                 };
+                if !allow_zoom_y {
+                    zoom_factor.y = 1.0;
+                }
                 if zoom_factor != Vec2::splat(1.0) {
                     transform.zoom(zoom_factor, hover_pos);
                     auto_bounds = false;
@@ -443,12 +673,28 @@ impl Widget for Plot {
 
         let bounds = *transform.bounds();
 
+        if let Some((link_id, _)) = linked_axes {
+            ui.memory()
+                .id_data_temp
+                .insert(link_id, LinkedBounds(bounds));
+            if reset_bounds || bounds != bounds_before_interaction {
+                // Let the plots of the group that come before this one catch up:
+                ui.ctx().request_repaint();
+            }
+        }
+
         let prepared = Prepared {
             curves,
             hlines,
             vlines,
+            spans,
+            intervals,
+            candles,
+            texts,
+            arrows,
             show_x,
             show_y,
+            crosshair,
             transform,
         };
         prepared.ui(ui, &response);
@@ -462,7 +708,7 @@ impl Widget for Plot {
             },
         );
 
-        if show_x || show_y {
+        if show_x || show_y || crosshair {
             response.on_hover_cursor(CursorIcon::Crosshair)
         } else {
             response
@@ -474,8 +720,14 @@ struct Prepared {
     curves: Vec<Curve>,
     hlines: Vec<HLine>,
     vlines: Vec<VLine>,
+    spans: Vec<Span>,
+    intervals: Vec<Intervals>,
+    candles: Vec<Candles>,
+    texts: Vec<Text>,
+    arrows: Vec<Arrow>,
     show_x: bool,
     show_y: bool,
+    crosshair: bool,
     transform: ScreenTransform,
 }
 
@@ -489,6 +741,18 @@ impl Prepared {
             self.paint_axis(ui, d, &mut shapes);
         }
 
+        for span in &self.spans {
+            let bounds = transform.bounds();
+            let (mut min, mut max) = (bounds.min, bounds.max);
+            min[span.axis] = span.min;
+            max[span.axis] = span.max;
+            let rect = Rect::from_two_pos(
+                transform.position_from_value(&Value::new(min[0], min[1])),
+                transform.position_from_value(&Value::new(max[0], max[1])),
+            );
+            shapes.push(Shape::rect_filled(rect, 0.0, span.color));
+        }
+
         for &hline in &self.hlines {
             let HLine { y, stroke } = hline;
             let points = [
@@ -507,24 +771,87 @@ impl Prepared {
             shapes.push(Shape::line_segment(points, stroke));
         }
 
+        for intervals in &self.intervals {
+            for interval in &intervals.intervals {
+                let (min, max) = intervals.bar(interval);
+                let rect = Rect::from_two_pos(
+                    transform.position_from_value(&min),
+                    transform.position_from_value(&max),
+                );
+                let color = interval.color.unwrap_or(intervals.color);
+                shapes.push(Shape::rect_filled(rect, 2.0, color.linear_multiply(0.5)));
+                shapes.push(Shape::rect_stroke(rect, 2.0, (1.0, color)));
+
+                // Only show labels that fit on the bar:
+                if !interval.label.is_empty() {
+                    let galley = ui
+                        .fonts()
+                        .layout_single_line(TextStyle::Small, interval.label.clone());
+                    if galley.size.x + 4.0 <= rect.width() && galley.size.y <= rect.height() {
+                        shapes.push(Shape::Text {
+                            pos: rect.left_center() + vec2(2.0, -0.5 * galley.size.y),
+                            galley,
+                            color: ui.visuals().strong_text_color(),
+                            fake_italics: false,
+                        });
+                    }
+                }
+            }
+        }
+
+        for candles in &self.candles {
+            self.paint_candles(candles, &mut shapes);
+        }
+
         for curve in &self.curves {
             let stroke = curve.stroke;
-            let values = &curve.values;
-            let shape = if values.len() == 1 {
-                let point = transform.position_from_value(&values[0]);
-                Shape::circle_filled(point, stroke.width / 2.0, stroke.color)
+            let values = curve.values_in(transform.bounds().range_x());
+            let points = downsample(&values, transform);
+            let shape = if points.len() == 1 {
+                Shape::circle_filled(points[0], stroke.width / 2.0, stroke.color)
             } else {
-                Shape::line(
-                    values
-                        .iter()
-                        .map(|v| transform.position_from_value(v))
-                        .collect(),
-                    stroke,
-                )
+                Shape::line(points, stroke)
             };
             shapes.push(shape);
         }
 
+        for arrow in &self.arrows {
+            let mut stroke = arrow.stroke;
+            if stroke.color == Color32::TRANSPARENT {
+                stroke.color = ui.visuals().text_color();
+            }
+            let origin = transform.position_from_value(&arrow.origin);
+            let tip = transform.position_from_value(&arrow.tip);
+            let rot = emath::Rot2::from_angle(std::f32::consts::TAU / 10.0);
+            let tip_length = 8.0;
+            let dir = (tip - origin).normalized();
+            shapes.push(Shape::line_segment([origin, tip], stroke));
+            shapes.push(Shape::line_segment(
+                [tip, tip - tip_length * (rot * dir)],
+                stroke,
+            ));
+            shapes.push(Shape::line_segment(
+                [tip, tip - tip_length * (rot.inverse() * dir)],
+                stroke,
+            ));
+        }
+
+        for text in &self.texts {
+            let color = if text.color == Color32::TRANSPARENT {
+                ui.visuals().text_color()
+            } else {
+                text.color
+            };
+            shapes.push(Shape::text(
+                ui.fonts(),
+                transform.position_from_value(&text.position),
+                text.anchor,
+                &text.text,
+                text.style,
+                color,
+            ));
+        }
+
         if let Some(pointer) = response.hover_pos() {
             self.hover(ui, pointer, &mut shapes);
         }
@@ -532,6 +859,85 @@ impl Prepared {
         ui.painter().sub_region(*transform.frame()).extend(shapes);
     }
 
+    fn paint_candles(&self, candles: &Candles, shapes: &mut Vec<Shape>) {
+        let transform = &self.transform;
+        let frame = *transform.frame();
+        let visible = candles.visible(transform.bounds().range_x());
+        let to_x = |x: f64| transform.position_from_value(&Value::new(x, 0.0)).x;
+        let to_y = |y: f64| transform.position_from_value(&Value::new(0.0, y)).y;
+
+        // When zoomed out, merge the candles that end up on the same pixel column:
+        let body_width = (candles.width * transform.dpos_dvalue_x()) as f32;
+        let mut merged: Vec<Candle> = Vec::new();
+        let visible = if body_width < 1.0 {
+            let mut merged_column = None;
+            for candle in visible {
+                let column = to_x(candle.x).floor();
+                match merged.last_mut() {
+                    Some(last) if merged_column == Some(column) => last.merge(candle),
+                    _ => {
+                        merged.push(*candle);
+                        merged_column = Some(column);
+                    }
+                }
+            }
+            &merged[..]
+        } else {
+            visible
+        };
+        let half_width = 0.5 * body_width.at_least(1.0);
+
+        let mut mesh = epaint::Mesh::default();
+
+        if candles.show_volume {
+            let max_volume = visible
+                .iter()
+                .map(|candle| candle.volume)
+                .fold(0.0, f64::max);
+            if max_volume > 0.0 {
+                let band_height = 0.2 * frame.height();
+                for candle in visible {
+                    let x = to_x(candle.x);
+                    let height = (candle.volume / max_volume) as f32 * band_height;
+                    let rect = Rect::from_x_y_ranges(
+                        x - half_width..=x + half_width,
+                        frame.bottom() - height..=frame.bottom(),
+                    );
+                    mesh.add_colored_rect(rect, candles.color(candle).linear_multiply(0.4));
+                }
+            }
+        }
+
+        for candle in visible {
+            let color = candles.color(candle);
+            let x = to_x(candle.x);
+            let (open, close) = (to_y(candle.open), to_y(candle.close));
+
+            // The wick (or the bar, for OHLC):
+            let wick =
+                Rect::from_x_y_ranges(x - 0.5..=x + 0.5, to_y(candle.high)..=to_y(candle.low));
+            mesh.add_colored_rect(wick, color);
+
+            if candles.ohlc {
+                let open_tick = Rect::from_x_y_ranges(x - half_width..=x, open - 0.5..=open + 0.5);
+                let close_tick =
+                    Rect::from_x_y_ranges(x..=x + half_width, close - 0.5..=close + 0.5);
+                mesh.add_colored_rect(open_tick, color);
+                mesh.add_colored_rect(close_tick, color);
+            } else {
+                let body = Rect::from_x_y_ranges(
+                    x - half_width..=x + half_width,
+                    open.min(close)..=open.max(close),
+                );
+                // Keep bodies of candles that closed where they opened visible:
+                let body = body.expand2(vec2(0.0, 0.5 * (1.0 - body.height()).at_least(0.0)));
+                mesh.add_colored_rect(body, color);
+            }
+        }
+
+        shapes.push(Shape::mesh(mesh));
+    }
+
     fn paint_axis(&self, ui: &Ui, axis: usize, shapes: &mut Vec<Shape>) {
         let Self { transform, .. } = self;
 
@@ -626,11 +1032,21 @@ impl Prepared {
             transform,
             show_x,
             show_y,
+            crosshair,
             curves,
             ..
         } = self;
 
-        if !show_x && !show_y {
+        if *crosshair {
+            self.paint_crosshair(ui, pointer, shapes);
+        }
+
+        if self.hover_candles(ui, pointer, shapes) || self.hover_intervals(ui, pointer, shapes) {
+            return;
+        }
+
+        // The crosshair replaces the lines to the closest value:
+        if *crosshair || (!show_x && !show_y) {
             return;
         }
 
@@ -638,8 +1054,11 @@ impl Prepared {
         let mut closest_value = None;
         let mut closest_curve = None;
         let mut closest_dist_sq = interact_radius.powi(2);
+        let pointer_x = transform.value_from_position(pointer).x;
+        let interact_radius_x = interact_radius as f64 / transform.dpos_dvalue_x().abs();
+        let x_range = (pointer_x - interact_radius_x)..=(pointer_x + interact_radius_x);
         for curve in curves {
-            for value in &curve.values {
+            for value in curve.values_in(x_range.clone()).into_iter().flatten() {
                 let pos = transform.position_from_value(value);
                 let dist_sq = pointer.distance_sq(pos);
                 if dist_sq < closest_dist_sq {
@@ -657,11 +1076,7 @@ impl Prepared {
             }
         }
 
-        let line_color = if ui.visuals().dark_mode {
-            Color32::from_gray(100).additive()
-        } else {
-            Color32::from_black_alpha(180)
-        };
+        let line_color = hover_line_color(ui);
 
         let value = if let Some(value) = closest_value {
             let position = transform.position_from_value(value);
@@ -716,4 +1131,274 @@ impl Prepared {
             ui.visuals().text_color(),
         ));
     }
+
+    /// Highlight and describe the bar under the pointer, if any. Returns `true` if there was one.
+    fn hover_intervals(&self, ui: &Ui, pointer: Pos2, shapes: &mut Vec<Shape>) -> bool {
+        let transform = &self.transform;
+        // The last one is painted on top:
+        let hovered = self
+            .intervals
+            .iter()
+            .flat_map(|intervals| {
+                intervals
+                    .intervals
+                    .iter()
+                    .map(move |interval| (intervals, interval))
+            })
+            .rev()
+            .find_map(|(intervals, interval)| {
+                let (min, max) = intervals.bar(interval);
+                let rect = Rect::from_two_pos(
+                    transform.position_from_value(&min),
+                    transform.position_from_value(&max),
+                );
+                rect.contains(pointer).then(|| (intervals, interval, rect))
+            });
+        let (intervals, interval, rect) = match hovered {
+            Some(hovered) => hovered,
+            None => return false,
+        };
+
+        let stroke = Stroke::new(2.0, ui.visuals().strong_text_color());
+        shapes.push(Shape::rect_stroke(rect, 2.0, stroke));
+
+        let decimals = ((-transform.dvalue_dpos()[0].abs().log10())
+            .ceil()
+            .at_least(0.0) as usize)
+            .at_most(6);
+        let mut text = String::new();
+        if !intervals.name.is_empty() {
+            text += &format!("{}\n", intervals.name);
+        }
+        if !interval.label.is_empty() {
+            text += &format!("{}\n", interval.label);
+        }
+        text += &format!(
+            "{:.*} – {:.*} ({:.*})",
+            decimals,
+            interval.start,
+            decimals,
+            interval.end,
+            decimals,
+            interval.end - interval.start
+        );
+        shapes.push(Shape::text(
+            ui.fonts(),
+            pointer + vec2(3.0, -2.0),
+            Align2::LEFT_BOTTOM,
+            text,
+            TextStyle::Body,
+            ui.visuals().text_color(),
+        ));
+        true
+    }
+
+    /// Lines through the pointer, with the values under it read off at the bottom and left edges.
+    fn paint_crosshair(&self, ui: &Ui, pointer: Pos2, shapes: &mut Vec<Shape>) {
+        let transform = &self.transform;
+        let rect = *transform.frame();
+        let line_color = hover_line_color(ui);
+        shapes.push(Shape::line_segment(
+            [pos2(pointer.x, rect.top()), pos2(pointer.x, rect.bottom())],
+            (1.0, line_color),
+        ));
+        shapes.push(Shape::line_segment(
+            [pos2(rect.left(), pointer.y), pos2(rect.right(), pointer.y)],
+            (1.0, line_color),
+        ));
+
+        let value = transform.value_from_position(pointer);
+        let scale = transform.dvalue_dpos();
+        let x_decimals = ((-scale[0].abs().log10()).ceil().at_least(0.0) as usize).at_most(6);
+        let y_decimals = ((-scale[1].abs().log10()).ceil().at_least(0.0) as usize).at_most(6);
+        let mut readout = |text: String, pos: Pos2, anchor: Align2| {
+            let galley = ui.fonts().layout_no_wrap(TextStyle::Small, text);
+            let text_rect = anchor.anchor_rect(Rect::from_min_size(pos, galley.size));
+            shapes.push(Shape::Rect {
+                rect: text_rect.expand(2.0),
+                corner_radius: 2.0,
+                fill: ui.visuals().window_fill(),
+                stroke: ui.visuals().window_stroke(),
+            });
+            shapes.push(Shape::Text {
+                pos: text_rect.min,
+                galley,
+                color: ui.visuals().text_color(),
+                fake_italics: false,
+            });
+        };
+        readout(
+            format!("{:.*}", x_decimals, value.x),
+            pos2(pointer.x, rect.bottom() - 2.0),
+            Align2::CENTER_BOTTOM,
+        );
+        readout(
+            format!("{:.*}", y_decimals, value.y),
+            pos2(rect.left() + 2.0, pointer.y),
+            Align2::LEFT_CENTER,
+        );
+    }
+
+    /// Highlight and describe the candle under the pointer, if any. Returns `true` if there was one.
+    fn hover_candles(&self, ui: &Ui, pointer: Pos2, shapes: &mut Vec<Shape>) -> bool {
+        let transform = &self.transform;
+        let x = transform.value_from_position(pointer).x;
+        // At least a few pixels, so zoomed out candles can still be hovered:
+        let pixel_width = transform.dvalue_dpos()[0].abs();
+        let hovered = self.candles.iter().rev().find_map(|candles| {
+            let max_distance = (0.5 * candles.width).max(3.0 * pixel_width);
+            candles
+                .closest(x, max_distance)
+                .map(|candle| (candles, candle))
+        });
+        let (candles, candle) = match hovered {
+            Some(hovered) => hovered,
+            None => return false,
+        };
+
+        let half_width = 0.5 * candles.width;
+        let rect = Rect::from_two_pos(
+            transform.position_from_value(&Value::new(candle.x - half_width, candle.low)),
+            transform.position_from_value(&Value::new(candle.x + half_width, candle.high)),
+        );
+        let stroke = Stroke::new(1.0, ui.visuals().strong_text_color());
+        shapes.push(Shape::rect_stroke(rect.expand(2.0), 2.0, stroke));
+
+        let decimals = ((-transform.dvalue_dpos()[1].abs().log10())
+            .ceil()
+            .at_least(0.0) as usize)
+            .at_most(6);
+        let mut text = String::new();
+        if !candles.name.is_empty() {
+            text += &format!("{}\n", candles.name);
+        }
+        text += &format!(
+            "O {:.*}  H {:.*}\nL {:.*}  C {:.*}",
+            decimals,
+            candle.open,
+            decimals,
+            candle.high,
+            decimals,
+            candle.low,
+            decimals,
+            candle.close
+        );
+        if candle.volume > 0.0 {
+            text += &format!("\nV {}", candle.volume);
+        }
+        shapes.push(Shape::text(
+            ui.fonts(),
+            pointer + vec2(3.0, -2.0),
+            Align2::LEFT_BOTTOM,
+            text,
+            TextStyle::Body,
+            ui.visuals().text_color(),
+        ));
+        true
+    }
+}
+
+/// Collect the legend entries. If multiple items have the same name, they share a
+/// checkbox. If their colors don't match, we pick a neutral color for the checkbox.
+fn legend_entries(
+    ui: &Ui,
+    curves: &[Curve],
+    intervals: &[Intervals],
+    candles: &[Candles],
+    hidden_curves: &HashSet<String>,
+) -> BTreeMap<String, LegendEntry> {
+    let named_items = curves
+        .iter()
+        .map(|curve| (&curve.name, curve.stroke.color))
+        .chain(
+            intervals
+                .iter()
+                .map(|intervals| (&intervals.name, intervals.color)),
+        )
+        .chain(
+            candles
+                .iter()
+                .map(|candles| (&candles.name, candles.up_color)),
+        )
+        .filter(|(name, _)| !name.is_empty());
+
+    let mut legend_entries: BTreeMap<String, LegendEntry> = BTreeMap::new();
+    for (name, color) in named_items {
+        let checked = !hidden_curves.contains(name);
+        legend_entries
+            .entry(name.clone())
+            .and_modify(|entry| {
+                if entry.color != color {
+                    entry.color = ui.visuals().noninteractive().fg_stroke.color
+                }
+            })
+            .or_insert_with(|| LegendEntry::new(name.clone(), color, checked));
+    }
+    legend_entries
+}
+
+/// Screen positions of the values, where each run of values within one pixel column
+/// is reduced to at most four points: the first, the lowest, the highest and the last.
+/// This keeps the shape of the curve while limiting the work for the tessellator.
+///
+/// Only columns with more than four values are reduced, so sparse curves are unaffected.
+fn downsample(values: &[&[Value]], transform: &ScreenTransform) -> Vec<Pos2> {
+    let count: usize = values.iter().map(|values| values.len()).sum();
+    if count <= 4 * transform.frame().width() as usize {
+        return values
+            .iter()
+            .flat_map(|values| values.iter())
+            .map(|value| transform.position_from_value(value))
+            .collect();
+    }
+
+    fn flush(column: &mut Vec<Pos2>, points: &mut Vec<Pos2>) {
+        if column.len() <= 4 {
+            points.append(column);
+            return;
+        }
+        let mut lowest = 0;
+        let mut highest = 0;
+        for (i, point) in column.iter().enumerate() {
+            // Screen y points down.
+            if point.y > column[lowest].y {
+                lowest = i;
+            }
+            if point.y < column[highest].y {
+                highest = i;
+            }
+        }
+        let mut indices = [0, lowest, highest, column.len() - 1];
+        indices.sort_unstable();
+        let mut previous = None;
+        for &i in &indices {
+            if previous != Some(i) {
+                points.push(column[i]);
+                previous = Some(i);
+            }
+        }
+        column.clear();
+    }
+
+    let mut points = Vec::new();
+    let mut column: Vec<Pos2> = Vec::new();
+    for value in values.iter().flat_map(|values| values.iter()) {
+        let point = transform.position_from_value(value);
+        if let Some(first) = column.first() {
+            if first.x.floor() != point.x.floor() {
+                flush(&mut column, &mut points);
+            }
+        }
+        column.push(point);
+    }
+    flush(&mut column, &mut points);
+    points
+}
+
+fn hover_line_color(ui: &Ui) -> Color32 {
+    if ui.visuals().dark_mode {
+        Color32::from_gray(100).additive()
+    } else {
+        Color32::from_black_alpha(180)
+    }
 }
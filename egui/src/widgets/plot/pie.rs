@@ -0,0 +1,363 @@
+use std::collections::HashSet;
+use std::f32::consts::TAU;
+
+use super::{auto_color, legend::LegendEntry};
+use crate::*;
+
+/// How far the hovered slice moves out of the pie.
+const POP_OUT: f32 = 6.0;
+
+/// Information about the pie chart that has to persist between frames.
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Default)]
+struct PieMemory {
+    hidden_slices: HashSet<String>,
+}
+
+/// A slice of a [`PieChart`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Slice {
+    pub(crate) name: String,
+    pub(crate) value: f64,
+    pub(crate) color: Color32,
+}
+
+impl Slice {
+    /// A slice whose size is proportional to `value`. Slices with a value of zero or less are not shown.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(name: impl ToString, value: impl Into<f64>) -> Self {
+        Self {
+            name: name.to_string(),
+            value: value.into(),
+            color: Color32::TRANSPARENT,
+        }
+    }
+
+    /// Fill color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self
+    }
+}
+
+/// A pie or donut chart.
+///
+/// Hovering a slice (or its legend entry) highlights it,
+/// and clicking a legend entry hides or shows the slice.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// use egui::plot::{PieChart, Slice};
+/// ui.add(
+///     PieChart::new("Fruit")
+///         .slice(Slice::new("Apples", 3.0))
+///         .slice(Slice::new("Pears", 2.0))
+///         .slice(Slice::new("Plums", 1.0))
+///         .donut(0.5),
+/// );
+/// ```
+pub struct PieChart {
+    name: String,
+    next_auto_color_idx: usize,
+    slices: Vec<Slice>,
+    hole: f32,
+    width: Option<f32>,
+    height: Option<f32>,
+    show_labels: bool,
+    show_legend: bool,
+}
+
+impl PieChart {
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(name: impl ToString) -> Self {
+        Self {
+            name: name.to_string(),
+            next_auto_color_idx: 0,
+            slices: Default::default(),
+            hole: 0.0,
+            width: None,
+            height: None,
+            show_labels: true,
+            show_legend: true,
+        }
+    }
+
+    /// Add a slice. Slices are laid out clockwise, starting at the top.
+    pub fn slice(mut self, mut slice: Slice) -> Self {
+        if slice.color == Color32::TRANSPARENT {
+            slice.color = auto_color(self.next_auto_color_idx);
+            self.next_auto_color_idx += 1;
+        }
+        self.slices.push(slice);
+        self
+    }
+
+    /// Turn the pie into a donut, with a hole of the given fraction of the radius, e.g. `0.5`.
+    /// Default: `0.0` (a pie).
+    pub fn donut(mut self, hole: f32) -> Self {
+        self.hole = hole.clamp(0.0, 0.95);
+        self
+    }
+
+    /// Width of the chart. By default it will fill the available width.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Height of the chart. By default it is half of the width.
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Show the slice names next to the slices, connected by leader lines. Default: `true`.
+    pub fn show_labels(mut self, show: bool) -> Self {
+        self.show_labels = show;
+        self
+    }
+
+    /// Show a legend that can be used to hide slices. Default: `true`.
+    pub fn show_legend(mut self, show: bool) -> Self {
+        self.show_legend = show;
+        self
+    }
+}
+
+/// A visible slice, with its angles in radians (clockwise from the positive x axis).
+struct Wedge<'a> {
+    index: usize,
+    slice: &'a Slice,
+    start: f32,
+    end: f32,
+}
+
+impl Wedge<'_> {
+    fn mid(&self) -> f32 {
+        0.5 * (self.start + self.end)
+    }
+}
+
+impl Widget for PieChart {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            name,
+            next_auto_color_idx: _,
+            slices,
+            hole,
+            width,
+            height,
+            show_labels,
+            show_legend,
+        } = self;
+
+        let pie_id = ui.make_persistent_id(name);
+        let PieMemory { mut hidden_slices } = ui
+            .memory()
+            .id_data
+            .get_or_default::<PieMemory>(pie_id)
+            .clone();
+
+        let width = width
+            .unwrap_or_else(|| ui.available_size_before_wrap_finite().x)
+            .at_least(64.0);
+        let height = height.unwrap_or(0.5 * width).at_least(64.0);
+        let (rect, response) = ui.allocate_exact_size(vec2(width, height), Sense::hover());
+
+        // --- Legend ---
+
+        let mut highlighted = None;
+        let mut pie_rect = rect;
+        if show_legend {
+            let mut legend_ui = ui.child_ui(rect, Layout::top_down(Align::LEFT));
+            for (index, slice) in slices.iter().enumerate() {
+                if slice.name.is_empty() {
+                    continue;
+                }
+                let checked = !hidden_slices.contains(&slice.name);
+                let mut entry = LegendEntry::new(slice.name.clone(), slice.color, checked);
+                legend_ui.add(&mut entry);
+                if entry.checked != checked {
+                    if entry.checked {
+                        hidden_slices.remove(&slice.name);
+                    } else {
+                        hidden_slices.insert(slice.name.clone());
+                    }
+                }
+                if entry.hovered {
+                    highlighted = Some(index);
+                }
+            }
+            pie_rect.min.x = legend_ui.min_rect().right() + ui.spacing().item_spacing.x;
+        }
+
+        // --- Layout ---
+
+        // Slices with a non-finite value are skipped, like empty ones:
+        let is_shown = |slice: &Slice| {
+            slice.value.is_finite() && slice.value > 0.0 && !hidden_slices.contains(&slice.name)
+        };
+        let total: f64 = slices
+            .iter()
+            .filter(|slice| is_shown(slice))
+            .map(|slice| slice.value)
+            .sum();
+        let mut wedges = Vec::new();
+        let mut angle = -TAU / 4.0; // Start at the top
+        for (index, slice) in slices.iter().enumerate() {
+            if is_shown(slice) {
+                let sweep = (slice.value / total) as f32 * TAU;
+                wedges.push(Wedge {
+                    index,
+                    slice,
+                    start: angle,
+                    end: angle + sweep,
+                });
+                angle += sweep;
+            }
+        }
+
+        let text_style = TextStyle::Small;
+        let row_height = ui.fonts().row_height(text_style);
+        let leader_length = 2.0 * POP_OUT;
+        let label_width = if show_labels {
+            wedges
+                .iter()
+                .map(|wedge| {
+                    ui.fonts()
+                        .layout_no_wrap(text_style, &wedge.slice.name)
+                        .size
+                        .x
+                })
+                .fold(0.0, f32::max)
+                + 2.0 * leader_length
+        } else {
+            POP_OUT
+        };
+        let label_height = if show_labels {
+            leader_length + row_height
+        } else {
+            POP_OUT
+        };
+        let center = pie_rect.center();
+        let radius = (0.5 * pie_rect.width() - label_width)
+            .min(0.5 * pie_rect.height() - label_height)
+            .at_least(8.0);
+        let hole_radius = hole * radius;
+
+        let mut hovered = None;
+        if let Some(pointer) = response.hover_pos() {
+            let delta = pointer - center;
+            if (hole_radius..=radius + POP_OUT).contains(&delta.length()) {
+                // Bring the pointer angle into the range covered by the wedges:
+                let angle = (delta.angle() + TAU / 4.0).rem_euclid(TAU) - TAU / 4.0;
+                if let Some(wedge) = wedges
+                    .iter()
+                    .find(|wedge| wedge.start <= angle && angle < wedge.end)
+                {
+                    hovered = Some((pointer, wedge.slice));
+                    highlighted = Some(wedge.index);
+                }
+            }
+        }
+
+        // --- Painting ---
+
+        let painter = ui.painter().sub_region(rect);
+        let separator = Stroke::new(1.0, ui.visuals().extreme_bg_color);
+        let mut labels = [Vec::new(), Vec::new()]; // left and right
+        for wedge in &wedges {
+            let mid = Vec2::angled(wedge.mid());
+            let center = if highlighted == Some(wedge.index) {
+                center + POP_OUT * mid
+            } else {
+                center
+            };
+
+            let segments = (((wedge.end - wedge.start) / TAU * 64.0).ceil() as usize).at_least(1);
+            let arc = |radius: f32| {
+                (0..=segments).map(move |i| {
+                    let t = i as f32 / segments as f32;
+                    center + radius * Vec2::angled(lerp(wedge.start..=wedge.end, t))
+                })
+            };
+
+            let mut mesh = epaint::Mesh::default();
+            for (outer, inner) in arc(radius).zip(arc(hole_radius)) {
+                mesh.colored_vertex(outer, wedge.slice.color);
+                mesh.colored_vertex(inner, wedge.slice.color);
+            }
+            for i in 0..segments as u32 {
+                let (outer, inner) = (2 * i, 2 * i + 1);
+                mesh.add_triangle(outer, outer + 2, inner + 2);
+                mesh.add_triangle(outer, inner + 2, inner);
+            }
+            painter.add(Shape::mesh(mesh));
+
+            let mut outline: Vec<Pos2> = arc(radius).collect();
+            if hole_radius > 0.0 {
+                outline.extend(arc(hole_radius).rev());
+            } else {
+                outline.push(center);
+            }
+            painter.add(Shape::closed_line(outline, separator));
+
+            if show_labels && !wedge.slice.name.is_empty() {
+                let side = (mid.x >= 0.0) as usize;
+                labels[side].push((
+                    wedge,
+                    center + radius * mid,
+                    center + (radius + leader_length) * mid,
+                ));
+            }
+        }
+
+        // Push labels apart so they don't overlap:
+        for labels in &mut labels {
+            labels.sort_by(|a, b| a.2.y.total_cmp(&b.2.y));
+            let mut min_y = f32::NEG_INFINITY;
+            for (wedge, anchor, elbow) in labels {
+                elbow.y = elbow.y.max(min_y);
+                min_y = elbow.y + row_height;
+
+                let sign = if anchor.x >= center.x { 1.0 } else { -1.0 };
+                let end = *elbow + vec2(sign * leader_length, 0.0);
+                let color = wedge.slice.color;
+                painter.add(Shape::line(vec![*anchor, *elbow, end], (1.0, color)));
+                let align = if sign > 0.0 {
+                    Align2::LEFT_CENTER
+                } else {
+                    Align2::RIGHT_CENTER
+                };
+                painter.text(
+                    end + vec2(sign * 2.0, 0.0),
+                    align,
+                    &wedge.slice.name,
+                    text_style,
+                    ui.visuals().text_color(),
+                );
+            }
+        }
+
+        if let Some((pointer, slice)) = hovered {
+            let mut text = String::new();
+            if !slice.name.is_empty() {
+                text += &format!("{}\n", slice.name);
+            }
+            text += &format!("{} ({:.1}%)", slice.value, 100.0 * slice.value / total);
+            painter.text(
+                pointer + vec2(3.0, -2.0),
+                Align2::LEFT_BOTTOM,
+                text,
+                TextStyle::Body,
+                ui.visuals().text_color(),
+            );
+        }
+
+        ui.memory()
+            .id_data
+            .insert(pie_id, PieMemory { hidden_slices });
+
+        response
+    }
+}
@@ -0,0 +1,492 @@
+use std::collections::HashSet;
+use std::f64::consts::TAU;
+
+use super::{auto_color, hover_line_color, items::Value, legend::LegendEntry, Curve};
+use crate::*;
+
+/// Information about the polar plot that has to persist between frames.
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Default)]
+struct PolarMemory {
+    hidden_items: HashSet<String>,
+}
+
+/// A closed shape with one value per axis of a [`PolarPlot::radar_axes`],
+/// for comparing several quantities at once. Also known as a spider chart.
+pub struct Radar {
+    pub(crate) values: Vec<f64>,
+    pub(crate) color: Color32,
+    pub(crate) fill: bool,
+    pub(crate) name: String,
+}
+
+impl Radar {
+    /// One value per axis, in the order of the axes. Missing values are shown as zero.
+    pub fn new(values: Vec<f64>) -> Self {
+        Self {
+            values,
+            color: Color32::TRANSPARENT,
+            fill: true,
+            name: Default::default(),
+        }
+    }
+
+    /// Line color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Fill the shape with a translucent version of its color. Default: `true`.
+    pub fn fill(mut self, fill: bool) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    /// Name of this radar.
+    ///
+    /// If a radar is given a name it will show up in the plot legend.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+}
+
+/// A plot in polar coordinates, with a circular grid.
+///
+/// [`Curve`]s are interpreted with `x` as the angle in radians,
+/// counter-clockwise from the right, and `y` as the radius.
+///
+/// With [`Self::radar_axes`] it instead shows one spoke per axis,
+/// for comparing [`Radar`]s.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// use egui::plot::{Curve, PolarPlot, Radar};
+/// let spiral = Curve::from_explicit_callback(|angle| angle, 0.0..=6.0, 256);
+/// ui.add(PolarPlot::new("Spiral").curve(spiral.name("spiral")));
+///
+/// ui.add(
+///     PolarPlot::new("Stats")
+///         .radar_axes(&["Speed", "Power", "Range", "Armor", "Cost"])
+///         .radar(Radar::new(vec![3.0, 4.0, 2.0, 5.0, 3.0]).name("Tank"))
+///         .radar(Radar::new(vec![5.0, 2.0, 4.0, 1.0, 2.0]).name("Scout")),
+/// );
+/// ```
+pub struct PolarPlot {
+    name: String,
+    next_auto_color_idx: usize,
+    curves: Vec<Curve>,
+    radars: Vec<Radar>,
+    radar_axes: Vec<String>,
+    max_radius: Option<f64>,
+    rings: usize,
+    width: Option<f32>,
+    height: Option<f32>,
+    show_legend: bool,
+}
+
+impl PolarPlot {
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(name: impl ToString) -> Self {
+        Self {
+            name: name.to_string(),
+            next_auto_color_idx: 0,
+            curves: Default::default(),
+            radars: Default::default(),
+            radar_axes: Default::default(),
+            max_radius: None,
+            rings: 4,
+            width: None,
+            height: None,
+            show_legend: true,
+        }
+    }
+
+    fn auto_color(&mut self, color: &mut Color32) {
+        if *color == Color32::TRANSPARENT {
+            *color = auto_color(self.next_auto_color_idx);
+            self.next_auto_color_idx += 1;
+        }
+    }
+
+    /// Add a data curve, with `x` as the angle in radians and `y` as the radius.
+    pub fn curve(mut self, mut curve: Curve) -> Self {
+        if !curve.no_data() {
+            self.auto_color(&mut curve.stroke.color);
+            self.curves.push(curve);
+        }
+        self
+    }
+
+    /// Add a radar. Use together with [`Self::radar_axes`].
+    pub fn radar(mut self, mut radar: Radar) -> Self {
+        self.auto_color(&mut radar.color);
+        self.radars.push(radar);
+        self
+    }
+
+    /// The names of the axes of the radars, clockwise from the top.
+    /// Replaces the angle grid with one spoke per axis.
+    pub fn radar_axes(mut self, axes: &[impl ToString]) -> Self {
+        self.radar_axes = axes.iter().map(ToString::to_string).collect();
+        self
+    }
+
+    /// The radius at the edge of the plot. By default it fits the data.
+    pub fn max_radius(mut self, max_radius: impl Into<f64>) -> Self {
+        self.max_radius = Some(max_radius.into());
+        self
+    }
+
+    /// Roughly how many rings to show in the grid. Default: `4`.
+    pub fn rings(mut self, rings: usize) -> Self {
+        self.rings = rings.at_least(1);
+        self
+    }
+
+    /// Width of the plot. By default it will fill the available width.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Height of the plot. By default it is the same as the width.
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Show a legend that can be used to hide curves and radars. Default: `true`.
+    pub fn show_legend(mut self, show: bool) -> Self {
+        self.show_legend = show;
+        self
+    }
+}
+
+/// Something that can be hovered, for showing its value.
+enum HoverTarget {
+    /// A value of the curve with the given index.
+    Curve(usize, Value),
+    /// The value on an axis of the radar with the given index.
+    Radar(usize, usize),
+}
+
+/// A step of 1, 2 or 5 times a power of ten, so that about `count` steps cover `max`.
+fn nice_step(max: f64, count: usize) -> f64 {
+    let rough = max / count as f64;
+    let magnitude = 10.0_f64.powf(rough.log10().floor());
+    [1.0, 2.0, 5.0, 10.0]
+        .iter()
+        .map(|factor| factor * magnitude)
+        .find(|step| *step >= rough)
+        .unwrap_or(rough)
+}
+
+impl Widget for PolarPlot {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            name,
+            next_auto_color_idx: _,
+            mut curves,
+            mut radars,
+            radar_axes,
+            max_radius,
+            rings,
+            width,
+            height,
+            show_legend,
+        } = self;
+
+        let plot_id = ui.make_persistent_id(name);
+        let PolarMemory { mut hidden_items } = ui
+            .memory()
+            .id_data
+            .get_or_default::<PolarMemory>(plot_id)
+            .clone();
+
+        let width = width
+            .unwrap_or_else(|| ui.available_size_before_wrap_finite().x)
+            .at_least(64.0);
+        let height = height.unwrap_or(width).at_least(64.0);
+        let (rect, response) = ui.allocate_exact_size(vec2(width, height), Sense::hover());
+
+        // --- Legend ---
+
+        let mut plot_rect = rect;
+        let mut highlighted = None;
+        let named_items: Vec<(String, Color32)> = curves
+            .iter()
+            .map(|curve| (curve.name.clone(), curve.stroke.color))
+            .chain(radars.iter().map(|radar| (radar.name.clone(), radar.color)))
+            .filter(|(name, _)| !name.is_empty())
+            .collect();
+        if show_legend && !named_items.is_empty() {
+            let mut legend_ui = ui.child_ui(rect, Layout::top_down(Align::LEFT));
+            let mut shown = HashSet::new();
+            for (name, color) in named_items {
+                if !shown.insert(name.clone()) {
+                    continue; // Items with the same name share a legend entry.
+                }
+                let checked = !hidden_items.contains(&name);
+                let mut entry = LegendEntry::new(name.clone(), color, checked);
+                legend_ui.add(&mut entry);
+                if entry.checked != checked {
+                    if entry.checked {
+                        hidden_items.remove(&name);
+                    } else {
+                        hidden_items.insert(name.clone());
+                    }
+                }
+                if entry.hovered {
+                    highlighted = Some(name);
+                }
+            }
+            plot_rect.min.x = legend_ui.min_rect().right() + ui.spacing().item_spacing.x;
+        }
+
+        if let Some(highlighted) = &highlighted {
+            curves
+                .iter_mut()
+                .filter(|curve| &curve.name == highlighted)
+                .for_each(|curve| curve.stroke.width *= 2.0);
+        }
+        curves.retain(|curve| !hidden_items.contains(&curve.name));
+        radars.retain(|radar| !hidden_items.contains(&radar.name));
+        curves
+            .iter_mut()
+            .for_each(|curve| curve.generate_points(0.0..=TAU));
+
+        // --- Layout ---
+
+        let max_radius = max_radius.unwrap_or_else(|| {
+            let curve_max = curves
+                .iter()
+                .map(|curve| curve.bounds.max[1].max(-curve.bounds.min[1]))
+                .fold(0.0, f64::max);
+            let radar_max = radars
+                .iter()
+                .flat_map(|radar| radar.values.iter().copied())
+                .fold(0.0, f64::max);
+            curve_max.max(radar_max)
+        });
+        let max_radius = if max_radius.is_finite() && max_radius > 0.0 {
+            max_radius
+        } else {
+            1.0
+        };
+        let ring_step = nice_step(max_radius, rings);
+        // Round the edge up to a whole ring:
+        let max_radius = ring_step * (max_radius / ring_step - 1e-9).ceil();
+        let ring_count = (max_radius / ring_step).round() as usize;
+
+        let text_style = TextStyle::Small;
+        let row_height = ui.fonts().row_height(text_style);
+        let label_margin = if radar_axes.is_empty() {
+            2.0 * row_height
+        } else {
+            radar_axes
+                .iter()
+                .map(|axis| ui.fonts().layout_no_wrap(text_style, axis).size.x)
+                .fold(2.0 * row_height, f32::max)
+                .at_most(0.25 * plot_rect.width())
+        };
+        let center = plot_rect.center();
+        let radius = (0.5 * plot_rect.width().min(plot_rect.height()) - label_margin).at_least(8.0);
+
+        let position = |angle: f64, r: f64| {
+            let length = (r / max_radius) as f32 * radius;
+            center + length * vec2(angle.cos() as f32, -(angle.sin() as f32))
+        };
+        // Radar axes go clockwise from the top:
+        let axis_angle = |i: usize| TAU / 4.0 - i as f64 * TAU / radar_axes.len() as f64;
+
+        // --- Grid ---
+
+        let painter = ui.painter().sub_region(rect);
+        let grid_stroke = ui.visuals().widgets.noninteractive.bg_stroke;
+        let label_color = ui.visuals().weak_text_color();
+
+        for ring in 1..=ring_count {
+            let r = ring as f64 * ring_step;
+            if radar_axes.is_empty() {
+                let ring_radius = (r / max_radius) as f32 * radius;
+                painter.add(Shape::circle_stroke(center, ring_radius, grid_stroke));
+            } else {
+                let web = (0..radar_axes.len())
+                    .map(|i| position(axis_angle(i), r))
+                    .collect();
+                painter.add(Shape::closed_line(web, grid_stroke));
+            }
+            let label_pos = position(TAU / 4.0, r);
+            painter.text(
+                label_pos + vec2(2.0, 0.0),
+                Align2::LEFT_BOTTOM,
+                emath::round_to_decimals(r, 6).to_string(),
+                text_style,
+                label_color,
+            );
+        }
+
+        let spokes: Vec<(f64, String)> = if radar_axes.is_empty() {
+            (0..12)
+                .map(|i| (i as f64 * TAU / 12.0, format!("{}°", i * 30)))
+                .collect()
+        } else {
+            radar_axes
+                .iter()
+                .enumerate()
+                .map(|(i, axis)| (axis_angle(i), axis.clone()))
+                .collect()
+        };
+        for (angle, label) in spokes {
+            let end = position(angle, max_radius);
+            painter.line_segment([center, end], grid_stroke);
+
+            let dir = (end - center).normalized();
+            let align = Align2([
+                if dir.x > 0.3 {
+                    Align::LEFT
+                } else if dir.x < -0.3 {
+                    Align::RIGHT
+                } else {
+                    Align::Center
+                },
+                if dir.y > 0.3 {
+                    Align::TOP
+                } else if dir.y < -0.3 {
+                    Align::BOTTOM
+                } else {
+                    Align::Center
+                },
+            ]);
+            painter.text(
+                end + 4.0 * dir,
+                align,
+                label,
+                text_style,
+                ui.visuals().text_color(),
+            );
+        }
+
+        // --- Items ---
+
+        // Screen positions of everything that can be hovered:
+        let mut points: Vec<(Pos2, HoverTarget)> = Vec::new();
+
+        for (index, curve) in curves.iter().enumerate() {
+            let stroke = curve.stroke;
+            let values: Vec<Value> = curve
+                .values_in(f64::NEG_INFINITY..=f64::INFINITY)
+                .into_iter()
+                .flatten()
+                .copied()
+                .collect();
+            let curve_points: Vec<Pos2> = values
+                .iter()
+                .map(|value| position(value.x, value.y))
+                .collect();
+            if curve_points.len() == 1 {
+                painter.add(Shape::circle_filled(
+                    curve_points[0],
+                    stroke.width / 2.0,
+                    stroke.color,
+                ));
+            } else {
+                painter.add(Shape::line(curve_points.clone(), stroke));
+            }
+            points.extend(
+                curve_points
+                    .into_iter()
+                    .zip(values)
+                    .map(|(pos, value)| (pos, HoverTarget::Curve(index, value))),
+            );
+        }
+
+        if !radar_axes.is_empty() {
+            for (index, radar) in radars.iter().enumerate() {
+                let vertices: Vec<Pos2> = (0..radar_axes.len())
+                    .map(|i| {
+                        let value = radar.values.get(i).copied().unwrap_or(0.0);
+                        position(axis_angle(i), value.at_least(0.0))
+                    })
+                    .collect();
+
+                if radar.fill {
+                    // The shape is not necessarily convex, but it is always star-shaped around the center:
+                    let fill = radar.color.linear_multiply(0.25);
+                    let mut mesh = epaint::Mesh::default();
+                    mesh.colored_vertex(center, fill);
+                    for vertex in &vertices {
+                        mesh.colored_vertex(*vertex, fill);
+                    }
+                    let n = vertices.len() as u32;
+                    for i in 0..n {
+                        mesh.add_triangle(0, 1 + i, 1 + (i + 1) % n);
+                    }
+                    painter.add(Shape::mesh(mesh));
+                }
+                let width = if highlighted.as_ref() == Some(&radar.name) {
+                    4.0
+                } else {
+                    2.0
+                };
+                painter.add(Shape::closed_line(
+                    vertices.clone(),
+                    Stroke::new(width, radar.color),
+                ));
+
+                for (i, vertex) in vertices.into_iter().enumerate() {
+                    painter.add(Shape::circle_filled(vertex, 2.5, radar.color));
+                    points.push((vertex, HoverTarget::Radar(index, i)));
+                }
+            }
+        }
+
+        // --- Hover ---
+
+        if let Some(pointer) = response.hover_pos() {
+            let interact_radius: f32 = 16.0;
+            let closest = points
+                .into_iter()
+                .map(|(pos, target)| (pointer.distance_sq(pos), pos, target))
+                .filter(|(dist_sq, _, _)| *dist_sq < interact_radius.powi(2))
+                .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+            if let Some((_, pos, target)) = closest {
+                let (name, value_text) = match target {
+                    HoverTarget::Curve(index, value) => (
+                        &curves[index].name,
+                        format!("θ = {:.1}°\nr = {:.3}", value.x.to_degrees(), value.y),
+                    ),
+                    HoverTarget::Radar(index, axis) => {
+                        let value = radars[index].values.get(axis).copied().unwrap_or(0.0);
+                        (
+                            &radars[index].name,
+                            format!("{}: {}", radar_axes[axis], value),
+                        )
+                    }
+                };
+                let text = if name.is_empty() {
+                    value_text
+                } else {
+                    format!("{}\n{}", name, value_text)
+                };
+                painter.add(Shape::circle_filled(pos, 3.0, hover_line_color(ui)));
+                painter.text(
+                    pos + vec2(3.0, -2.0),
+                    Align2::LEFT_BOTTOM,
+                    text,
+                    TextStyle::Body,
+                    ui.visuals().text_color(),
+                );
+            }
+        }
+
+        ui.memory()
+            .id_data
+            .insert(plot_id, PolarMemory { hidden_items });
+
+        response
+    }
+}
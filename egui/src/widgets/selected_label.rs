@@ -24,17 +24,18 @@ use crate::*;
 #[derive(Debug)]
 pub struct SelectableLabel {
     selected: bool,
-    text: String,
+    text: WidgetText,
     text_style: Option<TextStyle>,
+    id_source: Option<Id>,
 }
 
 impl SelectableLabel {
-    #[allow(clippy::needless_pass_by_value)]
-    pub fn new(selected: bool, text: impl ToString) -> Self {
+    pub fn new(selected: bool, text: impl Into<WidgetText>) -> Self {
         Self {
             selected,
-            text: text.to_string(),
+            text: text.into(),
             text_style: None,
+            id_source: None,
         }
     }
 
@@ -42,6 +43,12 @@ impl SelectableLabel {
         self.text_style = Some(text_style);
         self
     }
+
+    /// Base the `Id` on `id_source` instead of the position in the [`Ui`], see [`Ui::push_id`].
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
 }
 
 impl Widget for SelectableLabel {
@@ -50,6 +57,7 @@ impl Widget for SelectableLabel {
             selected,
             text,
             text_style,
+            id_source,
         } = self;
 
         let text_style = text_style
@@ -59,16 +67,13 @@ impl Widget for SelectableLabel {
         let button_padding = ui.spacing().button_padding;
         let total_extra = button_padding + button_padding;
 
-        let galley = if ui.wrap_text() {
-            ui.fonts()
-                .layout_multiline(text_style, text, ui.available_width() - total_extra.x)
-        } else {
-            ui.fonts().layout_no_wrap(text_style, text)
-        };
+        let wrap_width = Some(ui.available_width() - total_extra.x).filter(|_| ui.wrap_text());
+        let galley = text.layout(ui, text_style, wrap_width);
 
         let mut desired_size = total_extra + galley.size;
         desired_size.y = desired_size.y.at_least(ui.spacing().interact_size.y);
-        let (rect, response) = ui.allocate_at_least(desired_size, Sense::click());
+        let response = ui.allocate_response_with_id_source(id_source, desired_size, Sense::click());
+        let rect = response.rect;
         response.widget_info(|| {
             WidgetInfo::selected(WidgetType::SelectableLabel, selected, &galley.text)
         });
@@ -93,7 +98,7 @@ impl Widget for SelectableLabel {
             .visuals
             .override_text_color
             .unwrap_or_else(|| visuals.text_color());
-        ui.painter().galley(text_pos, galley, text_color);
+        text.paint_galley(ui, text_pos, galley, text_color);
         response
     }
 }
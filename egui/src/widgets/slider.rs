@@ -49,6 +49,10 @@ struct SliderSpec {
 /// ui.add(egui::Slider::new(&mut my_f32, 0.0..=100.0).text("My value"));
 /// ```
 ///
+/// Hold down shift while dragging for ten times finer adjustment.
+/// Ctrl/cmd-click the slider to type in an exact value.
+/// See also [`Slider::default_value`].
+///
 /// The default `Slider` size is set by [`crate::style::Spacing::slider_width`].
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 pub struct Slider<'a> {
@@ -64,6 +68,12 @@ pub struct Slider<'a> {
     text_color: Option<Color32>,
     min_decimals: usize,
     max_decimals: Option<usize>,
+    id_source: Option<Id>,
+    step: Option<f64>,
+    show_ticks: bool,
+    ticks: Vec<f64>,
+    snap_to_ticks: bool,
+    default_value: Option<f64>,
 }
 
 macro_rules! impl_integer_constructor {
@@ -152,6 +162,12 @@ impl<'a> Slider<'a> {
             text_color: None,
             min_decimals: 0,
             max_decimals: None,
+            id_source: None,
+            step: None,
+            show_ticks: false,
+            ticks: vec![],
+            snap_to_ticks: false,
+            default_value: None,
         }
     }
 
@@ -185,6 +201,12 @@ impl<'a> Slider<'a> {
         self
     }
 
+    /// Base the `Id` on `id_source` instead of the position in the [`Ui`], see [`Ui::push_id`].
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
     /// Make this a logarithmic slider.
     /// This is great for when the slider spans a huge range,
     /// e.g. from one to a million.
@@ -217,6 +239,49 @@ impl<'a> Slider<'a> {
         self
     }
 
+    /// Only allow values that are a whole number of `step`:s from the start of the range,
+    /// e.g. `Slider::new(&mut volume, 0.0..=1.0).step_by(0.1)`.
+    ///
+    /// The arrow keys move the value one step. A `step` of zero (the default) turns this off.
+    /// The user can hold down Alt while dragging for free adjustment.
+    pub fn step_by(mut self, step: f64) -> Self {
+        self.step = if step > 0.0 { Some(step) } else { None };
+        self
+    }
+
+    /// Show tick marks along the slider at each [`Self::step_by`], and at any custom [`Self::ticks`].
+    ///
+    /// Ticks that would be too close together to be useful are not shown.
+    /// Default: `false`.
+    pub fn show_ticks(mut self, show_ticks: bool) -> Self {
+        self.show_ticks = show_ticks;
+        self
+    }
+
+    /// Tick marks at these values, e.g. at some presets.
+    /// This also turns on [`Self::show_ticks`].
+    pub fn ticks(mut self, ticks: impl IntoIterator<Item = f64>) -> Self {
+        self.ticks = ticks.into_iter().collect();
+        self.show_ticks = true;
+        self
+    }
+
+    /// When dragging, snap to the nearest of the [`Self::ticks`].
+    /// The user can hold down Alt for free adjustment.
+    /// Default: `false`.
+    pub fn snap_to_ticks(mut self, snap_to_ticks: bool) -> Self {
+        self.snap_to_ticks = snap_to_ticks;
+        self
+    }
+
+    /// Right-clicking or double-clicking the slider resets it to this value.
+    ///
+    /// Without a default value, double-clicking lets the user type in a value instead (like ctrl/cmd-click).
+    pub fn default_value(mut self, default_value: f64) -> Self {
+        self.default_value = Some(default_value);
+        self
+    }
+
     /// Turn smart aim on/off. Default is ON.
     /// There is almost no point in turning this off.
     pub fn smart_aim(mut self, smart_aim: bool) -> Self {
@@ -277,6 +342,15 @@ impl<'a> Slider<'a> {
     }
 
     fn set_value(&mut self, mut value: f64) {
+        if let Some(step) = self.step {
+            let start = *self.range.start();
+            value = start + ((value - start) / step).round() * step;
+        }
+        self.set_value_without_step(value);
+    }
+
+    /// Like [`Self::set_value`], but without snapping to the [`Self::step_by`].
+    fn set_value_without_step(&mut self, mut value: f64) {
         if self.clamp_to_range {
             let start = *self.range.start();
             let end = *self.range.end();
@@ -300,6 +374,31 @@ impl<'a> Slider<'a> {
         self.range.clone()
     }
 
+    /// The tick closest to `value`, if any.
+    fn nearest_tick(&self, value: f64) -> Option<f64> {
+        self.ticks
+            .iter()
+            .copied()
+            .filter(|tick| tick.is_finite())
+            .min_by(|a, b| (a - value).abs().total_cmp(&(b - value).abs()))
+    }
+
+    /// The values to paint tick marks at.
+    fn tick_values(&self, x_range: &RangeInclusive<f32>) -> Vec<f64> {
+        let mut values = self.ticks.clone();
+        if let Some(step) = self.step {
+            let (start, end) = (*self.range.start(), *self.range.end());
+            let num_steps = ((end - start) / step).abs().floor();
+            let slider_width = (x_range.end() - x_range.start()) as f64;
+            const MIN_TICK_SPACING: f64 = 4.0; // points
+            if num_steps.is_finite() && num_steps * MIN_TICK_SPACING <= slider_width {
+                let step = step.copysign(end - start);
+                values.extend((0..=num_steps as usize).map(|i| start + i as f64 * step));
+            }
+        }
+        values
+    }
+
     /// For instance, `x` is the mouse position and `x_range` is the physical location of the slider on the screen.
     fn value_from_x(&self, x: f32, x_range: RangeInclusive<f32>) -> f64 {
         let normalized = remap_clamp(x, x_range, 0.0..=1.0) as f64;
@@ -312,6 +411,10 @@ impl<'a> Slider<'a> {
     }
 }
 
+fn inline_edit_id(response: &Response) -> Id {
+    response.id.with("inline_edit")
+}
+
 fn handle_radius(rect: &Rect) -> f32 {
     rect.height() / 2.5
 }
@@ -323,10 +426,9 @@ fn x_range(rect: &Rect) -> RangeInclusive<f32> {
 
 impl<'a> Slider<'a> {
     /// Just the slider, no text
-    #[allow(clippy::unused_self)]
     fn allocate_slider_space(&self, ui: &mut Ui, height: f32) -> Response {
         let desired_size = vec2(ui.spacing().slider_width, height);
-        ui.allocate_response(desired_size, Sense::click_and_drag())
+        ui.allocate_response_with_id_source(self.id_source, desired_size, Sense::click_and_drag())
     }
 
     /// Just the slider, no text
@@ -334,7 +436,28 @@ impl<'a> Slider<'a> {
         let rect = &response.rect;
         let x_range = x_range(rect);
 
-        if let Some(pointer_pos) = response.interact_pointer_pos() {
+        let modifiers = ui.input().modifiers;
+        let wants_reset = response.secondary_clicked() || response.double_clicked();
+        let wants_inline_edit = (response.clicked() && modifiers.command)
+            || (response.double_clicked() && self.default_value.is_none());
+        let is_pressing_primary = ui.input().pointer.button_down(PointerButton::Primary);
+
+        if let (true, Some(default_value)) = (wants_reset, self.default_value) {
+            self.set_value(default_value);
+        } else if wants_inline_edit {
+            ui.memory().request_focus(inline_edit_id(response));
+        } else if modifiers.shift && response.dragged() && !response.drag_started() {
+            // Fine adjustment: move the value at a tenth of the pointer speed.
+            // No smart aim or snapping, since that would keep the value from moving.
+            let value = self.get_value();
+            let x = self.x_from_value(value, x_range.clone()) + ui.input().pointer.delta().x / 10.0;
+            let new_value = self.value_from_x(x, x_range.clone());
+            self.set_value_without_step(new_value);
+        } else if let (Some(pointer_pos), true, false) = (
+            response.interact_pointer_pos(),
+            is_pressing_primary || response.clicked(),
+            modifiers.command,
+        ) {
             let new_value = if self.smart_aim {
                 let aim_radius = ui.input().aim_radius();
                 emath::smart_aim::best_in_range_f64(
@@ -344,7 +467,16 @@ impl<'a> Slider<'a> {
             } else {
                 self.value_from_x(pointer_pos.x, x_range.clone())
             };
-            self.set_value(new_value);
+            let free_adjustment = modifiers.alt;
+            let new_value = match self.nearest_tick(new_value) {
+                Some(tick) if self.snap_to_ticks && !free_adjustment => tick,
+                _ => new_value,
+            };
+            if free_adjustment {
+                self.set_value_without_step(new_value);
+            } else {
+                self.set_value(new_value);
+            }
         }
 
         let value = self.get_value();
@@ -354,7 +486,12 @@ impl<'a> Slider<'a> {
             let kb_step = ui.input().num_presses(Key::ArrowRight) as f32
                 - ui.input().num_presses(Key::ArrowLeft) as f32;
 
-            if kb_step != 0.0 {
+            if let (true, Some(step)) = (kb_step != 0.0, self.step) {
+                // Right is towards the end of the range:
+                let step = step.copysign(*self.range.end() - *self.range.start());
+                let new_value = self.get_value() + kb_step as f64 * step;
+                self.set_value(new_value);
+            } else if kb_step != 0.0 {
                 let prev_value = self.get_value();
                 let prev_x = self.x_from_value(prev_value, x_range.clone());
                 let new_x = prev_x + kb_step;
@@ -380,7 +517,7 @@ impl<'a> Slider<'a> {
                 pos2(rect.left(), rect.center().y - rail_radius),
                 pos2(rect.right(), rect.center().y + rail_radius),
             );
-            let marker_center_x = self.x_from_value(value, x_range);
+            let marker_center_x = self.x_from_value(value, x_range.clone());
 
             let visuals = ui.style().interact(response);
             ui.painter().add(Shape::Rect {
@@ -395,6 +532,22 @@ impl<'a> Slider<'a> {
                 // stroke: ui.visuals().widgets.inactive.bg_stroke,
             });
 
+            if self.show_ticks {
+                let tick_stroke = ui.visuals().widgets.noninteractive.bg_stroke;
+                for tick in self.tick_values(&x_range) {
+                    let x = ui
+                        .painter()
+                        .round_to_pixel(self.x_from_value(tick, x_range.clone()));
+                    ui.painter().line_segment(
+                        [
+                            pos2(x, rail_rect.bottom()),
+                            pos2(x, rect.bottom() - rail_radius),
+                        ],
+                        tick_stroke,
+                    );
+                }
+            }
+
             ui.painter().add(Shape::Circle {
                 center: pos2(marker_center_x, rail_rect.center().y),
                 radius: handle_radius(rect) + visuals.expansion,
@@ -404,6 +557,46 @@ impl<'a> Slider<'a> {
         }
     }
 
+    /// Lets the user type in a value in place of the slider.
+    fn inline_edit_ui(&mut self, ui: &mut Ui, response: &Response) {
+        let edit_id = inline_edit_id(response);
+        let localization = ui.ctx().localization();
+        let mut text = ui
+            .memory()
+            .id_data_temp
+            .get::<String>(&edit_id)
+            .cloned()
+            .unwrap_or_else(|| localization.format_number(self.get_value().to_string()));
+
+        let mut child_ui = ui.child_ui(
+            response.rect,
+            Layout::centered_and_justified(Direction::LeftToRight),
+        );
+        let edit_response = child_ui.add(
+            TextEdit::singleline(&mut text)
+                .id(edit_id)
+                .text_style(TextStyle::Monospace),
+        );
+
+        ui.memory().id_data_temp.insert(edit_id, text);
+        if edit_response.lost_focus() {
+            self.end_inline_edit(ui, edit_id);
+        }
+    }
+
+    /// Use the value the user typed in, unless they pressed escape.
+    fn end_inline_edit(&mut self, ui: &mut Ui, edit_id: Id) {
+        let text = ui.memory().id_data_temp.get::<String>(&edit_id).cloned();
+        ui.memory().id_data_temp.remove(&edit_id);
+        if ui.input().key_pressed(Key::Escape) {
+            return;
+        }
+        let localization = ui.ctx().localization();
+        if let Some(value) = text.and_then(|text| localization.parse_number(&text)) {
+            self.set_value(value);
+        }
+    }
+
     fn label_ui(&mut self, ui: &mut Ui) {
         if !self.text.is_empty() {
             let text_color = self.text_color.unwrap_or_else(|| ui.visuals().text_color());
@@ -413,15 +606,17 @@ impl<'a> Slider<'a> {
 
     fn value_ui(&mut self, ui: &mut Ui, x_range: RangeInclusive<f32>) {
         let mut value = self.get_value();
-        ui.add(
-            DragValue::new(&mut value)
-                .speed(self.current_gradient(&x_range))
-                .clamp_range(self.clamp_range())
-                .min_decimals(self.min_decimals)
-                .max_decimals_opt(self.max_decimals)
-                .suffix(self.suffix.clone())
-                .prefix(self.prefix.clone()),
-        );
+        let mut drag_value = DragValue::new(&mut value)
+            .speed(self.current_gradient(&x_range))
+            .clamp_range(self.clamp_range())
+            .min_decimals(self.min_decimals)
+            .max_decimals_opt(self.max_decimals)
+            .suffix(self.suffix.clone())
+            .prefix(self.prefix.clone());
+        if let Some(id_source) = self.id_source {
+            drag_value = drag_value.id_source(id_source.with("value"));
+        }
+        ui.add(drag_value);
         if value != self.get_value() {
             self.set_value(value);
         }
@@ -451,7 +646,20 @@ impl<'a> Widget for Slider<'a> {
 
         let inner_response = ui.horizontal(|ui| {
             let slider_response = self.allocate_slider_space(ui, height);
-            self.slider_ui(ui, &slider_response);
+            let edit_id = inline_edit_id(&slider_response);
+            let (is_editing, stopped_editing) = {
+                let memory = ui.memory();
+                (memory.has_focus(edit_id), memory.lost_focus(edit_id))
+            };
+            if is_editing {
+                self.inline_edit_ui(ui, &slider_response);
+            } else {
+                if stopped_editing {
+                    // e.g. the user clicked somewhere else
+                    self.end_inline_edit(ui, edit_id);
+                }
+                self.slider_ui(ui, &slider_response);
+            }
 
             if self.show_value {
                 let x_range = x_range(&slider_response.rect);
@@ -0,0 +1,86 @@
+use crate::*;
+
+/// Shows the progress of a [`Task`]: a progress bar, the latest message, and a cancel button.
+///
+/// See [`crate::task`] for an example.
+///
+/// The bar is painted from the state of the task when this is called,
+/// so show it every frame to have it update.
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct TaskProgress<'a> {
+    task: &'a Task,
+    cancel_button: bool,
+}
+
+impl<'a> TaskProgress<'a> {
+    pub fn new(task: &'a Task) -> Self {
+        Self {
+            task,
+            cancel_button: true,
+        }
+    }
+
+    /// Show a button for cancelling the task. Default: `true`.
+    pub fn cancel_button(mut self, cancel_button: bool) -> Self {
+        self.cancel_button = cancel_button;
+        self
+    }
+}
+
+impl<'a> Widget for TaskProgress<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let TaskProgress {
+            task,
+            cancel_button,
+        } = self;
+
+        let progress = task.progress();
+        let message = task.message();
+        let finished = task.is_finished();
+        let cancelled = task.is_cancelled();
+
+        ui.horizontal(|ui| {
+            let size = vec2(ui.spacing().slider_width, ui.spacing().interact_size.y);
+            let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+
+            let visuals = ui.visuals();
+            let corner_radius = visuals.widgets.inactive.corner_radius;
+            let mut filled = rect;
+            filled.max.x = lerp(rect.left()..=rect.right(), progress);
+            ui.painter()
+                .rect_filled(rect, corner_radius, visuals.extreme_bg_color);
+            ui.painter()
+                .rect_filled(filled, corner_radius, visuals.selection.bg_fill);
+            ui.painter().text(
+                rect.center(),
+                Align2::CENTER_CENTER,
+                format!("{:.0}%", 100.0 * progress),
+                TextStyle::Button,
+                visuals.text_color(),
+            );
+
+            if !message.is_empty() {
+                ui.label(&message);
+            }
+
+            if cancel_button && !finished {
+                let text = if cancelled {
+                    ui.ctx().tr("Cancelling…")
+                } else {
+                    ui.ctx().tr("Cancel")
+                };
+                if ui.add(Button::new(text).enabled(!cancelled)).clicked() {
+                    task.cancel();
+                }
+            }
+
+            response.widget_info(|| {
+                WidgetInfo::labeled(
+                    WidgetType::Other,
+                    format!("{:.0}% {}", 100.0 * progress, message),
+                )
+            });
+        })
+        .response
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{util::undoer::Undoer, *};
 use epaint::{text::cursor::*, *};
 use std::ops::Range;
+use unicode_segmentation::UnicodeSegmentation as _;
 
 #[derive(Clone, Debug, Default)]
 #[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
@@ -14,6 +15,18 @@ pub(crate) struct State<S: TextBuffer> {
     // If IME candidate window is shown on this text edit.
     #[cfg_attr(feature = "persistence", serde(skip))]
     has_ime: bool,
+
+    /// Which of the suggestions is selected with the arrow keys (if any).
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    suggestion_index: Option<usize>,
+
+    /// Was the suggestion popup shown last frame?
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    suggestion_popup_open: bool,
+
+    /// The user closed the suggestion popup with escape. It opens again when the text changes.
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    suggestions_closed: bool,
 }
 
 #[derive(Clone, Copy, Debug, Default)]
@@ -155,6 +168,72 @@ impl TextBuffer for String {
     }
 }
 
+// ----------------------------------------------------------------------------
+
+/// How serious a [`TextDiagnostic`] is. Decides the color of the underline.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiagnosticSeverity {
+    /// Painted with [`crate::style::Visuals::error_fg_color`].
+    Error,
+    /// Painted with [`crate::style::Visuals::warn_fg_color`].
+    Warning,
+}
+
+/// A squiggly underline on some of the text in a [`TextEdit`], e.g. a spelling mistake or a lint.
+///
+/// Hovering the underlined text shows the message.
+/// See [`TextEdit::diagnostics`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextDiagnostic {
+    /// Which characters to underline. Character indices, not byte indices.
+    pub char_range: Range<usize>,
+    pub severity: DiagnosticSeverity,
+    pub message: String,
+}
+
+/// A completion offered in the popup of [`TextEdit::suggestions`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextSuggestion {
+    /// Shown in the popup, and inserted at the caret when picked.
+    pub text: String,
+    /// How many characters before the caret to replace with [`Self::text`],
+    /// e.g. the start of the word the user has typed so far.
+    pub replace_before: usize,
+}
+
+/// See [`TextEdit::suggestions`].
+type DynSuggestionsFn<'t> = dyn 't + Fn(&str, usize) -> Vec<TextSuggestion>;
+
+struct SuggestionsFn<'t>(Box<DynSuggestionsFn<'t>>);
+
+impl std::fmt::Debug for SuggestionsFn<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SuggestionsFn")
+    }
+}
+
+impl TextDiagnostic {
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn error(char_range: Range<usize>, message: impl ToString) -> Self {
+        Self {
+            char_range,
+            severity: DiagnosticSeverity::Error,
+            message: message.to_string(),
+        }
+    }
+
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn warning(char_range: Range<usize>, message: impl ToString) -> Self {
+        Self {
+            char_range,
+            severity: DiagnosticSeverity::Warning,
+            message: message.to_string(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// A text region that the user can edit the contents of.
 ///
 /// See also [`Ui::text_edit_singleline`] and  [`Ui::text_edit_multiline`].
@@ -196,7 +275,14 @@ pub struct TextEdit<'t, S: TextBuffer = String> {
     enabled: bool,
     desired_width: Option<f32>,
     desired_height_rows: usize,
+    max_height_rows: Option<usize>,
     lock_focus: bool,
+    valid: bool,
+    error_message: Option<String>,
+    suggestions: Option<SuggestionsFn<'t>>,
+    diagnostics: Vec<TextDiagnostic>,
+    find_bar: bool,
+    find_highlight: Option<FindHighlight>,
 }
 impl<'t, S: TextBuffer> TextEdit<'t, S> {
     pub fn cursor(ui: &Ui, id: Id) -> Option<CursorPair> {
@@ -228,7 +314,14 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
             enabled: true,
             desired_width: None,
             desired_height_rows: 1,
+            max_height_rows: None,
             lock_focus: false,
+            valid: true,
+            error_message: None,
+            suggestions: Default::default(),
+            diagnostics: Default::default(),
+            find_bar: false,
+            find_highlight: None,
         }
     }
 
@@ -247,7 +340,14 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
             enabled: true,
             desired_width: None,
             desired_height_rows: 4,
+            max_height_rows: None,
             lock_focus: false,
+            valid: true,
+            error_message: None,
+            suggestions: Default::default(),
+            diagnostics: Default::default(),
+            find_bar: false,
+            find_highlight: None,
         }
     }
 
@@ -325,6 +425,120 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
         self
     }
 
+    /// A multiline `TextEdit` grows to fit its contents.
+    /// Set this to stop it from growing beyond the given number of rows
+    /// and show a scroll bar instead.
+    ///
+    /// Default: `None` (grow forever). Has no effect on singleline text.
+    pub fn max_rows(mut self, max_height_rows: usize) -> Self {
+        self.max_height_rows = Some(max_height_rows);
+        self
+    }
+
+    /// Mark the contents as valid or invalid.
+    ///
+    /// An invalid `TextEdit` gets its frame painted with [`crate::style::Visuals::error_fg_color`].
+    /// You decide what is valid, e.g.:
+    ///
+    /// ```
+    /// # let mut ui = egui::Ui::__test();
+    /// # let mut age = String::new();
+    /// let valid = age.parse::<u32>().is_ok();
+    /// ui.add(egui::TextEdit::singleline(&mut age).hint_text("Age").valid(valid));
+    /// ```
+    ///
+    /// Default: `true`.
+    pub fn valid(mut self, valid: bool) -> Self {
+        self.valid = valid;
+        self
+    }
+
+    /// Mark the contents as invalid (see [`Self::valid`]) and say why.
+    ///
+    /// The message is shown when hovering the `TextEdit`.
+    ///
+    /// ```
+    /// # let mut ui = egui::Ui::__test();
+    /// # let mut age = String::new();
+    /// let error = age.parse::<u32>().err();
+    /// let mut text_edit = egui::TextEdit::singleline(&mut age);
+    /// if let Some(error) = error {
+    ///     text_edit = text_edit.error_message(error);
+    /// }
+    /// ui.add(text_edit);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn error_message(mut self, message: impl ToString) -> Self {
+        self.valid = false;
+        self.error_message = Some(message.to_string());
+        self
+    }
+
+    /// Show a popup with completions below the text edit.
+    ///
+    /// `suggestions` is called with the text and the character index of the caret,
+    /// and returns the completions to show (if any).
+    /// The user can pick a suggestion with the mouse, or with the up/down arrow keys and enter,
+    /// and close the popup with escape.
+    /// The picked suggestion is inserted at the caret.
+    ///
+    /// ```
+    /// # let mut ui = egui::Ui::__test();
+    /// # let mut query = String::new();
+    /// let fruits = ["apple", "banana", "cherry"];
+    /// ui.add(egui::TextEdit::singleline(&mut query).suggestions(|text, caret| {
+    ///     let before_caret: String = text.chars().take(caret).collect();
+    ///     let word = before_caret.rsplit(' ').next().unwrap_or_default();
+    ///     if word.is_empty() {
+    ///         return vec![];
+    ///     }
+    ///     fruits
+    ///         .iter()
+    ///         .filter(|fruit| fruit.starts_with(word) && **fruit != word)
+    ///         .map(|fruit| egui::TextSuggestion {
+    ///             text: fruit.to_string(),
+    ///             replace_before: word.chars().count(),
+    ///         })
+    ///         .collect()
+    /// }));
+    /// ```
+    pub fn suggestions(
+        mut self,
+        suggestions: impl 't + Fn(&str, usize) -> Vec<TextSuggestion>,
+    ) -> Self {
+        self.suggestions = Some(SuggestionsFn(Box::new(suggestions)));
+        self
+    }
+
+    /// Underline parts of the text, e.g. spelling mistakes or lints, and show a message when hovered.
+    ///
+    /// The diagnostics are not stored, so you need to supply them each frame
+    /// (and update them when the text changes).
+    ///
+    /// ```
+    /// # let mut ui = egui::Ui::__test();
+    /// # let mut code = String::from("let x = 1");
+    /// let mut diagnostics = vec![];
+    /// if !code.ends_with(';') {
+    ///     let len = code.chars().count();
+    ///     diagnostics.push(egui::TextDiagnostic::error(0..len, "missing semicolon"));
+    /// }
+    /// ui.add(egui::TextEdit::multiline(&mut code).diagnostics(diagnostics));
+    /// ```
+    pub fn diagnostics(mut self, diagnostics: impl IntoIterator<Item = TextDiagnostic>) -> Self {
+        self.diagnostics = diagnostics.into_iter().collect();
+        self
+    }
+
+    /// Let the user open a find-and-replace bar with ctrl/cmd+F (only for multiline text).
+    ///
+    /// Matches are highlighted, F3 and shift+F3 go to the next and previous match,
+    /// and escape closes the bar again.
+    pub fn find_bar(mut self, find_bar: bool) -> Self {
+        self.find_bar = find_bar;
+        self
+    }
+
     /// When `false` (default), pressing TAB will move focus
     /// to the next widget.
     ///
@@ -337,21 +551,85 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
 }
 
 impl<'t, S: TextBuffer> Widget for TextEdit<'t, S> {
-    fn ui(self, ui: &mut Ui) -> Response {
+    fn ui(mut self, ui: &mut Ui) -> Response {
         let frame = self.frame;
+        let valid = self.valid;
+        let error_message = self.error_message.take();
         let where_to_put_background = ui.painter().add(Shape::Noop);
 
         let margin = Vec2::new(4.0, 2.0);
-        let max_rect = ui.available_rect_before_wrap().shrink2(margin);
+        let mut max_rect = ui.available_rect_before_wrap().shrink2(margin);
+
+        let find_bar_response = if self.find_bar && self.multiline && !self.password {
+            let id = self.id.unwrap_or_else(|| match self.id_source {
+                Some(id_source) => ui.make_persistent_id(id_source),
+                None => ui.next_auto_id(),
+            });
+            self.id = Some(id);
+            let mut bar_ui = ui.child_ui(max_rect, Layout::top_down(Align::Min));
+            let bar_response = self.find_bar_ui(&mut bar_ui, id);
+            if let Some(bar_response) = &bar_response {
+                max_rect.min.y = bar_response.rect.bottom() + ui.spacing().item_spacing.y;
+            }
+            bar_response
+        } else {
+            None
+        };
+
         let mut content_ui = ui.child_ui(max_rect, *ui.layout());
-        let response = self.content_ui(&mut content_ui);
-        let frame_rect = response.rect.expand2(margin);
-        let response = response | ui.allocate_rect(frame_rect, Sense::hover());
+
+        let max_height = self.max_height_rows.filter(|_| self.multiline).map(|rows| {
+            let text_style = self
+                .text_style
+                .or(ui.style().override_text_style)
+                .unwrap_or_else(|| ui.style().body_text_style);
+            rows.at_least(1) as f32 * ui.fonts().row_height(text_style)
+        });
+
+        let response = if let Some(max_height) = max_height {
+            let scroll_id_source = self
+                .id
+                .or(self.id_source)
+                .unwrap_or_else(|| ui.next_auto_id())
+                .with("scroll");
+            let mut response = ScrollArea::from_max_height(max_height)
+                .id_source(scroll_id_source)
+                .show(&mut content_ui, |ui| self.content_ui(ui));
+            // Only the visible part:
+            response.rect = response.rect.intersect(content_ui.min_rect());
+            response
+        } else {
+            self.content_ui(&mut content_ui)
+        };
+        let mut frame_rect = response.rect.expand2(margin);
+        if let Some(bar_response) = &find_bar_response {
+            frame_rect = frame_rect.union(bar_response.rect.expand2(margin));
+        }
+        let mut response = response | ui.allocate_rect(frame_rect, Sense::hover());
+        if let Some(find_bar_response) = find_bar_response {
+            if find_bar_response.changed() {
+                response.mark_changed();
+            }
+            if response.changed() {
+                // The matches were found in the old text:
+                ui.ctx().request_repaint();
+            }
+        }
 
         if frame {
             let visuals = ui.style().interact(&response);
             let frame_rect = response.rect.expand(visuals.expansion);
-            let shape = if response.has_focus() {
+            let shape = if !valid {
+                Shape::Rect {
+                    rect: frame_rect,
+                    corner_radius: visuals.corner_radius,
+                    fill: ui.visuals().extreme_bg_color,
+                    stroke: Stroke::new(
+                        ui.visuals().selection.stroke.width.at_least(1.0),
+                        ui.visuals().error_fg_color,
+                    ),
+                }
+            } else if response.has_focus() {
                 Shape::Rect {
                     rect: frame_rect,
                     corner_radius: visuals.corner_radius,
@@ -371,11 +649,150 @@ impl<'t, S: TextBuffer> Widget for TextEdit<'t, S> {
             ui.painter().set(where_to_put_background, shape);
         }
 
+        if let Some(error_message) = error_message {
+            response = response.on_hover_text(error_message);
+        }
+
         response
     }
 }
 
 impl<'t, S: TextBuffer> TextEdit<'t, S> {
+    /// Shows the find-and-replace bar if it is open, and opens/closes it on ctrl/cmd+F/escape.
+    ///
+    /// The returned response is marked as changed if the text was changed by a replace.
+    fn find_bar_ui(&mut self, ui: &mut Ui, id: Id) -> Option<Response> {
+        let find_id = id.with("find");
+        let query_id = find_id.with("query");
+        let replacement_id = find_id.with("replacement");
+
+        let has_focus = {
+            let memory = ui.memory();
+            memory.has_focus(id) || memory.has_focus(query_id) || memory.has_focus(replacement_id)
+        };
+
+        let mut find = ui.memory().id_data_temp.get::<FindState>(&find_id).cloned();
+        let mut scroll_to_current = false;
+        if has_focus && ui.input().modifiers.command && ui.input().key_pressed(Key::F) {
+            find.get_or_insert_with(Default::default);
+            ui.memory().request_focus(query_id);
+            scroll_to_current = true;
+        }
+        let mut find = find?;
+
+        if has_focus && ui.input().key_pressed(Key::Escape) {
+            ui.memory().id_data_temp.remove(&find_id);
+            ui.memory().request_focus(id);
+            return None;
+        }
+
+        let mut matches = find_matches(self.text.as_ref(), &find.query);
+        let mut go_to_next = has_focus && ui.input().key_pressed(Key::F3);
+        let mut go_to_previous = go_to_next && ui.input().modifiers.shift;
+        go_to_next &= !go_to_previous;
+        let mut close = false;
+        let mut replace_current = false;
+        let mut replace_all = false;
+
+        let mut response = ui
+            .horizontal(|ui| {
+                let query_response = ui.add(
+                    TextEdit::singleline(&mut find.query)
+                        .id(query_id)
+                        .hint_text(ui.ctx().tr("Find"))
+                        .desired_width(160.0),
+                );
+                if query_response.changed() {
+                    matches = find_matches(self.text.as_ref(), &find.query);
+                    find.current = 0;
+                    scroll_to_current = true;
+                }
+                if query_response.lost_focus() && ui.input().key_pressed(Key::Enter) {
+                    go_to_next = true;
+                    ui.memory().request_focus(query_id);
+                }
+
+                if find.query.is_empty() {
+                    ui.label("");
+                } else if matches.is_empty() {
+                    ui.colored_label(ui.visuals().warn_fg_color, ui.ctx().tr("No results"));
+                } else {
+                    ui.label(format!("{}/{}", find.current + 1, matches.len()));
+                }
+
+                go_to_previous |= ui
+                    .small_button("⏶")
+                    .on_hover_text(ui.ctx().tr("Previous match (shift+F3)"))
+                    .clicked();
+                go_to_next |= ui
+                    .small_button("⏷")
+                    .on_hover_text(ui.ctx().tr("Next match (F3)"))
+                    .clicked();
+                ui.checkbox(&mut find.replace, ui.ctx().tr("Replace"));
+                close = ui
+                    .small_button("✖")
+                    .on_hover_text(ui.ctx().tr("Close (Esc)"))
+                    .clicked();
+            })
+            .response;
+
+        if find.replace {
+            response |= ui
+                .horizontal(|ui| {
+                    ui.add(
+                        TextEdit::singleline(&mut find.replacement)
+                            .id(replacement_id)
+                            .hint_text(ui.ctx().tr("Replace"))
+                            .desired_width(160.0),
+                    );
+                    replace_current = ui.button(ui.ctx().tr("Replace")).clicked();
+                    replace_all = ui.button(ui.ctx().tr("Replace all")).clicked();
+                })
+                .response;
+        }
+
+        if close {
+            ui.memory().id_data_temp.remove(&find_id);
+            ui.memory().request_focus(id);
+            return None;
+        }
+
+        if !matches.is_empty() {
+            let n = matches.len();
+            find.current = find.current.min(n - 1);
+            if go_to_next {
+                find.current = (find.current + 1) % n;
+                scroll_to_current = true;
+            } else if go_to_previous {
+                find.current = (find.current + n - 1) % n;
+                scroll_to_current = true;
+            }
+
+            if replace_current {
+                replace_char_range(self.text, matches[find.current].clone(), &find.replacement);
+                response.mark_changed();
+                scroll_to_current = true;
+            } else if replace_all {
+                for char_range in matches.iter().rev() {
+                    replace_char_range(self.text, char_range.clone(), &find.replacement);
+                }
+                response.mark_changed();
+            }
+            if response.changed() {
+                matches = find_matches(self.text.as_ref(), &find.query);
+                find.current = find.current.min(matches.len().saturating_sub(1));
+            }
+        }
+
+        self.find_highlight = Some(FindHighlight {
+            current: Some(find.current).filter(|&current| current < matches.len()),
+            matches,
+            scroll_to_current,
+        });
+        ui.memory().id_data_temp.insert(find_id, find);
+        Some(response)
+    }
+
     fn content_ui(self, ui: &mut Ui) -> Response {
         let TextEdit {
             text,
@@ -390,8 +807,24 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
             enabled,
             desired_width,
             desired_height_rows,
+            max_height_rows: _,
             lock_focus,
+            valid: _,
+            error_message: _,
+            suggestions,
+            diagnostics,
+            find_bar: _,
+            find_highlight,
         } = self;
+        let suggestions = suggestions.filter(|_| !password);
+        let get_suggestions = |text: &str, caret: usize| match &suggestions {
+            Some(suggestions) => {
+                let mut suggestions = (suggestions.0)(text, caret);
+                suggestions.truncate(MAX_SUGGESTIONS);
+                suggestions
+            }
+            None => vec![],
+        };
 
         let text_style = text_style
             .or(ui.style().override_text_style)
@@ -400,12 +833,13 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
         let available_width = ui.available_width();
 
         let make_galley = |ui: &Ui, text: &str| {
-            let text = if password {
+            let text: std::borrow::Cow<'_, str> = if password {
                 std::iter::repeat(epaint::text::PASSWORD_REPLACEMENT_CHAR)
                     .take(text.chars().count())
                     .collect::<String>()
+                    .into()
             } else {
-                text.to_owned()
+                text.into()
             };
             if multiline {
                 ui.fonts()
@@ -439,6 +873,7 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
             }
         });
         let mut state = ui.memory().id_data.get_or_default::<State<S>>(id).clone();
+        let prev_cursorp = state.cursorp.map(|cursorp| cursorp.as_ccursorp());
 
         let sense = if enabled {
             Sense::click_and_drag()
@@ -447,6 +882,26 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
         };
         let mut response = ui.interact(rect, id, sense);
 
+        let suggestion_popup_id = id.with("suggestions");
+        if state.suggestion_popup_open && ui.memory().had_focus_last_frame(id) {
+            if ui.input().key_pressed(Key::Escape) {
+                // Escape closes the suggestions, but should not make us lose focus:
+                ui.memory().request_focus(id);
+                state.suggestions_closed = true;
+            }
+
+            let pointer = &ui.input().pointer;
+            let pointer_layer = pointer
+                .interact_pos()
+                .and_then(|pos| ui.ctx().layer_id_at(pos));
+            if pointer.any_click()
+                && pointer_layer.map(|layer| layer.id) == Some(suggestion_popup_id)
+            {
+                // Clicking a suggestion should not make us lose focus:
+                ui.memory().request_focus(id);
+            }
+        }
+
         if enabled {
             if let Some(pointer_pos) = ui.input().pointer.interact_pos() {
                 // TODO: triple-click to select whole paragraph
@@ -522,8 +977,46 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
                 .undoer
                 .feed_state(ui.input().time, &(cursorp.as_ccursorp(), text.clone()));
 
+            let text_before_events = text.clone();
+            let ccursorp_before_events = cursorp.as_ccursorp();
+            let shown_suggestions = if state.suggestions_closed {
+                vec![]
+            } else {
+                get_suggestions(text.as_ref(), cursorp.primary.ccursor.index)
+            };
+            let selected_suggestion = state
+                .suggestion_index
+                .and_then(|index| shown_suggestions.get(index));
+
             for event in &ui.input().events {
                 let did_mutate_text = match event {
+                    Event::Key {
+                        key: Key::ArrowDown,
+                        pressed: true,
+                        ..
+                    } if !shown_suggestions.is_empty() => {
+                        let n = shown_suggestions.len();
+                        state.suggestion_index =
+                            Some(state.suggestion_index.map_or(0, |i| (i + 1) % n));
+                        None
+                    }
+                    Event::Key {
+                        key: Key::ArrowUp,
+                        pressed: true,
+                        ..
+                    } if !shown_suggestions.is_empty() => {
+                        let n = shown_suggestions.len();
+                        state.suggestion_index =
+                            Some(state.suggestion_index.map_or(n - 1, |i| (i + n - 1) % n));
+                        None
+                    }
+                    Event::Key {
+                        key: Key::Enter,
+                        pressed: true,
+                        ..
+                    } if selected_suggestion.is_some() => selected_suggestion
+                        .map(|suggestion| insert_suggestion(text, &cursorp, suggestion)),
+
                     Event::Copy => {
                         if cursorp.is_empty() {
                             copy_if_not_password(ui, text.as_ref().to_owned());
@@ -668,16 +1161,63 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
             }
             state.cursorp = Some(cursorp);
 
+            if text.as_ref() != text_before_events.as_ref() {
+                state.suggestion_index = None;
+                state.suggestions_closed = false;
+            } else if cursorp.as_ccursorp() != ccursorp_before_events {
+                state.suggestion_index = None;
+            }
+
             state
                 .undoer
                 .feed_state(ui.input().time, &(cursorp.as_ccursorp(), text.clone()));
         }
 
+        if let Some(find_highlight) = &find_highlight {
+            paint_find_highlight(ui, response.rect.min, &galley, find_highlight);
+        }
+
+        state.suggestion_popup_open = false;
+        if let Some(cursorp) = state.cursorp {
+            if ui.memory().has_focus(id) && !state.suggestions_closed {
+                let shown_suggestions =
+                    get_suggestions(text.as_ref(), cursorp.primary.ccursor.index);
+                if !shown_suggestions.is_empty() {
+                    state.suggestion_popup_open = true;
+                    let picked = suggestion_popup_ui(
+                        ui,
+                        suggestion_popup_id,
+                        response.rect,
+                        &shown_suggestions,
+                        state.suggestion_index,
+                    );
+                    if let Some(picked) = picked {
+                        let ccursorp =
+                            insert_suggestion(text, &cursorp, &shown_suggestions[picked]);
+                        galley = make_galley(ui, text.as_ref());
+                        state.cursorp =
+                            Some(CursorPair::one(galley.from_ccursor(ccursorp.primary)));
+                        state.suggestion_index = None;
+                        response.mark_changed();
+                        ui.ctx().request_repaint();
+                    }
+                }
+            }
+        }
+
         if ui.memory().has_focus(id) {
             if let Some(cursorp) = state.cursorp {
                 paint_cursor_selection(ui, response.rect.min, &galley, &cursorp);
                 paint_cursor_end(ui, response.rect.min, &galley, &cursorp.primary);
 
+                if multiline && Some(cursorp.as_ccursorp()) != prev_cursorp {
+                    // Keep the cursor visible, e.g. when we are inside a `ScrollArea`:
+                    let cursor_rect = galley
+                        .pos_from_cursor(&cursorp.primary)
+                        .translate(response.rect.min.to_vec2());
+                    ui.scroll_to_rect(cursor_rect, None);
+                }
+
                 if enabled {
                     ui.ctx().output().text_cursor_pos = Some(
                         galley
@@ -693,7 +1233,12 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
             .or(ui.visuals().override_text_color)
             // .unwrap_or_else(|| ui.style().interact(&response).text_color()); // too bright
             .unwrap_or_else(|| ui.visuals().widgets.inactive.text_color());
-        ui.painter().galley(response.rect.min, galley, text_color);
+        ui.painter()
+            .galley(response.rect.min, galley.clone(), text_color);
+
+        if !diagnostics.is_empty() && !password {
+            paint_diagnostics(ui, &response, &galley, &diagnostics);
+        }
 
         if text.as_ref().is_empty() && !hint_text.is_empty() {
             let galley = if multiline {
@@ -716,6 +1261,185 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
 
 // ----------------------------------------------------------------------------
 
+const MAX_SUGGESTIONS: usize = 10;
+
+/// Show the suggestions below the text edit. Returns the index of the one that was clicked, if any.
+fn suggestion_popup_ui(
+    ui: &Ui,
+    popup_id: Id,
+    text_edit_rect: Rect,
+    suggestions: &[TextSuggestion],
+    selected: Option<usize>,
+) -> Option<usize> {
+    let mut picked = None;
+    Area::new(popup_id)
+        .order(Order::Foreground)
+        .fixed_pos(text_edit_rect.left_bottom() + vec2(0.0, ui.spacing().item_spacing.y))
+        .show(ui.ctx(), |ui| {
+            Frame::popup(ui.style()).show(ui, |ui| {
+                ui.set_min_width(text_edit_rect.width());
+                ui.with_layout(Layout::top_down_justified(Align::LEFT), |ui| {
+                    for (i, suggestion) in suggestions.iter().enumerate() {
+                        let label = SelectableLabel::new(selected == Some(i), &suggestion.text);
+                        if ui.add(label).clicked() {
+                            picked = Some(i);
+                        }
+                    }
+                });
+            });
+        });
+    picked
+}
+
+/// Paint squiggly underlines below the diagnostics, and show the message of the hovered one.
+fn paint_diagnostics(
+    ui: &Ui,
+    response: &Response,
+    galley: &Galley,
+    diagnostics: &[TextDiagnostic],
+) {
+    let pos = response.rect.min;
+    let hover_pos = ui
+        .input()
+        .pointer
+        .hover_pos()
+        .filter(|_| response.hovered());
+    let mut hovered_messages = vec![];
+
+    for diagnostic in diagnostics {
+        let color = match diagnostic.severity {
+            DiagnosticSeverity::Error => ui.visuals().error_fg_color,
+            DiagnosticSeverity::Warning => ui.visuals().warn_fg_color,
+        };
+        let mut is_hovered = false;
+        for rect in char_range_rects(pos, galley, &diagnostic.char_range) {
+            paint_squiggle(ui, rect.left_bottom(), rect.right_bottom(), color);
+            is_hovered |= hover_pos.map_or(false, |hover_pos| rect.contains(hover_pos));
+        }
+
+        if is_hovered && !diagnostic.message.is_empty() {
+            hovered_messages.push((color, diagnostic.message.as_str()));
+        }
+    }
+
+    if !hovered_messages.is_empty() {
+        show_tooltip_at_pointer(ui.ctx(), response.id.with("diagnostics"), |ui| {
+            for (color, message) in hovered_messages {
+                ui.colored_label(color, message);
+            }
+        });
+    }
+}
+
+/// The rectangles (one per row) covering the characters in `char_range`.
+fn char_range_rects(pos: Pos2, galley: &Galley, char_range: &Range<usize>) -> Vec<Rect> {
+    let min = galley.from_ccursor(CCursor::new(char_range.start)).rcursor;
+    let max = galley.from_ccursor(CCursor::new(char_range.end)).rcursor;
+
+    let mut rects = vec![];
+    for ri in min.row..=max.row {
+        let row = &galley.rows[ri];
+        let left = if ri == min.row {
+            row.x_offset(min.column)
+        } else {
+            row.min_x()
+        };
+        let right = if ri == max.row {
+            row.x_offset(max.column)
+        } else {
+            row.max_x()
+        };
+        if left < right {
+            rects.push(Rect::from_min_max(
+                pos + vec2(left, row.y_min),
+                pos + vec2(right, row.y_max),
+            ));
+        }
+    }
+    rects
+}
+
+/// A zig-zag line from `left` to `right`, along the baseline of some text.
+fn paint_squiggle(ui: &Ui, left: Pos2, right: Pos2, color: Color32) {
+    let amplitude = 1.5;
+    let wavelength = 4.0;
+    let y = left.y - amplitude;
+    let mut points = vec![];
+    let mut x = left.x;
+    let mut up = false;
+    while x < right.x {
+        points.push(pos2(x, if up { y - amplitude } else { y + amplitude }));
+        x += wavelength / 2.0;
+        up = !up;
+    }
+    points.push(pos2(
+        right.x,
+        if up { y - amplitude } else { y + amplitude },
+    ));
+    ui.painter()
+        .add(Shape::line(points, Stroke::new(1.0, color)));
+}
+
+/// The state of an open find bar. Stored in `Memory::id_data_temp`.
+#[derive(Clone, Debug, Default)]
+struct FindState {
+    query: String,
+    replacement: String,
+    /// Show the replace row?
+    replace: bool,
+    /// Index of the current match.
+    current: usize,
+}
+
+/// What the find bar wants highlighted in the text.
+#[derive(Clone, Debug)]
+struct FindHighlight {
+    /// Character ranges.
+    matches: Vec<Range<usize>>,
+    current: Option<usize>,
+    scroll_to_current: bool,
+}
+
+/// The character ranges where `query` occurs in `text`.
+fn find_matches(text: &str, query: &str) -> Vec<Range<usize>> {
+    if query.is_empty() {
+        return vec![];
+    }
+    let query_len = query.chars().count();
+    let mut matches = vec![];
+    let mut char_index = 0;
+    let mut byte_index = 0;
+    for (match_byte_index, _) in text.match_indices(query) {
+        char_index += text[byte_index..match_byte_index].chars().count();
+        byte_index = match_byte_index;
+        matches.push(char_index..char_index + query_len);
+    }
+    matches
+}
+
+fn paint_find_highlight(ui: &Ui, pos: Pos2, galley: &Galley, find_highlight: &FindHighlight) {
+    let current_color = ui.visuals().selection.bg_fill;
+    let other_color = current_color.linear_multiply(0.4);
+    for (i, char_range) in find_highlight.matches.iter().enumerate() {
+        let is_current = find_highlight.current == Some(i);
+        let color = if is_current {
+            current_color
+        } else {
+            other_color
+        };
+        let rects = char_range_rects(pos, galley, char_range);
+        for rect in &rects {
+            ui.painter().rect_filled(*rect, 0.0, color);
+        }
+        if is_current && find_highlight.scroll_to_current {
+            if let Some(rect) = rects.first() {
+                // Keep the match visible, e.g. when we are inside a `ScrollArea`:
+                ui.scroll_to_rect(*rect, Some(Align::Center));
+            }
+        }
+    }
+}
+
 fn paint_cursor_selection(ui: &mut Ui, pos: Pos2, galley: &Galley, cursorp: &CursorPair) {
     let color = ui.visuals().selection.bg_fill;
     if cursorp.is_empty() {
@@ -794,6 +1518,28 @@ fn byte_index_from_char_index(s: &str, char_index: usize) -> usize {
     s.len()
 }
 
+/// Replace the characters in `char_range` with `replacement`.
+fn replace_char_range<S: TextBuffer>(text: &mut S, char_range: Range<usize>, replacement: &str) {
+    let mut ccursor = CCursor::new(char_range.start);
+    text.delete_char_range(char_range);
+    insert_text(&mut ccursor, text, replacement);
+}
+
+/// Replace the selection, and the [`TextSuggestion::replace_before`] characters before it,
+/// with the suggestion. Returns a cursor at the end of the inserted text.
+fn insert_suggestion<S: TextBuffer>(
+    text: &mut S,
+    cursorp: &CursorPair,
+    suggestion: &TextSuggestion,
+) -> CCursorPair {
+    let mut ccursor = delete_selected(text, cursorp);
+    let start = ccursor.index.saturating_sub(suggestion.replace_before);
+    text.delete_char_range(start..ccursor.index);
+    ccursor.index = start;
+    insert_text(&mut ccursor, text, &suggestion.text);
+    CCursorPair::one(ccursor)
+}
+
 fn insert_text<S: TextBuffer>(ccursor: &mut CCursor, text: &mut S, text_to_insert: &str) {
     ccursor.index += text.insert_text(text_to_insert, ccursor.index);
 }
@@ -813,18 +1559,21 @@ fn delete_selected_ccursor_range<S: TextBuffer>(text: &mut S, [min, max]: [CCurs
     }
 }
 
+/// Deletes the whole grapheme cluster before the cursor, e.g. an emoji with a skin tone modifier.
 fn delete_previous_char<S: TextBuffer>(text: &mut S, ccursor: CCursor) -> CCursor {
     if ccursor.index > 0 {
         let max_ccursor = ccursor;
-        let min_ccursor = max_ccursor - 1;
+        let min_ccursor = CCursor::new(previous_grapheme_char_index(text.as_ref(), ccursor.index));
         delete_selected_ccursor_range(text, [min_ccursor, max_ccursor])
     } else {
         ccursor
     }
 }
 
+/// Deletes the whole grapheme cluster after the cursor, e.g. a letter with combining accents.
 fn delete_next_char<S: TextBuffer>(text: &mut S, ccursor: CCursor) -> CCursor {
-    delete_selected_ccursor_range(text, [ccursor, ccursor + 1])
+    let max_ccursor = CCursor::new(next_grapheme_char_index(text.as_ref(), ccursor.index));
+    delete_selected_ccursor_range(text, [ccursor, max_ccursor])
 }
 
 fn delete_previous_word<S: TextBuffer>(text: &mut S, max_ccursor: CCursor) -> CCursor {
@@ -925,6 +1674,19 @@ fn on_key_press<S: TextBuffer>(
             None
         }
 
+        Key::A | Key::E if modifiers.ctrl && !modifiers.command => {
+            // mac (emacs-style) begin/end of line. On Windows/Linux ctrl+A is select all (above).
+            cursorp.primary = if key == Key::A {
+                galley.cursor_begin_of_row(&cursorp.primary)
+            } else {
+                galley.cursor_end_of_row(&cursorp.primary)
+            };
+            if !modifiers.shift {
+                cursorp.secondary = cursorp.primary;
+            }
+            None
+        }
+
         Key::K if modifiers.ctrl => {
             let ccursor = delete_paragraph_after_cursor(text, galley, cursorp);
             Some(CCursorPair::one(ccursor))
@@ -944,6 +1706,14 @@ fn on_key_press<S: TextBuffer>(
             Some(CCursorPair::one(ccursor))
         }
 
+        Key::ArrowLeft | Key::ArrowRight if !cursorp.is_empty() && !modifiers.any() => {
+            // Collapse the selection to the side we are moving towards:
+            let [min, max] = cursorp.sorted();
+            let cursor = if key == Key::ArrowLeft { min } else { max };
+            *cursorp = CursorPair::one(cursor);
+            None
+        }
+
         Key::ArrowLeft | Key::ArrowRight | Key::ArrowUp | Key::ArrowDown | Key::Home | Key::End => {
             move_single_cursor(&mut cursorp.primary, galley, key, modifiers);
             if !modifiers.shift {
@@ -956,6 +1726,18 @@ fn on_key_press<S: TextBuffer>(
     }
 }
 
+/// Keybindings for moving the cursor:
+///
+/// | Keys                            | Movement                               |
+/// |---------------------------------|----------------------------------------|
+/// | ←/→                             | one character (grapheme cluster)       |
+/// | ctrl+←/→ (alt+←/→ on Mac)       | one word                               |
+/// | cmd+←/→ (Mac), home/end         | begin/end of row                       |
+/// | ↑/↓                             | one row                                |
+/// | alt+↑/↓ (Mac)                   | begin/end of paragraph                 |
+/// | ctrl+home/end, cmd+↑/↓          | begin/end of text                      |
+///
+/// Holding shift extends the selection.
 fn move_single_cursor(cursor: &mut Cursor, galley: &Galley, key: Key, modifiers: &Modifiers) {
     match key {
         Key::ArrowLeft => {
@@ -965,7 +1747,10 @@ fn move_single_cursor(cursor: &mut Cursor, galley: &Galley, key: Key, modifiers:
             } else if modifiers.mac_cmd {
                 *cursor = galley.cursor_begin_of_row(cursor);
             } else {
-                *cursor = galley.cursor_left_one_character(cursor);
+                *cursor = galley.from_ccursor(CCursor {
+                    index: previous_grapheme_char_index(&galley.text, cursor.ccursor.index),
+                    prefer_next_row: true,
+                });
             }
         }
         Key::ArrowRight => {
@@ -975,13 +1760,23 @@ fn move_single_cursor(cursor: &mut Cursor, galley: &Galley, key: Key, modifiers:
             } else if modifiers.mac_cmd {
                 *cursor = galley.cursor_end_of_row(cursor);
             } else {
-                *cursor = galley.cursor_right_one_character(cursor);
+                *cursor = galley.from_ccursor(CCursor {
+                    index: next_grapheme_char_index(&galley.text, cursor.ccursor.index),
+                    prefer_next_row: true,
+                });
             }
         }
         Key::ArrowUp => {
             if modifiers.command {
                 // mac and windows behavior
                 *cursor = Cursor::default();
+            } else if modifiers.alt {
+                // mac behavior: begin of paragraph
+                *cursor = galley.from_pcursor(PCursor {
+                    paragraph: cursor.pcursor.paragraph,
+                    offset: 0,
+                    prefer_next_row: true,
+                });
             } else {
                 *cursor = galley.cursor_up_one_row(cursor);
             }
@@ -990,6 +1785,13 @@ fn move_single_cursor(cursor: &mut Cursor, galley: &Galley, key: Key, modifiers:
             if modifiers.command {
                 // mac and windows behavior
                 *cursor = galley.end();
+            } else if modifiers.alt {
+                // mac behavior: end of paragraph
+                *cursor = galley.from_pcursor(PCursor {
+                    paragraph: cursor.pcursor.paragraph,
+                    offset: usize::MAX,
+                    prefer_next_row: false,
+                });
             } else {
                 *cursor = galley.cursor_down_one_row(cursor);
             }
@@ -1018,77 +1820,88 @@ fn move_single_cursor(cursor: &mut Cursor, galley: &Galley, key: Key, modifiers:
 
 // ----------------------------------------------------------------------------
 
+/// The word (or the whitespace or punctuation) at the cursor.
 fn select_word_at(text: &str, ccursor: CCursor) -> CCursorPair {
-    if ccursor.index == 0 {
-        CCursorPair::two(ccursor, ccursor_next_word(text, ccursor))
-    } else {
-        let it = text.chars();
-        let mut it = it.skip(ccursor.index - 1);
-        if let Some(char_before_cursor) = it.next() {
-            if let Some(char_after_cursor) = it.next() {
-                if is_word_char(char_before_cursor) && is_word_char(char_after_cursor) {
-                    let min = ccursor_previous_word(text, ccursor + 1);
-                    let max = ccursor_next_word(text, min);
-                    CCursorPair::two(min, max)
-                } else if is_word_char(char_before_cursor) {
-                    let min = ccursor_previous_word(text, ccursor);
-                    let max = ccursor_next_word(text, min);
-                    CCursorPair::two(min, max)
-                } else if is_word_char(char_after_cursor) {
-                    let max = ccursor_next_word(text, ccursor);
-                    CCursorPair::two(ccursor, max)
-                } else {
-                    let min = ccursor_previous_word(text, ccursor);
-                    let max = ccursor_next_word(text, ccursor);
-                    CCursorPair::two(min, max)
-                }
-            } else {
-                let min = ccursor_previous_word(text, ccursor);
-                CCursorPair::two(min, ccursor)
-            }
-        } else {
-            let max = ccursor_next_word(text, ccursor);
-            CCursorPair::two(ccursor, max)
+    let mut before = None;
+    let mut after = None;
+    for (range, segment) in word_segments(text) {
+        if range.start < ccursor.index && ccursor.index <= range.end {
+            before = Some((range.clone(), is_word(segment)));
+        }
+        if range.start <= ccursor.index && ccursor.index < range.end {
+            after = Some((range, is_word(segment)));
         }
     }
+
+    // Prefer a word over whitespace or punctuation, and what comes after the cursor over what comes before:
+    let range = match (before, after) {
+        (Some((before, true)), Some((_, false)) | None) | (Some((before, false)), None) => before,
+        (_, Some((after, _))) => after,
+        (None, None) => ccursor.index..ccursor.index,
+    };
+    CCursorPair::two(CCursor::new(range.start), CCursor::new(range.end))
 }
 
+/// The end of the next word.
 fn ccursor_next_word(text: &str, ccursor: CCursor) -> CCursor {
+    let index = word_segments(text)
+        .find(|(range, segment)| range.end > ccursor.index && is_word(segment))
+        .map_or_else(|| text.chars().count(), |(range, _)| range.end);
     CCursor {
-        index: next_word_boundary_char_index(text.chars(), ccursor.index),
+        index,
         prefer_next_row: false,
     }
 }
 
+/// The start of the previous word.
 fn ccursor_previous_word(text: &str, ccursor: CCursor) -> CCursor {
-    let num_chars = text.chars().count();
+    let index = word_segments(text)
+        .take_while(|(range, _)| range.start < ccursor.index)
+        .filter(|(_, segment)| is_word(segment))
+        .last()
+        .map_or(0, |(range, _)| range.start);
     CCursor {
-        index: num_chars
-            - next_word_boundary_char_index(text.chars().rev(), num_chars - ccursor.index),
+        index,
         prefer_next_row: true,
     }
 }
 
-fn next_word_boundary_char_index(it: impl Iterator<Item = char>, mut index: usize) -> usize {
-    let mut it = it.skip(index);
-    if let Some(_first) = it.next() {
-        index += 1;
+/// Split the text at the Unicode word boundaries (UAX #29),
+/// returning the char range of each segment (words, but also whitespace and punctuation).
+///
+/// This handles e.g. "naïve", "don't" and `snake_case` as one word each.
+fn word_segments(text: &str) -> impl Iterator<Item = (Range<usize>, &str)> + '_ {
+    let mut char_index = 0;
+    text.split_word_bounds().map(move |segment| {
+        let start = char_index;
+        char_index += segment.chars().count();
+        (start..char_index, segment)
+    })
+}
 
-        if let Some(second) = it.next() {
-            index += 1;
-            for next in it {
-                if is_word_char(next) != is_word_char(second) {
-                    break;
-                }
-                index += 1;
-            }
-        }
+fn is_word(segment: &str) -> bool {
+    segment.chars().any(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// The char index of the start of the grapheme cluster before `char_index`.
+///
+/// A grapheme cluster is what the user thinks of as a single character,
+/// e.g. an emoji with a skin tone modifier, or a letter followed by combining accents.
+fn previous_grapheme_char_index(text: &str, char_index: usize) -> usize {
+    let byte_index = byte_index_from_char_index(text, char_index);
+    match text[..byte_index].graphemes(true).next_back() {
+        Some(grapheme) => char_index - grapheme.chars().count(),
+        None => 0,
     }
-    index
 }
 
-fn is_word_char(c: char) -> bool {
-    c.is_ascii_alphanumeric() || c == '_'
+/// The char index of the end of the grapheme cluster at `char_index`.
+fn next_grapheme_char_index(text: &str, char_index: usize) -> usize {
+    let byte_index = byte_index_from_char_index(text, char_index);
+    match text[byte_index..].graphemes(true).next() {
+        Some(grapheme) => char_index + grapheme.chars().count(),
+        None => char_index,
+    }
 }
 
 /// Accepts and returns character offset (NOT byte offset!).
@@ -1134,3 +1947,42 @@ fn decrease_identation<S: TextBuffer>(ccursor: &mut CCursor, text: &mut S) {
         }
     }
 }
+
+#[cfg(test)]
+#[test]
+fn grapheme_cursor_movement() {
+    // "e" followed by a combining acute accent, and a thumbs up with a skin tone modifier:
+    let text = "ae\u{301}\u{1F44D}\u{1F3FD}b";
+    assert_eq!(next_grapheme_char_index(text, 0), 1);
+    assert_eq!(next_grapheme_char_index(text, 1), 3);
+    assert_eq!(next_grapheme_char_index(text, 3), 5);
+    assert_eq!(next_grapheme_char_index(text, 6), 6);
+    assert_eq!(previous_grapheme_char_index(text, 5), 3);
+    assert_eq!(previous_grapheme_char_index(text, 3), 1);
+    assert_eq!(previous_grapheme_char_index(text, 0), 0);
+}
+
+#[cfg(test)]
+#[test]
+fn word_cursor_movement() {
+    let text = "cafe\u{301} don't, snake_case";
+    let next = |index| ccursor_next_word(text, CCursor::new(index)).index;
+    let previous = |index| ccursor_previous_word(text, CCursor::new(index)).index;
+    assert_eq!(next(0), 5);
+    assert_eq!(next(5), 11);
+    assert_eq!(next(11), 23);
+    assert_eq!(next(23), 23);
+    assert_eq!(previous(23), 13);
+    assert_eq!(previous(13), 6);
+    assert_eq!(previous(6), 0);
+    assert_eq!(previous(0), 0);
+
+    let selected = |index| {
+        let ccursorp = select_word_at(text, CCursor::new(index));
+        ccursorp.secondary.index..ccursorp.primary.index
+    };
+    assert_eq!(selected(2), 0..5);
+    assert_eq!(selected(5), 0..5);
+    assert_eq!(selected(6), 6..11);
+    assert_eq!(selected(12), 12..13);
+}
@@ -0,0 +1,168 @@
+use std::{ops::RangeInclusive, str::FromStr};
+
+use crate::*;
+
+/// Turns the value into the text shown in a [`ValueTextEdit`].
+type Formatter<'a, T> = Box<dyn 'a + Fn(&T) -> String>;
+
+/// A single line [`TextEdit`] bound to a value that is parsed from (and formatted to) a string.
+///
+/// While the field has keyboard focus the user edits a text buffer, leaving the value untouched.
+/// The text is parsed when the user presses enter or moves the focus elsewhere,
+/// and the value is updated if it parses (clamped to the [`Self::range`], if any).
+/// Pressing escape reverts the edit.
+/// Text that does not parse is marked as invalid (see [`TextEdit::valid`]).
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut port: u16 = 8080;
+/// # let mut ratio: f64 = 0.5;
+/// ui.add(egui::ValueTextEdit::new(&mut port));
+/// ui.add(egui::ValueTextEdit::new(&mut ratio).range(0.0..=1.0));
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct ValueTextEdit<'a, T> {
+    value: &'a mut T,
+    id_source: Option<Id>,
+    range: Option<RangeInclusive<T>>,
+    formatter: Option<Formatter<'a, T>>,
+    hint_text: String,
+    desired_width: Option<f32>,
+    text_style: Option<TextStyle>,
+}
+
+impl<'a, T> ValueTextEdit<'a, T>
+where
+    T: Clone + FromStr + ToString + PartialOrd,
+{
+    pub fn new(value: &'a mut T) -> Self {
+        Self {
+            value,
+            id_source: None,
+            range: None,
+            formatter: None,
+            hint_text: Default::default(),
+            desired_width: None,
+            text_style: None,
+        }
+    }
+
+    /// A source for the unique `Id`, e.g. `.id_source("port")` or `.id_source(loop_index)`.
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
+    /// Clamp entered values to this range.
+    pub fn range(mut self, range: RangeInclusive<T>) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    /// How to show the value when the field is not being edited.
+    /// Default: [`ToString::to_string`].
+    pub fn formatter(mut self, formatter: impl 'a + Fn(&T) -> String) -> Self {
+        self.formatter = Some(Box::new(formatter));
+        self
+    }
+
+    /// Show a faint hint text when the text field is empty.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn hint_text(mut self, hint_text: impl ToString) -> Self {
+        self.hint_text = hint_text.to_string();
+        self
+    }
+
+    /// Set to 0.0 to keep as small as possible
+    pub fn desired_width(mut self, desired_width: f32) -> Self {
+        self.desired_width = Some(desired_width);
+        self
+    }
+
+    pub fn text_style(mut self, text_style: TextStyle) -> Self {
+        self.text_style = Some(text_style);
+        self
+    }
+}
+
+impl<'a, T> Widget for ValueTextEdit<'a, T>
+where
+    T: Clone + FromStr + ToString + PartialOrd,
+{
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            value,
+            id_source,
+            range,
+            formatter,
+            hint_text,
+            desired_width,
+            text_style,
+        } = self;
+
+        let id = id_source.map_or_else(
+            || ui.next_auto_id(),
+            |id_source| ui.make_persistent_id(id_source),
+        );
+
+        let format = |value: &T| match &formatter {
+            Some(formatter) => formatter(value),
+            None => value.to_string(),
+        };
+
+        let has_focus = ui.memory().has_focus(id);
+        let mut text = if has_focus {
+            ui.memory()
+                .id_data_temp
+                .get::<String>(&id)
+                .cloned()
+                .unwrap_or_else(|| format(value))
+        } else {
+            format(value)
+        };
+
+        let was_valid = text.trim().parse::<T>().is_ok();
+        let mut text_edit = TextEdit::singleline(&mut text)
+            .id(id)
+            .hint_text(hint_text)
+            .valid(was_valid);
+        if let Some(desired_width) = desired_width {
+            text_edit = text_edit.desired_width(desired_width);
+        }
+        if let Some(text_style) = text_style {
+            text_edit = text_edit.text_style(text_style);
+        }
+        let mut response = ui.add(text_edit);
+
+        let parsed = text.trim().parse::<T>().ok();
+        if response.changed() && parsed.is_some() != was_valid {
+            // The frame was painted with the old validity:
+            ui.ctx().request_repaint();
+        }
+
+        let mut changed = false;
+        // Enter and escape both make the field lose focus, but escape reverts the edit:
+        if response.lost_focus() && !ui.input().key_pressed(Key::Escape) {
+            if let Some(mut parsed) = parsed {
+                if let Some(range) = &range {
+                    if parsed < *range.start() {
+                        parsed = range.start().clone();
+                    } else if parsed > *range.end() {
+                        parsed = range.end().clone();
+                    }
+                }
+                changed = *value != parsed;
+                *value = parsed;
+            }
+        }
+
+        if response.has_focus() {
+            ui.memory().id_data_temp.insert(id, text);
+        } else {
+            ui.memory().id_data_temp.remove(&id);
+        }
+
+        response.changed = changed;
+        response
+    }
+}
@@ -0,0 +1,178 @@
+use crate::*;
+use epaint::Galley;
+use std::{borrow::Cow, sync::Arc};
+
+/// The text of a widget (e.g. of a [`Button`] or a [`Checkbox`]), with optional styling.
+///
+/// Everything that takes an `impl Into<WidgetText>` accepts a `&str` or a `String`,
+/// so you only need this when you want to change the look of that one piece of text,
+/// without having to change the whole [`Style`]:
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// ui.button("Plain");
+/// ui.button(egui::WidgetText::new("Danger").strong().text_color(egui::Color32::RED));
+/// ui.checkbox(&mut true, egui::WidgetText::new("Monospace").monospace());
+/// ```
+///
+/// A [`Label`] can also be turned into a [`WidgetText`] (and vice versa).
+/// An `Arc<str>` or `Cow<'static, str>` is used without copying the text.
+#[derive(Clone, Debug)]
+pub struct WidgetText {
+    label: Label,
+}
+
+impl WidgetText {
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(text: impl ToString) -> Self {
+        Self {
+            label: Label::new(text),
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        self.label.text()
+    }
+
+    /// Overrides the text style the widget would otherwise use.
+    pub fn text_style(mut self, text_style: TextStyle) -> Self {
+        self.label = self.label.text_style(text_style);
+        self
+    }
+
+    pub fn heading(self) -> Self {
+        self.text_style(TextStyle::Heading)
+    }
+
+    pub fn monospace(self) -> Self {
+        self.text_style(TextStyle::Monospace)
+    }
+
+    /// Monospace text with gray background
+    pub fn code(mut self) -> Self {
+        self.label = self.label.code();
+        self
+    }
+
+    /// Smaller text
+    pub fn small(self) -> Self {
+        self.text_style(TextStyle::Small)
+    }
+
+    /// Extra strong text (stronger color).
+    pub fn strong(mut self) -> Self {
+        self.label = self.label.strong();
+        self
+    }
+
+    /// Extra weak text (fainter color).
+    pub fn weak(mut self) -> Self {
+        self.label = self.label.weak();
+        self
+    }
+
+    /// draw a line under the text
+    pub fn underline(mut self) -> Self {
+        self.label = self.label.underline();
+        self
+    }
+
+    /// draw a line through the text, crossing it out
+    pub fn strikethrough(mut self) -> Self {
+        self.label = self.label.strikethrough();
+        self
+    }
+
+    /// tilt the characters to the right.
+    pub fn italics(mut self) -> Self {
+        self.label = self.label.italics();
+        self
+    }
+
+    /// Fill-color behind the text
+    pub fn background_color(mut self, background_color: impl Into<Color32>) -> Self {
+        self.label = self.label.background_color(background_color);
+        self
+    }
+
+    /// Overrides the text color the widget would otherwise use.
+    pub fn text_color(mut self, text_color: impl Into<Color32>) -> Self {
+        self.label = self.label.text_color(text_color);
+        self
+    }
+
+    /// Use the text style of the text, if it has one, or else `default_text_style`.
+    ///
+    /// Wraps at `wrap_width`, if any.
+    pub(crate) fn layout(
+        &self,
+        ui: &Ui,
+        default_text_style: TextStyle,
+        wrap_width: Option<f32>,
+    ) -> Arc<Galley> {
+        let text_style = self.label.text_style.unwrap_or(default_text_style);
+        let text = &self.label.text;
+        match wrap_width {
+            Some(wrap_width) => ui.fonts().layout_multiline(text_style, text, wrap_width),
+            None => ui.fonts().layout_no_wrap(text_style, text),
+        }
+    }
+
+    /// Paint the galley from [`Self::layout`], using `default_color` unless the text has a color of its own.
+    pub(crate) fn paint_galley(
+        &self,
+        ui: &Ui,
+        pos: Pos2,
+        galley: Arc<Galley>,
+        default_color: Color32,
+    ) {
+        self.label
+            .paint_galley_impl(ui, pos, galley, false, default_color);
+    }
+
+    pub(crate) fn into_label(self) -> Label {
+        self.label
+    }
+}
+
+impl From<&str> for WidgetText {
+    fn from(s: &str) -> Self {
+        Self::new(s)
+    }
+}
+
+impl From<&String> for WidgetText {
+    fn from(s: &String) -> Self {
+        Self::new(s)
+    }
+}
+
+impl From<String> for WidgetText {
+    fn from(s: String) -> Self {
+        Self::new(s)
+    }
+}
+
+impl From<Arc<str>> for WidgetText {
+    fn from(s: Arc<str>) -> Self {
+        Label::from(s).into()
+    }
+}
+
+impl From<Cow<'static, str>> for WidgetText {
+    fn from(s: Cow<'static, str>) -> Self {
+        Label::from(s).into()
+    }
+}
+
+impl From<Label> for WidgetText {
+    fn from(label: Label) -> Self {
+        Self { label }
+    }
+}
+
+impl From<WidgetText> for Label {
+    fn from(text: WidgetText) -> Label {
+        text.into_label()
+    }
+}
@@ -271,14 +271,14 @@ impl EguiWindows {
 
         Window::new("🔧 Settings")
             .open(settings)
-            .scroll(true)
+            .vscroll(true)
             .show(ctx, |ui| {
                 ctx.settings_ui(ui);
             });
 
         Window::new("🔍 Inspection")
             .open(inspection)
-            .scroll(true)
+            .vscroll(true)
             .show(ctx, |ui| {
                 ctx.inspection_ui(ui);
             });
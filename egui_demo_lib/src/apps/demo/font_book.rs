@@ -24,7 +24,9 @@ impl FontBook {
                 || name.contains(&self.filter)
                 || self.filter == chr.to_string()
             {
-                let button = Button::new(chr).text_style(self.text_style).frame(false);
+                let button = Button::new(chr.to_string())
+                    .text_style(self.text_style)
+                    .frame(false);
 
                 let tooltip_ui = |ui: &mut egui::Ui| {
                     ui.add(Label::new(chr).text_style(self.text_style));
@@ -34,7 +34,7 @@ impl Demo for MiscDemoWindow {
     fn show(&mut self, ctx: &CtxRef, open: &mut bool) {
         Window::new(self.name())
             .open(open)
-            .scroll(true)
+            .vscroll(true)
             .show(ctx, |ui| self.ui(ui));
     }
 }
@@ -209,7 +209,8 @@ impl Widgets {
         ui.add(
             TextEdit::multiline(code_snippet)
                 .code_editor()
-                .lock_focus(*lock_focus),
+                .lock_focus(*lock_focus)
+                .find_bar(true),
         );
     }
 }
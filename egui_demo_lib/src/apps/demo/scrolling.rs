@@ -7,6 +7,7 @@ pub struct Scrolling {
     track_item: usize,
     tack_item_align: Align,
     offset: f32,
+    smooth_scroll_duration: f32,
 }
 
 impl Default for Scrolling {
@@ -15,6 +16,7 @@ impl Default for Scrolling {
             track_item: 25,
             tack_item_align: Align::Center,
             offset: 0.0,
+            smooth_scroll_duration: 0.0,
         }
     }
 }
@@ -71,12 +73,19 @@ impl super::View for Scrolling {
                 .dragged();
         });
 
+        ui.add(
+            Slider::new(&mut self.smooth_scroll_duration, 0.0..=1.0)
+                .text("Smooth scrolling duration")
+                .suffix(" s"),
+        );
+
         ui.horizontal(|ui| {
             scroll_top |= ui.button("Scroll to top").clicked();
             scroll_bottom |= ui.button("Scroll to bottom").clicked();
         });
 
-        let mut scroll_area = ScrollArea::from_max_height(200.0);
+        let mut scroll_area =
+            ScrollArea::from_max_height(200.0).smooth_scrolling(self.smooth_scroll_duration);
         if go_to_scroll_offset {
             scroll_area = scroll_area.scroll_offset(self.offset);
         }
@@ -115,6 +124,22 @@ impl super::View for Scrolling {
             current_scroll, max_scroll
         ));
 
+        ui.separator();
+        ui.label("This scroll area scrolls in both directions. Hold shift to scroll sideways with the mouse wheel.");
+        ScrollArea::both()
+            .max_height(200.0)
+            .id_source("both")
+            .show(ui, |ui| {
+                Grid::new("scroll_grid").show(ui, |ui| {
+                    for row in 0..50 {
+                        for col in 0..20 {
+                            ui.label(format!("Cell {}:{}", row, col));
+                        }
+                        ui.end_row();
+                    }
+                });
+            });
+
         ui.separator();
         ui.vertical_centered(|ui| {
             egui::reset_button(ui, self);
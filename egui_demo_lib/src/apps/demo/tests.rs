@@ -179,6 +179,7 @@ pub struct TableTest {
     num_rows: usize,
     min_col_width: f32,
     max_col_width: f32,
+    clicked_row: Option<usize>,
 }
 
 impl Default for TableTest {
@@ -188,6 +189,7 @@ impl Default for TableTest {
             num_rows: 4,
             min_col_width: 10.0,
             max_col_width: 200.0,
+            clicked_row: None,
         }
     }
 }
@@ -223,11 +225,11 @@ impl super::View for TableTest {
             "with", "some", "more",
         ];
 
-        egui::Grid::new("my_grid")
+        let (_, clicked_row) = egui::Grid::new("my_grid")
             .striped(true)
             .min_col_width(self.min_col_width)
             .max_col_width(self.max_col_width)
-            .show(ui, |ui| {
+            .show_clickable(ui, |ui| {
                 for row in 0..self.num_rows {
                     for col in 0..self.num_cols {
                         if col == 0 {
@@ -246,6 +248,15 @@ impl super::View for TableTest {
                     ui.end_row();
                 }
             });
+        if clicked_row.is_some() {
+            self.clicked_row = clicked_row;
+        }
+
+        ui.separator();
+        match self.clicked_row {
+            Some(row) => ui.label(format!("Last clicked row: {}", row)),
+            None => ui.label("Click a row"),
+        };
 
         ui.vertical_centered(|ui| {
             egui::reset_button(ui, self);
@@ -353,7 +364,7 @@ impl super::Demo for WindowResizeTest {
 
         Window::new("↔ resizable + scroll")
             .open(open)
-            .scroll(true)
+            .vscroll(true)
             .resizable(true)
             .default_height(300.0)
             .show(ctx, |ui| {
@@ -366,7 +377,7 @@ impl super::Demo for WindowResizeTest {
 
         Window::new("↔ resizable + embedded scroll")
             .open(open)
-            .scroll(false)
+            .vscroll(false)
             .resizable(true)
             .default_height(300.0)
             .show(ctx, |ui| {
@@ -382,7 +393,7 @@ impl super::Demo for WindowResizeTest {
 
         Window::new("↔ resizable without scroll")
             .open(open)
-            .scroll(false)
+            .vscroll(false)
             .resizable(true)
             .show(ctx, |ui| {
                 ui.label("This window is resizable but has no scroll area. This means it can only be resized to a size where all the contents is visible.");
@@ -393,7 +404,7 @@ impl super::Demo for WindowResizeTest {
 
         Window::new("↔ resizable with TextEdit")
             .open(open)
-            .scroll(false)
+            .vscroll(false)
             .resizable(true)
             .show(ctx, |ui| {
                 ui.label("Shows how you can fill an area with a widget.");
@@ -154,6 +154,10 @@ impl WidgetGallery {
         ui.add(egui::DragValue::new(scalar).speed(1.0));
         ui.end_row();
 
+        ui.add(doc_link_label("ValueTextEdit", "ValueTextEdit"));
+        ui.add(egui::ValueTextEdit::new(scalar).range(0.0..=360.0));
+        ui.end_row();
+
         ui.add(doc_link_label("Color picker", "color_edit"));
         ui.color_edit_button_srgba(color);
         ui.end_row();
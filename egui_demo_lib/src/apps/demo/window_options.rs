@@ -61,7 +61,7 @@ impl super::Demo for WindowOptions {
             .resizable(resizable)
             .collapsible(collapsible)
             .title_bar(title_bar)
-            .scroll(scroll)
+            .vscroll(scroll)
             .enabled(enabled);
         if closable {
             window = window.open(open);
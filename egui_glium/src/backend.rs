@@ -40,6 +40,16 @@ impl epi::TextureAllocator for Painter {
         id
     }
 
+    fn update_srgba_premultiplied(
+        &mut self,
+        id: egui::TextureId,
+        size: (usize, usize),
+        srgba_pixels: &[Color32],
+    ) -> egui::TextureId {
+        self.update_user_texture(id, size, srgba_pixels);
+        id
+    }
+
     fn free(&mut self, id: egui::TextureId) {
         self.free_user_texture(id)
     }
@@ -84,6 +94,7 @@ fn create_display(
     let mut window_builder = glutin::window::WindowBuilder::new()
         .with_always_on_top(native_options.always_on_top)
         .with_decorations(native_options.decorated)
+        .with_maximized(native_options.maximized)
         .with_resizable(native_options.resizable)
         .with_title(app.name())
         .with_transparent(native_options.transparent)
@@ -94,6 +105,24 @@ fn create_display(
 
     let initial_size_points = native_options.initial_window_size;
 
+    if native_options.fullscreen {
+        window_builder =
+            window_builder.with_fullscreen(Some(glutin::window::Fullscreen::Borderless(None)));
+    }
+
+    if let Some(min_size) = native_options.min_window_size {
+        window_builder = window_builder.with_min_inner_size(glutin::dpi::LogicalSize {
+            width: min_size.x as f64,
+            height: min_size.y as f64,
+        });
+    }
+    if let Some(max_size) = native_options.max_window_size {
+        window_builder = window_builder.with_max_inner_size(glutin::dpi::LogicalSize {
+            width: max_size.x as f64,
+            height: max_size.y as f64,
+        });
+    }
+
     if let Some(window_settings) = &window_settings {
         window_builder = window_settings.initialize_size(window_builder);
     } else if let Some(initial_size_points) = initial_size_points {
@@ -113,6 +142,14 @@ fn create_display(
 
     if let Some(window_settings) = &window_settings {
         window_settings.restore_positions(&display);
+    } else if let Some(pos) = native_options.initial_window_pos {
+        display
+            .gl_window()
+            .window()
+            .set_outer_position(glutin::dpi::LogicalPosition {
+                x: pos.x as f64,
+                y: pos.y as f64,
+            });
     }
 
     display
@@ -161,6 +198,17 @@ fn load_icon(icon_data: epi::IconData) -> Option<glutin::window::Icon> {
     glutin::window::Icon::from_rgba(icon_data.rgba, icon_data.width, icon_data.height).ok()
 }
 
+/// Keep the size within [`epi::NativeOptions::min_window_size`] and [`epi::NativeOptions::max_window_size`].
+fn clamp_window_size(native_options: &epi::NativeOptions, mut size: egui::Vec2) -> egui::Vec2 {
+    if let Some(min_size) = native_options.min_window_size {
+        size = size.max(min_size);
+    }
+    if let Some(max_size) = native_options.max_window_size {
+        size = size.min(max_size);
+    }
+    size
+}
+
 // ----------------------------------------------------------------------------
 
 /// Run an egui app
@@ -181,6 +229,7 @@ pub fn run(mut app: Box<dyn epi::App>, nativve_options: epi::NativeOptions) -> !
     )));
 
     let mut egui = EguiGlium::new(&display);
+    egui.set_maximized(nativve_options.maximized);
     *egui.ctx().memory() = deserialize_memory(&storage).unwrap_or_default();
 
     app.setup(&egui.ctx());
@@ -269,7 +318,23 @@ pub fn run(mut app: Box<dyn epi::App>, nativve_options: epi::NativeOptions) -> !
             }
 
             {
-                let epi::backend::AppOutput { quit, window_size } = app_output;
+                let epi::backend::AppOutput {
+                    quit,
+                    window_size,
+                    fit_window_to_contents,
+                } = app_output;
+
+                let window_size = window_size.or_else(|| {
+                    if fit_window_to_contents || nativve_options.auto_resize {
+                        let size = clamp_window_size(&nativve_options, egui.ctx().used_size());
+                        let current_size = egui.ctx().input().screen_rect().size();
+                        // Only resize on a real change, or we could end up fighting the window manager:
+                        let changed = (size - current_size).length() >= 1.0;
+                        changed.then(|| size)
+                    } else {
+                        None
+                    }
+                });
 
                 if let Some(window_size) = window_size {
                     display.gl_window().window().set_inner_size(
@@ -286,6 +351,10 @@ pub fn run(mut app: Box<dyn epi::App>, nativve_options: epi::NativeOptions) -> !
                 } else if needs_repaint {
                     display.gl_window().window().request_redraw();
                     glutin::event_loop::ControlFlow::Poll
+                } else if let Some(repaint_after) = egui.repaint_after() {
+                    glutin::event_loop::ControlFlow::WaitUntil(
+                        Instant::now() + std::time::Duration::from_secs_f32(repaint_after),
+                    )
                 } else {
                     glutin::event_loop::ControlFlow::Wait
                 };
@@ -342,6 +411,11 @@ pub fn run(mut app: Box<dyn epi::App>, nativve_options: epi::NativeOptions) -> !
             glutin::event::Event::UserEvent(RequestRepaintEvent) => {
                 display.gl_window().window().request_redraw();
             }
+            glutin::event::Event::NewEvents(glutin::event::StartCause::ResumeTimeReached {
+                ..
+            }) => {
+                display.gl_window().window().request_redraw();
+            }
 
             _ => (),
         }
@@ -188,12 +188,8 @@ pub fn input_to_egui(
                 delta.x *= -1.0;
             }
 
-            if input_state.raw.modifiers.ctrl || input_state.raw.modifiers.command {
-                // Treat as zoom instead:
-                input_state.raw.zoom_delta *= (delta.y / 200.0).exp();
-            } else {
-                input_state.raw.scroll_delta += delta;
-            }
+            // egui turns ctrl/cmd + scroll into zoom for us.
+            input_state.raw.scroll_delta += delta;
         }
         WindowEvent::TouchpadPressure {
             // device_id,
@@ -316,6 +312,19 @@ pub fn translate_virtual_key_code(key: VirtualKeyCode) -> Option<egui::Key> {
         Y => Key::Y,
         Z => Key::Z,
 
+        F1 => Key::F1,
+        F2 => Key::F2,
+        F3 => Key::F3,
+        F4 => Key::F4,
+        F5 => Key::F5,
+        F6 => Key::F6,
+        F7 => Key::F7,
+        F8 => Key::F8,
+        F9 => Key::F9,
+        F10 => Key::F10,
+        F11 => Key::F11,
+        F12 => Key::F12,
+
         _ => {
             return None;
         }
@@ -387,6 +396,11 @@ pub fn handle_output(
             .window()
             .set_ime_position(glium::glutin::dpi::LogicalPosition { x, y })
     }
+
+    if output.drag_window {
+        // Fails on platforms that don't support it, and then there is nothing we can do.
+        let _ = display.gl_window().window().drag_window();
+    }
 }
 
 pub fn init_clipboard() -> Option<ClipboardContext> {
@@ -435,6 +449,9 @@ pub struct EguiGlium {
     painter: crate::Painter,
     current_cursor_icon: egui::CursorIcon,
     screen_reader: crate::screen_reader::ScreenReader,
+    repaint_after: Option<f32>,
+    /// winit can't tell us if the window is maximized, so we keep track of it ourselves.
+    maximized: bool,
 }
 
 impl EguiGlium {
@@ -449,13 +466,27 @@ impl EguiGlium {
             painter: crate::Painter::new(display),
             current_cursor_icon: egui::CursorIcon::Default,
             screen_reader: crate::screen_reader::ScreenReader::default(),
+            repaint_after: None,
+            maximized: false,
         }
     }
 
+    /// Let [`Self::end_frame`] know if the window starts out maximized,
+    /// so that [`egui::Output::toggle_maximized`] works the first time.
+    pub fn set_maximized(&mut self, maximized: bool) {
+        self.maximized = maximized;
+    }
+
     pub fn ctx(&self) -> &egui::CtxRef {
         &self.egui_ctx
     }
 
+    /// egui wants a repaint after this many seconds, even if there are no new events.
+    /// Updated by [`Self::end_frame`].
+    pub fn repaint_after(&self) -> Option<f32> {
+        self.repaint_after
+    }
+
     pub fn ctx_and_painter_mut(&mut self) -> (&egui::CtxRef, &mut crate::Painter) {
         (&self.egui_ctx, &mut self.painter)
     }
@@ -508,6 +539,12 @@ impl EguiGlium {
         }
 
         let needs_repaint = egui_output.needs_repaint;
+        self.repaint_after = egui_output.repaint_after;
+
+        if egui_output.toggle_maximized {
+            self.maximized = !self.maximized;
+            display.gl_window().window().set_maximized(self.maximized);
+        }
 
         handle_output(egui_output, self.clipboard.as_mut(), display);
 
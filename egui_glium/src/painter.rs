@@ -281,6 +281,42 @@ impl Painter {
         }
     }
 
+    /// Like [`Self::set_user_texture`], but if the texture has already been uploaded
+    /// and has the same size, it is written to in place.
+    pub fn update_user_texture(
+        &mut self,
+        id: egui::TextureId,
+        size: (usize, usize),
+        pixels: &[Color32],
+    ) {
+        assert_eq!(size.0 * size.1, pixels.len());
+
+        if let egui::TextureId::User(id) = id {
+            if let Some(Some(UserTexture {
+                gl_texture: Some(gl_texture),
+                ..
+            })) = self.user_textures.get(id as usize)
+            {
+                if (gl_texture.width() as usize, gl_texture.height() as usize) == size {
+                    let pixels: Vec<Vec<(u8, u8, u8, u8)>> = pixels
+                        .chunks(size.0 as usize)
+                        .map(|row| row.iter().map(|srgba| srgba.to_tuple()).collect())
+                        .collect();
+                    let rect = glium::Rect {
+                        left: 0,
+                        bottom: 0,
+                        width: size.0 as u32,
+                        height: size.1 as u32,
+                    };
+                    gl_texture.write(rect, pixels);
+                    return;
+                }
+            }
+        }
+
+        self.set_user_texture(id, size, pixels);
+    }
+
     pub fn free_user_texture(&mut self, id: egui::TextureId) {
         if let egui::TextureId::User(id) = id {
             let index = id as usize;
@@ -236,6 +236,7 @@ impl AppRunner {
             let epi::backend::AppOutput {
                 quit: _,        // Can't quit a web page
                 window_size: _, // Can't resize a web page
+                fit_window_to_contents: _,
             } = app_output;
         }
 
@@ -298,8 +298,11 @@ pub fn handle_output(output: &egui::Output, runner: &mut AppRunner) {
         open_url,
         copied_text,
         needs_repaint: _, // handled elsewhere
+        repaint_after: _, // handled elsewhere
         events: _,        // we ignore these (TODO: accessibility screen reader)
         text_cursor_pos,
+        drag_window: _,      // Can't move a web page
+        toggle_maximized: _, // Can't maximize a web page
     } = output;
 
     set_cursor_icon(*cursor_icon);
@@ -491,6 +494,19 @@ pub fn translate_key(key: &str) -> Option<egui::Key> {
         "y" | "Y" => Some(egui::Key::Y),
         "z" | "Z" => Some(egui::Key::Z),
 
+        "F1" => Some(egui::Key::F1),
+        "F2" => Some(egui::Key::F2),
+        "F3" => Some(egui::Key::F3),
+        "F4" => Some(egui::Key::F4),
+        "F5" => Some(egui::Key::F5),
+        "F6" => Some(egui::Key::F6),
+        "F7" => Some(egui::Key::F7),
+        "F8" => Some(egui::Key::F8),
+        "F9" => Some(egui::Key::F9),
+        "F10" => Some(egui::Key::F10),
+        "F11" => Some(egui::Key::F11),
+        "F12" => Some(egui::Key::F12),
+
         _ => None,
     }
 }
@@ -508,6 +524,9 @@ fn paint_and_schedule(runner_ref: AppRunnerRef) -> Result<(), JsValue> {
             runner_lock.paint(clipped_meshes)?;
             if output.needs_repaint {
                 runner_lock.needs_repaint.set_true();
+            } else if let Some(repaint_after) = output.repaint_after {
+                let needs_repaint = runner_lock.needs_repaint.clone();
+                set_timeout(move || needs_repaint.set_true(), repaint_after)?;
             }
             runner_lock.auto_save();
         }
@@ -528,6 +547,19 @@ fn paint_and_schedule(runner_ref: AppRunnerRef) -> Result<(), JsValue> {
     request_animation_frame(runner_ref)
 }
 
+/// Call `callback` once, after `seconds`.
+fn set_timeout(callback: impl FnOnce() + 'static, seconds: f32) -> Result<(), JsValue> {
+    use wasm_bindgen::JsCast;
+    let window = web_sys::window().unwrap();
+    let closure = Closure::once(callback);
+    window.set_timeout_with_callback_and_timeout_and_arguments_0(
+        closure.as_ref().unchecked_ref(),
+        (1000.0 * seconds).round() as i32,
+    )?;
+    closure.forget(); // We must forget it, or else the callback is canceled on drop
+    Ok(())
+}
+
 fn text_agent() -> web_sys::HtmlInputElement {
     use wasm_bindgen::JsCast;
     web_sys::window()
@@ -917,6 +949,29 @@ fn install_canvas_events(runner_ref: &AppRunnerRef) -> Result<(), JsValue> {
         let closure = Closure::wrap(Box::new(move |event: web_sys::MouseEvent| {
             let mut runner_lock = runner_ref.0.lock();
             if !runner_lock.input.is_touch {
+                // We won't get the `mouseup` outside the canvas, so release the buttons now
+                // to end any ongoing drag:
+                let pos = pos_from_mouse_event(runner_lock.canvas_id(), &event);
+                let modifiers = runner_lock.input.raw.modifiers;
+                let buttons = [
+                    (1, egui::PointerButton::Primary),
+                    (2, egui::PointerButton::Secondary),
+                    (4, egui::PointerButton::Middle),
+                ];
+                for &(mask, button) in &buttons {
+                    if event.buttons() & mask != 0 {
+                        runner_lock
+                            .input
+                            .raw
+                            .events
+                            .push(egui::Event::PointerButton {
+                                pos,
+                                button,
+                                pressed: false,
+                                modifiers,
+                            });
+                    }
+                }
                 runner_lock.input.raw.events.push(egui::Event::PointerGone);
                 runner_lock.needs_repaint.set_true();
                 event.stop_propagation();
@@ -1053,14 +1108,8 @@ fn install_canvas_events(runner_ref: &AppRunnerRef) -> Result<(), JsValue> {
             let delta = -scroll_multiplier
                 * egui::Vec2::new(event.delta_x() as f32, event.delta_y() as f32);
 
-            // Report a zoom event in case CTRL (on Windows or Linux) or CMD (on Mac) is pressed.
-            // This if-statement is equivalent to how `Modifiers.command` is determined in
-            // `modifiers_from_event()`, but we cannot directly use that fn for a `WheelEvent`.
-            if event.ctrl_key() || event.meta_key() {
-                runner_lock.input.raw.zoom_delta *= (delta.y / 200.0).exp();
-            } else {
-                runner_lock.input.raw.scroll_delta += delta;
-            }
+            // egui turns ctrl/cmd + scroll into zoom for us.
+            runner_lock.input.raw.scroll_delta += delta;
 
             runner_lock.needs_repaint.set_true();
             event.stop_propagation();
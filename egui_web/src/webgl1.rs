@@ -110,17 +110,9 @@ impl WebGlPainter {
         assert_eq!(size.0 * size.1, srgba_pixels.len());
 
         if let Some(Some(user_texture)) = self.user_textures.get_mut(index) {
-            let mut pixels: Vec<u8> = Vec::with_capacity(srgba_pixels.len() * 4);
-            for srgba in srgba_pixels {
-                pixels.push(srgba.r());
-                pixels.push(srgba.g());
-                pixels.push(srgba.b());
-                pixels.push(srgba.a());
-            }
-
             *user_texture = UserTexture {
                 size,
-                pixels,
+                pixels: to_rgba_bytes(srgba_pixels),
                 gl_texture: None,
             };
         }
@@ -128,6 +120,27 @@ impl WebGlPainter {
         egui::TextureId::User(index as u64)
     }
 
+    /// Replace the pixels of a user texture.
+    /// If it has already been uploaded and has the same size, it is updated in place on the next paint.
+    fn update_user_texture(
+        &mut self,
+        id: egui::TextureId,
+        size: (usize, usize),
+        srgba_pixels: &[Color32],
+    ) {
+        assert_eq!(size.0 * size.1, srgba_pixels.len());
+
+        if let egui::TextureId::User(id) = id {
+            if let Some(Some(user_texture)) = self.user_textures.get_mut(id as usize) {
+                if user_texture.size != size {
+                    user_texture.size = size;
+                    user_texture.gl_texture = None;
+                }
+                user_texture.pixels = to_rgba_bytes(srgba_pixels);
+            }
+        }
+    }
+
     fn free_user_texture(&mut self, id: egui::TextureId) {
         if let egui::TextureId::User(id) = id {
             let index = id as usize;
@@ -184,6 +197,26 @@ impl WebGlPainter {
                 .unwrap();
 
                 user_texture.gl_texture = Some(gl_texture);
+            } else if !user_texture.pixels.is_empty() {
+                // Updated with the same size: write in place.
+                let pixels = std::mem::take(&mut user_texture.pixels);
+                gl.bind_texture(Gl::TEXTURE_2D, user_texture.gl_texture.as_ref());
+
+                let level = 0;
+                let src_format = Gl::RGBA;
+                let src_type = Gl::UNSIGNED_BYTE;
+                gl.tex_sub_image_2d_with_i32_and_i32_and_u32_and_type_and_opt_u8_array(
+                    Gl::TEXTURE_2D,
+                    level,
+                    0,
+                    0,
+                    user_texture.size.0 as i32,
+                    user_texture.size.1 as i32,
+                    src_format,
+                    src_type,
+                    Some(&pixels),
+                )
+                .unwrap();
             }
         }
     }
@@ -330,6 +363,16 @@ impl epi::TextureAllocator for WebGlPainter {
         self.alloc_user_texture(size, srgba_pixels)
     }
 
+    fn update_srgba_premultiplied(
+        &mut self,
+        id: egui::TextureId,
+        size: (usize, usize),
+        srgba_pixels: &[egui::Color32],
+    ) -> egui::TextureId {
+        self.update_user_texture(id, size, srgba_pixels);
+        id
+    }
+
     fn free(&mut self, id: egui::TextureId) {
         self.free_user_texture(id)
     }
@@ -531,3 +574,14 @@ fn link_program<'a, T: IntoIterator<Item = &'a WebGlShader>>(
             .unwrap_or_else(|| "Unknown error creating program object".into()))
     }
 }
+
+fn to_rgba_bytes(srgba_pixels: &[Color32]) -> Vec<u8> {
+    let mut pixels: Vec<u8> = Vec::with_capacity(srgba_pixels.len() * 4);
+    for srgba in srgba_pixels {
+        pixels.push(srgba.r());
+        pixels.push(srgba.g());
+        pixels.push(srgba.b());
+        pixels.push(srgba.a());
+    }
+    pixels
+}
@@ -96,7 +96,9 @@ mod pos2;
 mod rect;
 mod rect_transform;
 mod rot2;
+mod rotated_rect;
 pub mod smart_aim;
+pub mod spline;
 mod vec2;
 
 pub use {
@@ -106,6 +108,7 @@ pub use {
     rect::*,
     rect_transform::*,
     rot2::*,
+    rotated_rect::*,
     vec2::*,
 };
 
@@ -138,6 +138,37 @@ impl Pos2 {
         (self - other).length_sq()
     }
 
+    /// The point on the line segment `[a, b]` that is closest to `self`.
+    ///
+    /// ```
+    /// # use emath::*;
+    /// let segment = [pos2(0.0, 0.0), pos2(10.0, 0.0)];
+    /// assert_eq!(pos2(3.0, 4.0).closest_point_on_segment(segment), pos2(3.0, 0.0));
+    /// assert_eq!(pos2(-3.0, 4.0).closest_point_on_segment(segment), pos2(0.0, 0.0));
+    /// ```
+    pub fn closest_point_on_segment(self, [a, b]: [Pos2; 2]) -> Pos2 {
+        let ab = b - a;
+        let length_sq = ab.length_sq();
+        if length_sq <= 0.0 {
+            return a;
+        }
+        let t = ((self - a).dot(ab) / length_sq).clamp(0.0, 1.0);
+        a + t * ab
+    }
+
+    /// Distance to the line segment `[a, b]`, e.g. for hit-testing a line a user can click.
+    ///
+    /// ```
+    /// # use emath::*;
+    /// let segment = [pos2(0.0, 0.0), pos2(10.0, 0.0)];
+    /// assert_eq!(pos2(3.0, 4.0).distance_to_segment(segment), 4.0);
+    /// assert_eq!(pos2(13.0, 4.0).distance_to_segment(segment), 5.0);
+    /// ```
+    #[inline]
+    pub fn distance_to_segment(self, segment: [Pos2; 2]) -> f32 {
+        self.distance(self.closest_point_on_segment(segment))
+    }
+
     #[inline(always)]
     pub fn floor(self) -> Self {
         pos2(self.x.floor(), self.y.floor())
@@ -164,6 +164,24 @@ impl Rect {
         Rect::from_min_size(self.min + amnt, self.size())
     }
 
+    /// Scale the size by this factor, keeping the center.
+    ///
+    /// ```
+    /// # use emath::*;
+    /// let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(4.0, 2.0));
+    /// assert_eq!(rect.scale_from_center(0.5), Rect::from_min_max(pos2(1.0, 0.5), pos2(3.0, 1.5)));
+    /// ```
+    #[must_use]
+    pub fn scale_from_center(self, scale_factor: f32) -> Self {
+        self.scale_from_center2(Vec2::splat(scale_factor))
+    }
+
+    /// Scale the width and height by these factors, keeping the center.
+    #[must_use]
+    pub fn scale_from_center2(self, scale_factor: Vec2) -> Self {
+        Rect::from_center_size(self.center(), self.size() * scale_factor)
+    }
+
     /// The intersection of two `Rect`, i.e. the area covered by both.
     #[must_use]
     pub fn intersect(self, other: Rect) -> Self {
@@ -233,6 +251,62 @@ impl Rect {
         }
     }
 
+    /// The smallest `Rect` containing all the given rectangles.
+    /// Returns [`Self::NOTHING`] if there are none.
+    ///
+    /// ```
+    /// # use emath::*;
+    /// let a = Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0));
+    /// let b = Rect::from_min_max(pos2(2.0, 0.5), pos2(3.0, 3.0));
+    /// assert_eq!(Rect::union_all(vec![a, b]), Rect::from_min_max(pos2(0.0, 0.0), pos2(3.0, 3.0)));
+    /// assert_eq!(Rect::union_all(vec![]), Rect::NOTHING);
+    /// ```
+    pub fn union_all(rects: impl IntoIterator<Item = Rect>) -> Rect {
+        rects.into_iter().fold(Rect::NOTHING, Rect::union)
+    }
+
+    /// The area covered by all the given rectangles.
+    /// Returns [`Self::EVERYTHING`] if there are none.
+    ///
+    /// The result is negative (see [`Self::is_negative`]) if they don't all overlap.
+    pub fn intersect_all(rects: impl IntoIterator<Item = Rect>) -> Rect {
+        rects.into_iter().fold(Rect::EVERYTHING, Rect::intersect)
+    }
+
+    /// The parts of `self` that are not covered by `other`, as at most four non-overlapping rectangles.
+    ///
+    /// Useful for e.g. only repainting the parts of a canvas that were uncovered.
+    ///
+    /// ```
+    /// # use emath::*;
+    /// let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(4.0, 4.0));
+    /// let hole = Rect::from_min_max(pos2(1.0, 1.0), pos2(2.0, 2.0));
+    /// let area: f32 = rect.difference(hole).map(|r| r.area()).sum();
+    /// assert_eq!(area, 16.0 - 1.0);
+    /// assert_eq!(rect.difference(rect).count(), 0);
+    /// ```
+    pub fn difference(self, other: Rect) -> impl Iterator<Item = Rect> {
+        let overlap = self.intersect(other);
+        let parts = if overlap.is_positive() {
+            vec![
+                // Full-width top and bottom, then the left and right of the overlap:
+                Rect::from_min_max(self.min, pos2(self.max.x, overlap.min.y)),
+                Rect::from_min_max(pos2(self.min.x, overlap.max.y), self.max),
+                Rect::from_min_max(
+                    pos2(self.min.x, overlap.min.y),
+                    pos2(overlap.min.x, overlap.max.y),
+                ),
+                Rect::from_min_max(
+                    pos2(overlap.max.x, overlap.min.y),
+                    pos2(self.max.x, overlap.max.y),
+                ),
+            ]
+        } else {
+            vec![self]
+        };
+        parts.into_iter().filter(|rect| rect.is_positive())
+    }
+
     #[inline(always)]
     pub fn center(&self) -> Pos2 {
         Pos2 {
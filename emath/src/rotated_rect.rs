@@ -0,0 +1,133 @@
+use crate::*;
+
+/// A rectangle that has been rotated around its center, i.e. an oriented bounding box.
+///
+/// Use this for hit-testing things you paint rotated, e.g. the handles of a transform gizmo
+/// or a label along a line.
+///
+/// ```
+/// # use emath::*;
+/// use std::f32::consts::TAU;
+/// let rect = Rect::from_center_size(pos2(0.0, 0.0), vec2(4.0, 2.0));
+/// let rotated = RotatedRect::new(rect, Rot2::from_angle(TAU / 4.0));
+/// assert!(rotated.contains(pos2(0.0, 1.5)));
+/// assert!(!rotated.contains(pos2(1.5, 0.0)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RotatedRect {
+    pub center: Pos2,
+    /// Half the width and height, before rotation.
+    pub half_size: Vec2,
+    /// Should be normalized (see [`Rot2::normalized`]).
+    pub rot: Rot2,
+}
+
+impl RotatedRect {
+    /// Rotate `rect` around its center.
+    pub fn new(rect: Rect, rot: Rot2) -> Self {
+        Self::from_center_size(rect.center(), rect.size(), rot)
+    }
+
+    pub fn from_center_size(center: Pos2, size: Vec2, rot: Rot2) -> Self {
+        Self {
+            center,
+            half_size: size / 2.0,
+            rot,
+        }
+    }
+
+    /// The width and height, before rotation.
+    pub fn size(&self) -> Vec2 {
+        2.0 * self.half_size
+    }
+
+    /// The local x and y axes (unit vectors, after rotation).
+    pub fn axes(&self) -> [Vec2; 2] {
+        [self.rot * Vec2::X, self.rot * Vec2::Y]
+    }
+
+    /// The corners in order: left top, right top, right bottom, left bottom (before rotation).
+    pub fn corners(&self) -> [Pos2; 4] {
+        let [x, y] = self.axes();
+        let x = self.half_size.x * x;
+        let y = self.half_size.y * y;
+        [
+            self.center - x - y,
+            self.center + x - y,
+            self.center + x + y,
+            self.center - x + y,
+        ]
+    }
+
+    /// The smallest axis-aligned [`Rect`] containing this.
+    pub fn bounding_rect(&self) -> Rect {
+        let mut rect = Rect::NOTHING;
+        for &corner in &self.corners() {
+            rect.extend_with(corner);
+        }
+        rect
+    }
+
+    /// Transform a point from screen space into the space of the rectangle,
+    /// where the rectangle spans `-half_size..=half_size`.
+    pub fn to_local(&self, p: Pos2) -> Vec2 {
+        self.rot.inverse() * (p - self.center)
+    }
+
+    pub fn contains(&self, p: Pos2) -> bool {
+        let local = self.to_local(p);
+        local.x.abs() <= self.half_size.x && local.y.abs() <= self.half_size.y
+    }
+
+    /// Do the two rectangles overlap? (using the separating axis theorem)
+    pub fn intersects(&self, other: &RotatedRect) -> bool {
+        let corners = self.corners();
+        let other_corners = other.corners();
+        let [a0, a1] = self.axes();
+        let [b0, b1] = other.axes();
+        [a0, a1, b0, b1].iter().all(|&axis| {
+            let (min, max) = project(&corners, axis);
+            let (other_min, other_max) = project(&other_corners, axis);
+            min <= other_max && other_min <= max
+        })
+    }
+
+    /// Does this overlap the axis-aligned `rect`?
+    pub fn intersects_rect(&self, rect: Rect) -> bool {
+        self.intersects(&RotatedRect::new(rect, Rot2::IDENTITY))
+    }
+}
+
+impl From<Rect> for RotatedRect {
+    fn from(rect: Rect) -> Self {
+        Self::new(rect, Rot2::IDENTITY)
+    }
+}
+
+/// The range covered by the points along the axis.
+fn project(points: &[Pos2; 4], axis: Vec2) -> (f32, f32) {
+    points
+        .iter()
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), p| {
+            let d = p.to_vec2().dot(axis);
+            (min.min(d), max.max(d))
+        })
+}
+
+#[test]
+fn test_rotated_rect() {
+    use std::f32::consts::TAU;
+    let rect = Rect::from_center_size(pos2(10.0, 10.0), vec2(4.0, 2.0));
+    let diamond = RotatedRect::new(rect, Rot2::from_angle(TAU / 8.0));
+
+    assert!(diamond.contains(pos2(10.0, 10.0)));
+    assert!(!diamond.contains(rect.left_top()));
+
+    let bounds = diamond.bounding_rect();
+    assert!((bounds.width() - bounds.height()).abs() < 1e-4);
+    assert!((bounds.width() - 6.0 / 2.0_f32.sqrt()).abs() < 1e-4);
+
+    assert!(diamond.intersects_rect(rect));
+    assert!(diamond.intersects_rect(Rect::from_center_size(pos2(11.5, 11.5), vec2(1.0, 1.0))));
+    assert!(!diamond.intersects_rect(Rect::from_center_size(pos2(8.0, 12.5), vec2(1.0, 1.0))));
+}
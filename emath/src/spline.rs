@@ -0,0 +1,231 @@
+//! Curves and smoothing: cubic splines through points, monotone interpolation of samples,
+//! and frame-rate independent exponential smoothing.
+//!
+//! Used for smoothed plot lines, animations and kinetic scrolling.
+
+use std::ops::{Add, Mul, Sub};
+
+use crate::Pos2;
+
+/// Evaluate a cubic Hermite curve going from `p0` (with tangent `m0`) at `t = 0`
+/// to `p1` (with tangent `m1`) at `t = 1`.
+pub fn hermite<T>(p0: T, m0: T, p1: T, m1: T, t: f32) -> T
+where
+    T: Copy + Add<T, Output = T> + Mul<f32, Output = T>,
+{
+    let t2 = t * t;
+    let t3 = t2 * t;
+    p0 * (2.0 * t3 - 3.0 * t2 + 1.0)
+        + m0 * (t3 - 2.0 * t2 + t)
+        + p1 * (-2.0 * t3 + 3.0 * t2)
+        + m1 * (t3 - t2)
+}
+
+/// Evaluate a (uniform) Catmull-Rom spline segment between `p1` (`t = 0`) and `p2` (`t = 1`).
+///
+/// `p0` and `p3` are the points before and after, and decide the tangents.
+/// A Catmull-Rom spline goes through all its control points, so it is a good way to draw a smooth line
+/// through data points.
+///
+/// ```
+/// # use emath::{spline::catmull_rom, vec2};
+/// let points = [vec2(0.0, 0.0), vec2(1.0, 1.0), vec2(2.0, 0.0), vec2(3.0, 1.0)];
+/// assert_eq!(catmull_rom(points, 0.0), points[1]);
+/// assert_eq!(catmull_rom(points, 1.0), points[2]);
+/// ```
+pub fn catmull_rom<T>([p0, p1, p2, p3]: [T; 4], t: f32) -> T
+where
+    T: Copy + Add<T, Output = T> + Sub<T, Output = T> + Mul<f32, Output = T>,
+{
+    let m1 = (p2 - p0) * 0.5;
+    let m2 = (p3 - p1) * 0.5;
+    hermite(p1, m1, p2, m2, t)
+}
+
+/// A smooth line through all the `points`, with `samples_per_segment` points between each pair of them.
+///
+/// Use the result with e.g. `Shape::line`. The first and last points are repeated
+/// to get the tangents at the ends.
+pub fn catmull_rom_points(points: &[Pos2], samples_per_segment: usize) -> Vec<Pos2> {
+    if points.len() < 3 || samples_per_segment <= 1 {
+        return points.to_vec();
+    }
+
+    let mut out = Vec::with_capacity((points.len() - 1) * samples_per_segment + 1);
+    for i in 0..points.len() - 1 {
+        let p0 = points[i.saturating_sub(1)].to_vec2();
+        let p1 = points[i].to_vec2();
+        let p2 = points[i + 1].to_vec2();
+        let p3 = points[(i + 2).min(points.len() - 1)].to_vec2();
+        for s in 0..samples_per_segment {
+            let t = s as f32 / samples_per_segment as f32;
+            out.push(Pos2::ZERO + catmull_rom([p0, p1, p2, p3], t));
+        }
+    }
+    out.push(*points.last().unwrap());
+    out
+}
+
+// ----------------------------------------------------------------------------
+
+/// Interpolates between samples `(x, y)` with a smooth curve that never overshoots
+/// (Fritsch-Carlson monotone cubic interpolation).
+///
+/// Where the samples are increasing, the curve is increasing, and it never goes
+/// above or below its neighboring samples. This makes it good for smoothing plot lines of data
+/// where overshoot would be a lie (e.g. a counter that can't go negative).
+///
+/// ```
+/// # use emath::spline::MonotoneCubic;
+/// let curve = MonotoneCubic::new(&[[0.0, 0.0], [1.0, 1.0], [2.0, 1.0], [3.0, 5.0]]);
+/// assert_eq!(curve.eval(1.0), 1.0);
+/// assert_eq!(curve.eval(1.5), 1.0); // flat between two equal samples
+/// assert_eq!(curve.eval(-10.0), 0.0); // clamped outside the samples
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MonotoneCubic {
+    /// `[x, y]`, sorted by `x`.
+    points: Vec<[f64; 2]>,
+    /// The tangent at each point.
+    tangents: Vec<f64>,
+}
+
+impl MonotoneCubic {
+    /// `points` must be sorted by `x`, with no duplicate `x`.
+    pub fn new(points: &[[f64; 2]]) -> Self {
+        crate::emath_assert!(points.windows(2).all(|w| w[0][0] < w[1][0]));
+        let n = points.len();
+        if n < 2 {
+            return Self {
+                points: points.to_vec(),
+                tangents: vec![0.0; n],
+            };
+        }
+
+        let slopes: Vec<f64> = points
+            .windows(2)
+            .map(|w| (w[1][1] - w[0][1]) / (w[1][0] - w[0][0]))
+            .collect();
+
+        let mut tangents = Vec::with_capacity(n);
+        tangents.push(slopes[0]);
+        for i in 1..n - 1 {
+            if slopes[i - 1] * slopes[i] <= 0.0 {
+                tangents.push(0.0); // local extremum (or flat)
+            } else {
+                tangents.push((slopes[i - 1] + slopes[i]) / 2.0);
+            }
+        }
+        tangents.push(slopes[n - 2]);
+
+        // Limit the tangents to prevent overshoot:
+        for (i, &slope) in slopes.iter().enumerate() {
+            if slope == 0.0 {
+                tangents[i] = 0.0;
+                tangents[i + 1] = 0.0;
+            } else {
+                let a = tangents[i] / slope;
+                let b = tangents[i + 1] / slope;
+                let h = a.hypot(b);
+                if h > 3.0 {
+                    tangents[i] = 3.0 / h * a * slope;
+                    tangents[i + 1] = 3.0 / h * b * slope;
+                }
+            }
+        }
+
+        Self {
+            points: points.to_vec(),
+            tangents,
+        }
+    }
+
+    /// The interpolated `y` at `x`. Clamps to the first and last sample outside their range.
+    pub fn eval(&self, x: f64) -> f64 {
+        let points = &self.points;
+        match points.len() {
+            0 => return 0.0,
+            1 => return points[0][1],
+            _ => {}
+        }
+        if x <= points[0][0] {
+            return points[0][1];
+        }
+        if x >= points[points.len() - 1][0] {
+            return points[points.len() - 1][1];
+        }
+
+        let i = match points.binary_search_by(|p| p[0].partial_cmp(&x).unwrap()) {
+            Ok(i) => return points[i][1],
+            Err(i) => i - 1,
+        };
+        let [x0, y0] = points[i];
+        let [x1, y1] = points[i + 1];
+        let h = x1 - x0;
+        let t = (x - x0) / h;
+        let t2 = t * t;
+        let t3 = t2 * t;
+        y0 * (2.0 * t3 - 3.0 * t2 + 1.0)
+            + self.tangents[i] * h * (t3 - 2.0 * t2 + t)
+            + y1 * (-2.0 * t3 + 3.0 * t2)
+            + self.tangents[i + 1] * h * (t3 - t2)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// How much to move towards a target this frame, so that half of the distance
+/// is covered every `half_life` seconds, independent of the frame rate.
+///
+/// `dt` is the time since last frame, e.g. `ui.input().unstable_dt` in egui.
+pub fn exponential_smoothing_factor(half_life: f32, dt: f32) -> f32 {
+    if half_life <= 0.0 {
+        1.0
+    } else {
+        1.0 - 0.5_f32.powf(dt / half_life)
+    }
+}
+
+/// Move `current` towards `target`, covering half the distance every `half_life` seconds.
+///
+/// Unlike `current + (target - current) * 0.1` this looks the same at any frame rate.
+///
+/// ```
+/// # use emath::spline::exponential_smooth;
+/// let mut value = 0.0_f32;
+/// value = exponential_smooth(value, 10.0, 0.5, 0.5);
+/// assert!((value - 5.0).abs() < 1e-5);
+/// ```
+pub fn exponential_smooth<T>(current: T, target: T, half_life: f32, dt: f32) -> T
+where
+    T: Copy + Add<T, Output = T> + Sub<T, Output = T> + Mul<f32, Output = T>,
+{
+    current + (target - current) * exponential_smoothing_factor(half_life, dt)
+}
+
+/// Decay `value` towards zero, halving it every `half_life` seconds, e.g. the velocity of a kinetic scroll.
+pub fn exponential_decay<T>(value: T, half_life: f32, dt: f32) -> T
+where
+    T: Mul<f32, Output = T>,
+{
+    value * (1.0 - exponential_smoothing_factor(half_life, dt))
+}
+
+#[test]
+fn test_monotone_cubic() {
+    let points = [[0.0, 0.0], [1.0, 0.1], [2.0, 5.0], [3.0, 5.0], [4.0, 4.0]];
+    let curve = MonotoneCubic::new(&points);
+    for &[x, y] in &points {
+        assert!((curve.eval(x) - y).abs() < 1e-6);
+    }
+    let mut last = curve.eval(0.0);
+    for i in 1..=30 {
+        let y = curve.eval(i as f64 * 0.1);
+        assert!(last <= y, "Should be increasing");
+        last = y;
+    }
+    for i in 20..=40 {
+        let y = curve.eval(i as f64 * 0.1);
+        assert!((4.0..=5.0).contains(&y), "Should not overshoot");
+    }
+}
@@ -162,6 +162,19 @@ impl Vec2 {
         self.x * self.x + self.y * self.y
     }
 
+    /// The dot product: `|a| * |b| * cos(angle between them)`.
+    #[inline(always)]
+    pub fn dot(self, other: Self) -> f32 {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Rotate the vector by this many radians (clockwise in egui coordinates).
+    #[must_use]
+    #[inline]
+    pub fn rotated(self, angle: f32) -> Self {
+        crate::Rot2::from_angle(angle) * self
+    }
+
     /// Measures the angle of the vector.
     ///
     /// ```
@@ -3,6 +3,8 @@
 //! If you want a compact color representation, use [`Color32`].
 //! If you want to manipulate RGBA colors use [`Rgba`].
 //! If you want to manipulate colors in a way closer to how humans think about colors, use [`HsvaGamma`].
+//! For CSS-style colors use [`Hsla`]. For perceptually uniform colors (gradients, palettes) use [`Oklab`] or [`Hsluva`].
+//! For HDR (values above one) in half-float render targets use [`Rgba16f`].
 
 /// This format is used for space-efficient color representation (32 bits).
 ///
@@ -158,6 +160,25 @@ impl Color32 {
         (self.r(), self.g(), self.b(), self.a())
     }
 
+    /// To `sRGBA` WITHOUT premultiplied alpha, i.e. the inverse of [`Self::from_rgba_unmultiplied`].
+    ///
+    /// Low alpha values lose precision in the colors, and an additive color (alpha = 0) becomes transparent black.
+    pub fn to_srgba_unmultiplied(&self) -> [u8; 4] {
+        match self.a() {
+            255 => self.to_array(),
+            0 => [0, 0, 0, 0],
+            a => {
+                let [r, g, b, _] = Rgba::from(*self).to_rgba_unmultiplied();
+                [
+                    gamma_u8_from_linear_f32(r),
+                    gamma_u8_from_linear_f32(g),
+                    gamma_u8_from_linear_f32(b),
+                    a,
+                ]
+            }
+        }
+    }
+
     /// Multiply with 0.5 to make color half as opaque.
     pub fn linear_multiply(self, factor: f32) -> Color32 {
         crate::epaint_assert!(0.0 <= factor && factor <= 1.0);
@@ -203,6 +224,12 @@ impl Rgba {
         Self([r, g, b, a])
     }
 
+    /// From linear `RGBA` WITHOUT premultiplied alpha.
+    #[inline(always)]
+    pub fn from_rgba_unmultiplied(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self([r * a, g * a, b * a, a])
+    }
+
     #[inline(always)]
     pub const fn from_rgb(r: f32, g: f32, b: f32) -> Self {
         Self([r, g, b, 1.0])
@@ -304,6 +331,17 @@ impl Rgba {
     pub fn to_tuple(&self) -> (f32, f32, f32, f32) {
         (self.r(), self.g(), self.b(), self.a())
     }
+
+    /// Linear RGBA WITHOUT premultiplied alpha.
+    /// An additive color (alpha = 0) keeps its rgb values.
+    pub fn to_rgba_unmultiplied(&self) -> [f32; 4] {
+        let a = self.a();
+        if a == 0.0 {
+            self.to_array()
+        } else {
+            [self.r() / a, self.g() / a, self.b() / a, a]
+        }
+    }
 }
 
 impl std::ops::Add for Rgba {
@@ -434,7 +472,11 @@ pub fn test_srgba_conversion() {
 }
 
 /// gamma [0, 1] -> linear [0, 1] (not clamped).
-/// Works for numbers outside this range (e.g. negative numbers).
+///
+/// This is the sRGB transfer function, applied to a single color channel (not alpha).
+/// "Gamma" values are what you find in CSS colors, images and [`Color32`],
+/// "linear" values are proportional to light intensity, and are what [`Rgba`] uses for blending.
+/// Works for numbers outside this range (e.g. negative numbers, and HDR values above one).
 pub fn linear_from_gamma(gamma: f32) -> f32 {
     if gamma < 0.0 {
         -linear_from_gamma(-gamma)
@@ -446,7 +488,9 @@ pub fn linear_from_gamma(gamma: f32) -> f32 {
 }
 
 /// linear [0, 1] -> gamma [0, 1] (not clamped).
-/// Works for numbers outside this range (e.g. negative numbers).
+///
+/// The inverse of [`linear_from_gamma`].
+/// Works for numbers outside this range (e.g. negative numbers, and HDR values above one).
 pub fn gamma_from_linear(linear: f32) -> f32 {
     if linear < 0.0 {
         -gamma_from_linear(-linear)
@@ -747,6 +791,564 @@ impl From<Hsva> for HsvaGamma {
 
 // ----------------------------------------------------------------------------
 
+/// Hue, saturation, lightness, alpha. All in the range [0, 1].
+/// No premultiplied alpha.
+///
+/// This is HSL as used by CSS, i.e. computed from the gamma-space (sRGB) values,
+/// so `Hsla::new(h, s, 0.5, 1.0)` is the same color as `hsl(h * 360, s * 100%, 50%)`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Hsla {
+    /// hue 0-1
+    pub h: f32,
+    /// saturation 0-1
+    pub s: f32,
+    /// lightness 0-1, where 0 is black, 0.5 is the pure hue and 1 is white
+    pub l: f32,
+    /// alpha 0-1
+    pub a: f32,
+}
+
+impl Hsla {
+    pub fn new(h: f32, s: f32, l: f32, a: f32) -> Self {
+        Self { h, s, l, a }
+    }
+}
+
+impl From<Hsla> for Rgba {
+    fn from(hsla: Hsla) -> Rgba {
+        let [r, g, b] = rgb_from_hsl((hsla.h, hsla.s, hsla.l));
+        Rgba::from_rgba_unmultiplied(
+            linear_from_gamma(r),
+            linear_from_gamma(g),
+            linear_from_gamma(b),
+            hsla.a,
+        )
+    }
+}
+
+impl From<Rgba> for Hsla {
+    fn from(rgba: Rgba) -> Hsla {
+        let [r, g, b, a] = rgba.to_rgba_unmultiplied();
+        let (h, s, l) = hsl_from_rgb([
+            gamma_from_linear(r),
+            gamma_from_linear(g),
+            gamma_from_linear(b),
+        ]);
+        Hsla { h, s, l, a }
+    }
+}
+
+impl From<Hsla> for Color32 {
+    fn from(hsla: Hsla) -> Color32 {
+        Rgba::from(hsla).into()
+    }
+}
+
+impl From<Color32> for Hsla {
+    fn from(srgba: Color32) -> Hsla {
+        Rgba::from(srgba).into()
+    }
+}
+
+/// All ranges in 0-1. Works the same on linear or gamma `rgb`
+/// (but CSS HSL is defined on gamma-space values).
+pub fn hsl_from_rgb(rgb: [f32; 3]) -> (f32, f32, f32) {
+    let (h, _, _) = hsv_from_rgb(rgb);
+    let [r, g, b] = rgb;
+    let min = r.min(g.min(b));
+    let max = r.max(g.max(b));
+    let l = (max + min) / 2.0;
+    let s = if max == min {
+        0.0
+    } else {
+        (max - min) / (1.0 - (2.0 * l - 1.0).abs())
+    };
+    (h, s, l)
+}
+
+/// All ranges in 0-1. The inverse of [`hsl_from_rgb`].
+pub fn rgb_from_hsl((h, s, l): (f32, f32, f32)) -> [f32; 3] {
+    let v = l + s * l.min(1.0 - l);
+    let s_v = if v == 0.0 { 0.0 } else { 2.0 * (1.0 - l / v) };
+    rgb_from_hsv((h, s_v, v))
+}
+
+#[test]
+fn test_hsl_roundtrip() {
+    for &srgb in &[
+        [0, 0, 0],
+        [255, 255, 255],
+        [255, 0, 0],
+        [12, 200, 99],
+        [128, 128, 128],
+        [240, 20, 250],
+    ] {
+        let [r, g, b] = srgb;
+        let color = Color32::from_rgb(r, g, b);
+        assert_eq!(Color32::from(Hsla::from(color)), color);
+    }
+    let red = Hsla::new(0.0, 1.0, 0.5, 1.0);
+    assert_eq!(Color32::from(red), Color32::RED);
+}
+
+// ----------------------------------------------------------------------------
+
+/// The Oklab perceptual color space, with alpha. No premultiplied alpha.
+///
+/// Equal distances in Oklab look like roughly equal differences in color,
+/// which makes it good for gradients, color ramps in plots, and for picking colors
+/// with the same perceived lightness: <https://bottosson.github.io/posts/oklab/>.
+///
+/// ```
+/// # use epaint::{color::Oklab, Color32};
+/// let gradient_middle = Oklab::from(Color32::RED).lerp(Oklab::from(Color32::BLUE), 0.5);
+/// let color = Color32::from(gradient_middle);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Oklab {
+    /// Perceived lightness, 0 for black and 1 for white.
+    pub l: f32,
+    /// How green (negative) or red (positive) the color is.
+    pub a: f32,
+    /// How blue (negative) or yellow (positive) the color is.
+    pub b: f32,
+    /// 0-1
+    pub alpha: f32,
+}
+
+impl Oklab {
+    /// Interpolate in Oklab space, which gives gradients without the muddy middle you get in sRGB.
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        let lerp = |a: f32, b: f32| a + (b - a) * t;
+        Self {
+            l: lerp(self.l, other.l),
+            a: lerp(self.a, other.a),
+            b: lerp(self.b, other.b),
+            alpha: lerp(self.alpha, other.alpha),
+        }
+    }
+
+    /// Chroma (how colorful the color is) and hue (in radians), i.e. Oklch.
+    pub fn chroma_hue(&self) -> (f32, f32) {
+        (self.a.hypot(self.b), self.b.atan2(self.a))
+    }
+}
+
+impl From<Oklab> for Rgba {
+    fn from(oklab: Oklab) -> Rgba {
+        let [r, g, b] = linear_rgb_from_oklab([oklab.l, oklab.a, oklab.b]);
+        Rgba::from_rgba_unmultiplied(r, g, b, oklab.alpha)
+    }
+}
+
+impl From<Rgba> for Oklab {
+    fn from(rgba: Rgba) -> Oklab {
+        let [r, g, b, alpha] = rgba.to_rgba_unmultiplied();
+        let [l, a, b] = oklab_from_linear_rgb([r, g, b]);
+        Oklab { l, a, b, alpha }
+    }
+}
+
+impl From<Oklab> for Color32 {
+    fn from(oklab: Oklab) -> Color32 {
+        Rgba::from(oklab).into()
+    }
+}
+
+impl From<Color32> for Oklab {
+    fn from(srgba: Color32) -> Oklab {
+        Rgba::from(srgba).into()
+    }
+}
+
+/// Linear sRGB -> Oklab `[L, a, b]`.
+pub fn oklab_from_linear_rgb([r, g, b]: [f32; 3]) -> [f32; 3] {
+    let l = 0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b;
+    let m = 0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b;
+    let s = 0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b;
+
+    let (l, m, s) = (l.cbrt(), m.cbrt(), s.cbrt());
+
+    [
+        0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+        1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+        0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+    ]
+}
+
+/// Oklab `[L, a, b]` -> linear sRGB. Colors outside the sRGB gamut give values outside [0, 1].
+pub fn linear_rgb_from_oklab([l, a, b]: [f32; 3]) -> [f32; 3] {
+    let l_ = l + 0.396_337_78 * a + 0.215_803_76 * b;
+    let m_ = l - 0.105_561_346 * a - 0.063_854_17 * b;
+    let s_ = l - 0.089_484_18 * a - 1.291_485_5 * b;
+
+    let (l, m, s) = (l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_);
+
+    [
+        4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+        -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+        -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+    ]
+}
+
+#[test]
+fn test_oklab() {
+    let white = Oklab::from(Color32::WHITE);
+    assert!((white.l - 1.0).abs() < 1e-3);
+    assert!(white.a.abs() < 1e-3 && white.b.abs() < 1e-3);
+
+    for &color in &[
+        Color32::RED,
+        Color32::GOLD,
+        Color32::LIGHT_BLUE,
+        Color32::GRAY,
+    ] {
+        assert_eq!(Color32::from(Oklab::from(color)), color);
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// `HSLuv`: like [`Hsla`], but perceptually uniform, so all colors with the same `l` look equally light.
+///
+/// Good for generating palettes (e.g. for plot lines) where no color stands out more than the others.
+/// All values are in the range [0, 1]. No premultiplied alpha. See <https://www.hsluv.org/>.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Hsluva {
+    /// hue 0-1
+    pub h: f32,
+    /// saturation 0-1
+    pub s: f32,
+    /// lightness 0-1
+    pub l: f32,
+    /// alpha 0-1
+    pub a: f32,
+}
+
+impl Hsluva {
+    pub fn new(h: f32, s: f32, l: f32, a: f32) -> Self {
+        Self { h, s, l, a }
+    }
+}
+
+impl From<Hsluva> for Rgba {
+    fn from(hsluva: Hsluva) -> Rgba {
+        let [r, g, b] = hsluv::linear_rgb_from_hsluv([
+            hsluva.h as f64 * 360.0,
+            hsluva.s as f64 * 100.0,
+            hsluva.l as f64 * 100.0,
+        ]);
+        Rgba::from_rgba_unmultiplied(r as f32, g as f32, b as f32, hsluva.a)
+    }
+}
+
+impl From<Rgba> for Hsluva {
+    fn from(rgba: Rgba) -> Hsluva {
+        let [r, g, b, a] = rgba.to_rgba_unmultiplied();
+        let [h, s, l] = hsluv::hsluv_from_linear_rgb([r as f64, g as f64, b as f64]);
+        Hsluva {
+            h: (h / 360.0) as f32,
+            s: (s / 100.0) as f32,
+            l: (l / 100.0) as f32,
+            a,
+        }
+    }
+}
+
+impl From<Hsluva> for Color32 {
+    fn from(hsluva: Hsluva) -> Color32 {
+        Rgba::from(hsluva).into()
+    }
+}
+
+impl From<Color32> for Hsluva {
+    fn from(srgba: Color32) -> Hsluva {
+        Rgba::from(srgba).into()
+    }
+}
+
+/// Port of the reference implementation at <https://github.com/hsluv/hsluv>.
+/// Hue in degrees, saturation and lightness in 0-100.
+mod hsluv {
+    const M: [[f64; 3]; 3] = [
+        [
+            3.240_969_941_904_521,
+            -1.537_383_177_570_093,
+            -0.498_610_760_293,
+        ],
+        [
+            -0.969_243_636_280_87,
+            1.875_967_501_507_72,
+            0.041_555_057_407_175,
+        ],
+        [
+            0.055_630_079_696_993,
+            -0.203_976_958_888_97,
+            1.056_971_514_242_878,
+        ],
+    ];
+    const M_INV: [[f64; 3]; 3] = [
+        [
+            0.412_390_799_265_95,
+            0.357_584_339_383_87,
+            0.180_480_788_401_83,
+        ],
+        [
+            0.212_639_005_871_51,
+            0.715_168_678_767_75,
+            0.072_192_315_360_733,
+        ],
+        [
+            0.019_330_818_715_591,
+            0.119_194_779_794_62,
+            0.950_532_152_249_66,
+        ],
+    ];
+    const REF_U: f64 = 0.197_830_006_642_83;
+    const REF_V: f64 = 0.468_319_994_938_79;
+    const KAPPA: f64 = 903.296_296_296_296_3;
+    const EPSILON: f64 = 0.008_856_451_679_035_631;
+
+    pub fn hsluv_from_linear_rgb(rgb: [f64; 3]) -> [f64; 3] {
+        let [l, u, v] = luv_from_xyz(mul(&M_INV, rgb));
+        let c = u.hypot(v);
+        let h = if c < 1e-8 {
+            0.0
+        } else {
+            v.atan2(u).to_degrees().rem_euclid(360.0)
+        };
+        let s = if l > 99.999_999_9 || l < 1e-8 {
+            0.0
+        } else {
+            (c / max_chroma_for_lh(l, h) * 100.0).min(100.0)
+        };
+        [h, s, l]
+    }
+
+    pub fn linear_rgb_from_hsluv([h, s, l]: [f64; 3]) -> [f64; 3] {
+        let c = if l > 99.999_999_9 || l < 1e-8 {
+            0.0
+        } else {
+            max_chroma_for_lh(l, h) / 100.0 * s
+        };
+        let h = h.to_radians();
+        mul(&M, xyz_from_luv([l, h.cos() * c, h.sin() * c]))
+    }
+
+    fn mul(m: &[[f64; 3]; 3], v: [f64; 3]) -> [f64; 3] {
+        let dot = |row: &[f64; 3]| row[0] * v[0] + row[1] * v[1] + row[2] * v[2];
+        [dot(&m[0]), dot(&m[1]), dot(&m[2])]
+    }
+
+    fn luv_from_xyz([x, y, z]: [f64; 3]) -> [f64; 3] {
+        let l = if y <= EPSILON {
+            y * KAPPA
+        } else {
+            116.0 * y.cbrt() - 16.0
+        };
+        let divider = x + 15.0 * y + 3.0 * z;
+        if l == 0.0 || divider == 0.0 {
+            return [l, 0.0, 0.0];
+        }
+        let var_u = 4.0 * x / divider;
+        let var_v = 9.0 * y / divider;
+        [l, 13.0 * l * (var_u - REF_U), 13.0 * l * (var_v - REF_V)]
+    }
+
+    fn xyz_from_luv([l, u, v]: [f64; 3]) -> [f64; 3] {
+        if l == 0.0 {
+            return [0.0, 0.0, 0.0];
+        }
+        let var_u = u / (13.0 * l) + REF_U;
+        let var_v = v / (13.0 * l) + REF_V;
+        let y = if l <= 8.0 {
+            l / KAPPA
+        } else {
+            ((l + 16.0) / 116.0).powi(3)
+        };
+        let x = -(9.0 * y * var_u) / ((var_u - 4.0) * var_v - var_u * var_v);
+        let z = (9.0 * y - 15.0 * var_v * y - var_v * x) / (3.0 * var_v);
+        [x, y, z]
+    }
+
+    /// The largest chroma in the sRGB gamut for this lightness and hue (in degrees).
+    fn max_chroma_for_lh(l: f64, h: f64) -> f64 {
+        let h = h.to_radians();
+        let sub1 = (l + 16.0).powi(3) / 1_560_896.0;
+        let sub2 = if sub1 > EPSILON { sub1 } else { l / KAPPA };
+
+        let mut min_length = f64::INFINITY;
+        for [m1, m2, m3] in M.iter().copied() {
+            for t in 0..2 {
+                let t = t as f64;
+                let top1 = (284_517.0 * m1 - 94_839.0 * m3) * sub2;
+                let top2 = (838_422.0 * m3 + 769_860.0 * m2 + 731_718.0 * m1) * l * sub2
+                    - 769_860.0 * t * l;
+                let bottom = (632_260.0 * m3 - 126_452.0 * m2) * sub2 + 126_452.0 * t;
+
+                let (slope, intercept) = (top1 / bottom, top2 / bottom);
+                let length = intercept / (h.sin() - slope * h.cos());
+                if length >= 0.0 {
+                    min_length = min_length.min(length);
+                }
+            }
+        }
+        min_length
+    }
+}
+
+#[test]
+fn test_hsluv_roundtrip() {
+    for &color in &[
+        Color32::RED,
+        Color32::GOLD,
+        Color32::LIGHT_BLUE,
+        Color32::GRAY,
+    ] {
+        assert_eq!(Color32::from(Hsluva::from(color)), color);
+    }
+    let white = Hsluva::from(Color32::WHITE);
+    assert!((white.l - 1.0).abs() < 1e-3);
+}
+
+// ----------------------------------------------------------------------------
+
+/// Linear space `RGBA` with premultiplied alpha, stored as half-precision floats (8 bytes).
+///
+/// This is the pixel format of `RGBA16F` float render targets.
+/// Unlike [`Color32`] it can hold values above one (HDR), e.g. for bloom or extended range displays.
+/// [`Rgba`] can also hold such values, but is twice as large.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+pub struct Rgba16f(pub [u16; 4]);
+
+impl Rgba16f {
+    /// The half-float bits of premultiplied RGBA, ready to upload.
+    #[inline(always)]
+    pub fn to_bits(&self) -> [u16; 4] {
+        self.0
+    }
+}
+
+impl From<Rgba> for Rgba16f {
+    fn from(rgba: Rgba) -> Rgba16f {
+        let [r, g, b, a] = rgba.to_array();
+        Rgba16f([
+            f16_bits_from_f32(r),
+            f16_bits_from_f32(g),
+            f16_bits_from_f32(b),
+            f16_bits_from_f32(a),
+        ])
+    }
+}
+
+impl From<Rgba16f> for Rgba {
+    fn from(rgba: Rgba16f) -> Rgba {
+        let [r, g, b, a] = rgba.0;
+        Rgba::from_rgba_premultiplied(
+            f32_from_f16_bits(r),
+            f32_from_f16_bits(g),
+            f32_from_f16_bits(b),
+            f32_from_f16_bits(a),
+        )
+    }
+}
+
+/// `f32` -> IEEE 754 half-precision float bits, rounding to nearest even.
+/// Values too large for a half float become infinity.
+pub fn f16_bits_from_f32(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exponent = ((bits >> 23) & 0xff) as i32;
+    let mantissa = bits & 0x007f_ffff;
+
+    if exponent == 0xff {
+        // Infinity or NaN:
+        let nan_bit = if mantissa == 0 { 0 } else { 0x0200 };
+        return sign | 0x7c00 | nan_bit;
+    }
+
+    let exponent = exponent - 127 + 15;
+    if exponent >= 0x1f {
+        return sign | 0x7c00; // Too large: infinity
+    }
+
+    // `round_bit` is the highest bit we throw away.
+    // Round up if it is set, unless we are exactly half way and the result is even.
+    let round = |value: u32, round_bit: u32| -> u32 {
+        let truncated = value / (2 * round_bit);
+        if value & round_bit != 0 && value & (3 * round_bit - 1) != 0 {
+            truncated + 1
+        } else {
+            truncated
+        }
+    };
+
+    if exponent <= 0 {
+        // Subnormal half float (or zero)
+        if exponent < -10 {
+            return sign;
+        }
+        let mantissa = mantissa | 0x0080_0000;
+        let shift = (14 - exponent) as u32;
+        sign | round(mantissa, 1 << (shift - 1)) as u16
+    } else {
+        // The rounding may carry into the exponent, which is what we want.
+        let value = ((exponent as u32) << 23) | mantissa;
+        sign | round(value, 1 << 12) as u16
+    }
+}
+
+/// IEEE 754 half-precision float bits -> `f32` (exact).
+pub fn f32_from_f16_bits(half: u16) -> f32 {
+    let sign = ((half & 0x8000) as u32) << 16;
+    let exponent = ((half >> 10) & 0x1f) as u32;
+    let mantissa = (half & 0x03ff) as u32;
+
+    let bits = if exponent == 0 {
+        if mantissa == 0 {
+            sign
+        } else {
+            // Subnormal: normalize it
+            let mut exponent = 127 - 15 + 1;
+            let mut mantissa = mantissa;
+            while mantissa & 0x0400 == 0 {
+                mantissa <<= 1;
+                exponent -= 1;
+            }
+            sign | (exponent << 23) | ((mantissa & 0x03ff) << 13)
+        }
+    } else if exponent == 0x1f {
+        sign | 0x7f80_0000 | (mantissa << 13)
+    } else {
+        sign | ((exponent + 127 - 15) << 23) | (mantissa << 13)
+    };
+    f32::from_bits(bits)
+}
+
+#[test]
+fn test_f16_roundtrip() {
+    for &value in &[
+        0.0,
+        -0.0,
+        1.0,
+        -2.5,
+        0.5,
+        65504.0,
+        5.960_464_5e-8,
+        6.103_515_6e-5,
+        100.125,
+    ] {
+        let half = f16_bits_from_f32(value);
+        assert_eq!(f32_from_f16_bits(half), value, "{}", value);
+    }
+    assert_eq!(f16_bits_from_f32(1.0), 0x3c00);
+    assert_eq!(f16_bits_from_f32(1e6), 0x7c00);
+    assert!(f32_from_f16_bits(f16_bits_from_f32(f32::NAN)).is_nan());
+}
+
+// ----------------------------------------------------------------------------
+
 /// Cheap and ugly.
 /// Made for graying out disabled `Ui`:s.
 pub fn tint_color_towards(color: Color32, target: Color32) -> Color32 {
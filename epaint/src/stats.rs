@@ -148,6 +148,11 @@ pub struct PaintStats {
     pub clipped_meshes: AllocInfo,
     pub vertices: AllocInfo,
     pub indices: AllocInfo,
+
+    /// Number of textures that need to be uploaded (i.e. 1 if the font texture changed, else 0).
+    ///
+    /// Only textures that egui knows about are counted, not user textures.
+    pub texture_uploads: usize,
 }
 
 impl PaintStats {
@@ -318,7 +318,10 @@ impl Fonts {
     /// Will line break at `\n`.
     ///
     /// Always returns at least one row.
-    pub fn layout_no_wrap(&self, text_style: TextStyle, text: String) -> Arc<Galley> {
+    ///
+    /// The `text` can be anything that derefs to a `str`, e.g. a `&str`, `String` or `Arc<str>`.
+    /// If the same text was laid out recently this does not allocate.
+    pub fn layout_no_wrap(&self, text_style: TextStyle, text: impl AsRef<str>) -> Arc<Galley> {
         self.layout_multiline(text_style, text, f32::INFINITY)
     }
 
@@ -328,14 +331,12 @@ impl Fonts {
     ///
     /// Most often you probably want `\n` to produce a new row,
     /// and so [`Self::layout_no_wrap`] may be a better choice.
-    pub fn layout_single_line(&self, text_style: TextStyle, text: String) -> Arc<Galley> {
+    pub fn layout_single_line(&self, text_style: TextStyle, text: impl AsRef<str>) -> Arc<Galley> {
         self.galley_cache.lock().layout(
             &self.fonts,
-            LayoutJob {
-                text_style,
-                text,
-                layout_params: LayoutParams::SingleLine,
-            },
+            text_style,
+            LayoutParams::SingleLine,
+            text.as_ref(),
         )
     }
 
@@ -345,7 +346,7 @@ impl Fonts {
     pub fn layout_multiline(
         &self,
         text_style: TextStyle,
-        text: String,
+        text: impl AsRef<str>,
         max_width_in_points: f32,
     ) -> Arc<Galley> {
         self.layout_multiline_with_indentation_and_max_width(
@@ -363,20 +364,18 @@ impl Fonts {
     pub fn layout_multiline_with_indentation_and_max_width(
         &self,
         text_style: TextStyle,
-        text: String,
+        text: impl AsRef<str>,
         first_row_indentation: f32,
         max_width_in_points: f32,
     ) -> Arc<Galley> {
         self.galley_cache.lock().layout(
             &self.fonts,
-            LayoutJob {
-                text_style,
-                text,
-                layout_params: LayoutParams::Multiline {
-                    first_row_indentation: first_row_indentation.into(),
-                    max_width_in_points: max_width_in_points.into(),
-                },
+            text_style,
+            LayoutParams::Multiline {
+                first_row_indentation: first_row_indentation.into(),
+                max_width_in_points: max_width_in_points.into(),
             },
+            text.as_ref(),
         )
     }
 
@@ -410,59 +409,70 @@ enum LayoutParams {
     },
 }
 
-#[derive(Clone, Eq, PartialEq, Hash)]
-struct LayoutJob {
-    text_style: TextStyle,
-    layout_params: LayoutParams,
-    text: String,
-}
-
 struct CachedGalley {
     /// When it was last used
     last_used: u32,
+    layout_params: LayoutParams,
     galley: Arc<Galley>,
 }
 
+/// The galleys are keyed on a hash of the text (and how to lay it out),
+/// so that looking up a galley doesn't require an owned copy of the text.
 #[derive(Default)]
 struct GalleyCache {
     /// Frame counter used to do garbage collection on the cache
     generation: u32,
-    cache: AHashMap<LayoutJob, CachedGalley>,
+    hash_builder: ahash::RandomState,
+    cache: AHashMap<u64, CachedGalley>,
 }
 
 impl GalleyCache {
-    fn layout(&mut self, fonts: &BTreeMap<TextStyle, Font>, job: LayoutJob) -> Arc<Galley> {
-        if let Some(cached) = self.cache.get_mut(&job) {
-            cached.last_used = self.generation;
-            cached.galley.clone()
-        } else {
-            let LayoutJob {
-                text_style,
-                layout_params,
-                text,
-            } = job.clone();
-            let font = &fonts[&text_style];
-            let galley = match layout_params {
-                LayoutParams::SingleLine => font.layout_single_line(text),
-                LayoutParams::Multiline {
-                    first_row_indentation,
-                    max_width_in_points,
-                } => font.layout_multiline_with_indentation_and_max_width(
-                    text,
-                    first_row_indentation.into_inner(),
-                    max_width_in_points.into_inner(),
-                ),
-            };
-            let galley = Arc::new(galley);
-            self.cache.insert(
-                job,
-                CachedGalley {
-                    last_used: self.generation,
-                    galley: galley.clone(),
-                },
-            );
-            galley
+    fn layout(
+        &mut self,
+        fonts: &BTreeMap<TextStyle, Font>,
+        text_style: TextStyle,
+        layout_params: LayoutParams,
+        text: &str,
+    ) -> Arc<Galley> {
+        use std::hash::BuildHasher as _;
+        let mut hasher = self.hash_builder.build_hasher();
+        (text_style, layout_params, text).hash(&mut hasher);
+        let hash = hasher.finish();
+
+        if let Some(cached) = self.cache.get_mut(&hash) {
+            // Guard against hash collisions:
+            if cached.layout_params == layout_params
+                && cached.galley.text_style == text_style
+                && cached.galley.text == text
+            {
+                cached.last_used = self.generation;
+                return cached.galley.clone();
+            }
         }
+
+        let font = &fonts[&text_style];
+        let text = text.to_owned();
+        let galley = match layout_params {
+            LayoutParams::SingleLine => font.layout_single_line(text),
+            LayoutParams::Multiline {
+                first_row_indentation,
+                max_width_in_points,
+            } => font.layout_multiline_with_indentation_and_max_width(
+                text,
+                first_row_indentation.into_inner(),
+                max_width_in_points.into_inner(),
+            ),
+        };
+        let galley = Arc::new(galley);
+        self.cache.insert(
+            hash,
+            CachedGalley {
+                last_used: self.generation,
+                layout_params,
+                galley: galley.clone(),
+            },
+        );
+        galley
     }
 
     pub fn num_galleys_in_cache(&self) -> usize {
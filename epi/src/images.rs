@@ -0,0 +1,339 @@
+//! Decode images (PNG, JPEG and GIF) into textures. Requires the `image` feature.
+//!
+//! The simplest way to show an image is [`UiImageExt::image_from_bytes`]:
+//!
+//! ``` no_run
+//! use epi::images::UiImageExt as _;
+//! # fn show_icon(ui: &mut egui::Ui, frame: &mut epi::Frame<'_>, png_bytes: &[u8]) {
+//! ui.image_from_bytes(frame, "icon", png_bytes);
+//! # }
+//! ```
+//!
+//! The textures are cached in [`egui::Memory`] under the `Id` you give,
+//! so the image is only decoded once.
+//! An image that was not shown during a frame is freed the next time any image is shown with [`UiImageExt`].
+//!
+//! Animated GIF and APNG images can be shown with [`UiImageExt::animated_image_from_bytes`],
+//! or loaded with [`load_animation`] and shown with an [`egui::AnimatedImage`].
+
+use egui::{AnimationFrame, Color32, Id, Response, TextureId, Ui, Vec2};
+
+/// A decoded image, with premultiplied alpha.
+#[derive(Clone)]
+pub struct DecodedImage {
+    /// width, height
+    pub size: (usize, usize),
+    /// Row-major, top to bottom.
+    pub pixels: Vec<Color32>,
+}
+
+/// Decode a PNG, JPEG or GIF image. For animated images this is the first frame.
+pub fn decode_image(bytes: &[u8]) -> Result<DecodedImage, String> {
+    let image = image::load_from_memory(bytes).map_err(|err| err.to_string())?;
+    Ok(from_rgba8(image.to_rgba8()))
+}
+
+fn from_rgba8(image_buffer: image::RgbaImage) -> DecodedImage {
+    let size = (
+        image_buffer.width() as usize,
+        image_buffer.height() as usize,
+    );
+    let pixels = image_buffer
+        .into_vec()
+        .chunks_exact(4)
+        .map(|p| Color32::from_rgba_unmultiplied(p[0], p[1], p[2], p[3]))
+        .collect();
+    DecodedImage { size, pixels }
+}
+
+/// Decode all the frames of an animated GIF or APNG, with how long to show each frame (in seconds).
+///
+/// Other images (including PNG:s that are not animated) are returned as a single frame.
+pub fn decode_animation(bytes: &[u8]) -> Result<Vec<(DecodedImage, f32)>, String> {
+    use image::AnimationDecoder as _;
+
+    let frames = match image::guess_format(bytes) {
+        Ok(image::ImageFormat::Gif) => image::codecs::gif::GifDecoder::new(bytes)
+            .and_then(|decoder| decoder.into_frames().collect_frames()),
+        Ok(image::ImageFormat::Png) => image::codecs::png::PngDecoder::new(bytes)
+            .and_then(|decoder| decoder.apng().into_frames().collect_frames()),
+        _ => return Ok(vec![(decode_image(bytes)?, 0.0)]),
+    };
+
+    match frames {
+        Ok(frames) if !frames.is_empty() => Ok(frames
+            .into_iter()
+            .map(|frame| {
+                let (numer, denom) = frame.delay().numer_denom_ms();
+                let delay = numer as f32 / denom.max(1) as f32 / 1000.0;
+                (from_rgba8(frame.into_buffer()), delay)
+            })
+            .collect()),
+        _ => Ok(vec![(decode_image(bytes)?, 0.0)]),
+    }
+}
+
+/// A texture that was loaded with [`load_texture`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LoadedTexture {
+    pub texture_id: TextureId,
+    /// Size of the image in pixels.
+    pub size: Vec2,
+}
+
+/// Decode the image and allocate a texture for it, unless it is already loaded under this `id`.
+///
+/// `bytes` is only called if the image is not already loaded,
+/// so it is fine to e.g. read the bytes from a file in it.
+///
+/// Errors are also cached, so a broken image is not decoded over and over again.
+/// The texture stays allocated until you call [`free_texture`], which also forgets about an error.
+pub fn load_texture<Bytes: AsRef<[u8]>>(
+    ctx: &egui::Context,
+    tex_allocator: &mut dyn crate::TextureAllocator,
+    id: Id,
+    bytes: impl FnOnce() -> Result<Bytes, String>,
+) -> Result<LoadedTexture, String> {
+    if let Some(loaded) = ctx
+        .memory()
+        .id_data_temp
+        .get::<Result<LoadedTexture, String>>(&id)
+    {
+        return loaded.clone();
+    }
+
+    let loaded = bytes().and_then(|bytes| {
+        let image = decode_image(bytes.as_ref())?;
+        let texture_id = tex_allocator.alloc_srgba_premultiplied(image.size, &image.pixels);
+        let size = Vec2::new(image.size.0 as f32, image.size.1 as f32);
+        Ok(LoadedTexture { texture_id, size })
+    });
+    ctx.memory().id_data_temp.insert(id, loaded.clone());
+    loaded
+}
+
+/// Free a texture loaded with [`load_texture`].
+pub fn free_texture(ctx: &egui::Context, tex_allocator: &mut dyn crate::TextureAllocator, id: Id) {
+    let loaded = ctx
+        .memory()
+        .id_data_temp
+        .get::<Result<LoadedTexture, String>>(&id)
+        .cloned();
+    if let Some(loaded) = loaded {
+        if let Ok(loaded) = loaded {
+            tex_allocator.free(loaded.texture_id);
+        }
+        ctx.memory().id_data_temp.remove(&id);
+    }
+}
+
+/// An animation that was loaded with [`load_animation`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoadedAnimation {
+    pub frames: Vec<AnimationFrame>,
+    /// Size of the image in pixels.
+    pub size: Vec2,
+}
+
+/// Like [`load_texture`], but decodes all the frames of an animated GIF or APNG.
+///
+/// The textures stay allocated until you call [`free_animation`].
+pub fn load_animation<Bytes: AsRef<[u8]>>(
+    ctx: &egui::Context,
+    tex_allocator: &mut dyn crate::TextureAllocator,
+    id: Id,
+    bytes: impl FnOnce() -> Result<Bytes, String>,
+) -> Result<LoadedAnimation, String> {
+    if let Some(loaded) = ctx
+        .memory()
+        .id_data_temp
+        .get::<Result<LoadedAnimation, String>>(&id)
+    {
+        return loaded.clone();
+    }
+
+    let loaded = bytes().and_then(|bytes| {
+        let decoded = decode_animation(bytes.as_ref())?;
+        let (first, _) = &decoded[0];
+        let size = Vec2::new(first.size.0 as f32, first.size.1 as f32);
+        let frames = decoded
+            .iter()
+            .map(|(image, delay)| AnimationFrame {
+                texture_id: tex_allocator.alloc_srgba_premultiplied(image.size, &image.pixels),
+                delay: *delay,
+            })
+            .collect();
+        Ok(LoadedAnimation { frames, size })
+    });
+    ctx.memory().id_data_temp.insert(id, loaded.clone());
+    loaded
+}
+
+/// Free the textures loaded with [`load_animation`].
+pub fn free_animation(
+    ctx: &egui::Context,
+    tex_allocator: &mut dyn crate::TextureAllocator,
+    id: Id,
+) {
+    let loaded = ctx
+        .memory()
+        .id_data_temp
+        .get::<Result<LoadedAnimation, String>>(&id)
+        .cloned();
+    if let Some(loaded) = loaded {
+        if let Ok(loaded) = loaded {
+            for frame in loaded.frames {
+                tex_allocator.free(frame.texture_id);
+            }
+        }
+        ctx.memory().id_data_temp.remove(&id);
+    }
+}
+
+/// Read the bytes of an image file, for use with [`load_texture`].
+#[cfg(not(target_arch = "wasm32"))]
+pub fn read_file(path: impl AsRef<std::path::Path>) -> Result<Vec<u8>, String> {
+    let path = path.as_ref();
+    std::fs::read(path).map_err(|err| format!("{}: {}", path.display(), err))
+}
+
+/// Show images with a single call. See the [module-level documentation](self).
+pub trait UiImageExt {
+    /// Show a PNG or JPEG image at its native size (in points).
+    ///
+    /// The image is decoded the first time and then cached under `id_source`.
+    /// If the image can't be decoded the error is shown instead.
+    fn image_from_bytes(
+        &mut self,
+        frame: &mut crate::Frame<'_>,
+        id_source: impl std::hash::Hash,
+        bytes: &[u8],
+    ) -> Response;
+
+    /// Show an animated GIF or APNG at its native size (in points), with play/pause and loop buttons.
+    ///
+    /// The frames are decoded the first time and then cached under `id_source`.
+    fn animated_image_from_bytes(
+        &mut self,
+        frame: &mut crate::Frame<'_>,
+        id_source: impl std::hash::Hash,
+        bytes: &[u8],
+    ) -> Response;
+
+    /// Show a PNG or JPEG file at its native size (in points).
+    ///
+    /// The file is only read the first time, and then cached under its path.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn image_from_path(
+        &mut self,
+        frame: &mut crate::Frame<'_>,
+        path: impl AsRef<std::path::Path>,
+    ) -> Response;
+}
+
+impl UiImageExt for Ui {
+    fn image_from_bytes(
+        &mut self,
+        frame: &mut crate::Frame<'_>,
+        id_source: impl std::hash::Hash,
+        bytes: &[u8],
+    ) -> Response {
+        let id = Id::new("epi::images").with(id_source);
+        free_images_not_shown(self.ctx(), frame.tex_allocator(), id, ShownKind::Texture);
+        let loaded = load_texture(self.ctx(), frame.tex_allocator(), id, || Ok(bytes));
+        show_loaded(self, loaded)
+    }
+
+    fn animated_image_from_bytes(
+        &mut self,
+        frame: &mut crate::Frame<'_>,
+        id_source: impl std::hash::Hash,
+        bytes: &[u8],
+    ) -> Response {
+        let id = Id::new("epi::images::animation").with(id_source);
+        free_images_not_shown(self.ctx(), frame.tex_allocator(), id, ShownKind::Animation);
+        match load_animation(self.ctx(), frame.tex_allocator(), id, || Ok(bytes)) {
+            Ok(loaded) => self.add(
+                egui::AnimatedImage::new(&loaded.frames, loaded.size)
+                    .id_source(id)
+                    .show_controls(true),
+            ),
+            Err(err) => self.colored_label(self.visuals().error_fg_color, err),
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn image_from_path(
+        &mut self,
+        frame: &mut crate::Frame<'_>,
+        path: impl AsRef<std::path::Path>,
+    ) -> Response {
+        let path = path.as_ref();
+        let id = Id::new("epi::images").with(path);
+        free_images_not_shown(self.ctx(), frame.tex_allocator(), id, ShownKind::Texture);
+        let loaded = load_texture(self.ctx(), frame.tex_allocator(), id, || read_file(path));
+        show_loaded(self, loaded)
+    }
+}
+
+fn show_loaded(ui: &mut Ui, loaded: Result<LoadedTexture, String>) -> Response {
+    match loaded {
+        Ok(loaded) => ui.image(loaded.texture_id, loaded.size),
+        Err(err) => ui.colored_label(ui.visuals().error_fg_color, err),
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum ShownKind {
+    Texture,
+    Animation,
+}
+
+/// The images shown with [`UiImageExt`], so they can be freed once they are no longer shown.
+#[derive(Clone, Default)]
+struct ShownImages {
+    /// The [`egui::InputState::time`] of the current frame, and of the frame before it.
+    frame_time: f64,
+    prev_frame_time: f64,
+    /// When each image was last shown.
+    last_shown: std::collections::HashMap<Id, (f64, ShownKind)>,
+}
+
+/// Remember that the image `id` is shown this frame,
+/// and free the images that were not shown during the previous frame.
+fn free_images_not_shown(
+    ctx: &egui::Context,
+    tex_allocator: &mut dyn crate::TextureAllocator,
+    id: Id,
+    kind: ShownKind,
+) {
+    let time = ctx.input().time;
+    let shown_id = Id::new("epi::images::shown");
+    let mut shown = ctx
+        .memory()
+        .id_data_temp
+        .get_or_default::<ShownImages>(shown_id)
+        .clone();
+    let mut not_shown = vec![];
+    if shown.frame_time != time {
+        shown.prev_frame_time = shown.frame_time;
+        shown.frame_time = time;
+        let prev_frame_time = shown.prev_frame_time;
+        shown.last_shown.retain(|&id, &mut (last_shown, kind)| {
+            let keep = last_shown >= prev_frame_time;
+            if !keep {
+                not_shown.push((id, kind));
+            }
+            keep
+        });
+    }
+    shown.last_shown.insert(id, (time, kind));
+    ctx.memory().id_data_temp.insert(shown_id, shown);
+
+    for (id, kind) in not_shown {
+        match kind {
+            ShownKind::Texture => free_texture(ctx, tex_allocator, id),
+            ShownKind::Animation => free_animation(ctx, tex_allocator, id),
+        }
+    }
+}
@@ -172,6 +172,18 @@ pub struct NativeOptions {
     /// The initial size of the native window in points (logical pixels).
     pub initial_window_size: Option<egui::Vec2>,
 
+    /// The initial position of the top left corner of the native window (including decorations),
+    /// in points (logical pixels) from the top left corner of the desktop.
+    ///
+    /// Like [`Self::initial_window_size`] this is ignored if the window position was persisted from a previous run.
+    pub initial_window_pos: Option<egui::Pos2>,
+
+    /// Start with the window maximized.
+    pub maximized: bool,
+
+    /// Start in borderless fullscreen on the current monitor.
+    pub fullscreen: bool,
+
     /// Should the app window be resizable?
     pub resizable: bool,
 
@@ -179,6 +191,21 @@ pub struct NativeOptions {
     /// You control the transparency with [`App::clear_color()`].
     /// You should avoid having a [`egui::CentralPanel`], or make sure its frame is also transparent.
     pub transparent: bool,
+
+    /// On desktop: resize the window every frame to fit the contents ([`egui::Context::used_size`]),
+    /// within [`Self::min_window_size`] and [`Self::max_window_size`].
+    ///
+    /// Good for small dialog-like utility apps. Put your contents in a [`egui::CentralPanel`]
+    /// or [`egui::Window`]:s, and don't use widgets that fill all available space.
+    ///
+    /// To only fit the window once in a while, use [`Frame::fit_window_to_contents`] instead.
+    pub auto_resize: bool,
+
+    /// The smallest size of the native window in points (logical pixels).
+    pub min_window_size: Option<egui::Vec2>,
+
+    /// The largest size of the native window in points (logical pixels).
+    pub max_window_size: Option<egui::Vec2>,
 }
 
 impl Default for NativeOptions {
@@ -189,8 +216,14 @@ impl Default for NativeOptions {
             drag_and_drop_support: true,
             icon_data: None,
             initial_window_size: None,
+            initial_window_pos: None,
+            maximized: false,
+            fullscreen: false,
             resizable: true,
             transparent: false,
+            auto_resize: false,
+            min_window_size: None,
+            max_window_size: None,
         }
     }
 }
@@ -241,11 +274,30 @@ impl<'a> Frame<'a> {
         self.0.output.window_size = Some(size);
     }
 
+    /// Resize the window to fit the contents ([`egui::Context::used_size`]) at the end of this frame.
+    ///
+    /// Use [`NativeOptions::auto_resize`] to do this every frame.
+    pub fn fit_window_to_contents(&mut self) {
+        self.0.output.fit_window_to_contents = true;
+    }
+
     /// If you need to request a repaint from another thread, clone this and send it to that other thread.
     pub fn repaint_signal(&self) -> std::sync::Arc<dyn RepaintSignal> {
         self.0.repaint_signal.clone()
     }
 
+    /// Run `work` on a new thread, waking up the ui whenever it reports progress.
+    ///
+    /// Show the returned task with [`egui::widgets::TaskProgress`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn spawn_task(
+        &self,
+        work: impl FnOnce(&egui::TaskReporter) + Send + 'static,
+    ) -> egui::Task {
+        let repaint_signal = self.repaint_signal();
+        egui::Task::spawn(move || repaint_signal.request_repaint(), work)
+    }
+
     /// Very simple Http fetch API.
     /// Calls the given callback when done.
     #[cfg(feature = "http")]
@@ -289,14 +341,30 @@ pub struct IntegrationInfo {
 pub trait TextureAllocator {
     /// Allocate a new user texture.
     ///
-    /// There is no way to change a texture.
-    /// Instead allocate a new texture and free the previous one with [`Self::free`].
+    /// To change the pixels later, use [`Self::update_srgba_premultiplied`].
     fn alloc_srgba_premultiplied(
         &mut self,
         size: (usize, usize),
         srgba_pixels: &[egui::Color32],
     ) -> egui::TextureId;
 
+    /// Replace the pixels of a texture allocated with [`Self::alloc_srgba_premultiplied`].
+    ///
+    /// Returns the [`egui::TextureId`] to use from now on.
+    /// The default implementation frees the old texture and allocates a new one (with a new id).
+    /// Integrations that can update the texture in place keep the id,
+    /// and don't reallocate it on the GPU if the size is unchanged.
+    /// This is what you want for video, see [`crate::video`].
+    fn update_srgba_premultiplied(
+        &mut self,
+        id: egui::TextureId,
+        size: (usize, usize),
+        srgba_pixels: &[egui::Color32],
+    ) -> egui::TextureId {
+        self.free(id);
+        self.alloc_srgba_premultiplied(size, srgba_pixels)
+    }
+
     /// Free the given texture.
     fn free(&mut self, id: egui::TextureId);
 }
@@ -358,6 +426,12 @@ pub const APP_KEY: &str = "app";
 
 // ----------------------------------------------------------------------------
 
+#[cfg(feature = "image")]
+pub mod images;
+
+pub mod tiled_image;
+pub mod video;
+
 #[cfg(feature = "http")]
 /// `epi` supports simple HTTP requests with [`Frame::http_fetch`].
 pub mod http {
@@ -467,5 +541,9 @@ pub mod backend {
 
         /// Set to some size to resize the outer window (e.g. glium window) to this size.
         pub window_size: Option<egui::Vec2>,
+
+        /// Set to `true` to resize the outer window to fit the egui contents.
+        /// Ignored if [`Self::window_size`] is set.
+        pub fit_window_to_contents: bool,
     }
 }
@@ -0,0 +1,261 @@
+//! Show images that are too large for a single texture, e.g. huge photos or scans.
+//!
+//! A [`TiledImage`] splits the image into tiles that each fit in a texture.
+//! Only the tiles that are visible are uploaded (and the rest are freed),
+//! and when the image is zoomed out a downscaled version (mip level) is used instead,
+//! so you don't upload a gigapixel image to show it in a small window.
+//!
+//! ``` no_run
+//! # fn ui(ui: &mut egui::Ui, frame: &mut epi::Frame<'_>, image: &mut epi::tiled_image::TiledImage, zoom: f32) {
+//! let size = zoom * image.size();
+//! egui::ScrollArea::auto_sized().show(ui, |ui| {
+//!     image.show(ui, frame.tex_allocator(), size);
+//! });
+//! # }
+//! ```
+
+use egui::{pos2, Color32, Rect, Response, Sense, TextureId, Ui, Vec2};
+
+/// The default [`TiledImage::tile_size`]. All GPUs we care about support textures at least this large.
+pub const DEFAULT_TILE_SIZE: usize = 2048;
+
+/// An image split into tiles, with lazily created mip levels. See the [module-level documentation](self).
+pub struct TiledImage {
+    tile_size: usize,
+    /// Level 0 is the full image, and each level after it is half the size of the previous one.
+    levels: Vec<MipLevel>,
+}
+
+struct MipLevel {
+    /// width, height
+    size: (usize, usize),
+    /// Premultiplied, row-major, top to bottom.
+    pixels: Vec<Color32>,
+    /// Indexed by `tile_y * num_tiles_x + tile_x`.
+    tiles: Vec<Option<TextureId>>,
+}
+
+impl TiledImage {
+    /// `pixels` are premultiplied, row-major, top to bottom.
+    pub fn new(size: (usize, usize), pixels: Vec<Color32>) -> Self {
+        assert_eq!(size.0 * size.1, pixels.len());
+        let mut image = Self {
+            tile_size: DEFAULT_TILE_SIZE,
+            levels: vec![],
+        };
+        image.levels.push(image.new_level(size, pixels));
+        image
+    }
+
+    /// Use [`crate::images::decode_image`] to get the pixels from a PNG or JPEG.
+    #[cfg(feature = "image")]
+    pub fn from_decoded(image: crate::images::DecodedImage) -> Self {
+        Self::new(image.size, image.pixels)
+    }
+
+    /// The largest width and height of each texture. Default: [`DEFAULT_TILE_SIZE`].
+    ///
+    /// Call this before the image is shown the first time.
+    pub fn tile_size(mut self, tile_size: usize) -> Self {
+        assert!(tile_size > 0);
+        self.tile_size = tile_size;
+        let levels = std::mem::take(&mut self.levels);
+        self.levels = levels
+            .into_iter()
+            .map(|level| self.new_level(level.size, level.pixels))
+            .collect();
+        self
+    }
+
+    /// Size of the full image in pixels.
+    pub fn size(&self) -> Vec2 {
+        let (width, height) = self.levels[0].size;
+        Vec2::new(width as f32, height as f32)
+    }
+
+    /// Show the image scaled to `size` (in points).
+    ///
+    /// Only the visible tiles are uploaded, using the mip level that best matches the zoom.
+    /// Tiles that are not visible are freed.
+    pub fn show(
+        &mut self,
+        ui: &mut Ui,
+        tex_allocator: &mut dyn crate::TextureAllocator,
+        size: Vec2,
+    ) -> Response {
+        let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+        let visible_rect = rect.intersect(ui.clip_rect());
+
+        let level_nr = if visible_rect.is_positive() {
+            let image_pixels_per_screen_pixel =
+                self.size().x / (size.x * ui.ctx().pixels_per_point());
+            let level_nr = image_pixels_per_screen_pixel.log2().floor().max(0.0) as usize;
+            Some(self.ensure_level(level_nr))
+        } else {
+            None
+        };
+
+        for (nr, level) in self.levels.iter_mut().enumerate() {
+            let visible = match level_nr {
+                Some(level_nr) if level_nr == nr => {
+                    level.visible_tiles(self.tile_size, rect, visible_rect)
+                }
+                _ => vec![],
+            };
+
+            for (tile_nr, tile) in level.tiles.iter_mut().enumerate() {
+                if !visible.contains(&tile_nr) {
+                    if let Some(texture_id) = tile.take() {
+                        tex_allocator.free(texture_id);
+                    }
+                }
+            }
+
+            for tile_nr in visible {
+                let tile_rect = level.tile_pixel_rect(self.tile_size, tile_nr);
+                let texture_id = match level.tiles[tile_nr] {
+                    Some(texture_id) => texture_id,
+                    None => {
+                        let pixels = level.tile_pixels(tile_rect);
+                        let tile_size = (tile_rect.width() as usize, tile_rect.height() as usize);
+                        let texture_id =
+                            tex_allocator.alloc_srgba_premultiplied(tile_size, &pixels);
+                        level.tiles[tile_nr] = Some(texture_id);
+                        texture_id
+                    }
+                };
+
+                let level_size = Vec2::new(level.size.0 as f32, level.size.1 as f32);
+                let screen_rect = Rect::from_min_max(
+                    rect.min + (tile_rect.min.to_vec2() / level_size) * rect.size(),
+                    rect.min + (tile_rect.max.to_vec2() / level_size) * rect.size(),
+                );
+                egui::Image::new(texture_id, screen_rect.size()).paint_at(ui, screen_rect);
+            }
+        }
+
+        response
+    }
+
+    /// Free all textures. They are uploaded again the next time the image is shown.
+    pub fn free(&mut self, tex_allocator: &mut dyn crate::TextureAllocator) {
+        for level in &mut self.levels {
+            for tile in &mut level.tiles {
+                if let Some(texture_id) = tile.take() {
+                    tex_allocator.free(texture_id);
+                }
+            }
+        }
+    }
+
+    fn new_level(&self, size: (usize, usize), pixels: Vec<Color32>) -> MipLevel {
+        let num_tiles = div_ceil(size.0, self.tile_size) * div_ceil(size.1, self.tile_size);
+        MipLevel {
+            size,
+            pixels,
+            tiles: vec![None; num_tiles],
+        }
+    }
+
+    /// Create mip levels up to `level_nr` (if needed) and return the level we can use,
+    /// which is lower if the image can't be made any smaller.
+    fn ensure_level(&mut self, level_nr: usize) -> usize {
+        while self.levels.len() <= level_nr {
+            let last = self.levels.last().unwrap();
+            if last.size.0 <= 1 && last.size.1 <= 1 {
+                return self.levels.len() - 1;
+            }
+            let (size, pixels) = downsample(last.size, &last.pixels);
+            let level = self.new_level(size, pixels);
+            self.levels.push(level);
+        }
+        level_nr
+    }
+}
+
+impl MipLevel {
+    fn num_tiles_x(&self, tile_size: usize) -> usize {
+        div_ceil(self.size.0, tile_size)
+    }
+
+    /// The tiles that overlap `visible_rect`, when the whole level is shown in `rect`.
+    fn visible_tiles(&self, tile_size: usize, rect: Rect, visible_rect: Rect) -> Vec<usize> {
+        if self.tiles.is_empty() {
+            return vec![];
+        }
+        let to_pixels = |x: f32, min: f32, width: f32, size: usize| {
+            ((x - min) / width * size as f32).max(0.0) as usize
+        };
+        let x_min = to_pixels(visible_rect.min.x, rect.min.x, rect.width(), self.size.0);
+        let x_max = to_pixels(visible_rect.max.x, rect.min.x, rect.width(), self.size.0);
+        let y_min = to_pixels(visible_rect.min.y, rect.min.y, rect.height(), self.size.1);
+        let y_max = to_pixels(visible_rect.max.y, rect.min.y, rect.height(), self.size.1);
+
+        let num_tiles_x = self.num_tiles_x(tile_size);
+        let num_tiles_y = div_ceil(self.size.1, tile_size);
+        let mut tiles = vec![];
+        for tile_y in (y_min / tile_size)..=(y_max / tile_size).min(num_tiles_y - 1) {
+            for tile_x in (x_min / tile_size)..=(x_max / tile_size).min(num_tiles_x - 1) {
+                tiles.push(tile_y * num_tiles_x + tile_x);
+            }
+        }
+        tiles
+    }
+
+    /// The pixels covered by the tile, in level pixel coordinates.
+    fn tile_pixel_rect(&self, tile_size: usize, tile_nr: usize) -> Rect {
+        let num_tiles_x = self.num_tiles_x(tile_size);
+        let x = (tile_nr % num_tiles_x) * tile_size;
+        let y = (tile_nr / num_tiles_x) * tile_size;
+        Rect::from_min_max(
+            pos2(x as f32, y as f32),
+            pos2(
+                (x + tile_size).min(self.size.0) as f32,
+                (y + tile_size).min(self.size.1) as f32,
+            ),
+        )
+    }
+
+    fn tile_pixels(&self, tile_rect: Rect) -> Vec<Color32> {
+        let (x_min, x_max) = (tile_rect.min.x as usize, tile_rect.max.x as usize);
+        let (y_min, y_max) = (tile_rect.min.y as usize, tile_rect.max.y as usize);
+        let mut pixels = Vec::with_capacity((x_max - x_min) * (y_max - y_min));
+        for y in y_min..y_max {
+            let row = y * self.size.0;
+            pixels.extend_from_slice(&self.pixels[row + x_min..row + x_max]);
+        }
+        pixels
+    }
+}
+
+/// Half the size (rounded up), averaging each 2x2 block of pixels.
+fn downsample(size: (usize, usize), pixels: &[Color32]) -> ((usize, usize), Vec<Color32>) {
+    let (width, height) = size;
+    let new_size = (div_ceil(width, 2), div_ceil(height, 2));
+    let mut new_pixels = Vec::with_capacity(new_size.0 * new_size.1);
+    for y in 0..new_size.1 {
+        for x in 0..new_size.0 {
+            let mut sum = [0_u32; 4];
+            for (dx, dy) in [(0, 0), (1, 0), (0, 1), (1, 1)].iter() {
+                let sx = (2 * x + dx).min(width - 1);
+                let sy = (2 * y + dy).min(height - 1);
+                let p = pixels[sy * width + sx];
+                sum[0] += p.r() as u32;
+                sum[1] += p.g() as u32;
+                sum[2] += p.b() as u32;
+                sum[3] += p.a() as u32;
+            }
+            new_pixels.push(Color32::from_rgba_premultiplied(
+                ((sum[0] + 2) / 4) as u8,
+                ((sum[1] + 2) / 4) as u8,
+                ((sum[2] + 2) / 4) as u8,
+                ((sum[3] + 2) / 4) as u8,
+            ));
+        }
+    }
+    (new_size, new_pixels)
+}
+
+fn div_ceil(a: usize, b: usize) -> usize {
+    (a + b - 1) / b
+}
@@ -0,0 +1,222 @@
+//! Show video (or any other image that changes every frame), e.g. from a camera.
+//!
+//! Give each new frame to a [`VideoTexture`], either as RGBA or as YUV planes,
+//! and show it with [`VideoTexture::show`]:
+//!
+//! ``` no_run
+//! # fn next_camera_frame() -> (usize, usize, Vec<u8>, Vec<u8>, Vec<u8>) { unimplemented!() }
+//! # fn ui(ui: &mut egui::Ui, frame: &mut epi::Frame<'_>, video: &mut epi::video::VideoTexture) {
+//! let (width, height, y, u, v) = next_camera_frame();
+//! let result = video.update(
+//!     frame.tex_allocator(),
+//!     epi::video::VideoFrame::Yuv420 {
+//!         size: (width, height),
+//!         y: &y,
+//!         u: &u,
+//!         v: &v,
+//!         y_stride: width,
+//!         uv_stride: width / 2,
+//!     },
+//! );
+//! if let Err(err) = result {
+//!     ui.label(err);
+//! }
+//! let available_size = ui.available_size();
+//! video.show(ui, available_size);
+//! # }
+//! ```
+//!
+//! The texture is allocated once and then updated in place for as long as the size stays the same.
+
+use egui::{Color32, Rect, Response, Sense, TextureId, Ui, Vec2};
+
+/// One frame of video.
+#[derive(Clone, Copy, Debug)]
+pub enum VideoFrame<'a> {
+    /// Premultiplied RGBA, row-major, top to bottom.
+    Rgba {
+        /// width, height
+        size: (usize, usize),
+        pixels: &'a [Color32],
+    },
+
+    /// Planar YUV 4:2:0 (I420), BT.601 limited range, which is what most cameras and video decoders give you.
+    ///
+    /// The `u` and `v` planes have half the resolution of the `y` plane (rounded up).
+    /// The strides are the number of bytes per row of each plane, which may be larger than the width.
+    Yuv420 {
+        /// width, height
+        size: (usize, usize),
+        y: &'a [u8],
+        u: &'a [u8],
+        v: &'a [u8],
+        y_stride: usize,
+        uv_stride: usize,
+    },
+}
+
+/// A texture for video that is updated every frame. See the [module-level documentation](self).
+#[derive(Default)]
+pub struct VideoTexture {
+    texture_id: Option<TextureId>,
+    size: (usize, usize),
+    /// Reused between frames to avoid allocations.
+    pixels: Vec<Color32>,
+}
+
+impl VideoTexture {
+    /// The texture, if any frame has been given yet.
+    pub fn texture_id(&self) -> Option<TextureId> {
+        self.texture_id
+    }
+
+    /// Size of the last frame in pixels.
+    pub fn size(&self) -> Vec2 {
+        Vec2::new(self.size.0 as f32, self.size.1 as f32)
+    }
+
+    /// Upload a new frame.
+    ///
+    /// # Errors
+    /// If the pixel data doesn't match the size (and strides) of the frame.
+    /// The frame is then skipped and the previous one is kept.
+    pub fn update(
+        &mut self,
+        tex_allocator: &mut dyn crate::TextureAllocator,
+        frame: VideoFrame<'_>,
+    ) -> Result<(), String> {
+        let (size, pixels) = match frame {
+            VideoFrame::Rgba { size, pixels } => {
+                if pixels.len() != size.0 * size.1 {
+                    return Err(format!(
+                        "Expected {}x{} pixels, got {}",
+                        size.0,
+                        size.1,
+                        pixels.len()
+                    ));
+                }
+                (size, pixels)
+            }
+            VideoFrame::Yuv420 {
+                size,
+                y,
+                u,
+                v,
+                y_stride,
+                uv_stride,
+            } => {
+                yuv420_to_rgba(size, y, u, v, y_stride, uv_stride, &mut self.pixels)?;
+                (size, &self.pixels[..])
+            }
+        };
+
+        self.texture_id = Some(match self.texture_id {
+            Some(texture_id) => tex_allocator.update_srgba_premultiplied(texture_id, size, pixels),
+            None => tex_allocator.alloc_srgba_premultiplied(size, pixels),
+        });
+        self.size = size;
+        Ok(())
+    }
+
+    /// Free the texture. The next [`Self::update`] will allocate a new one.
+    pub fn free(&mut self, tex_allocator: &mut dyn crate::TextureAllocator) {
+        if let Some(texture_id) = self.texture_id.take() {
+            tex_allocator.free(texture_id);
+        }
+    }
+
+    /// Fill `size` with black and show the video centered within it,
+    /// as large as possible while keeping its aspect ratio (letterboxing).
+    pub fn show(&self, ui: &mut Ui, size: Vec2) -> Response {
+        let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+        ui.painter().rect_filled(rect, 0.0, Color32::BLACK);
+        if let Some(texture_id) = self.texture_id {
+            let image_rect = letterbox(rect, self.size());
+            egui::Image::new(texture_id, image_rect.size()).paint_at(ui, image_rect);
+        }
+        response
+    }
+}
+
+/// The largest rectangle with the aspect ratio of `image_size` that fits centered within `rect`.
+pub fn letterbox(rect: Rect, image_size: Vec2) -> Rect {
+    if image_size.x <= 0.0 || image_size.y <= 0.0 {
+        return Rect::from_center_size(rect.center(), Vec2::ZERO);
+    }
+    let scale = (rect.width() / image_size.x).min(rect.height() / image_size.y);
+    Rect::from_center_size(rect.center(), scale * image_size)
+}
+
+/// Convert planar YUV 4:2:0 (BT.601, limited range) to RGBA, reusing the `rgba` buffer.
+///
+/// # Errors
+/// If a plane is too short for the size and strides. `rgba` is then left alone.
+pub fn yuv420_to_rgba(
+    size: (usize, usize),
+    y: &[u8],
+    u: &[u8],
+    v: &[u8],
+    y_stride: usize,
+    uv_stride: usize,
+    rgba: &mut Vec<Color32>,
+) -> Result<(), String> {
+    let (width, height) = size;
+    let uv_size = ((width + 1) / 2, (height + 1) / 2);
+    check_plane("y", y, size, y_stride)?;
+    check_plane("u", u, uv_size, uv_stride)?;
+    check_plane("v", v, uv_size, uv_stride)?;
+
+    rgba.clear();
+    rgba.reserve(width * height);
+    for row in 0..height {
+        let y_row = &y[row * y_stride..];
+        let u_row = &u[(row / 2) * uv_stride..];
+        let v_row = &v[(row / 2) * uv_stride..];
+        for col in 0..width {
+            let c = y_row[col] as i32 - 16;
+            let d = u_row[col / 2] as i32 - 128;
+            let e = v_row[col / 2] as i32 - 128;
+            let r = (298 * c + 409 * e + 128) >> 8;
+            let g = (298 * c - 100 * d - 208 * e + 128) >> 8;
+            let b = (298 * c + 516 * d + 128) >> 8;
+            rgba.push(Color32::from_rgb(
+                r.clamp(0, 255) as u8,
+                g.clamp(0, 255) as u8,
+                b.clamp(0, 255) as u8,
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Check that a plane of `size` (width, height) with `stride` bytes per row fits in `plane`.
+fn check_plane(
+    name: &str,
+    plane: &[u8],
+    size: (usize, usize),
+    stride: usize,
+) -> Result<(), String> {
+    let (width, height) = size;
+    if height == 0 || width == 0 {
+        return Ok(());
+    }
+    if stride < width {
+        return Err(format!(
+            "The {} stride ({}) is less than the width ({})",
+            name, stride, width
+        ));
+    }
+    let needed = (height - 1) * stride + width;
+    if plane.len() < needed {
+        return Err(format!(
+            "The {} plane has {} bytes, but {}x{} with stride {} needs {}",
+            name,
+            plane.len(),
+            width,
+            height,
+            stride,
+            needed
+        ));
+    }
+    Ok(())
+}